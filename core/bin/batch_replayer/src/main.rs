@@ -0,0 +1,190 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::PathBuf,
+};
+
+use anyhow::Context as _;
+use clap::{Parser, ValueEnum};
+use zksync_config::{
+    configs::{chain::NetworkConfig, DatabaseSecrets, ObservabilityConfig},
+    GenesisConfig,
+};
+use zksync_core_leftovers::temp_config_store::decode_yaml_repr;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_env_config::FromEnv;
+use zksync_types::{vm::FastVmMode, L1BatchNumber, L2ChainId};
+use zksync_vm_runner::impls::BatchReplayer;
+
+/// VM mode used to replay batches.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum VmMode {
+    /// Legacy VM.
+    Old,
+    /// Fast VM.
+    New,
+    /// Both VMs, comparing their outputs for each transaction. Divergences between VMs are reported as execution errors.
+    Shadow,
+}
+
+impl From<VmMode> for FastVmMode {
+    fn from(mode: VmMode) -> Self {
+        match mode {
+            VmMode::Old => Self::Old,
+            VmMode::New => Self::New,
+            VmMode::Shadow => Self::Shadow,
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+#[command(
+    author = "Matter Labs",
+    version,
+    about = "Re-executes historical L1 batches and compares results with sealed data",
+    long_about = "Re-executes historical L1 batches stored in Postgres and compares results with sealed data. \
+        Snapshots stored in an object store are not supported as a data source; to replay batches from a snapshot, \
+        recover a node from it and point the tool to the node database."
+)]
+struct Cli {
+    /// First L1 batch to replay. If not specified, replays from the first batch that can be replayed,
+    /// i.e. batch #1, or the first batch after the snapshot the node was recovered from.
+    #[arg(long)]
+    from_batch: Option<u32>,
+    /// Last L1 batch to replay (inclusive). If not specified, replays up to the latest sealed batch.
+    #[arg(long)]
+    to_batch: Option<u32>,
+    /// VM mode to replay batches with.
+    #[arg(long, value_enum, default_value_t = VmMode::New)]
+    vm_mode: VmMode,
+    /// Path to write the report to, one JSON object per replayed batch.
+    #[arg(long)]
+    output: PathBuf,
+    /// Stop on the first divergent batch.
+    #[arg(long)]
+    fail_fast: bool,
+    /// Path to yaml secrets config. If set, it will be used instead of env vars
+    #[arg(long)]
+    secrets_path: Option<PathBuf>,
+    /// Path to yaml genesis config. If set, it will be used instead of env vars
+    #[arg(long)]
+    genesis_path: Option<PathBuf>,
+}
+
+impl Cli {
+    async fn run(self, pool: ConnectionPool<Core>, l2_chain_id: L2ChainId) -> anyhow::Result<()> {
+        let mut conn = pool.connection().await?;
+        let first_batch = match self.from_batch {
+            Some(number) => L1BatchNumber(number),
+            None => {
+                let snapshot_recovery = conn
+                    .snapshot_recovery_dal()
+                    .get_applied_snapshot_status()
+                    .await?;
+                snapshot_recovery.map_or(L1BatchNumber(1), |recovery| recovery.l1_batch_number + 1)
+            }
+        };
+        let last_batch = match self.to_batch {
+            Some(number) => L1BatchNumber(number),
+            None => conn
+                .blocks_dal()
+                .get_sealed_l1_batch_number()
+                .await?
+                .context("no L1 batches in Postgres")?,
+        };
+        drop(conn);
+        anyhow::ensure!(
+            first_batch <= last_batch,
+            "invalid batch range: L1 batch #{first_batch} is after #{last_batch}"
+        );
+
+        let file = File::create(&self.output)
+            .with_context(|| format!("cannot create report file `{}`", self.output.display()))?;
+        let mut output = BufWriter::new(file);
+
+        let vm_mode = FastVmMode::from(self.vm_mode);
+        let mut replayer = BatchReplayer::new(pool, vm_mode, l2_chain_id).await?;
+        tracing::info!(
+            "Replaying L1 batches #{first_batch}..=#{last_batch} with {vm_mode:?} VM mode"
+        );
+
+        let mut replayed_count = 0;
+        let mut diverged_batches = vec![];
+        for number in first_batch.0..=last_batch.0 {
+            let l1_batch_number = L1BatchNumber(number);
+            let report = replayer
+                .replay_batch(l1_batch_number)
+                .await
+                .with_context(|| format!("failed replaying L1 batch #{l1_batch_number}"))?
+                .with_context(|| {
+                    format!("L1 batch #{l1_batch_number} is not sealed or cannot be replayed")
+                })?;
+            serde_json::to_writer(&mut output, &report)?;
+            writeln!(output)?;
+            output.flush()?;
+
+            replayed_count += 1;
+            if report.is_diverged() {
+                tracing::warn!(
+                    "L1 batch #{l1_batch_number} has diverged: {} divergences, execution error: {:?}",
+                    report.divergences.len(),
+                    report.execution_error
+                );
+                diverged_batches.push(l1_batch_number);
+                if self.fail_fast {
+                    break;
+                }
+            }
+        }
+
+        tracing::info!(
+            "Replayed {replayed_count} L1 batches; {} of them diverged",
+            diverged_batches.len()
+        );
+        anyhow::ensure!(
+            diverged_batches.is_empty(),
+            "L1 batches have diverged: {diverged_batches:?}"
+        );
+        Ok(())
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let opts = Cli::parse();
+    let observability_config =
+        ObservabilityConfig::from_env().context("ObservabilityConfig::from_env()")?;
+    let _observability_guard = observability_config.install()?;
+
+    let database_secrets = match &opts.secrets_path {
+        Some(path) => {
+            let yaml = std::fs::read_to_string(path).with_context(|| path.display().to_string())?;
+            let secrets =
+                decode_yaml_repr::<zksync_protobuf_config::proto::secrets::Secrets>(&yaml)
+                    .context("failed decoding secrets YAML config")?;
+            secrets.database.context("Failed to find database config")?
+        }
+        None => DatabaseSecrets::from_env().context("DatabaseSecrets::from_env()")?,
+    };
+    let l2_chain_id = match &opts.genesis_path {
+        Some(path) => {
+            let yaml = std::fs::read_to_string(path).with_context(|| path.display().to_string())?;
+            let genesis: GenesisConfig =
+                decode_yaml_repr::<zksync_protobuf_config::proto::genesis::Genesis>(&yaml)
+                    .context("failed decoding genesis YAML config")?;
+            genesis.l2_chain_id
+        }
+        None => {
+            NetworkConfig::from_env()
+                .context("NetworkConfig::from_env()")?
+                .zksync_network_id
+        }
+    };
+
+    // One connection is used by the VM storage, and another one to load sealed batch data.
+    let pool = ConnectionPool::<Core>::builder(database_secrets.replica_url()?, 2)
+        .build()
+        .await
+        .context("failed to build a connection pool")?;
+    opts.run(pool, l2_chain_id).await
+}
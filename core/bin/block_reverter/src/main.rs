@@ -105,6 +105,21 @@ enum Command {
     /// Clears failed L1 transactions.
     #[command(name = "clear-failed-transactions")]
     ClearFailedL1Transactions,
+
+    /// Reconciles unconfirmed L1 transactions with the on-chain operator nonce. Mined transactions are confirmed,
+    /// and the remaining ones get new nonces, so that they are resent. Should be run while eth_sender is stopped.
+    #[command(name = "reconcile-eth-sender-nonces")]
+    ReconcileEthSenderNonces {
+        /// Displays the outcome as a JSON object, so that it is machine-readable.
+        #[arg(long)]
+        json: bool,
+        /// Operator address.
+        #[arg(long = "operator-address")]
+        operator_address: Address,
+        /// Flag that specifies if the operator is the one used for blob transactions.
+        #[arg(long)]
+        blobs_operator: bool,
+    },
 }
 
 #[tokio::main]
@@ -404,6 +419,26 @@ async fn main() -> anyhow::Result<()> {
         Command::ClearFailedL1Transactions => {
             block_reverter.clear_failed_l1_transactions().await?;
         }
+        Command::ReconcileEthSenderNonces {
+            json,
+            operator_address,
+            blobs_operator,
+        } => {
+            let eth_client = Client::<L1>::http(l1_secrets.l1_rpc_url.clone())
+                .context("Ethereum client")?
+                .build();
+            // Transactions of the main operator are stored without a sender address.
+            let eth_tx_from_addr = blobs_operator.then_some(operator_address);
+
+            let reconciliation = block_reverter
+                .reconcile_eth_sender_nonces(&eth_client, operator_address, eth_tx_from_addr)
+                .await?;
+            if json {
+                println!("{}", serde_json::to_string(&reconciliation)?);
+            } else {
+                println!("Reconciled eth_sender nonces: {:#?}", reconciliation);
+            }
+        }
     }
     Ok(())
 }
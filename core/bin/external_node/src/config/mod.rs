@@ -251,6 +251,9 @@ pub(crate) struct OptionalENConfig {
     /// Limit for fee history block range.
     #[serde(default = "OptionalENConfig::default_fee_history_limit")]
     pub fee_history_limit: u64,
+    /// Maximum number of L2 blocks scanned by a single `trace_filter` call.
+    #[serde(default = "OptionalENConfig::default_trace_filter_block_range_limit")]
+    pub trace_filter_block_range_limit: u32,
     /// Maximum number of requests in a single batch JSON RPC request. Default is 500.
     #[serde(default = "OptionalENConfig::default_max_batch_request_size")]
     pub max_batch_request_size: usize,
@@ -479,6 +482,11 @@ impl OptionalENConfig {
                 web3_json_rpc.fee_history_limit,
                 default_fee_history_limit
             ),
+            trace_filter_block_range_limit: load_optional_config_or_default!(
+                general_config.api_config,
+                web3_json_rpc.trace_filter_block_range_limit,
+                default_trace_filter_block_range_limit
+            ),
             max_batch_request_size: load_optional_config_or_default!(
                 general_config.api_config,
                 web3_json_rpc.max_batch_request_size,
@@ -752,6 +760,10 @@ impl OptionalENConfig {
         1_024
     }
 
+    const fn default_trace_filter_block_range_limit() -> u32 {
+        10_000
+    }
+
     const fn default_max_batch_request_size() -> usize {
         500 // The default limit is chosen to be reasonably permissive.
     }
@@ -919,6 +931,14 @@ pub(crate) struct RequiredENConfig {
     pub healthcheck_port: u16,
     /// Address of the Ethereum node API.
     pub eth_client_url: SensitiveUrl,
+    /// Addresses of fallback Ethereum node APIs. If specified, L1 requests fail over to these providers
+    /// if the main one is unavailable.
+    #[serde(default)]
+    pub eth_client_fallback_urls: Vec<SensitiveUrl>,
+    /// Number of Ethereum node APIs (including the main one) that must return the same event logs
+    /// and finalized / safe blocks. If not specified, the response of a single provider is trusted.
+    #[serde(default)]
+    pub eth_client_quorum: Option<NonZeroUsize>,
     /// Main node URL - used by external node to proxy transactions to, query state from, etc.
     pub main_node_url: SensitiveUrl,
     /// Path to the database data directory that serves state cache.
@@ -951,6 +971,7 @@ impl RequiredENConfig {
             .db_config
             .as_ref()
             .context("Database config is required")?;
+        let l1_secrets = secrets.l1.as_ref().context("L1 secrets are required")?;
         Ok(RequiredENConfig {
             l1_chain_id: en_config.l1_chain_id,
             sl_chain_id: None,
@@ -958,12 +979,9 @@ impl RequiredENConfig {
             http_port: api_config.web3_json_rpc.http_port,
             ws_port: api_config.web3_json_rpc.ws_port,
             healthcheck_port: api_config.healthcheck.port,
-            eth_client_url: secrets
-                .l1
-                .as_ref()
-                .context("L1 secrets are required")?
-                .l1_rpc_url
-                .clone(),
+            eth_client_url: l1_secrets.l1_rpc_url.clone(),
+            eth_client_fallback_urls: l1_secrets.fallback_l1_rpc_urls.clone(),
+            eth_client_quorum: l1_secrets.l1_rpc_quorum,
             main_node_url: en_config.main_node_url.clone(),
             state_cache_path: db_config.state_keeper_db_path.clone(),
             merkle_tree_path: db_config.merkle_tree.path.clone(),
@@ -981,6 +999,8 @@ impl RequiredENConfig {
             healthcheck_port: 0,
             // L1 and L2 clients must be instantiated before accessing mocks, so these values don't matter
             eth_client_url: "http://localhost".parse().unwrap(),
+            eth_client_fallback_urls: vec![],
+            eth_client_quorum: None,
             main_node_url: "http://localhost".parse().unwrap(),
             state_cache_path: temp_dir
                 .path()
@@ -1391,6 +1411,7 @@ impl From<&ExternalNodeConfig> for InternalApiConfig {
             l2_testnet_paymaster_addr: config.remote.l2_testnet_paymaster_addr,
             req_entities_limit: config.optional.req_entities_limit,
             fee_history_limit: config.optional.fee_history_limit,
+            trace_filter_block_range_limit: config.optional.trace_filter_block_range_limit,
             base_token_address: Some(config.remote.base_token_addr),
             filters_disabled: config.optional.filters_disabled,
             dummy_verifier: config.remote.dummy_verifier,
@@ -72,6 +72,7 @@ fn parsing_optional_config_from_empty_env() {
     assert_eq!(config.filters_limit, 10_000);
     assert_eq!(config.subscriptions_limit, 10_000);
     assert_eq!(config.fee_history_limit, 1_024);
+    assert_eq!(config.trace_filter_block_range_limit, 10_000);
     assert_eq!(config.polling_interval(), Duration::from_millis(200));
     assert_eq!(config.max_tx_size_bytes, 1_000_000);
     assert_eq!(
@@ -184,6 +184,10 @@ impl ExternalNodeBuilder {
             self.config.required.eth_client_url.clone(),
             // TODO(EVM-676): add this config for external node
             Default::default(),
+        )
+        .with_fallback_urls(
+            self.config.required.eth_client_fallback_urls.clone(),
+            self.config.required.eth_client_quorum,
         );
         self.node.add_layer(query_eth_client_layer);
         Ok(self)
@@ -433,7 +437,8 @@ impl ExternalNodeBuilder {
             pruning_info_refresh_interval: Some(pruning_info_refresh_interval),
             polling_interval: Some(self.config.optional.polling_interval()),
             websocket_requests_per_minute_limit: None, // To be set by WS server layer method if required.
-            replication_lag_limit: None,               // TODO: Support replication lag limit
+            compute_unit_limiter: None,
+            replication_lag_limit: None, // TODO: Support replication lag limit
         }
     }
 
@@ -0,0 +1,60 @@
+//! Object store pruner utility. Performs a single pruning iteration according to the retention policies
+//! in the `object_store_pruning` config and exits. Intended to run on a schedule as an alternative to running
+//! the `object_store_pruner` component as a part of the main node.
+
+use anyhow::Context as _;
+use structopt::StructOpt;
+use tokio::sync::watch;
+use zksync_core_leftovers::temp_config_store::{load_database_secrets, load_general_config};
+use zksync_dal::{ConnectionPool, Core};
+use zksync_object_store::ObjectStoreFactory;
+use zksync_object_store_pruner::ObjectStorePruner;
+
+#[derive(StructOpt)]
+#[structopt(name = "ZKsync object store pruner", author = "Matter Labs")]
+struct Opt {
+    /// Path to the configuration file.
+    #[structopt(long)]
+    config_path: Option<std::path::PathBuf>,
+
+    /// Path to the secrets file.
+    #[structopt(long)]
+    secrets_path: Option<std::path::PathBuf>,
+
+    /// Only report objects eligible for removal without removing them, regardless of the config.
+    #[structopt(long)]
+    dry_run: bool,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let opt = Opt::from_args();
+    let general_config = load_general_config(opt.config_path).context("general config")?;
+    let database_secrets = load_database_secrets(opt.secrets_path).context("database secrets")?;
+
+    let observability_config = general_config
+        .observability
+        .context("observability config")?;
+    let _observability_guard = observability_config.install()?;
+
+    let mut pruning_config = general_config
+        .object_store_pruning
+        .context("object store pruning config")?;
+    pruning_config.dry_run |= opt.dry_run;
+    let object_store_config = general_config
+        .core_object_store
+        .context("core object store config")?;
+    let object_store = ObjectStoreFactory::new(object_store_config)
+        .create_store()
+        .await?;
+    let pool = ConnectionPool::<Core>::singleton(database_secrets.master_url()?)
+        .build()
+        .await?;
+
+    tracing::info!("Starting object store pruner with config {pruning_config:?}");
+    let pruner = ObjectStorePruner::new(&pruning_config, pool, object_store)?;
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    let stats = pruner.run_once(&stop_receiver).await?;
+    tracing::info!("Finished object store pruning: {stats:?}");
+    Ok(())
+}
@@ -3,22 +3,29 @@
 
 use anyhow::Context;
 use zksync_config::{
-    configs::{eth_sender::PubdataSendingMode, wallets::Wallets, GeneralConfig, Secrets},
+    configs::{
+        api::Web3JsonRpcConfig, eth_sender::PubdataSendingMode, wallets::Wallets, GeneralConfig,
+        Secrets,
+    },
     ContractsConfig, GenesisConfig,
 };
 use zksync_core_leftovers::Component;
 use zksync_default_da_clients::{
+    avail::{config::AvailConfig, wiring_layer::AvailWiringLayer},
+    celestia::{config::CelestiaConfig, wiring_layer::CelestiaWiringLayer},
+    config::{DAClientKind, DAClientSelection},
     no_da::wiring_layer::NoDAClientWiringLayer,
     object_store::{config::DAObjectStoreConfig, wiring_layer::ObjectStorageClientWiringLayer},
 };
 use zksync_metadata_calculator::MetadataCalculatorConfig;
 use zksync_node_api_server::{
     tx_sender::{ApiContracts, TxSenderConfig},
-    web3::{state::InternalApiConfig, Namespace},
+    web3::{backend_jsonrpsee::ComputeUnitLimiter, state::InternalApiConfig, Namespace},
 };
 use zksync_node_framework::{
     implementations::layers::{
         base_token::{
+            aggregated_price_client::AggregatedPriceClientLayer,
             base_token_ratio_persister::BaseTokenRatioPersisterLayer,
             base_token_ratio_provider::BaseTokenRatioProviderLayer,
             coingecko_client::CoingeckoClientLayer, forced_price_client::ForcedPriceClientLayer,
@@ -43,6 +50,7 @@ use zksync_node_framework::{
             main_node_strategy::MainNodeInitStrategyLayer, NodeStorageInitializerLayer,
         },
         object_store::ObjectStoreLayer,
+        object_store_pruner::ObjectStorePrunerLayer,
         pk_signing_eth_client::PKSigningEthClientLayer,
         pools_layer::PoolsLayerBuilder,
         postgres_metrics::PostgresMetricsLayer,
@@ -87,6 +95,8 @@ pub struct MainNodeBuilder {
     genesis_config: GenesisConfig,
     contracts_config: ContractsConfig,
     secrets: Secrets,
+    /// Shared among HTTP and WS servers; initialized lazily.
+    compute_unit_limiter: Option<ComputeUnitLimiter>,
 }
 
 impl MainNodeBuilder {
@@ -104,6 +114,7 @@ impl MainNodeBuilder {
             genesis_config,
             contracts_config,
             secrets,
+            compute_unit_limiter: None,
         })
     }
 
@@ -111,6 +122,18 @@ impl MainNodeBuilder {
         self.node.runtime_handle()
     }
 
+    /// Returns the compute unit limiter shared by all API servers, or `None` if compute unit limits are not configured.
+    fn compute_unit_limiter(
+        &mut self,
+        rpc_config: &Web3JsonRpcConfig,
+    ) -> Option<ComputeUnitLimiter> {
+        let limits = rpc_config.compute_unit_limits()?;
+        let limiter = self
+            .compute_unit_limiter
+            .get_or_insert_with(|| ComputeUnitLimiter::new(&limits));
+        Some(limiter.clone())
+    }
+
     fn add_sigint_handler_layer(mut self) -> anyhow::Result<Self> {
         self.node.add_layer(SigintHandlerLayer);
         Ok(self)
@@ -142,7 +165,8 @@ impl MainNodeBuilder {
 
     fn add_pk_signing_client_layer(mut self) -> anyhow::Result<Self> {
         let eth_config = try_load_config!(self.configs.eth);
-        let wallets = try_load_config!(self.wallets.eth_sender);
+        // Operator wallets are not required if a remote signer is configured; this is checked by the layer.
+        let wallets = self.wallets.eth_sender.clone();
         self.node.add_layer(PKSigningEthClientLayer::new(
             eth_config,
             self.contracts_config.clone(),
@@ -163,7 +187,8 @@ impl MainNodeBuilder {
                 .as_ref()
                 .and_then(|x| Some(x.gas_adjuster?.settlement_mode))
                 .unwrap_or(SettlementMode::SettlesToL1),
-        );
+        )
+        .with_fallback_urls(eth_config.fallback_l1_rpc_urls, eth_config.l1_rpc_quorum);
         self.node.add_layer(query_eth_client_layer);
         Ok(self)
     }
@@ -308,7 +333,9 @@ impl MainNodeBuilder {
         };
 
         // On main node we always use master pool sink.
-        self.node.add_layer(MasterPoolSinkLayer);
+        let mempool_config = try_load_config!(self.configs.mempool_config);
+        self.node
+            .add_layer(MasterPoolSinkLayer::new(&mempool_config));
         self.node.add_layer(TxSenderLayer::new(
             TxSenderConfig::new(
                 &sk_config,
@@ -365,6 +392,7 @@ impl MainNodeBuilder {
             subscriptions_limit: Some(rpc_config.subscriptions_limit()),
             batch_request_size_limit: Some(rpc_config.max_batch_request_size()),
             response_body_size_limit: Some(rpc_config.max_response_body_size()),
+            compute_unit_limiter: self.compute_unit_limiter(&rpc_config),
             ..Default::default()
         };
         self.node.add_layer(Web3ServerLayer::http(
@@ -404,6 +432,7 @@ impl MainNodeBuilder {
             websocket_requests_per_minute_limit: Some(
                 rpc_config.websocket_requests_per_minute_limit(),
             ),
+            compute_unit_limiter: self.compute_unit_limiter(&rpc_config),
             replication_lag_limit: circuit_breaker_config.replication_lag_limit(),
             with_extended_tracing: rpc_config.extended_api_tracing,
             ..Default::default()
@@ -509,16 +538,30 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
-    fn add_no_da_client_layer(mut self) -> anyhow::Result<Self> {
-        self.node.add_layer(NoDAClientWiringLayer);
-        Ok(self)
-    }
-
-    #[allow(dead_code)]
-    fn add_object_storage_da_client_layer(mut self) -> anyhow::Result<Self> {
-        let object_store_config = DAObjectStoreConfig::from_env()?;
-        self.node
-            .add_layer(ObjectStorageClientWiringLayer::new(object_store_config.0));
+    /// Adds a DA client layer for the DA client selected in the config.
+    fn add_da_client_layer(mut self) -> anyhow::Result<Self> {
+        let selection = DAClientSelection::from_env()?;
+        match selection.client {
+            DAClientKind::NoDA => {
+                self.node.add_layer(NoDAClientWiringLayer);
+            }
+            DAClientKind::ObjectStore => {
+                let config = DAObjectStoreConfig::from_env()?;
+                tracing::info!("Using object store DA client: {config:?}");
+                self.node
+                    .add_layer(ObjectStorageClientWiringLayer::new(config.0));
+            }
+            DAClientKind::Celestia => {
+                let config = CelestiaConfig::from_env()?;
+                tracing::info!("Using Celestia DA client: {config:?}");
+                self.node.add_layer(CelestiaWiringLayer::new(config));
+            }
+            DAClientKind::Avail => {
+                let config = AvailConfig::from_env()?;
+                tracing::info!("Using Avail DA client: {config:?}");
+                self.node.add_layer(AvailWiringLayer::new(config));
+            }
+        }
         Ok(self)
     }
 
@@ -564,6 +607,9 @@ impl MainNodeBuilder {
             ForcedPriceClientLayer::CLIENT_NAME => {
                 self.node.add_layer(ForcedPriceClientLayer::new(config));
             }
+            AggregatedPriceClientLayer::CLIENT_NAME => {
+                self.node.add_layer(AggregatedPriceClientLayer::new(config));
+            }
             _ => {
                 anyhow::bail!(
                     "Unknown external price API client source: {}",
@@ -621,6 +667,13 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
+    fn add_object_store_pruner_layer(mut self) -> anyhow::Result<Self> {
+        let config = try_load_config!(self.configs.object_store_pruning);
+        self.node.add_layer(ObjectStorePrunerLayer::new(config));
+
+        Ok(self)
+    }
+
     fn add_logs_bloom_backfill_layer(mut self) -> anyhow::Result<Self> {
         self.node.add_layer(LogsBloomBackfillLayer);
 
@@ -759,7 +812,7 @@ impl MainNodeBuilder {
                     self = self.add_commitment_generator_layer()?;
                 }
                 Component::DADispatcher => {
-                    self = self.add_no_da_client_layer()?.add_da_dispatcher_layer()?;
+                    self = self.add_da_client_layer()?.add_da_dispatcher_layer()?;
                 }
                 Component::VmRunnerProtectiveReads => {
                     self = self.add_vm_runner_protective_reads_layer()?;
@@ -779,6 +832,9 @@ impl MainNodeBuilder {
                 Component::ExternalProofIntegrationApi => {
                     self = self.add_external_proof_integration_api_layer()?;
                 }
+                Component::ObjectStorePruner => {
+                    self = self.add_object_store_pruner_layer()?;
+                }
             }
         }
         Ok(self.node.build())
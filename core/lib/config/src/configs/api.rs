@@ -1,7 +1,7 @@
 use std::{
     collections::HashMap,
     fmt,
-    net::SocketAddr,
+    net::{IpAddr, SocketAddr},
     num::{NonZeroU32, NonZeroUsize},
     str::FromStr,
     time::Duration,
@@ -123,6 +123,84 @@ impl<'de> Deserialize<'de> for MaxResponseSizeOverrides {
     }
 }
 
+/// Compute unit values keyed by a name, such as a full RPC method name or an API key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComputeUnitsByName(HashMap<String, NonZeroU32>);
+
+impl<S: Into<String>> FromIterator<(S, NonZeroU32)> for ComputeUnitsByName {
+    fn from_iter<I: IntoIterator<Item = (S, NonZeroU32)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(name, units)| (name.into(), units))
+                .collect(),
+        )
+    }
+}
+
+impl FromStr for ComputeUnitsByName {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut entries = HashMap::new();
+        for part in s.split(',') {
+            let (name, units) = part
+                .split_once('=')
+                .with_context(|| format!("Part `{part}` doesn't have form <name>=<int>"))?;
+            let name = name.trim();
+            let units = units.trim();
+            let units: NonZeroU32 = units.parse().with_context(|| {
+                format!("`{units}` specified for `{name}` is not a valid number of compute units")
+            })?;
+
+            if let Some(prev_units) = entries.insert(name.to_owned(), units) {
+                anyhow::bail!(
+                    "Compute units for `{name}` are redefined from {prev_units} to {units}"
+                );
+            }
+        }
+        Ok(Self(entries))
+    }
+}
+
+impl ComputeUnitsByName {
+    pub fn empty() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Gets the number of compute units for the specified name, or `None` if it's not set.
+    pub fn get(&self, name: &str) -> Option<NonZeroU32> {
+        self.0.get(name).copied()
+    }
+
+    /// Iterates over all entries.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, NonZeroU32)> + '_ {
+        self.0.iter().map(|(name, &units)| (name.as_str(), units))
+    }
+}
+
+impl<'de> Deserialize<'de> for ComputeUnitsByName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ParseVisitor;
+
+        impl<'v> de::Visitor<'v> for ParseVisitor {
+            type Value = ComputeUnitsByName;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("comma-separated list of <name>=<int> tuples, such as: eth_getLogs=100,debug_traceCall=500")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(ParseVisitor)
+    }
+}
+
 /// Response size limits for JSON-RPC servers.
 #[derive(Debug)]
 pub struct MaxResponseSize {
@@ -132,6 +210,19 @@ pub struct MaxResponseSize {
     pub overrides: MaxResponseSizeOverrides,
 }
 
+/// Compute unit limits for JSON-RPC servers.
+#[derive(Debug, Clone)]
+pub struct ComputeUnitLimits {
+    /// Limit per minute applied to each client without a recognized API key. If `None`, such clients are not rate-limited.
+    pub per_client: Option<NonZeroU32>,
+    /// Limits per minute applied to specific API keys.
+    pub per_api_key: ComputeUnitsByName,
+    /// Method-specific compute unit costs overriding the defaults; method names are full (e.g., `eth_call`).
+    pub method_costs: ComputeUnitsByName,
+    /// Addresses of reverse proxies allowed to specify the client IP address via `x-forwarded-for` / `x-real-ip` headers.
+    pub trusted_proxies: Vec<IpAddr>,
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct Web3JsonRpcConfig {
     /// Port to which the HTTP RPC server is listening.
@@ -187,6 +278,8 @@ pub struct Web3JsonRpcConfig {
     pub latest_values_cache_size_mb: Option<usize>,
     /// Limit for fee history block range.
     pub fee_history_limit: Option<u64>,
+    /// Maximum number of L2 blocks scanned by a single `trace_filter` call. Default is 10,000.
+    pub trace_filter_block_range_limit: Option<u32>,
     /// Maximum number of requests in a single batch JSON RPC request. Default is 500.
     pub max_batch_request_size: Option<usize>,
     /// Maximum response body size in MiBs. Default is 10 MiB.
@@ -196,8 +289,23 @@ pub struct Web3JsonRpcConfig {
     pub max_response_body_size_overrides_mb: MaxResponseSizeOverrides,
     /// Maximum number of requests per minute for the WebSocket server.
     /// The value is per active connection.
-    /// Note: For HTTP, rate limiting is expected to be configured on the infra level.
+    /// Note: For HTTP, rate limiting is expected to be configured on the infra level, or via `compute_units_per_minute_limit`.
     pub websocket_requests_per_minute_limit: Option<NonZeroU32>,
+    /// Maximum number of compute units per minute for a single client across HTTP and WebSocket servers.
+    /// Clients are identified by their IP address, unless they provide an API key listed in
+    /// `api_key_compute_units_per_minute`. If not set, clients without an API key are not rate-limited.
+    pub compute_units_per_minute_limit: Option<NonZeroU32>,
+    /// Maximum number of compute units per minute for specific API keys. An API key is read from the `x-api-key` header
+    /// or from the URL path (e.g., `https://rpc.example.com/<api_key>`).
+    #[serde(default = "ComputeUnitsByName::empty")]
+    pub api_key_compute_units_per_minute: ComputeUnitsByName,
+    /// Method-specific overrides for the compute unit cost of RPC calls.
+    #[serde(default = "ComputeUnitsByName::empty")]
+    pub method_compute_unit_costs: ComputeUnitsByName,
+    /// IP addresses of reverse proxies trusted to report the client IP address via `x-forwarded-for` or `x-real-ip`
+    /// headers. Requests from other addresses are attributed to the connection peer address for compute unit limiting.
+    #[serde(default)]
+    pub rate_limit_trusted_proxies: Vec<IpAddr>,
     /// Tree API url, currently used to proxy `getProof` calls to the tree
     pub tree_api_url: Option<String>,
     /// Polling period for mempool cache update - how often the mempool cache is updated from the database.
@@ -244,10 +352,15 @@ impl Web3JsonRpcConfig {
             initial_writes_cache_size_mb: Default::default(),
             latest_values_cache_size_mb: Default::default(),
             fee_history_limit: Default::default(),
+            trace_filter_block_range_limit: Default::default(),
             max_batch_request_size: Default::default(),
             max_response_body_size_mb: Default::default(),
             max_response_body_size_overrides_mb: MaxResponseSizeOverrides::empty(),
             websocket_requests_per_minute_limit: Default::default(),
+            compute_units_per_minute_limit: None,
+            api_key_compute_units_per_minute: ComputeUnitsByName::empty(),
+            method_compute_unit_costs: ComputeUnitsByName::empty(),
+            rate_limit_trusted_proxies: vec![],
             mempool_cache_update_interval: Default::default(),
             mempool_cache_size: Default::default(),
             tree_api_url: None,
@@ -307,6 +420,10 @@ impl Web3JsonRpcConfig {
         self.fee_history_limit.unwrap_or(1024)
     }
 
+    pub fn trace_filter_block_range_limit(&self) -> u32 {
+        self.trace_filter_block_range_limit.unwrap_or(10_000)
+    }
+
     pub fn max_batch_request_size(&self) -> usize {
         // The default limit is chosen to be reasonably permissive.
         self.max_batch_request_size.unwrap_or(500)
@@ -326,6 +443,21 @@ impl Web3JsonRpcConfig {
             .unwrap_or(NonZeroU32::new(6000).unwrap())
     }
 
+    /// Returns compute unit limits, or `None` if compute unit rate limiting is disabled.
+    pub fn compute_unit_limits(&self) -> Option<ComputeUnitLimits> {
+        if self.compute_units_per_minute_limit.is_none()
+            && self.api_key_compute_units_per_minute.iter().len() == 0
+        {
+            return None;
+        }
+        Some(ComputeUnitLimits {
+            per_client: self.compute_units_per_minute_limit,
+            per_api_key: self.api_key_compute_units_per_minute.clone(),
+            method_costs: self.method_compute_unit_costs.clone(),
+            trusted_proxies: self.rate_limit_trusted_proxies.clone(),
+        })
+    }
+
     pub fn tree_api_url(&self) -> Option<&str> {
         self.tree_api_url.as_deref()
     }
@@ -416,4 +548,18 @@ mod tests {
         assert_eq!(scaled.get("zks_getProof"), Some(32_000));
         assert_eq!(scaled.get("eth_blockNumber"), None);
     }
+
+    #[test]
+    fn parsing_compute_units_by_name() {
+        let costs: ComputeUnitsByName = "eth_getLogs=100, debug_traceCall = 500".parse().unwrap();
+        assert_eq!(costs.iter().len(), 2);
+        assert_eq!(costs.get("eth_getLogs"), NonZeroU32::new(100));
+        assert_eq!(costs.get("debug_traceCall"), NonZeroU32::new(500));
+        assert_eq!(costs.get("eth_chainId"), None);
+
+        "eth_getLogs=0".parse::<ComputeUnitsByName>().unwrap_err();
+        "eth_getLogs=1,eth_getLogs=2"
+            .parse::<ComputeUnitsByName>()
+            .unwrap_err();
+    }
 }
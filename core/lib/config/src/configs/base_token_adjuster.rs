@@ -41,6 +41,12 @@ const DEFAULT_MAX_ACCEPTABLE_PRIORITY_FEE_IN_GWEI: u64 = 100_000_000_000;
 /// Default value for halting on error
 const DEFAULT_HALT_ON_ERROR: bool = false;
 
+/// By default, the ratio is sent to L1 after each update regardless of its deviation from the previously sent ratio.
+const DEFAULT_L1_UPDATE_DEVIATION_PERCENT: u32 = 0;
+
+/// By default, the ratio is re-sent to L1 at least once a day even if it hasn't changed significantly.
+const DEFAULT_L1_UPDATE_MAX_STALENESS_MS: u64 = 24 * 60 * 60 * 1_000;
+
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct BaseTokenAdjusterConfig {
     /// How often to spark a new cycle of the ratio persister to fetch external prices and persis ratios.
@@ -92,6 +98,27 @@ pub struct BaseTokenAdjusterConfig {
     /// the server process if an external api is not available or if L1 is congested.
     #[serde(default = "BaseTokenAdjusterConfig::default_halt_on_error")]
     pub halt_on_error: bool,
+
+    /// Maximum change of a fetched ratio compared to the latest persisted ratio, in percent. Larger changes
+    /// are either rejected or clamped depending on `clamp_ratio_changes`. If not set, ratio changes are not limited.
+    #[serde(default)]
+    pub max_ratio_change_percent: Option<u32>,
+
+    /// Defines whether ratio changes exceeding `max_ratio_change_percent` should be clamped to the maximum
+    /// allowed change. If false (the default), such ratios are rejected and the persister pauses updates
+    /// until a ratio within the allowed range is fetched.
+    #[serde(default)]
+    pub clamp_ratio_changes: bool,
+
+    /// Minimum deviation of the ratio from the ratio last sent to L1, in percent, for a new L1 update
+    /// to be sent. 0 means that each persisted ratio is sent to L1.
+    #[serde(default = "BaseTokenAdjusterConfig::default_l1_update_deviation_percent")]
+    pub l1_update_deviation_percent: u32,
+
+    /// Maximum age of the ratio last sent to L1 in milliseconds. Once elapsed, the ratio is sent to L1
+    /// regardless of its deviation.
+    #[serde(default = "BaseTokenAdjusterConfig::default_l1_update_max_staleness_ms")]
+    pub l1_update_max_staleness_ms: u64,
 }
 
 impl Default for BaseTokenAdjusterConfig {
@@ -110,6 +137,10 @@ impl Default for BaseTokenAdjusterConfig {
             price_fetching_sleep_ms: Self::default_price_fetching_sleep_ms(),
             price_fetching_max_attempts: Self::default_price_fetching_max_attempts(),
             halt_on_error: Self::default_halt_on_error(),
+            max_ratio_change_percent: None,
+            clamp_ratio_changes: false,
+            l1_update_deviation_percent: Self::default_l1_update_deviation_percent(),
+            l1_update_max_staleness_ms: Self::default_l1_update_max_staleness_ms(),
         }
     }
 }
@@ -183,6 +214,18 @@ impl BaseTokenAdjusterConfig {
         DEFAULT_MAX_TX_GAS
     }
 
+    pub fn default_l1_update_deviation_percent() -> u32 {
+        DEFAULT_L1_UPDATE_DEVIATION_PERCENT
+    }
+
+    pub fn default_l1_update_max_staleness_ms() -> u64 {
+        DEFAULT_L1_UPDATE_MAX_STALENESS_MS
+    }
+
+    pub fn l1_update_max_staleness(&self) -> Duration {
+        Duration::from_millis(self.l1_update_max_staleness_ms)
+    }
+
     pub fn private_key(&self) -> anyhow::Result<Option<K256PrivateKey>> {
         std::env::var("TOKEN_MULTIPLIER_SETTER_PRIVATE_KEY")
             .ok()
@@ -1,6 +1,7 @@
-use std::{str::FromStr, time::Duration};
+use std::{collections::HashMap, fmt, str::FromStr, time::Duration};
 
-use serde::Deserialize;
+use anyhow::Context as _;
+use serde::{de, Deserialize, Deserializer};
 use zksync_basic_types::{
     commitment::L1BatchCommitmentMode, network::Network, Address, L2ChainId, H256,
 };
@@ -132,6 +133,19 @@ pub struct StateKeeperConfig {
     #[serde(default)]
     pub protective_reads_persistence_enabled: bool,
 
+    /// Names of seal criteria that should not be used by the sequencer, such as `slots` or `tx_encoding_size`.
+    /// Names are the same as reported in seal reason metrics; unknown names are rejected when creating the sealer.
+    /// `gas_for_batch_tip` and `pub_data_size` cannot be disabled. Disabling other built-in criteria may lead
+    /// to batches that cannot be proven or committed, so this should be used with care.
+    #[serde(default)]
+    pub disabled_seal_criteria: Vec<String>,
+    /// Thresholds for seal criteria keyed by the criterion name. For built-in criteria, the threshold caps
+    /// the main criterion limit (e.g., `transaction_slots` for `slots` or `max_pubdata_per_batch` for `pub_data_size`),
+    /// i.e. it can only make the criterion stricter; `gas_for_batch_tip` ignores its threshold. For custom criteria
+    /// registered by the node builder, the meaning of the threshold is defined by the criterion itself.
+    #[serde(default)]
+    pub seal_criteria_thresholds: SealCriteriaThresholds,
+
     // Base system contract hashes, required only for generating genesis config.
     // #PLA-811
     #[deprecated(note = "Use GenesisConfig::bootloader_hash instead")]
@@ -176,11 +190,101 @@ impl StateKeeperConfig {
             save_call_traces: true,
             max_circuits_per_batch: 24100,
             protective_reads_persistence_enabled: true,
+            disabled_seal_criteria: vec![],
+            seal_criteria_thresholds: SealCriteriaThresholds::default(),
             bootloader_hash: None,
             default_aa_hash: None,
             l1_batch_commit_data_generator_mode: L1BatchCommitmentMode::Rollup,
         }
     }
+
+    /// Checks whether the seal criterion with the specified name is disabled.
+    pub fn is_seal_criterion_disabled(&self, name: &str) -> bool {
+        self.disabled_seal_criteria
+            .iter()
+            .any(|disabled| disabled == name)
+    }
+
+    /// Returns the threshold configured for the seal criterion with the specified name, or `None` if it's not set.
+    pub fn seal_criterion_threshold(&self, name: &str) -> Option<u64> {
+        self.seal_criteria_thresholds.get(name)
+    }
+}
+
+/// Seal criteria thresholds keyed by the criterion name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SealCriteriaThresholds(HashMap<String, u64>);
+
+impl<S: Into<String>> FromIterator<(S, u64)> for SealCriteriaThresholds {
+    fn from_iter<I: IntoIterator<Item = (S, u64)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(name, threshold)| (name.into(), threshold))
+                .collect(),
+        )
+    }
+}
+
+impl FromStr for SealCriteriaThresholds {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut entries = HashMap::new();
+        for part in s.split(',').filter(|part| !part.trim().is_empty()) {
+            let (name, threshold) = part
+                .split_once('=')
+                .with_context(|| format!("Part `{part}` doesn't have form <name>=<int>"))?;
+            let name = name.trim();
+            let threshold = threshold.trim();
+            let threshold: u64 = threshold.parse().with_context(|| {
+                format!("`{threshold}` specified for `{name}` is not a valid threshold")
+            })?;
+
+            if let Some(prev_threshold) = entries.insert(name.to_owned(), threshold) {
+                anyhow::bail!(
+                    "Threshold for `{name}` is redefined from {prev_threshold} to {threshold}"
+                );
+            }
+        }
+        Ok(Self(entries))
+    }
+}
+
+impl SealCriteriaThresholds {
+    /// Gets the threshold for the specified criterion, or `None` if it's not set.
+    pub fn get(&self, name: &str) -> Option<u64> {
+        self.0.get(name).copied()
+    }
+
+    /// Iterates over all entries.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, u64)> + '_ {
+        self.0
+            .iter()
+            .map(|(name, &threshold)| (name.as_str(), threshold))
+    }
+}
+
+impl<'de> Deserialize<'de> for SealCriteriaThresholds {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ParseVisitor;
+
+        impl<'v> de::Visitor<'v> for ParseVisitor {
+            type Value = SealCriteriaThresholds;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("comma-separated list of <name>=<int> tuples, such as: l1_tx_count=100,slots=500")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(ParseVisitor)
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq)]
@@ -218,6 +322,18 @@ impl CircuitBreakerConfig {
     }
 }
 
+/// Policy used to order L2 transactions in the mempool.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MempoolOrdering {
+    /// Transactions are ordered by the time they were received.
+    #[default]
+    Fifo,
+    /// Transactions are ordered by their effective tip, i.e. `max_fee_per_gas` above the fair L2 gas price
+    /// of the batch. Transactions with equal tips are ordered by the time they were received.
+    FeePriority,
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct MempoolConfig {
     pub sync_interval_ms: u64,
@@ -226,9 +342,20 @@ pub struct MempoolConfig {
     pub stuck_tx_timeout: u64,
     pub remove_stuck_txs: bool,
     pub delay_interval: u64,
+    /// Ordering policy for L2 transactions.
+    #[serde(default)]
+    pub ordering: MempoolOrdering,
+    /// Minimum bump of `max_fee_per_gas` (in percent) required for a transaction to replace a pending transaction
+    /// with the same initiator and nonce. Enforced on API submission; 0 disables the check.
+    #[serde(default = "MempoolConfig::default_replacement_fee_bump_percent")]
+    pub replacement_fee_bump_percent: u64,
 }
 
 impl MempoolConfig {
+    pub const fn default_replacement_fee_bump_percent() -> u64 {
+        0
+    }
+
     pub fn sync_interval(&self) -> Duration {
         Duration::from_millis(self.sync_interval_ms)
     }
@@ -2,7 +2,7 @@ use std::time::Duration;
 
 use anyhow::Context as _;
 use serde::Deserialize;
-use zksync_basic_types::{settlement::SettlementMode, H256};
+use zksync_basic_types::{settlement::SettlementMode, url::SensitiveUrl, Address, H256};
 use zksync_crypto_primitives::K256PrivateKey;
 
 use crate::EthWatchConfig;
@@ -15,6 +15,8 @@ pub struct EthConfig {
     /// Options related to the `GasAdjuster` submodule.
     pub gas_adjuster: Option<GasAdjusterConfig>,
     pub watcher: Option<EthWatchConfig>,
+    /// Remote signer used to sign L1 transactions. If set, operator private keys from wallets are not used.
+    pub remote_signer: Option<RemoteSignerConfig>,
 }
 
 impl EthConfig {
@@ -42,6 +44,10 @@ impl EthConfig {
                 pubdata_sending_mode: PubdataSendingMode::Calldata,
                 tx_aggregation_paused: false,
                 tx_aggregation_only_prove_and_execute: false,
+                resend_fee_strategy: ResendFeeStrategy::Minimal,
+                resend_fee_bump_percent: 20,
+                max_resend_fee_per_gas: None,
+                cancel_stuck_txs_after_blocks: None,
             }),
             gas_adjuster: Some(GasAdjusterConfig {
                 default_priority_fee_per_gas: 1000000000,
@@ -57,15 +63,43 @@ impl EthConfig {
                 internal_pubdata_pricing_multiplier: 1.0,
                 max_blob_base_fee: None,
                 settlement_mode: Default::default(),
+                base_fee_strategy: BaseFeeStrategy::Median,
+                priority_fee_strategy: PriorityFeeStrategy::Fixed,
+                priority_fee_percentile: 50.0,
             }),
             watcher: Some(EthWatchConfig {
                 confirmations_for_eth_event: None,
                 eth_node_poll_interval: 0,
             }),
+            remote_signer: None,
         }
     }
 }
 
+/// Configuration of a remote signer (e.g., Web3Signer) exposing the `eth_signTransaction` JSON-RPC method.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct RemoteSignerConfig {
+    /// JSON-RPC URL of the signer.
+    pub url: SensitiveUrl,
+    /// Address of the operator account. The signer must manage the key for this account.
+    pub operator_address: Address,
+    /// Address of the blob operator account. If not set, blob transactions are not supported.
+    pub blob_operator_address: Option<Address>,
+    /// Timeout for a single signing request in milliseconds.
+    #[serde(default = "RemoteSignerConfig::default_request_timeout_ms")]
+    pub request_timeout_ms: u64,
+}
+
+impl RemoteSignerConfig {
+    const fn default_request_timeout_ms() -> u64 {
+        10_000
+    }
+
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms)
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
 pub enum ProofSendingMode {
     OnlyRealProofs,
@@ -88,6 +122,39 @@ pub enum PubdataSendingMode {
     RelayedL2Calldata,
 }
 
+/// Strategy used to increase fees of a stuck L1 transaction when resending it.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum ResendFeeStrategy {
+    /// Fees are increased by the minimum required to replace the transaction in the mempool
+    /// (10% for the base fee and 20% for the priority fee; 2x for blob transactions).
+    #[default]
+    Minimal,
+    /// Fees are increased by `resend_fee_bump_percent` on each resend (but not less than for [`Self::Minimal`]),
+    /// so that the fees grow exponentially with the number of resends. Fees are capped by `max_resend_fee_per_gas`.
+    Exponential,
+}
+
+/// Strategy used by `GasAdjuster` to estimate the base fee for L1 transactions.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum BaseFeeStrategy {
+    /// Median base fee over the last `max_base_fee_samples` blocks.
+    #[default]
+    Median,
+    /// Base fee of the next block projected from the latest block using the EIP-1559 update rule.
+    Eip1559Projection,
+}
+
+/// Strategy used by `GasAdjuster` to estimate the priority fee for L1 transactions.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum PriorityFeeStrategy {
+    /// Constant `default_priority_fee_per_gas`.
+    #[default]
+    Fixed,
+    /// Median over the last `max_base_fee_samples` blocks of the priority fee paid at `priority_fee_percentile`
+    /// in each block, as reported by `eth_feeHistory`.
+    Percentile,
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct SenderConfig {
     pub aggregated_proof_sizes: Vec<usize>,
@@ -127,6 +194,20 @@ pub struct SenderConfig {
     /// special mode specifically for gateway migration to decrease number of non-executed batches
     #[serde(default = "SenderConfig::default_tx_aggregation_only_prove_and_execute")]
     pub tx_aggregation_only_prove_and_execute: bool,
+    /// Strategy used to increase fees when resending stuck transactions.
+    #[serde(default)]
+    pub resend_fee_strategy: ResendFeeStrategy,
+    /// Percentage by which fees are increased on each resend with [`ResendFeeStrategy::Exponential`].
+    #[serde(default = "SenderConfig::default_resend_fee_bump_percent")]
+    pub resend_fee_bump_percent: u32,
+    /// Max `max_fee_per_gas` (in wei) a transaction can be resent with when using [`ResendFeeStrategy::Exponential`].
+    /// If the bumped fees exceed this budget, the transaction is resent with capped fees or, if capped fees
+    /// are insufficient to replace the previous transaction, isn't resent at all.
+    pub max_resend_fee_per_gas: Option<u64>,
+    /// If set, in-flight transactions that weren't mined within this number of L1 blocks since being first sent
+    /// are cancelled by zero-value self-transfers with the same nonces. Cancelled operations are then sent again
+    /// with new nonces. Only transactions sent to L1 by the main (non-blob) operator are cancelled.
+    pub cancel_stuck_txs_after_blocks: Option<u32>,
 }
 
 impl SenderConfig {
@@ -168,6 +249,10 @@ impl SenderConfig {
     const fn default_tx_aggregation_only_prove_and_execute() -> bool {
         false
     }
+
+    pub const fn default_resend_fee_bump_percent() -> u32 {
+        20
+    }
 }
 
 #[derive(Debug, Deserialize, Copy, Clone, PartialEq, Default)]
@@ -202,6 +287,15 @@ pub struct GasAdjusterConfig {
     /// It offers a runtime check for correctly provided values.
     #[serde(default)]
     pub settlement_mode: SettlementMode,
+    /// Strategy used to estimate the base fee.
+    #[serde(default)]
+    pub base_fee_strategy: BaseFeeStrategy,
+    /// Strategy used to estimate the priority fee.
+    #[serde(default)]
+    pub priority_fee_strategy: PriorityFeeStrategy,
+    /// Percentile of priority fees paid in recent blocks used with [`PriorityFeeStrategy::Percentile`]. Must be in `0..=100`.
+    #[serde(default = "GasAdjusterConfig::default_priority_fee_percentile")]
+    pub priority_fee_percentile: f64,
 }
 
 impl GasAdjusterConfig {
@@ -225,4 +319,8 @@ impl GasAdjusterConfig {
     pub const fn default_internal_pubdata_pricing_multiplier() -> f64 {
         1.0
     }
+
+    pub const fn default_priority_fee_percentile() -> f64 {
+        50.0
+    }
 }
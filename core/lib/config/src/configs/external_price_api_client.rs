@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use serde::Deserialize;
+use zksync_basic_types::Address;
 
 pub const DEFAULT_TIMEOUT_MS: u64 = 10_000;
 
@@ -16,6 +17,64 @@ pub struct ForcedPriceClientConfig {
     pub fluctuation: Option<u32>,
 }
 
+/// Configuration of the aggregating price client, which queries several price sources and returns the median quote.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AggregatedPriceClientConfig {
+    /// Names of the aggregated price sources. Supported sources are `coingecko` (uses `base_url` and `api_key`
+    /// from the parent config), `coinmarketcap` and `uniswap_v3`.
+    pub sources: Vec<String>,
+    /// Minimum number of sources that must return fresh, non-outlying quotes for the aggregated quote to be returned.
+    /// If not specified, the majority of sources is required.
+    pub quorum: Option<u32>,
+    /// Maximum deviation of a source quote from the median of all quotes, in percent. Quotes deviating more
+    /// are considered outliers and are discarded.
+    #[serde(default = "AggregatedPriceClientConfig::default_max_deviation_percent")]
+    pub max_deviation_percent: u32,
+    /// Maximum age of a source quote. Older quotes are discarded.
+    #[serde(default = "AggregatedPriceClientConfig::default_max_quote_age_ms")]
+    pub max_quote_age_ms: u64,
+    /// API key for the CoinMarketCap source.
+    pub coinmarketcap_api_key: Option<String>,
+    /// Base URL for the CoinMarketCap source. If not specified, the CoinMarketCap Pro API is used.
+    pub coinmarketcap_base_url: Option<String>,
+    /// Address of the Uniswap v3 base token / WETH pool on L1 used by the `uniswap_v3` source.
+    pub uniswap_v3_pool_address: Option<Address>,
+    /// Address of the quote token (WETH) on L1 that the Uniswap v3 pool must pair the base token with.
+    /// Required by the `uniswap_v3` source.
+    pub uniswap_v3_quote_token_address: Option<Address>,
+    /// Interval for the time-weighted average price computed by the `uniswap_v3` source.
+    #[serde(default = "AggregatedPriceClientConfig::default_uniswap_v3_twap_interval_sec")]
+    pub uniswap_v3_twap_interval_sec: u32,
+}
+
+impl AggregatedPriceClientConfig {
+    pub const fn default_max_deviation_percent() -> u32 {
+        5
+    }
+
+    pub const fn default_max_quote_age_ms() -> u64 {
+        5 * 60 * 1_000
+    }
+
+    pub const fn default_uniswap_v3_twap_interval_sec() -> u32 {
+        600
+    }
+
+    /// Returns the minimum number of agreeing sources.
+    pub fn quorum(&self) -> usize {
+        self.quorum
+            .map_or(self.sources.len() / 2 + 1, |quorum| quorum as usize)
+    }
+
+    pub fn max_quote_age(&self) -> Duration {
+        Duration::from_millis(self.max_quote_age_ms)
+    }
+
+    pub fn uniswap_v3_twap_interval(&self) -> Duration {
+        Duration::from_secs(self.uniswap_v3_twap_interval_sec.into())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ExternalPriceApiClientConfig {
     pub source: String,
@@ -24,6 +83,7 @@ pub struct ExternalPriceApiClientConfig {
     #[serde(default = "ExternalPriceApiClientConfig::default_timeout")]
     pub client_timeout_ms: u64,
     pub forced: Option<ForcedPriceClientConfig>,
+    pub aggregated: Option<AggregatedPriceClientConfig>,
 }
 
 impl ExternalPriceApiClientConfig {
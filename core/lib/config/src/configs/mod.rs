@@ -20,6 +20,7 @@ pub use self::{
     general::GeneralConfig,
     genesis::GenesisConfig,
     object_store::ObjectStoreConfig,
+    object_store_pruning::{ObjectRetentionPolicy, ObjectStorePruningConfig},
     observability::{ObservabilityConfig, OpentelemetryConfig},
     proof_data_handler::ProofDataHandlerConfig,
     prover_job_monitor::ProverJobMonitorConfig,
@@ -56,6 +57,7 @@ mod general;
 pub mod genesis;
 pub mod house_keeper;
 pub mod object_store;
+pub mod object_store_pruning;
 pub mod observability;
 pub mod proof_data_handler;
 pub mod prover_job_monitor;
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use serde::Deserialize;
 
 /// Configuration for the object store
@@ -16,6 +18,16 @@ pub struct ObjectStoreConfig {
     /// **Important.** Mirroring logic assumes that objects in the underlying store are immutable. If this is not the case,
     /// the mirrored objects may become stale.
     pub local_mirror_path: Option<String>,
+    /// zstd compression level for stored objects. If specified, all objects put into the store are compressed
+    /// and supplied with a checksum that is verified when the object is fetched. Objects stored without compression
+    /// can still be read, so compression can be enabled for an existing store without migrating it.
+    ///
+    /// **Important.** All components reading from the store must support compression before it is enabled.
+    pub compression_level: Option<i32>,
+    /// Buckets (e.g., `storage_logs_snapshots`) in which objects are stored as-is even if `compression_level` is set.
+    /// Useful for buckets read by third parties or for data that is already compressed.
+    #[serde(default)]
+    pub compression_excluded_buckets: Vec<String>,
 }
 
 impl ObjectStoreConfig {
@@ -40,4 +52,43 @@ pub enum ObjectStoreMode {
     FileBacked {
         file_backed_base_path: String,
     },
+    /// Store compatible with the AWS S3 API (AWS itself, MinIO, Ceph etc.).
+    S3 {
+        /// Name of the S3 bucket.
+        bucket_name: String,
+        /// Region used to sign requests, e.g. `us-east-1`. Most S3-compatible servers accept any region.
+        region: String,
+        /// Custom endpoint URL, e.g. `http://localhost:9000` for a local MinIO instance. If not specified,
+        /// the AWS endpoint for the `region` is used.
+        endpoint: Option<String>,
+        #[serde(default)]
+        addressing_style: S3AddressingStyle,
+        /// Access key ID. If not specified together with `secret_access_key`, credentials are read
+        /// from the standard `AWS_ACCESS_KEY_ID` / `AWS_SECRET_ACCESS_KEY` / `AWS_SESSION_TOKEN` env vars.
+        access_key_id: Option<String>,
+        secret_access_key: Option<S3SecretAccessKey>,
+    },
+}
+
+/// Addressing style for S3 requests.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum S3AddressingStyle {
+    /// Bucket is specified as a subdomain of the endpoint host (`https://bucket.s3.amazonaws.com/key`).
+    /// This is the default style used by AWS.
+    #[default]
+    VirtualHosted,
+    /// Bucket is specified as the first path segment (`https://s3.amazonaws.com/bucket/key`).
+    /// Usually required by self-hosted S3-compatible servers such as MinIO.
+    Path,
+}
+
+/// Secret access key for an S3-compatible store. Redacted in the `Debug` output.
+#[derive(Clone, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct S3SecretAccessKey(pub String);
+
+impl fmt::Debug for S3SecretAccessKey {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("S3SecretAccessKey(_)")
+    }
 }
@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+
+/// Configuration of the object store pruner removing stale objects from object store buckets.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ObjectStorePruningConfig {
+    /// Interval between pruning iterations in seconds. Must be positive; the default value is 1 hour.
+    #[serde(default = "ObjectStorePruningConfig::default_interval_sec")]
+    pub interval_sec: u64,
+    /// If set, the pruner will only log and report in metrics the objects eligible for removal,
+    /// without actually removing them.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Retention policies for buckets. Buckets without a policy are not pruned.
+    #[serde(default)]
+    pub policies: Vec<ObjectRetentionPolicy>,
+}
+
+impl ObjectStorePruningConfig {
+    pub const fn default_interval_sec() -> u64 {
+        3_600
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval_sec)
+    }
+}
+
+/// Retention policy for a single object store bucket. An object is removed if it satisfies *any* of
+/// the enabled criteria.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ObjectRetentionPolicy {
+    /// Name of the bucket, e.g. `witness_inputs` or `proofs_fri`.
+    pub bucket: String,
+    /// If set, objects for L1 batches older than this age (in seconds) will be removed.
+    pub max_age_sec: Option<u64>,
+    /// If set, objects for L1 batches already executed on L1 will be removed.
+    #[serde(default)]
+    pub remove_executed: bool,
+}
+
+impl ObjectRetentionPolicy {
+    pub fn max_age(&self) -> Option<Duration> {
+        self.max_age_sec.map(Duration::from_secs)
+    }
+}
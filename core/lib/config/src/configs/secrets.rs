@@ -1,3 +1,5 @@
+use std::num::NonZeroUsize;
+
 use anyhow::Context;
 use zksync_basic_types::url::SensitiveUrl;
 
@@ -13,6 +15,12 @@ pub struct DatabaseSecrets {
 #[derive(Debug, Clone, PartialEq)]
 pub struct L1Secrets {
     pub l1_rpc_url: SensitiveUrl,
+    /// Fallback L1 RPC URLs. If specified, L1 requests fail over to these providers if the main one is unavailable,
+    /// and transactions are broadcast to all providers.
+    pub fallback_l1_rpc_urls: Vec<SensitiveUrl>,
+    /// Number of L1 RPC providers (including the main one) that must return the same event logs and finalized / safe
+    /// blocks. If not specified, the response of a single provider is trusted.
+    pub l1_rpc_quorum: Option<NonZeroUsize>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -1,4 +1,4 @@
-use std::num::NonZeroUsize;
+use std::num::{NonZeroU32, NonZeroUsize};
 
 use rand::{distributions::Distribution, Rng};
 use zksync_basic_types::{
@@ -13,7 +13,9 @@ use zksync_consensus_utils::EncodeDist;
 use zksync_crypto_primitives::K256PrivateKey;
 
 use crate::configs::{
-    self, eth_sender::PubdataSendingMode, external_price_api_client::ForcedPriceClientConfig,
+    self,
+    eth_sender::PubdataSendingMode,
+    external_price_api_client::{AggregatedPriceClientConfig, ForcedPriceClientConfig},
 };
 
 trait Sample {
@@ -80,6 +82,7 @@ impl Distribution<configs::api::Web3JsonRpcConfig> for EncodeDist {
             initial_writes_cache_size_mb: self.sample(rng),
             latest_values_cache_size_mb: self.sample(rng),
             fee_history_limit: self.sample(rng),
+            trace_filter_block_range_limit: self.sample(rng),
             max_batch_request_size: self.sample(rng),
             max_response_body_size_mb: self.sample(rng),
             max_response_body_size_overrides_mb: [
@@ -95,6 +98,29 @@ impl Distribution<configs::api::Web3JsonRpcConfig> for EncodeDist {
             .into_iter()
             .collect(),
             websocket_requests_per_minute_limit: self.sample(rng),
+            compute_units_per_minute_limit: self.sample(rng),
+            api_key_compute_units_per_minute: [(
+                "test_key",
+                NonZeroU32::new(self.sample(rng)).unwrap_or(NonZeroU32::MAX),
+            )]
+            .into_iter()
+            .collect(),
+            method_compute_unit_costs: [
+                (
+                    "eth_getLogs",
+                    NonZeroU32::new(self.sample(rng)).unwrap_or(NonZeroU32::MIN),
+                ),
+                (
+                    "debug_traceCall",
+                    NonZeroU32::new(self.sample(rng)).unwrap_or(NonZeroU32::MIN),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            rate_limit_trusted_proxies: vec![
+                std::net::Ipv4Addr::from(rng.gen::<u32>()).into(),
+                std::net::Ipv6Addr::from(rng.gen::<u128>()).into(),
+            ],
             tree_api_url: self.sample(rng),
             mempool_cache_update_interval: self.sample(rng),
             mempool_cache_size: self.sample(rng),
@@ -181,6 +207,8 @@ impl Distribution<configs::chain::StateKeeperConfig> for EncodeDist {
             save_call_traces: self.sample(rng),
             max_circuits_per_batch: self.sample(rng),
             protective_reads_persistence_enabled: self.sample(rng),
+            disabled_seal_criteria: self.sample_collect(rng),
+            seal_criteria_thresholds: [("custom", self.sample(rng))].into_iter().collect(),
             // These values are not involved into files serialization skip them
             fee_account_addr: None,
             bootloader_hash: None,
@@ -218,6 +246,18 @@ impl Distribution<configs::chain::MempoolConfig> for EncodeDist {
             stuck_tx_timeout: self.sample(rng),
             remove_stuck_txs: self.sample(rng),
             delay_interval: self.sample(rng),
+            ordering: self.sample(rng),
+            replacement_fee_bump_percent: self.sample(rng),
+        }
+    }
+}
+
+impl Distribution<configs::chain::MempoolOrdering> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::chain::MempoolOrdering {
+        type T = configs::chain::MempoolOrdering;
+        match rng.gen_range(0..2) {
+            0 => T::Fifo,
+            _ => T::FeePriority,
         }
     }
 }
@@ -354,6 +394,18 @@ impl Distribution<configs::EthConfig> for EncodeDist {
             sender: self.sample(rng),
             gas_adjuster: self.sample(rng),
             watcher: self.sample(rng),
+            remote_signer: self.sample(rng),
+        }
+    }
+}
+
+impl Distribution<configs::eth_sender::RemoteSignerConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::eth_sender::RemoteSignerConfig {
+        configs::eth_sender::RemoteSignerConfig {
+            url: format!("localhost:{}", rng.gen::<u16>()).parse().unwrap(),
+            operator_address: rng.gen(),
+            blob_operator_address: self.sample_opt(|| rng.gen()),
+            request_timeout_ms: self.sample(rng),
         }
     }
 }
@@ -390,6 +442,36 @@ impl Distribution<configs::eth_sender::PubdataSendingMode> for EncodeDist {
     }
 }
 
+impl Distribution<configs::eth_sender::ResendFeeStrategy> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::eth_sender::ResendFeeStrategy {
+        type T = configs::eth_sender::ResendFeeStrategy;
+        match rng.gen_range(0..2) {
+            0 => T::Minimal,
+            _ => T::Exponential,
+        }
+    }
+}
+
+impl Distribution<configs::eth_sender::BaseFeeStrategy> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::eth_sender::BaseFeeStrategy {
+        type T = configs::eth_sender::BaseFeeStrategy;
+        match rng.gen_range(0..2) {
+            0 => T::Median,
+            _ => T::Eip1559Projection,
+        }
+    }
+}
+
+impl Distribution<configs::eth_sender::PriorityFeeStrategy> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::eth_sender::PriorityFeeStrategy {
+        type T = configs::eth_sender::PriorityFeeStrategy;
+        match rng.gen_range(0..2) {
+            0 => T::Fixed,
+            _ => T::Percentile,
+        }
+    }
+}
+
 impl Distribution<configs::eth_sender::SenderConfig> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::eth_sender::SenderConfig {
         configs::eth_sender::SenderConfig {
@@ -412,6 +494,10 @@ impl Distribution<configs::eth_sender::SenderConfig> for EncodeDist {
             pubdata_sending_mode: PubdataSendingMode::Calldata,
             tx_aggregation_paused: false,
             tx_aggregation_only_prove_and_execute: false,
+            resend_fee_strategy: self.sample(rng),
+            resend_fee_bump_percent: self.sample(rng),
+            max_resend_fee_per_gas: self.sample(rng),
+            cancel_stuck_txs_after_blocks: self.sample(rng),
         }
     }
 }
@@ -433,6 +519,9 @@ impl Distribution<configs::eth_sender::GasAdjusterConfig> for EncodeDist {
             max_blob_base_fee: self.sample(rng),
             // TODO(EVM-676): generate it randomly once this value is used
             settlement_mode: Default::default(),
+            base_fee_strategy: self.sample(rng),
+            priority_fee_strategy: self.sample(rng),
+            priority_fee_percentile: self.sample(rng),
         }
     }
 }
@@ -650,7 +739,7 @@ impl Distribution<configs::house_keeper::HouseKeeperConfig> for EncodeDist {
 impl Distribution<configs::object_store::ObjectStoreMode> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::object_store::ObjectStoreMode {
         type T = configs::object_store::ObjectStoreMode;
-        match rng.gen_range(0..4) {
+        match rng.gen_range(0..5) {
             0 => T::GCS {
                 bucket_base_url: self.sample(rng),
             },
@@ -661,6 +750,15 @@ impl Distribution<configs::object_store::ObjectStoreMode> for EncodeDist {
             2 => T::FileBacked {
                 file_backed_base_path: self.sample(rng),
             },
+            3 => T::S3 {
+                bucket_name: self.sample(rng),
+                region: self.sample(rng),
+                endpoint: self.sample(rng),
+                addressing_style: self.sample(rng),
+                access_key_id: self.sample(rng),
+                secret_access_key: self
+                    .sample_opt(|| configs::object_store::S3SecretAccessKey(self.sample(rng))),
+            },
             _ => T::GCSAnonymousReadOnly {
                 bucket_base_url: self.sample(rng),
             },
@@ -668,12 +766,24 @@ impl Distribution<configs::object_store::ObjectStoreMode> for EncodeDist {
     }
 }
 
+impl Distribution<configs::object_store::S3AddressingStyle> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::object_store::S3AddressingStyle {
+        type T = configs::object_store::S3AddressingStyle;
+        match rng.gen_range(0..2) {
+            0 => T::VirtualHosted,
+            _ => T::Path,
+        }
+    }
+}
+
 impl Distribution<configs::ObjectStoreConfig> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::ObjectStoreConfig {
         configs::ObjectStoreConfig {
             mode: self.sample(rng),
             max_retries: self.sample(rng),
             local_mirror_path: self.sample(rng),
+            compression_level: self.sample(rng),
+            compression_excluded_buckets: self.sample_collect(rng),
         }
     }
 }
@@ -843,6 +953,10 @@ impl Distribution<configs::secrets::L1Secrets> for EncodeDist {
         use configs::secrets::L1Secrets;
         L1Secrets {
             l1_rpc_url: format!("localhost:{}", rng.gen::<u16>()).parse().unwrap(),
+            fallback_l1_rpc_urls: (0..rng.gen_range(0..3))
+                .map(|_| format!("localhost:{}", rng.gen::<u16>()).parse().unwrap())
+                .collect(),
+            l1_rpc_quorum: self.sample_opt(|| NonZeroUsize::new(rng.gen_range(1..3)).unwrap()),
         }
     }
 }
@@ -1031,6 +1145,32 @@ impl Distribution<configs::pruning::PruningConfig> for EncodeDist {
     }
 }
 
+impl Distribution<configs::object_store_pruning::ObjectRetentionPolicy> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> configs::object_store_pruning::ObjectRetentionPolicy {
+        configs::object_store_pruning::ObjectRetentionPolicy {
+            bucket: self.sample(rng),
+            max_age_sec: self.sample(rng),
+            remove_executed: self.sample(rng),
+        }
+    }
+}
+
+impl Distribution<configs::object_store_pruning::ObjectStorePruningConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> configs::object_store_pruning::ObjectStorePruningConfig {
+        configs::object_store_pruning::ObjectStorePruningConfig {
+            interval_sec: self.sample(rng),
+            dry_run: self.sample(rng),
+            policies: self.sample_collect(rng),
+        }
+    }
+}
+
 impl Distribution<configs::base_token_adjuster::BaseTokenAdjusterConfig> for EncodeDist {
     fn sample<R: Rng + ?Sized>(
         &self,
@@ -1049,6 +1189,10 @@ impl Distribution<configs::base_token_adjuster::BaseTokenAdjusterConfig> for Enc
             price_fetching_max_attempts: self.sample(rng),
             price_fetching_sleep_ms: self.sample(rng),
             halt_on_error: self.sample(rng),
+            max_ratio_change_percent: self.sample_opt(|| rng.gen()),
+            clamp_ratio_changes: self.sample(rng),
+            l1_update_deviation_percent: self.sample(rng),
+            l1_update_max_staleness_ms: self.sample(rng),
         }
     }
 }
@@ -1081,6 +1225,23 @@ impl Distribution<configs::external_price_api_client::ExternalPriceApiClientConf
                 denominator: self.sample(rng),
                 fluctuation: self.sample(rng),
             }),
+            aggregated: self.sample(rng),
+        }
+    }
+}
+
+impl Distribution<AggregatedPriceClientConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> AggregatedPriceClientConfig {
+        AggregatedPriceClientConfig {
+            sources: self.sample_collect(rng),
+            quorum: self.sample(rng),
+            max_deviation_percent: self.sample(rng),
+            max_quote_age_ms: self.sample(rng),
+            coinmarketcap_api_key: self.sample(rng),
+            coinmarketcap_base_url: self.sample(rng),
+            uniswap_v3_pool_address: self.sample_opt(|| rng.gen()),
+            uniswap_v3_quote_token_address: self.sample_opt(|| rng.gen()),
+            uniswap_v3_twap_interval_sec: self.sample(rng),
         }
     }
 }
@@ -1139,6 +1300,7 @@ impl Distribution<configs::GeneralConfig> for EncodeDist {
             snapshot_recovery: self.sample(rng),
             pruning: self.sample(rng),
             core_object_store: self.sample(rng),
+            object_store_pruning: self.sample(rng),
             base_token_adjuster: self.sample(rng),
             external_price_api_client_config: self.sample(rng),
             consensus_config: self.sample(rng),
@@ -1,6 +1,7 @@
 use std::{error, fmt::Display};
 
 use serde::Serialize;
+use zksync_types::ethabi;
 
 /// `DAError` is the error type returned by the DA clients.
 #[derive(Debug)]
@@ -36,9 +37,64 @@ pub struct DispatchResponse {
 }
 
 /// `InclusionData` is the data needed to verify on L1 that a blob is included in the DA layer.
-#[derive(Default, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
 pub struct InclusionData {
     /// The inclusion data serialized by the DA client. Serialization is done in a way that allows
     /// the deserialization of the data in Solidity contracts.
     pub data: Vec<u8>,
 }
+
+impl InclusionData {
+    /// Creates composite inclusion data for the pubdata dispatched in one or more parts. The data is the ABI-encoded
+    /// `bytes[]` array of the inclusion data for each part, in the order of parts in the pubdata.
+    ///
+    /// The DA dispatcher uses this format for all batches, including ones dispatched as a single blob,
+    /// so that the inclusion data has the same layout regardless of the number of parts.
+    pub fn from_parts(parts: Vec<InclusionData>) -> Self {
+        let parts = parts
+            .into_iter()
+            .map(|part| ethabi::Token::Bytes(part.data))
+            .collect();
+        Self {
+            data: ethabi::encode(&[ethabi::Token::Array(parts)]),
+        }
+    }
+
+    /// Decodes composite inclusion data created with [`Self::from_parts()`].
+    pub fn decode_parts(&self) -> anyhow::Result<Vec<InclusionData>> {
+        let param_type = ethabi::ParamType::Array(Box::new(ethabi::ParamType::Bytes));
+        let tokens = ethabi::decode(&[param_type], &self.data)?;
+        let [ethabi::Token::Array(parts)] = tokens.as_slice() else {
+            anyhow::bail!("unexpected composite inclusion data format");
+        };
+        parts
+            .iter()
+            .map(|part| match part {
+                ethabi::Token::Bytes(data) => Ok(InclusionData { data: data.clone() }),
+                _ => Err(anyhow::anyhow!("unexpected inclusion data part format")),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn composite_inclusion_data_roundtrip() {
+        let parts = vec![
+            InclusionData { data: vec![1; 10] },
+            InclusionData { data: vec![] },
+            InclusionData { data: vec![2; 100] },
+        ];
+        let composite = InclusionData::from_parts(parts.clone());
+        let decoded_parts = composite.decode_parts().unwrap();
+        assert_eq!(decoded_parts, parts);
+
+        let single_part = vec![InclusionData { data: vec![3; 32] }];
+        let composite = InclusionData::from_parts(single_part.clone());
+        assert_ne!(composite.data, single_part[0].data);
+        assert_eq!(composite.decode_parts().unwrap(), single_part);
+    }
+}
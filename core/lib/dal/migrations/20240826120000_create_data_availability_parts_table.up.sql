@@ -0,0 +1,17 @@
+-- Parts of the L1 batch pubdata dispatched to the DA layer as separate blobs. Only used for batches
+-- with pubdata exceeding the blob size limit of the DA client; other batches have a single blob
+-- recorded in `data_availability`.
+CREATE TABLE IF NOT EXISTS data_availability_parts
+(
+    l1_batch_number BIGINT    NOT NULL REFERENCES l1_batches (number) ON DELETE CASCADE,
+    part_index      INT       NOT NULL,
+
+    blob_id         TEXT      NOT NULL,
+    inclusion_data  BYTEA,
+    sent_at         TIMESTAMP NOT NULL,
+
+    created_at      TIMESTAMP NOT NULL,
+    updated_at      TIMESTAMP NOT NULL,
+
+    PRIMARY KEY (l1_batch_number, part_index)
+);
@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS base_token_rejected_ratios (
+    id SERIAL PRIMARY KEY,
+    created_at TIMESTAMP NOT NULL,
+
+    ratio_timestamp TIMESTAMP NOT NULL,
+    numerator NUMERIC(20,0) NOT NULL,
+    denominator NUMERIC(20,0) NOT NULL,
+
+    -- Latest persisted ratio the rejected ratio was compared to.
+    reference_ratio_id INT NOT NULL REFERENCES base_token_ratios (id) ON DELETE CASCADE,
+    -- Whether the ratio was clamped to the max allowed change (as opposed to being discarded).
+    clamped BOOLEAN NOT NULL
+);
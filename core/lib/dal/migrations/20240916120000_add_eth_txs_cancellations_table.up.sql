@@ -0,0 +1,12 @@
+-- Pending cancellations (zero-value self-transfers) of in-flight transactions sent by the main operator.
+CREATE TABLE IF NOT EXISTS eth_txs_cancellations (
+    -- Cancelled nonce of the main operator.
+    nonce BIGINT PRIMARY KEY,
+    -- Hash of the last sent cancellation transaction.
+    tx_hash BYTEA NOT NULL,
+    base_fee_per_gas BIGINT NOT NULL,
+    priority_fee_per_gas BIGINT NOT NULL,
+    sent_at_block INT NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL
+);
@@ -58,4 +58,83 @@ impl BaseTokenDal<'_, '_> {
 
         Ok(row.map(|r| r.into()))
     }
+
+    /// Returns the latest ratio that was sent to L1.
+    pub async fn get_latest_l1_ratio(&mut self) -> DalResult<Option<BaseTokenRatio>> {
+        let row = sqlx::query_as!(
+            StorageBaseTokenRatio,
+            r#"
+            SELECT
+                *
+            FROM
+                base_token_ratios
+            WHERE
+                used_in_l1
+            ORDER BY
+                ratio_timestamp DESC
+            LIMIT
+                1
+            "#,
+        )
+        .instrument("get_latest_l1_ratio")
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    pub async fn mark_ratio_as_used_in_l1(&mut self, id: u32) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE base_token_ratios
+            SET
+                used_in_l1 = TRUE,
+                updated_at = NOW()
+            WHERE
+                id = $1
+            "#,
+            id as i32,
+        )
+        .instrument("mark_ratio_as_used_in_l1")
+        .with_arg("id", &id)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Records a ratio that exceeded the allowed change compared to the `reference_ratio_id` ratio.
+    pub async fn insert_rejected_ratio(
+        &mut self,
+        numerator: NonZeroU64,
+        denominator: NonZeroU64,
+        ratio_timestamp: &chrono::NaiveDateTime,
+        reference_ratio_id: u32,
+        clamped: bool,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+                base_token_rejected_ratios (
+                    numerator,
+                    denominator,
+                    ratio_timestamp,
+                    reference_ratio_id,
+                    clamped,
+                    created_at
+                )
+            VALUES
+                ($1, $2, $3, $4, $5, NOW())
+            "#,
+            BigDecimal::from_u64(numerator.get()),
+            BigDecimal::from_u64(denominator.get()),
+            ratio_timestamp,
+            reference_ratio_id as i32,
+            clamped,
+        )
+        .instrument("insert_rejected_ratio")
+        .with_arg("reference_ratio_id", &reference_ratio_id)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
 }
@@ -150,6 +150,31 @@ impl BlocksDal<'_, '_> {
         Ok(row.number.map(|num| L1BatchNumber(num as u32)))
     }
 
+    /// Returns the number of the latest L1 batch with the timestamp less than the specified one, or `None`
+    /// if there are no such batches.
+    pub async fn get_last_l1_batch_number_older_than(
+        &mut self,
+        timestamp: u64,
+    ) -> DalResult<Option<L1BatchNumber>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                MAX(number) AS "number"
+            FROM
+                l1_batches
+            WHERE
+                timestamp < $1
+            "#,
+            timestamp as i64
+        )
+        .instrument("get_last_l1_batch_number_older_than")
+        .with_arg("timestamp", &timestamp)
+        .fetch_one(self.storage)
+        .await?;
+
+        Ok(row.number.map(|num| L1BatchNumber(num as u32)))
+    }
+
     pub async fn get_earliest_l2_block_number(&mut self) -> DalResult<Option<L2BlockNumber>> {
         let row = sqlx::query!(
             r#"
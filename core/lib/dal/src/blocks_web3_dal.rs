@@ -25,6 +25,16 @@ use crate::{
     Core, CoreDal,
 };
 
+/// Call trace of a transaction together with the location of the transaction.
+#[derive(Debug, Clone)]
+pub struct TransactionCallTrace {
+    pub l2_block_number: L2BlockNumber,
+    pub l2_block_hash: H256,
+    pub tx_hash: H256,
+    pub tx_index_in_l2_block: u32,
+    pub call: Call,
+}
+
 #[derive(Debug)]
 pub struct BlocksWeb3Dal<'a, 'c> {
     pub(crate) storage: &'a mut Connection<'c, Core>,
@@ -574,6 +584,62 @@ impl BlocksWeb3Dal<'_, '_> {
         .collect())
     }
 
+    /// Returns call traces for all transactions in the specified inclusive range of L2 blocks, ordered by the L2 block number
+    /// and then by the transaction execution order. Transactions without a persisted call trace are skipped.
+    pub async fn get_traces_for_l2_block_range(
+        &mut self,
+        from_block: L2BlockNumber,
+        to_block: L2BlockNumber,
+    ) -> DalResult<Vec<TransactionCallTrace>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                call_traces.call_trace,
+                call_traces.tx_hash,
+                transactions.index_in_block,
+                miniblocks.number AS "l2_block_number!",
+                miniblocks.hash AS "l2_block_hash!",
+                miniblocks.protocol_version
+            FROM
+                call_traces
+                INNER JOIN transactions ON call_traces.tx_hash = transactions.hash
+                INNER JOIN miniblocks ON transactions.miniblock_number = miniblocks.number
+            WHERE
+                miniblocks.number BETWEEN $1 AND $2
+            ORDER BY
+                miniblocks.number,
+                transactions.index_in_block
+            "#,
+            i64::from(from_block.0),
+            i64::from(to_block.0)
+        )
+        .instrument("get_traces_for_l2_block_range")
+        .with_arg("from_block", &from_block)
+        .with_arg("to_block", &to_block)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let protocol_version = row
+                    .protocol_version
+                    .map(|v| (v as u16).try_into().unwrap())
+                    .unwrap_or_else(ProtocolVersionId::last_potentially_undefined);
+                let call_trace = CallTrace {
+                    call_trace: row.call_trace,
+                };
+                TransactionCallTrace {
+                    l2_block_number: L2BlockNumber(row.l2_block_number as u32),
+                    l2_block_hash: H256::from_slice(&row.l2_block_hash),
+                    tx_hash: H256::from_slice(&row.tx_hash),
+                    tx_index_in_l2_block: row.index_in_block.unwrap_or_default() as u32,
+                    call: call_trace.into_call(protocol_version),
+                }
+            })
+            .collect())
+    }
+
     /// Returns `base_fee_per_gas` and `fair_pubdata_price` for L2 block range [min(newest_block - block_count + 1, 0), newest_block]
     /// in descending order of L2 block numbers.
     pub async fn get_fee_history(
@@ -3,10 +3,13 @@ use zksync_db_connection::{
     error::DalResult,
     instrument::{InstrumentExt, Instrumented},
 };
-use zksync_types::{pubdata_da::DataAvailabilityBlob, L1BatchNumber};
+use zksync_types::{
+    pubdata_da::{DataAvailabilityBlob, DataAvailabilityBlobPart},
+    L1BatchNumber,
+};
 
 use crate::{
-    models::storage_data_availability::{L1BatchDA, StorageDABlob},
+    models::storage_data_availability::{L1BatchDA, StorageDABlob, StorageDABlobPart},
     Core,
 };
 
@@ -147,6 +150,148 @@ impl DataAvailabilityDal<'_, '_> {
         Ok(())
     }
 
+    /// Inserts the blob_id for a part of the pubdata for the given L1 batch. If the blob_id is already present,
+    /// verifies that it matches the one provided in the function arguments.
+    pub async fn insert_l1_batch_da_part(
+        &mut self,
+        number: L1BatchNumber,
+        part_index: u32,
+        blob_id: &str,
+        sent_at: chrono::NaiveDateTime,
+    ) -> DalResult<()> {
+        let update_result = sqlx::query!(
+            r#"
+            INSERT INTO
+                data_availability_parts (
+                    l1_batch_number,
+                    part_index,
+                    blob_id,
+                    sent_at,
+                    created_at,
+                    updated_at
+                )
+            VALUES
+                ($1, $2, $3, $4, NOW(), NOW())
+            ON CONFLICT DO NOTHING
+            "#,
+            i64::from(number.0),
+            part_index as i32,
+            blob_id,
+            sent_at,
+        )
+        .instrument("insert_l1_batch_da_part")
+        .with_arg("number", &number)
+        .with_arg("part_index", &part_index)
+        .with_arg("blob_id", &blob_id)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+
+        if update_result.rows_affected() == 0 {
+            tracing::debug!(
+                "L1 batch #{number}: DA blob_id for part #{part_index} wasn't updated as it's already present"
+            );
+
+            let instrumentation = Instrumented::new("get_matching_batch_da_part_blob_id")
+                .with_arg("number", &number)
+                .with_arg("part_index", &part_index);
+
+            // Part was already processed. Verify that existing DA blob_id matches
+            let query = sqlx::query!(
+                r#"
+                SELECT
+                    blob_id
+                FROM
+                    data_availability_parts
+                WHERE
+                    l1_batch_number = $1
+                    AND part_index = $2
+                "#,
+                i64::from(number.0),
+                part_index as i32,
+            );
+
+            let matched: String = instrumentation
+                .clone()
+                .with(query)
+                .report_latency()
+                .fetch_one(self.storage)
+                .await?
+                .blob_id;
+
+            if matched != blob_id {
+                let err = instrumentation.constraint_error(anyhow::anyhow!(
+                    "Error storing DA blob id. DA blob_id {blob_id} for part #{part_index} of L1 batch #{number} does not match the expected value"
+                ));
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Saves the inclusion data for a part of the pubdata for the given L1 batch. Does nothing if the inclusion data
+    /// is already present.
+    pub async fn save_l1_batch_da_part_inclusion_data(
+        &mut self,
+        number: L1BatchNumber,
+        part_index: u32,
+        da_inclusion_data: &[u8],
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE data_availability_parts
+            SET
+                inclusion_data = $1,
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $2
+                AND part_index = $3
+                AND inclusion_data IS NULL
+            "#,
+            da_inclusion_data,
+            i64::from(number.0),
+            part_index as i32,
+        )
+        .instrument("save_l1_batch_da_part_inclusion_data")
+        .with_arg("number", &number)
+        .with_arg("part_index", &part_index)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Returns the parts of the pubdata dispatched for the given L1 batch ordered by the part index. Returns an empty
+    /// vector if the pubdata for the batch was dispatched as a single blob (or wasn't dispatched yet).
+    pub async fn get_l1_batch_da_parts(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Vec<DataAvailabilityBlobPart>> {
+        let parts = sqlx::query_as!(
+            StorageDABlobPart,
+            r#"
+            SELECT
+                l1_batch_number,
+                part_index,
+                blob_id,
+                inclusion_data,
+                sent_at
+            FROM
+                data_availability_parts
+            WHERE
+                l1_batch_number = $1
+            ORDER BY
+                part_index
+            "#,
+            i64::from(number.0),
+        )
+        .instrument("get_l1_batch_da_parts")
+        .with_arg("number", &number)
+        .fetch_all(self.storage)
+        .await?;
+        Ok(parts.into_iter().map(Into::into).collect())
+    }
+
     /// Assumes that the L1 batches are sorted by number, and returns the first one that is ready for DA dispatch.
     pub async fn get_first_da_blob_awaiting_inclusion(
         &mut self,
@@ -215,3 +360,107 @@ impl DataAvailabilityDal<'_, '_> {
             .collect())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use zksync_types::ProtocolVersion;
+
+    use super::*;
+    use crate::{tests::create_l1_batch_header, ConnectionPool, CoreDal};
+
+    async fn prepare_storage(conn: &mut Connection<'_, Core>) {
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&create_l1_batch_header(1))
+            .await
+            .unwrap();
+    }
+
+    fn sent_at() -> NaiveDateTime {
+        chrono::DateTime::from_timestamp(1_700_000_000, 0)
+            .unwrap()
+            .naive_utc()
+    }
+
+    #[tokio::test]
+    async fn inserting_da_parts() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        prepare_storage(&mut conn).await;
+
+        let number = L1BatchNumber(1);
+        let mut dal = conn.data_availability_dal();
+        assert!(dal.get_l1_batch_da_parts(number).await.unwrap().is_empty());
+
+        // Insert parts out of order to check that they are returned ordered by index.
+        dal.insert_l1_batch_da_part(number, 1, "blob1", sent_at())
+            .await
+            .unwrap();
+        dal.insert_l1_batch_da_part(number, 0, "blob0", sent_at())
+            .await
+            .unwrap();
+        // Repeated insertion with the same blob ID is a no-op.
+        dal.insert_l1_batch_da_part(number, 0, "blob0", sent_at())
+            .await
+            .unwrap();
+        // ...but insertion with a different blob ID is an error.
+        dal.insert_l1_batch_da_part(number, 0, "other", sent_at())
+            .await
+            .unwrap_err();
+
+        let parts = dal.get_l1_batch_da_parts(number).await.unwrap();
+        assert_eq!(parts.len(), 2);
+        for (i, part) in parts.iter().enumerate() {
+            assert_eq!(part.l1_batch_number, number);
+            assert_eq!(part.part_index, i as u32);
+            assert_eq!(part.blob_id, format!("blob{i}"));
+            assert_eq!(part.inclusion_data, None);
+            assert_eq!(part.sent_at, sent_at().and_utc());
+        }
+        // Parts are not visible as the batch-level DA blob.
+        assert!(dal
+            .get_first_da_blob_awaiting_inclusion()
+            .await
+            .unwrap()
+            .is_none());
+        assert!(dal
+            .get_l1_batch_da_parts(L1BatchNumber(2))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn saving_inclusion_data_for_da_parts() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        prepare_storage(&mut conn).await;
+
+        let number = L1BatchNumber(1);
+        let mut dal = conn.data_availability_dal();
+        for i in 0..3 {
+            dal.insert_l1_batch_da_part(number, i, &format!("blob{i}"), sent_at())
+                .await
+                .unwrap();
+        }
+        dal.save_l1_batch_da_part_inclusion_data(number, 1, &[1; 32])
+            .await
+            .unwrap();
+        // Inclusion data is never overwritten.
+        dal.save_l1_batch_da_part_inclusion_data(number, 1, &[2; 32])
+            .await
+            .unwrap();
+        // Saving inclusion data for a non-existing part is a no-op.
+        dal.save_l1_batch_da_part_inclusion_data(number, 5, &[5; 32])
+            .await
+            .unwrap();
+
+        let parts = dal.get_l1_batch_da_parts(number).await.unwrap();
+        let inclusion_data: Vec<_> = parts.into_iter().map(|part| part.inclusion_data).collect();
+        assert_eq!(inclusion_data, [None, Some(vec![1; 32]), None]);
+    }
+}
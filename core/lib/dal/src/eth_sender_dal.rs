@@ -5,8 +5,8 @@ use sqlx::types::chrono::{DateTime, Utc};
 use zksync_db_connection::{connection::Connection, interpolate_query, match_query_as};
 use zksync_types::{
     aggregated_operations::AggregatedActionType,
-    eth_sender::{EthTx, EthTxBlobSidecar, TxHistory, TxHistoryToSend},
-    Address, L1BatchNumber, H256, U256,
+    eth_sender::{EthTx, EthTxBlobSidecar, TxCancellation, TxHistory, TxHistoryToSend},
+    Address, L1BatchNumber, Nonce, H256, U256,
 };
 
 use crate::{
@@ -60,6 +60,35 @@ impl EthSenderDal<'_, '_> {
         Ok(txs.into_iter().map(|tx| tx.into()).collect())
     }
 
+    /// Returns all transactions of the specified operator that are not confirmed yet, including the ones
+    /// that were never sent, ordered by ID.
+    pub async fn get_unconfirmed_txs(
+        &mut self,
+        operator_address: Option<Address>,
+        is_gateway: bool,
+    ) -> sqlx::Result<Vec<EthTx>> {
+        let txs = sqlx::query_as!(
+            StorageEthTx,
+            r#"
+            SELECT
+                *
+            FROM
+                eth_txs
+            WHERE
+                from_addr IS NOT DISTINCT FROM $1 -- can't just use equality as NULL != NULL
+                AND confirmed_eth_tx_history_id IS NULL
+                AND is_gateway = $2
+            ORDER BY
+                id
+            "#,
+            operator_address.as_ref().map(|h160| h160.as_bytes()),
+            is_gateway
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+        Ok(txs.into_iter().map(|tx| tx.into()).collect())
+    }
+
     pub async fn get_non_gateway_inflight_txs_count_for_gateway_migration(
         &mut self,
     ) -> sqlx::Result<usize> {
@@ -652,6 +681,190 @@ impl EthSenderDal<'_, '_> {
         Ok(nonce.map(|row| row.nonce as u64 + 1))
     }
 
+    /// Assigns consecutive nonces starting from `first_nonce` to all unconfirmed transactions of the specified operator
+    /// with IDs starting from `first_eth_tx_id`, and removes all their sending attempts since these were signed
+    /// with the previous nonces. As a result, the transactions are considered new and will be sent again.
+    /// For the main operator, pending cancellations are removed as well. The caller must ensure that the operator
+    /// has no transactions in the mempool (i.e., its pending nonce is equal to `first_nonce`).
+    ///
+    /// Returns the number of transactions with reassigned nonces.
+    pub async fn reassign_nonces(
+        &mut self,
+        first_eth_tx_id: u32,
+        operator_address: Option<Address>,
+        is_gateway: bool,
+        first_nonce: u64,
+    ) -> anyhow::Result<u64> {
+        let mut transaction = self
+            .storage
+            .start_transaction()
+            .await
+            .context("start_transaction()")?;
+        let operator_address = operator_address.as_ref().map(|h160| h160.as_bytes());
+
+        sqlx::query!(
+            r#"
+            DELETE FROM eth_txs_history
+            WHERE
+                eth_tx_id IN (
+                    SELECT
+                        id
+                    FROM
+                        eth_txs
+                    WHERE
+                        id >= $1
+                        AND from_addr IS NOT DISTINCT FROM $2
+                        AND is_gateway = $3
+                        AND confirmed_eth_tx_history_id IS NULL
+                )
+            "#,
+            first_eth_tx_id as i32,
+            operator_address,
+            is_gateway
+        )
+        .execute(transaction.conn())
+        .await?;
+
+        let reassigned_count = sqlx::query!(
+            r#"
+            UPDATE eth_txs
+            SET
+                nonce = renumbered.nonce,
+                updated_at = NOW()
+            FROM
+                (
+                    SELECT
+                        id,
+                        $4 + ROW_NUMBER() OVER (
+                            ORDER BY
+                                id
+                        ) - 1 AS nonce
+                    FROM
+                        eth_txs
+                    WHERE
+                        id >= $1
+                        AND from_addr IS NOT DISTINCT FROM $2
+                        AND is_gateway = $3
+                        AND confirmed_eth_tx_history_id IS NULL
+                ) AS renumbered
+            WHERE
+                eth_txs.id = renumbered.id
+            "#,
+            first_eth_tx_id as i32,
+            operator_address,
+            is_gateway,
+            i64::try_from(first_nonce).context("Can't convert u64 to i64")?
+        )
+        .execute(transaction.conn())
+        .await?
+        .rows_affected();
+
+        if operator_address.is_none() && !is_gateway {
+            // Cancellations are tied to the previous nonces of the main operator.
+            sqlx::query!(
+                r#"
+                DELETE FROM eth_txs_cancellations
+                "#
+            )
+            .execute(transaction.conn())
+            .await?;
+        }
+
+        transaction.commit().await?;
+        Ok(reassigned_count)
+    }
+
+    /// Returns pending cancellations of the main operator transactions ordered by nonce.
+    pub async fn get_tx_cancellations(&mut self) -> sqlx::Result<Vec<TxCancellation>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                nonce,
+                tx_hash,
+                base_fee_per_gas,
+                priority_fee_per_gas,
+                sent_at_block
+            FROM
+                eth_txs_cancellations
+            ORDER BY
+                nonce
+            "#
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| TxCancellation {
+                nonce: Nonce(row.nonce as u32),
+                tx_hash: H256::from_slice(&row.tx_hash),
+                base_fee_per_gas: row.base_fee_per_gas as u64,
+                priority_fee_per_gas: row.priority_fee_per_gas as u64,
+                sent_at_block: row.sent_at_block as u32,
+            })
+            .collect())
+    }
+
+    /// Inserts a cancellation of the main operator transaction, or replaces the previously sent cancellation
+    /// with the same nonce.
+    pub async fn upsert_tx_cancellation(
+        &mut self,
+        cancellation: &TxCancellation,
+    ) -> anyhow::Result<()> {
+        let base_fee_per_gas =
+            i64::try_from(cancellation.base_fee_per_gas).context("Can't convert u64 to i64")?;
+        let priority_fee_per_gas =
+            i64::try_from(cancellation.priority_fee_per_gas).context("Can't convert u64 to i64")?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO
+                eth_txs_cancellations (
+                    nonce,
+                    tx_hash,
+                    base_fee_per_gas,
+                    priority_fee_per_gas,
+                    sent_at_block,
+                    created_at,
+                    updated_at
+                )
+            VALUES
+                ($1, $2, $3, $4, $5, NOW(), NOW())
+            ON CONFLICT (nonce) DO
+            UPDATE
+            SET
+                tx_hash = excluded.tx_hash,
+                base_fee_per_gas = excluded.base_fee_per_gas,
+                priority_fee_per_gas = excluded.priority_fee_per_gas,
+                sent_at_block = excluded.sent_at_block,
+                updated_at = NOW()
+            "#,
+            i64::from(cancellation.nonce.0),
+            cancellation.tx_hash.as_bytes(),
+            base_fee_per_gas,
+            priority_fee_per_gas,
+            cancellation.sent_at_block as i32
+        )
+        .execute(self.storage.conn())
+        .await?;
+        Ok(())
+    }
+
+    /// Removes cancellations with nonces less than `nonce`.
+    pub async fn delete_tx_cancellations_before(&mut self, nonce: Nonce) -> sqlx::Result<()> {
+        sqlx::query!(
+            r#"
+            DELETE FROM eth_txs_cancellations
+            WHERE
+                nonce < $1
+            "#,
+            i64::from(nonce.0)
+        )
+        .execute(self.storage.conn())
+        .await?;
+        Ok(())
+    }
+
     pub async fn mark_failed_transaction(&mut self, eth_tx_id: u32) -> sqlx::Result<()> {
         sqlx::query!(
             r#"
@@ -64,6 +64,8 @@ pub struct StorageTransaction {
     pub l1_tx_refund_recipient: Option<Vec<u8>>,
 
     pub upgrade_id: Option<i32>,
+    /// Private transactions are not exposed via API until they are included into an L2 block.
+    pub is_private: bool,
 
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
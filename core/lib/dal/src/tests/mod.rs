@@ -296,7 +296,7 @@ async fn remove_stuck_txs() {
     // Get all txs
     transactions_dal.reset_mempool().await.unwrap();
     let txs = transactions_dal
-        .sync_mempool(&[], &[], 0, 0, 1000)
+        .sync_mempool(&[], &[], &[], 0, 0, false, 1000)
         .await
         .unwrap();
     assert_eq!(txs.len(), 4);
@@ -322,7 +322,7 @@ async fn remove_stuck_txs() {
     // Get all txs
     transactions_dal.reset_mempool().await.unwrap();
     let txs = transactions_dal
-        .sync_mempool(&[], &[], 0, 0, 1000)
+        .sync_mempool(&[], &[], &[], 0, 0, false, 1000)
         .await
         .unwrap();
     assert_eq!(txs.len(), 3);
@@ -335,7 +335,7 @@ async fn remove_stuck_txs() {
     assert_eq!(removed_txs, 1);
     transactions_dal.reset_mempool().await.unwrap();
     let txs = transactions_dal
-        .sync_mempool(&[], &[], 0, 0, 1000)
+        .sync_mempool(&[], &[], &[], 0, 0, false, 1000)
         .await
         .unwrap();
     assert_eq!(txs.len(), 2);
@@ -350,3 +350,42 @@ async fn remove_stuck_txs() {
 
     assert_eq!(receipts.len(), 1);
 }
+
+#[tokio::test]
+async fn sync_mempool_with_fee_ordering() {
+    let connection_pool = ConnectionPool::<Core>::test_pool().await;
+    let storage = &mut connection_pool.connection().await.unwrap();
+    let mut protocol_versions_dal = ProtocolVersionsDal { storage };
+    protocol_versions_dal
+        .save_protocol_version_with_tx(&ProtocolVersion::default())
+        .await
+        .unwrap();
+
+    let storage = protocol_versions_dal.storage;
+    let mut transactions_dal = TransactionsDal { storage };
+    let mut cheap_tx = mock_l2_transaction();
+    cheap_tx.received_timestamp_ms = unix_timestamp_ms() - 1_000;
+    let mut expensive_tx = mock_l2_transaction();
+    expensive_tx.common_data.fee.max_fee_per_gas = U256::from(500_000_000_u32);
+    for tx in [&cheap_tx, &expensive_tx] {
+        transactions_dal
+            .insert_transaction_l2(tx, mock_tx_execution_metrics())
+            .await
+            .unwrap();
+    }
+
+    let txs = transactions_dal
+        .sync_mempool(&[], &[], &[], 0, 0, false, 1)
+        .await
+        .unwrap();
+    assert_eq!(txs.len(), 1);
+    assert_eq!(txs[0].hash(), cheap_tx.hash());
+
+    transactions_dal.reset_mempool().await.unwrap();
+    let txs = transactions_dal
+        .sync_mempool(&[], &[], &[], 0, 0, true, 1)
+        .await
+        .unwrap();
+    assert_eq!(txs.len(), 1);
+    assert_eq!(txs[0].hash(), expensive_tx.hash());
+}
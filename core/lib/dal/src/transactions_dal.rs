@@ -11,10 +11,10 @@ use zksync_db_connection::{
 };
 use zksync_types::{
     block::L2BlockExecutionData, l1::L1Tx, l2::L2Tx, protocol_upgrade::ProtocolUpgradeTx, Address,
-    ExecuteTransactionCommon, L1BatchNumber, L1BlockNumber, L2BlockNumber, PriorityOpId,
+    ExecuteTransactionCommon, L1BatchNumber, L1BlockNumber, L2BlockNumber, Nonce, PriorityOpId,
     ProtocolVersionId, Transaction, H256, PROTOCOL_UPGRADE_TX_TYPE, U256,
 };
-use zksync_utils::u256_to_big_decimal;
+use zksync_utils::{bigdecimal_to_u256, u256_to_big_decimal};
 use zksync_vm_interface::{
     Call, TransactionExecutionMetrics, TransactionExecutionResult, TxExecutionStatus,
 };
@@ -47,6 +47,9 @@ impl fmt::Display for L2TxSubmissionResult {
     }
 }
 
+/// Error recorded for transactions evicted from the mempool.
+const EVICTED_TX_ERROR: &str = "evicted from mempool: mempool capacity exceeded";
+
 #[derive(Debug)]
 pub struct TransactionsDal<'c, 'a> {
     pub(crate) storage: &'c mut Connection<'a, Core>,
@@ -259,6 +262,62 @@ impl TransactionsDal<'_, '_> {
         &mut self,
         tx: &L2Tx,
         exec_info: TransactionExecutionMetrics,
+    ) -> DalResult<L2TxSubmissionResult> {
+        self.insert_transaction_l2_inner(tx, exec_info, false).await
+    }
+
+    /// Same as [`Self::insert_transaction_l2()`], but marks the transaction as private. Private transactions
+    /// are not returned by Web3 DAL methods (e.g., [`TransactionsWeb3Dal::get_transaction_by_hash()`])
+    /// until they are included into an L2 block.
+    ///
+    /// [`TransactionsWeb3Dal::get_transaction_by_hash()`]: crate::transactions_web3_dal::TransactionsWeb3Dal::get_transaction_by_hash()
+    pub async fn insert_private_transaction_l2(
+        &mut self,
+        tx: &L2Tx,
+        exec_info: TransactionExecutionMetrics,
+    ) -> DalResult<L2TxSubmissionResult> {
+        self.insert_transaction_l2_inner(tx, exec_info, true).await
+    }
+
+    /// Returns `max_fee_per_gas` of the pending L2 transaction (i.e., one not included into an L2 block yet)
+    /// with the specified initiator and nonce. Such a transaction will be replaced by
+    /// [`Self::insert_transaction_l2()`] for a transaction with the same initiator and nonce.
+    pub async fn get_pending_l2_tx_max_fee_per_gas(
+        &mut self,
+        initiator_address: Address,
+        nonce: Nonce,
+    ) -> DalResult<Option<U256>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                max_fee_per_gas
+            FROM
+                transactions
+            WHERE
+                initiator_address = $1
+                AND nonce = $2
+                AND is_priority = FALSE
+                AND miniblock_number IS NULL
+            "#,
+            initiator_address.as_bytes(),
+            i64::from(nonce.0)
+        )
+        .instrument("get_pending_l2_tx_max_fee_per_gas")
+        .with_arg("initiator_address", &initiator_address)
+        .with_arg("nonce", &nonce)
+        .fetch_optional(self.storage)
+        .await?;
+        Ok(row.map(|row| {
+            row.max_fee_per_gas
+                .map_or_else(U256::zero, bigdecimal_to_u256)
+        }))
+    }
+
+    async fn insert_transaction_l2_inner(
+        &mut self,
+        tx: &L2Tx,
+        exec_info: TransactionExecutionMetrics,
+        is_private: bool,
     ) -> DalResult<L2TxSubmissionResult> {
         let tx_hash = tx.hash();
         let is_duplicate = sqlx::query!(
@@ -340,6 +399,7 @@ impl TransactionsDal<'_, '_> {
                     paymaster_input,
                     execution_info,
                     received_at,
+                    is_private,
                     created_at,
                     updated_at
                 )
@@ -363,6 +423,7 @@ impl TransactionsDal<'_, '_> {
                     $15,
                     JSONB_BUILD_OBJECT('gas_used', $16::BIGINT, 'storage_writes', $17::INT, 'contracts_used', $18::INT),
                     $19,
+                    $20,
                     NOW(),
                     NOW()
                 )
@@ -385,6 +446,7 @@ impl TransactionsDal<'_, '_> {
                 execution_info = JSONB_BUILD_OBJECT('gas_used', $16::BIGINT, 'storage_writes', $17::INT, 'contracts_used', $18::INT),
                 in_mempool = FALSE,
                 received_at = $19,
+                is_private = $20,
                 created_at = NOW(),
                 updated_at = NOW(),
                 error = NULL
@@ -420,10 +482,12 @@ impl TransactionsDal<'_, '_> {
             exec_info.gas_used as i64,
             (exec_info.initial_storage_writes + exec_info.repeated_storage_writes) as i32,
             exec_info.contracts_used as i32,
-            received_at
+            received_at,
+            is_private
         )
         .instrument("insert_transaction_l2")
         .with_arg("tx_hash", &tx_hash)
+        .with_arg("is_private", &is_private)
         .fetch_optional(self.storage)
         .await
         .map(|option_record| option_record.map(|record| record.is_replaced));
@@ -1690,12 +1754,19 @@ impl TransactionsDal<'_, '_> {
 
     /// Fetches new updates for mempool. Returns new transactions and current nonces for related accounts;
     /// the latter are only used to bootstrap mempool for given account.
+    ///
+    /// L1 transactions are always returned first. If `order_by_fee` is set, L2 transactions are returned
+    /// in the order of decreasing `max_fee_per_gas`, so that the most profitable transactions are loaded
+    /// to the mempool first; otherwise, they are returned in the order they were received.
+    #[allow(clippy::too_many_arguments)]
     pub async fn sync_mempool(
         &mut self,
         stashed_accounts: &[Address],
         purged_accounts: &[Address],
+        evicted_transactions: &[H256],
         gas_per_pubdata: u32,
         fee_per_gas: u64,
+        order_by_fee: bool,
         limit: usize,
     ) -> DalResult<Vec<Transaction>> {
         let stashed_addresses: Vec<_> = stashed_accounts.iter().map(Address::as_bytes).collect();
@@ -1732,6 +1803,28 @@ impl TransactionsDal<'_, '_> {
         .execute(self.storage)
         .await?;
 
+        // Evicted transactions are marked as rejected (rather than removed), so that their status can be queried
+        // via API. Like other rejected transactions, they can be replaced by a transaction with the same nonce.
+        let evicted_hashes: Vec<_> = evicted_transactions.iter().map(H256::as_bytes).collect();
+        sqlx::query!(
+            r#"
+            UPDATE transactions
+            SET
+                in_mempool = FALSE,
+                error = $2,
+                updated_at = NOW()
+            WHERE
+                in_mempool = TRUE
+                AND hash = ANY ($1)
+            "#,
+            &evicted_hashes as &[&[u8]],
+            EVICTED_TX_ERROR
+        )
+        .instrument("sync_mempool#reject_evicted")
+        .with_arg("evicted_hashes.len", &evicted_hashes.len())
+        .execute(self.storage)
+        .await?;
+
         // Note, that transactions are updated in order of their hashes to avoid deadlocks with other UPDATE queries.
         let transactions = sqlx::query_as!(
             StorageTransaction,
@@ -1764,6 +1857,9 @@ impl TransactionsDal<'_, '_> {
                             ORDER BY
                                 is_priority DESC,
                                 priority_op_id,
+                                CASE
+                                    WHEN $5 THEN max_fee_per_gas
+                                END DESC NULLS LAST,
                                 received_at
                             LIMIT
                                 $1
@@ -1779,11 +1875,13 @@ impl TransactionsDal<'_, '_> {
             limit as i32,
             BigDecimal::from(fee_per_gas),
             BigDecimal::from(gas_per_pubdata),
-            i32::from(PROTOCOL_UPGRADE_TX_TYPE)
+            i32::from(PROTOCOL_UPGRADE_TX_TYPE),
+            order_by_fee
         )
         .instrument("sync_mempool")
         .with_arg("fee_per_gas", &fee_per_gas)
         .with_arg("gas_per_pubdata", &gas_per_pubdata)
+        .with_arg("order_by_fee", &order_by_fee)
         .with_arg("limit", &limit)
         .fetch_all(self.storage)
         .await?;
@@ -1975,6 +2073,33 @@ impl TransactionsDal<'_, '_> {
             .await
     }
 
+    /// Returns transactions included into the specified L2 block in the order of their execution.
+    pub async fn get_l2_block_transactions(
+        &mut self,
+        l2_block_number: L2BlockNumber,
+    ) -> DalResult<Vec<Transaction>> {
+        let transactions = sqlx::query_as!(
+            StorageTransaction,
+            r#"
+            SELECT
+                *
+            FROM
+                transactions
+            WHERE
+                miniblock_number = $1
+            ORDER BY
+                index_in_block
+            "#,
+            i64::from(l2_block_number.0)
+        )
+        .instrument("get_l2_block_transactions")
+        .with_arg("l2_block_number", &l2_block_number)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(transactions.into_iter().map(Transaction::from).collect())
+    }
+
     async fn map_transactions_to_execution_data(
         &mut self,
         transactions: Vec<StorageTransaction>,
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, iter::once};
+use std::{collections::HashMap, iter::once, ops};
 
 use anyhow::Context as _;
 use sqlx::types::chrono::NaiveDateTime;
@@ -9,8 +9,8 @@ use zksync_db_connection::{
     interpolate_query, match_query_as,
 };
 use zksync_types::{
-    api, api::TransactionReceipt, block::build_bloom, Address, BloomInput, L2BlockNumber,
-    L2ChainId, Transaction, CONTRACT_DEPLOYER_ADDRESS, H256, U256,
+    api, api::TransactionReceipt, block::build_bloom, Address, BloomInput, L1BatchNumber,
+    L2BlockNumber, L2ChainId, Nonce, Transaction, CONTRACT_DEPLOYER_ADDRESS, H256, U256,
 };
 use zksync_vm_interface::VmEvent;
 
@@ -22,6 +22,18 @@ use crate::{
     Core, CoreDal,
 };
 
+/// Counts of pending L2 transactions in the mempool for a single account.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingTransactionCounts {
+    pub initiator_address: Address,
+    /// Total number of pending transactions.
+    pub total: u64,
+    /// Minimum nonce among pending transactions.
+    pub min_nonce: Nonce,
+    /// Number of pending transactions with consecutive nonces starting from `min_nonce`.
+    pub consecutive: u64,
+}
+
 #[derive(Debug, Clone, Copy)]
 enum TransactionSelector<'a> {
     Hashes(&'a [H256]),
@@ -233,7 +245,9 @@ impl TransactionsWeb3Dal<'_, '_> {
             ],
             match (selector) {
                 TransactionSelector::Hashes(hashes) => (
-                    "transactions.hash = ANY($1)";
+                    // Private transactions are hidden until they are included into an L2 block.
+                    "transactions.hash = ANY($1) \
+                     AND (transactions.is_private = FALSE OR transactions.miniblock_number IS NOT NULL)";
                     &hashes.iter().map(H256::as_bytes).collect::<Vec<_>>() as &[&[u8]]
                 ),
                 TransactionSelector::Position(block_number, idx) => (
@@ -319,6 +333,10 @@ impl TransactionsWeb3Dal<'_, '_> {
             WHERE
                 transactions.hash = $1
                 AND transactions.data != '{}'::jsonb
+                AND (
+                    transactions.is_private = FALSE
+                    OR transactions.miniblock_number IS NOT NULL
+                )
             "#,
             // ^ Filter out transactions with pruned data, which would lead to potentially incomplete / bogus
             // transaction info.
@@ -347,6 +365,7 @@ impl TransactionsWeb3Dal<'_, '_> {
                 transactions
             WHERE
                 received_at > $1
+                AND is_private = FALSE
             ORDER BY
                 received_at ASC
             LIMIT
@@ -368,6 +387,193 @@ impl TransactionsWeb3Dal<'_, '_> {
         Ok(hashes)
     }
 
+    /// Returns hashes and initiator addresses of transactions included into the specified range of L2 blocks,
+    /// together with the number of the including L2 block. Transactions are ordered by their position in the chain.
+    pub async fn get_tx_initiators_for_l2_blocks(
+        &mut self,
+        l2_blocks: ops::RangeInclusive<L2BlockNumber>,
+    ) -> DalResult<Vec<(H256, Address, L2BlockNumber)>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                hash,
+                initiator_address,
+                miniblock_number AS "miniblock_number!"
+            FROM
+                transactions
+            WHERE
+                miniblock_number BETWEEN $1 AND $2
+            ORDER BY
+                miniblock_number,
+                index_in_block
+            "#,
+            i64::from(l2_blocks.start().0),
+            i64::from(l2_blocks.end().0)
+        )
+        .instrument("get_tx_initiators_for_l2_blocks")
+        .with_arg("l2_blocks", &l2_blocks)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                (
+                    H256::from_slice(&row.hash),
+                    Address::from_slice(&row.initiator_address),
+                    L2BlockNumber(row.miniblock_number as u32),
+                )
+            })
+            .collect())
+    }
+
+    /// Returns hashes and initiator addresses of transactions included into the specified range of L1 batches,
+    /// together with the numbers of the including L2 block and L1 batch. Transactions are ordered by their position
+    /// in the chain.
+    pub async fn get_tx_initiators_for_l1_batches(
+        &mut self,
+        l1_batches: ops::RangeInclusive<L1BatchNumber>,
+    ) -> DalResult<Vec<(H256, Address, L2BlockNumber, L1BatchNumber)>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                hash,
+                initiator_address,
+                miniblock_number AS "miniblock_number!",
+                l1_batch_number AS "l1_batch_number!"
+            FROM
+                transactions
+            WHERE
+                l1_batch_number BETWEEN $1 AND $2
+            ORDER BY
+                miniblock_number,
+                index_in_block
+            "#,
+            i64::from(l1_batches.start().0),
+            i64::from(l1_batches.end().0)
+        )
+        .instrument("get_tx_initiators_for_l1_batches")
+        .with_arg("l1_batches", &l1_batches)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                (
+                    H256::from_slice(&row.hash),
+                    Address::from_slice(&row.initiator_address),
+                    L2BlockNumber(row.miniblock_number as u32),
+                    L1BatchNumber(row.l1_batch_number as u32),
+                )
+            })
+            .collect())
+    }
+
+    /// Returns L2 transactions in the mempool, i.e. ones that are neither included into an L2 block nor rejected.
+    /// Transactions are ordered by the initiator address and nonce. If `initiator_address` is specified,
+    /// only transactions from this account are returned.
+    pub async fn get_pending_l2_transactions(
+        &mut self,
+        initiator_address: Option<Address>,
+        limit: usize,
+        chain_id: L2ChainId,
+    ) -> DalResult<Vec<api::Transaction>> {
+        let hashes: Vec<_> = sqlx::query!(
+            r#"
+            SELECT
+                hash
+            FROM
+                transactions
+            WHERE
+                miniblock_number IS NULL
+                AND error IS NULL
+                AND is_priority = FALSE
+                AND is_private = FALSE
+                AND (
+                    $1::BYTEA IS NULL
+                    OR initiator_address = $1
+                )
+            ORDER BY
+                initiator_address,
+                nonce
+            LIMIT
+                $2
+            "#,
+            initiator_address.as_ref().map(Address::as_bytes),
+            limit as i64
+        )
+        .instrument("get_pending_l2_transactions")
+        .with_arg("initiator_address", &initiator_address)
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?
+        .into_iter()
+        .map(|row| H256::from_slice(&row.hash))
+        .collect();
+
+        let mut transactions = self.get_transactions(&hashes, chain_id).await?;
+        transactions.sort_unstable_by_key(|tx| (tx.from, tx.nonce));
+        Ok(transactions)
+    }
+
+    /// Returns counts of all pending L2 transactions grouped by the initiator address. Unlike
+    /// [`Self::get_pending_l2_transactions()`], the output isn't limited.
+    pub async fn get_pending_l2_transaction_counts(
+        &mut self,
+    ) -> DalResult<Vec<PendingTransactionCounts>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                initiator_address AS "initiator_address!",
+                COUNT(*) AS "total!",
+                MIN(nonce) AS "min_nonce!",
+                COUNT(*) FILTER (
+                    WHERE
+                        nonce - nonce_index = min_nonce
+                ) AS "consecutive!"
+            FROM
+                (
+                    SELECT
+                        initiator_address,
+                        nonce,
+                        ROW_NUMBER() OVER (
+                            PARTITION BY
+                                initiator_address
+                            ORDER BY
+                                nonce
+                        ) - 1 AS nonce_index,
+                        MIN(nonce) OVER (
+                            PARTITION BY
+                                initiator_address
+                        ) AS min_nonce
+                    FROM
+                        transactions
+                    WHERE
+                        miniblock_number IS NULL
+                        AND error IS NULL
+                        AND is_priority = FALSE
+                        AND is_private = FALSE
+                ) AS pending_transactions
+            GROUP BY
+                initiator_address
+            "#
+        )
+        .instrument("get_pending_l2_transaction_counts")
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| PendingTransactionCounts {
+                initiator_address: Address::from_slice(&row.initiator_address),
+                total: row.total as u64,
+                min_nonce: Nonce(row.min_nonce as u32),
+                consecutive: row.consecutive as u64,
+            })
+            .collect())
+    }
+
     /// `committed_next_nonce` should equal the nonce for `initiator_address` in the storage.
     pub async fn next_nonce_by_initiator_account(
         &mut self,
@@ -493,7 +699,10 @@ mod tests {
 
     use super::*;
     use crate::{
-        tests::{create_l2_block_header, mock_execution_result, mock_l2_transaction},
+        tests::{
+            create_l1_batch_header, create_l2_block_header, mock_execution_result,
+            mock_l2_transaction,
+        },
         ConnectionPool, Core, CoreDal,
     };
 
@@ -736,6 +945,198 @@ mod tests {
         assert_eq!(next_nonce, 2.into());
     }
 
+    #[tokio::test]
+    async fn getting_pending_l2_transactions() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let initiators = [Address::repeat_byte(1), Address::repeat_byte(2)];
+        let mut txs = vec![];
+        for (initiator, nonce) in [(0, 2), (0, 0), (1, 5), (0, 1)] {
+            let mut tx = mock_l2_transaction();
+            // Changing transaction fields invalidates its signature, but it's OK for test purposes
+            tx.common_data.nonce = Nonce(nonce);
+            tx.common_data.initiator_address = initiators[initiator];
+            conn.transactions_dal()
+                .insert_transaction_l2(&tx, TransactionExecutionMetrics::default())
+                .await
+                .unwrap();
+            txs.push(tx);
+        }
+        conn.transactions_dal()
+            .mark_tx_as_rejected(txs[0].hash(), "oops")
+            .await
+            .unwrap();
+
+        let chain_id = L2ChainId::default();
+        let pending_txs = conn
+            .transactions_web3_dal()
+            .get_pending_l2_transactions(None, 100, chain_id)
+            .await
+            .unwrap();
+        let pending_txs: Vec<_> = pending_txs
+            .iter()
+            .map(|tx| (tx.from.unwrap(), tx.nonce.as_u32()))
+            .collect();
+        assert_eq!(
+            pending_txs,
+            [(initiators[0], 0), (initiators[0], 1), (initiators[1], 5)]
+        );
+
+        let pending_txs = conn
+            .transactions_web3_dal()
+            .get_pending_l2_transactions(Some(initiators[1]), 100, chain_id)
+            .await
+            .unwrap();
+        assert_eq!(pending_txs.len(), 1);
+        assert_eq!(pending_txs[0].hash, txs[2].hash());
+
+        let pending_txs = conn
+            .transactions_web3_dal()
+            .get_pending_l2_transactions(None, 1, chain_id)
+            .await
+            .unwrap();
+        assert_eq!(pending_txs.len(), 1);
+        assert_eq!(pending_txs[0].hash, txs[1].hash());
+    }
+
+    #[tokio::test]
+    async fn private_transactions_are_hidden_until_included() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let public_tx = mock_l2_transaction();
+        let mut private_tx = mock_l2_transaction();
+        // Changing transaction fields invalidates its signature, but it's OK for test purposes
+        private_tx.common_data.initiator_address = Address::repeat_byte(1);
+        conn.transactions_dal()
+            .insert_transaction_l2(&public_tx, TransactionExecutionMetrics::default())
+            .await
+            .unwrap();
+        conn.transactions_dal()
+            .insert_private_transaction_l2(&private_tx, TransactionExecutionMetrics::default())
+            .await
+            .unwrap();
+
+        let chain_id = L2ChainId::default();
+        let pending_hashes = conn
+            .transactions_web3_dal()
+            .get_pending_txs_hashes_after(NaiveDateTime::default(), None)
+            .await
+            .unwrap();
+        let pending_hashes: Vec<_> = pending_hashes.into_iter().map(|(_, hash)| hash).collect();
+        assert_eq!(pending_hashes, [public_tx.hash()]);
+        let pending_txs = conn
+            .transactions_web3_dal()
+            .get_pending_l2_transactions(None, 100, chain_id)
+            .await
+            .unwrap();
+        assert_eq!(pending_txs.len(), 1);
+        assert_eq!(pending_txs[0].hash, public_tx.hash());
+
+        let web3_tx = conn
+            .transactions_web3_dal()
+            .get_transaction_by_hash(private_tx.hash(), chain_id)
+            .await
+            .unwrap();
+        assert!(web3_tx.is_none(), "{web3_tx:?}");
+        let tx_details = conn
+            .transactions_web3_dal()
+            .get_transaction_details(private_tx.hash())
+            .await
+            .unwrap();
+        assert!(tx_details.is_none(), "{tx_details:?}");
+
+        // Include the private transaction into an L2 block; it should become visible.
+        conn.blocks_dal()
+            .insert_l2_block(&create_l2_block_header(0))
+            .await
+            .unwrap();
+        let mut l2_block_header = create_l2_block_header(1);
+        l2_block_header.l2_tx_count = 1;
+        conn.blocks_dal()
+            .insert_l2_block(&l2_block_header)
+            .await
+            .unwrap();
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l2_block(
+                L2BlockNumber(1),
+                &[mock_execution_result(private_tx.clone())],
+                U256::from(1),
+                ProtocolVersionId::latest(),
+                false,
+            )
+            .await
+            .unwrap();
+
+        let web3_tx = conn
+            .transactions_web3_dal()
+            .get_transaction_by_hash(private_tx.hash(), chain_id)
+            .await
+            .unwrap()
+            .expect("private transaction is not returned after inclusion");
+        assert_eq!(web3_tx.block_number, Some(1.into()));
+        let tx_details = conn
+            .transactions_web3_dal()
+            .get_transaction_details(private_tx.hash())
+            .await
+            .unwrap();
+        assert!(tx_details.is_some());
+    }
+
+    #[tokio::test]
+    async fn getting_tx_initiators() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        let tx = mock_l2_transaction();
+        let tx_hash = tx.hash();
+        let initiator = tx.initiator_account();
+        prepare_transactions(&mut conn, vec![tx.clone()]).await;
+
+        let initiators = conn
+            .transactions_web3_dal()
+            .get_tx_initiators_for_l2_blocks(L2BlockNumber(0)..=L2BlockNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(initiators, [(tx_hash, initiator, L2BlockNumber(1))]);
+        let initiators = conn
+            .transactions_web3_dal()
+            .get_tx_initiators_for_l2_blocks(L2BlockNumber(2)..=L2BlockNumber(10))
+            .await
+            .unwrap();
+        assert_eq!(initiators, []);
+
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&create_l1_batch_header(1))
+            .await
+            .unwrap();
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l1_batch(L1BatchNumber(1), &[mock_execution_result(tx)])
+            .await
+            .unwrap();
+        let initiators = conn
+            .transactions_web3_dal()
+            .get_tx_initiators_for_l1_batches(L1BatchNumber(1)..=L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(
+            initiators,
+            [(tx_hash, initiator, L2BlockNumber(1), L1BatchNumber(1))]
+        );
+    }
+
     #[tokio::test]
     async fn getting_next_nonce_by_initiator_account_after_snapshot_recovery() {
         // Emulate snapshot recovery: no transactions with past nonces are present in the storage
@@ -0,0 +1,291 @@
+use anyhow::Context as _;
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+
+use crate::avail::{
+    config::AvailConfig,
+    types::{BlobId, BridgeProof, SubmitRequest, SubmitResponse},
+};
+
+/// An implementation of the `DataAvailabilityClient` trait that submits the pubdata to Avail
+/// via the HTTP API of an Avail light client.
+///
+/// Blob IDs have the `{hex(block_hash)}-{tx_index}` format. Inclusion data is obtained from the Avail bridge API
+/// once the block with the data is bridged to L1, and is the ABI-encoded Merkle proof that can be verified
+/// by the Avail bridge contracts.
+#[derive(Debug, Clone)]
+pub struct AvailClient {
+    client: reqwest::Client,
+    api_node_url: String,
+    bridge_api_url: String,
+    blob_size_limit: usize,
+}
+
+impl AvailClient {
+    pub fn new(config: AvailConfig) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout())
+            .build()
+            .context("failed creating HTTP client")?;
+        Ok(Self {
+            client,
+            api_node_url: config.api_node_url.trim_end_matches('/').to_owned(),
+            bridge_api_url: config.bridge_api_url.trim_end_matches('/').to_owned(),
+            blob_size_limit: config.blob_size_limit,
+        })
+    }
+
+    fn to_da_error(err: reqwest::Error, action: &str) -> DAError {
+        let is_retriable = err.is_timeout()
+            || err.is_connect()
+            || err
+                .status()
+                .map_or(false, |status| status.is_server_error());
+        DAError {
+            error: anyhow::Error::from(err).context(format!("failed {action}")),
+            is_retriable,
+        }
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for AvailClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        if data.len() > self.blob_size_limit {
+            return Err(DAError {
+                error: anyhow::anyhow!(
+                    "pubdata for batch #{batch_number} ({} bytes) exceeds the blob size limit ({} bytes)",
+                    data.len(),
+                    self.blob_size_limit
+                ),
+                is_retriable: false,
+            });
+        }
+
+        let url = format!("{}/v2/submit", self.api_node_url);
+        let response: SubmitResponse = self
+            .client
+            .post(url)
+            .json(&SubmitRequest { data })
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|err| Self::to_da_error(err, "submitting data"))?
+            .json()
+            .await
+            .map_err(|err| Self::to_da_error(err, "parsing submission response"))?;
+
+        let blob_id = BlobId {
+            block_hash: response.block_hash,
+            tx_index: response.index,
+        };
+        tracing::debug!(
+            "Submitted pubdata for batch #{batch_number} to Avail block #{} as {blob_id}",
+            response.block_number
+        );
+        Ok(DispatchResponse {
+            blob_id: blob_id.to_string(),
+        })
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let BlobId {
+            block_hash,
+            tx_index,
+        } = BlobId::parse(blob_id).map_err(|err| DAError {
+            error: err.context(format!("Failed to parse blob ID: {blob_id}")),
+            is_retriable: false,
+        })?;
+
+        let url = format!("{}/eth/proof/{block_hash:?}", self.bridge_api_url);
+        let response = self
+            .client
+            .get(url)
+            .query(&[("index", tx_index)])
+            .send()
+            .await
+            .map_err(|err| Self::to_da_error(err, "getting inclusion proof"))?;
+        // The proof is not available until the block is bridged to L1, which may take a while.
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let proof: BridgeProof = response
+            .error_for_status()
+            .map_err(|err| Self::to_da_error(err, "getting inclusion proof"))?
+            .json()
+            .await
+            .map_err(|err| Self::to_da_error(err, "parsing inclusion proof"))?;
+
+        Ok(Some(InclusionData {
+            data: proof.abi_encode(),
+        }))
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        Some(self.blob_size_limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::Ipv4Addr,
+        sync::{Arc, Mutex},
+    };
+
+    use axum::{
+        extract::{Path, Query, State},
+        http::StatusCode,
+        routing::{get, post},
+        Json, Router,
+    };
+    use zksync_types::H256;
+
+    use super::*;
+
+    /// Mock light client and bridge API storing each submitted blob in a separate block.
+    #[derive(Debug, Default)]
+    struct MockNode {
+        blobs: Mutex<Vec<Vec<u8>>>,
+    }
+
+    impl MockNode {
+        fn block_hash(number: usize) -> H256 {
+            H256::from_low_u64_be(number as u64 + 1)
+        }
+
+        fn proof(block_hash: H256, index: u32) -> BridgeProof {
+            BridgeProof {
+                data_root_proof: vec![H256::repeat_byte(1)],
+                leaf_proof: vec![H256::repeat_byte(2), H256::repeat_byte(3)],
+                range_hash: H256::repeat_byte(4),
+                data_root_index: 0,
+                blob_root: block_hash,
+                bridge_root: H256::repeat_byte(5),
+                leaf: H256::repeat_byte(6),
+                leaf_index: index.into(),
+            }
+        }
+    }
+
+    async fn submit(
+        State(node): State<Arc<MockNode>>,
+        Json(request): Json<SubmitRequest>,
+    ) -> Json<SubmitResponse> {
+        let mut blobs = node.blobs.lock().unwrap();
+        blobs.push(request.data);
+        let number = blobs.len() - 1;
+        Json(SubmitResponse {
+            block_number: number as u32,
+            block_hash: MockNode::block_hash(number),
+            hash: H256::repeat_byte(0xff),
+            index: 1,
+        })
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct ProofQuery {
+        index: u32,
+    }
+
+    async fn get_proof(
+        State(node): State<Arc<MockNode>>,
+        Path(block_hash): Path<H256>,
+        Query(query): Query<ProofQuery>,
+    ) -> Result<Json<BridgeProof>, StatusCode> {
+        let block_count = node.blobs.lock().unwrap().len();
+        let is_known = (0..block_count).any(|number| MockNode::block_hash(number) == block_hash);
+        if !is_known {
+            return Err(StatusCode::NOT_FOUND);
+        }
+        Ok(Json(MockNode::proof(block_hash, query.index)))
+    }
+
+    async fn spawn_mock_node(node: Arc<MockNode>) -> String {
+        let app = Router::new()
+            .route("/v2/submit", post(submit))
+            .route("/eth/proof/:block_hash", get(get_proof))
+            .with_state(node);
+        let listener = tokio::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+            .await
+            .unwrap();
+        let local_addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        format!("http://{local_addr}")
+    }
+
+    fn mock_config(url: String) -> AvailConfig {
+        AvailConfig {
+            api_node_url: url.clone(),
+            bridge_api_url: url,
+            blob_size_limit: 1_024,
+            timeout_ms: 5_000,
+        }
+    }
+
+    #[test]
+    fn parsing_blob_id() {
+        let blob_id = BlobId {
+            block_hash: H256::repeat_byte(0xaa),
+            tx_index: 3,
+        };
+        assert_eq!(BlobId::parse(&blob_id.to_string()).unwrap(), blob_id);
+        BlobId::parse("aa-3").unwrap_err();
+        BlobId::parse("3").unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn dispatching_blobs() {
+        let node = Arc::<MockNode>::default();
+        let url = spawn_mock_node(node.clone()).await;
+        let client = AvailClient::new(mock_config(url)).unwrap();
+
+        let response = client.dispatch_blob(1, vec![1; 100]).await.unwrap();
+        let blob_id = BlobId::parse(&response.blob_id).unwrap();
+        assert_eq!(blob_id.block_hash, MockNode::block_hash(0));
+        assert_eq!(blob_id.tx_index, 1);
+        assert_eq!(*node.blobs.lock().unwrap(), [vec![1_u8; 100]]);
+
+        let inclusion_data = client
+            .get_inclusion_data(&response.blob_id)
+            .await
+            .unwrap()
+            .expect("no inclusion data");
+        let expected_proof = MockNode::proof(blob_id.block_hash, 1);
+        assert_eq!(inclusion_data.data, expected_proof.abi_encode());
+
+        let missing_blob_id = BlobId {
+            block_hash: H256::repeat_byte(0xaa),
+            tx_index: 1,
+        };
+        let inclusion_data = client
+            .get_inclusion_data(&missing_blob_id.to_string())
+            .await
+            .unwrap();
+        assert!(inclusion_data.is_none());
+    }
+
+    #[tokio::test]
+    async fn oversized_blobs_are_rejected() {
+        let node = Arc::<MockNode>::default();
+        let url = spawn_mock_node(node.clone()).await;
+        let client = AvailClient::new(mock_config(url)).unwrap();
+        assert_eq!(client.blob_size_limit(), Some(1_024));
+
+        let err = client.dispatch_blob(1, vec![1; 2_000]).await.unwrap_err();
+        assert!(!err.is_retriable());
+        assert!(node.blobs.lock().unwrap().is_empty());
+    }
+}
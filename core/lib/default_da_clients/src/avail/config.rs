@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use zksync_env_config::envy_load;
+
+/// Configuration of the [Avail](https://www.availproject.org/) DA client.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AvailConfig {
+    /// URL of the Avail light client HTTP API. The light client must be configured with the application ID
+    /// used by the chain and must have a funded account to submit data.
+    pub api_node_url: String,
+    /// URL of the Avail bridge API providing Merkle proofs for the data bridged to L1.
+    pub bridge_api_url: String,
+    /// Maximum size of a single blob in bytes.
+    #[serde(default = "AvailConfig::default_blob_size_limit")]
+    pub blob_size_limit: usize,
+    /// Timeout for requests to the light client and the bridge API in milliseconds.
+    #[serde(default = "AvailConfig::default_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+impl AvailConfig {
+    const fn default_blob_size_limit() -> usize {
+        512 * 1_024
+    }
+
+    const fn default_timeout_ms() -> u64 {
+        60_000
+    }
+
+    pub fn from_env() -> anyhow::Result<Self> {
+        envy_load("avail", "DA_CLIENT_AVAIL_")
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_ms)
+    }
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{base64::Base64, serde_as};
+use zksync_types::{
+    ethabi::{self, Token},
+    H256, U256,
+};
+
+/// Request for the `POST /v2/submit` light client endpoint.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct SubmitRequest {
+    #[serde_as(as = "Base64")]
+    pub data: Vec<u8>,
+}
+
+/// Response of the `POST /v2/submit` light client endpoint.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct SubmitResponse {
+    pub block_number: u32,
+    pub block_hash: H256,
+    pub hash: H256,
+    pub index: u32,
+}
+
+/// Parsed blob ID returned from `dispatch_blob()`. Has the `{hex(block_hash)}-{tx_index}` format.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct BlobId {
+    pub block_hash: H256,
+    pub tx_index: u32,
+}
+
+impl BlobId {
+    pub fn parse(blob_id: &str) -> anyhow::Result<Self> {
+        let (block_hash, tx_index) = blob_id
+            .split_once('-')
+            .ok_or_else(|| anyhow::anyhow!("invalid blob ID format: `{blob_id}`"))?;
+        let block_hash = hex::decode(block_hash)?;
+        anyhow::ensure!(block_hash.len() == 32, "invalid block hash length");
+        Ok(Self {
+            block_hash: H256::from_slice(&block_hash),
+            tx_index: tx_index.parse()?,
+        })
+    }
+}
+
+impl std::fmt::Display for BlobId {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            formatter,
+            "{}-{}",
+            hex::encode(self.block_hash.as_bytes()),
+            self.tx_index
+        )
+    }
+}
+
+/// Merkle proof of data inclusion returned by the `GET /eth/proof/{block_hash}` bridge API endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BridgeProof {
+    pub data_root_proof: Vec<H256>,
+    pub leaf_proof: Vec<H256>,
+    pub range_hash: H256,
+    pub data_root_index: u64,
+    pub blob_root: H256,
+    pub bridge_root: H256,
+    pub leaf: H256,
+    pub leaf_index: u64,
+}
+
+impl BridgeProof {
+    /// ABI-encodes the proof as the `MerkleProofInput` struct consumed by the Avail bridge contracts.
+    pub fn abi_encode(&self) -> Vec<u8> {
+        let hashes = |hashes: &[H256]| {
+            Token::Array(
+                hashes
+                    .iter()
+                    .map(|hash| Token::FixedBytes(hash.as_bytes().to_vec()))
+                    .collect(),
+            )
+        };
+        ethabi::encode(&[Token::Tuple(vec![
+            hashes(&self.data_root_proof),
+            hashes(&self.leaf_proof),
+            Token::FixedBytes(self.range_hash.as_bytes().to_vec()),
+            Token::Uint(U256::from(self.data_root_index)),
+            Token::FixedBytes(self.blob_root.as_bytes().to_vec()),
+            Token::FixedBytes(self.bridge_root.as_bytes().to_vec()),
+            Token::FixedBytes(self.leaf.as_bytes().to_vec()),
+            Token::Uint(U256::from(self.leaf_index)),
+        ])])
+    }
+}
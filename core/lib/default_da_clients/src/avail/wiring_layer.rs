@@ -0,0 +1,42 @@
+use zksync_da_client::DataAvailabilityClient;
+use zksync_node_framework::{
+    implementations::resources::da_client::DAClientResource,
+    wiring_layer::{WiringError, WiringLayer},
+    IntoContext,
+};
+
+use crate::avail::{client::AvailClient, config::AvailConfig};
+
+#[derive(Debug)]
+pub struct AvailWiringLayer {
+    config: AvailConfig,
+}
+
+impl AvailWiringLayer {
+    pub fn new(config: AvailConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[derive(Debug, IntoContext)]
+pub struct Output {
+    pub client: DAClientResource,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for AvailWiringLayer {
+    type Input = ();
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "avail_da_layer"
+    }
+
+    async fn wire(self, _input: Self::Input) -> Result<Self::Output, WiringError> {
+        let client: Box<dyn DataAvailabilityClient> = Box::new(AvailClient::new(self.config)?);
+
+        Ok(Output {
+            client: DAClientResource(client),
+        })
+    }
+}
@@ -0,0 +1,341 @@
+use std::fmt;
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use jsonrpsee::{
+    core::{client::ClientT, ClientError},
+    http_client::{HeaderMap, HeaderValue, HttpClient, HttpClientBuilder},
+    rpc_params,
+};
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+use zksync_types::ethabi::{self, Token};
+
+use crate::celestia::{
+    config::CelestiaConfig,
+    types::{parse_namespace, Base64Bytes, Blob, BlobId, SubmitOptions, NAMESPACE_LEN},
+};
+
+/// An implementation of the `DataAvailabilityClient` trait that submits the pubdata to Celestia
+/// via the JSON-RPC API of a Celestia node.
+///
+/// Each batch's pubdata is submitted as a single blob to the configured namespace. Blob IDs have
+/// the `{height}-{hex(commitment)}` format, and inclusion data is the ABI-encoded `(height, commitment, proof)` tuple,
+/// where `proof` is the JSON-serialized NMT proof returned by `blob.GetProof`.
+#[derive(Clone)]
+pub struct CelestiaClient {
+    client: HttpClient,
+    namespace: [u8; NAMESPACE_LEN],
+    gas_price: Option<f64>,
+    blob_size_limit: usize,
+}
+
+impl fmt::Debug for CelestiaClient {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The client is skipped since it contains the auth token in headers.
+        formatter
+            .debug_struct("CelestiaClient")
+            .field("namespace", &hex::encode(self.namespace))
+            .field("gas_price", &self.gas_price)
+            .field("blob_size_limit", &self.blob_size_limit)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CelestiaClient {
+    pub fn new(config: CelestiaConfig) -> anyhow::Result<Self> {
+        let namespace = parse_namespace(&config.namespace).context("invalid namespace")?;
+        let mut headers = HeaderMap::new();
+        if let Some(auth_token) = &config.auth_token {
+            let mut value = HeaderValue::from_str(&format!("Bearer {auth_token}"))
+                .context("invalid auth token")?;
+            value.set_sensitive(true);
+            headers.insert("Authorization", value);
+        }
+        let client = HttpClientBuilder::default()
+            .set_headers(headers)
+            .request_timeout(config.timeout())
+            .build(&config.api_node_url)
+            .context("failed creating Celestia node client")?;
+
+        Ok(Self {
+            client,
+            namespace,
+            gas_price: config.gas_price,
+            blob_size_limit: config.blob_size_limit,
+        })
+    }
+
+    fn namespace_param(&self) -> Base64Bytes {
+        Base64Bytes(self.namespace.to_vec())
+    }
+
+    fn to_da_error(err: ClientError, action: &str) -> DAError {
+        let is_retriable = matches!(
+            err,
+            ClientError::Transport(_) | ClientError::RequestTimeout | ClientError::RestartNeeded(_)
+        );
+        DAError {
+            error: anyhow::Error::from(err).context(format!("failed {action}")),
+            is_retriable,
+        }
+    }
+
+    /// Finds the commitment of the blob with the specified data included at `height`.
+    async fn get_commitment(&self, height: u64, data: &[u8]) -> Result<Vec<u8>, DAError> {
+        let blobs: Vec<Blob> = self
+            .client
+            .request("blob.GetAll", rpc_params![height, [self.namespace_param()]])
+            .await
+            .map_err(|err| Self::to_da_error(err, "getting blobs"))?;
+        let blob = blobs
+            .into_iter()
+            .find(|blob| blob.data == data)
+            .ok_or_else(|| DAError {
+                error: anyhow::anyhow!("submitted blob is not found at height {height}"),
+                is_retriable: false,
+            })?;
+        Ok(blob.commitment)
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for CelestiaClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        if data.len() > self.blob_size_limit {
+            return Err(DAError {
+                error: anyhow::anyhow!(
+                    "pubdata for batch #{batch_number} ({} bytes) exceeds the blob size limit ({} bytes)",
+                    data.len(),
+                    self.blob_size_limit
+                ),
+                is_retriable: false,
+            });
+        }
+
+        let blob = Blob {
+            namespace: self.namespace.to_vec(),
+            data,
+            share_version: 0,
+            commitment: vec![],
+        };
+        let options = SubmitOptions {
+            gas_price: self.gas_price,
+            is_gas_price_set: self.gas_price.is_some(),
+        };
+        // `blob.Submit` returns once the blob is included into a block.
+        let height: u64 = self
+            .client
+            .request("blob.Submit", rpc_params![[&blob], options])
+            .await
+            .map_err(|err| Self::to_da_error(err, "submitting blob"))?;
+        let commitment = self.get_commitment(height, &blob.data).await?;
+
+        let blob_id = BlobId { height, commitment };
+        tracing::debug!("Submitted pubdata for batch #{batch_number} to Celestia as {blob_id}");
+        Ok(DispatchResponse {
+            blob_id: blob_id.to_string(),
+        })
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let BlobId { height, commitment } = BlobId::parse(blob_id).map_err(|err| DAError {
+            error: err.context(format!("Failed to parse blob ID: {blob_id}")),
+            is_retriable: false,
+        })?;
+
+        let proof: serde_json::Value = match self
+            .client
+            .request(
+                "blob.GetProof",
+                rpc_params![
+                    height,
+                    self.namespace_param(),
+                    Base64Bytes(commitment.clone())
+                ],
+            )
+            .await
+        {
+            Ok(proof) => proof,
+            Err(ClientError::Call(err)) if err.message().contains("not found") => return Ok(None),
+            Err(err) => return Err(Self::to_da_error(err, "getting blob proof")),
+        };
+
+        let proof = serde_json::to_vec(&proof).map_err(|err| DAError {
+            error: err.into(),
+            is_retriable: false,
+        })?;
+        let data = ethabi::encode(&[
+            Token::Uint(height.into()),
+            Token::Bytes(commitment),
+            Token::Bytes(proof),
+        ]);
+        Ok(Some(InclusionData { data }))
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        Some(self.blob_size_limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::Ipv4Addr,
+        sync::{Arc, Mutex},
+    };
+
+    use jsonrpsee::{server::ServerBuilder, types::ErrorObjectOwned, RpcModule};
+    use zksync_types::{web3::keccak256, U256};
+
+    use super::*;
+
+    /// Mock Celestia node storing each submitted blob in a separate block.
+    #[derive(Debug, Default)]
+    struct MockNode {
+        blocks: Mutex<Vec<Vec<Blob>>>,
+    }
+
+    fn not_found() -> ErrorObjectOwned {
+        ErrorObjectOwned::owned(1, "blob: not found", None::<()>)
+    }
+
+    async fn spawn_mock_node(node: Arc<MockNode>) -> String {
+        let mut rpc_module = RpcModule::new(node);
+        rpc_module
+            .register_method("blob.Submit", |params, node, _ext| {
+                let (blobs, _options): (Vec<Blob>, serde_json::Value) = params.parse()?;
+                let blobs = blobs
+                    .into_iter()
+                    .map(|blob| Blob {
+                        commitment: keccak256(&blob.data).to_vec(),
+                        ..blob
+                    })
+                    .collect();
+                let mut blocks = node.blocks.lock().unwrap();
+                blocks.push(blobs);
+                Ok::<_, ErrorObjectOwned>(blocks.len() as u64)
+            })
+            .unwrap();
+        rpc_module
+            .register_method("blob.GetAll", |params, node, _ext| {
+                let (height, namespaces): (u64, Vec<Base64Bytes>) = params.parse()?;
+                let blocks = node.blocks.lock().unwrap();
+                let block = blocks.get(height as usize - 1).ok_or_else(not_found)?;
+                let blobs: Vec<_> = block
+                    .iter()
+                    .filter(|blob| namespaces.iter().any(|ns| ns.0 == blob.namespace))
+                    .cloned()
+                    .collect();
+                Ok::<_, ErrorObjectOwned>(blobs)
+            })
+            .unwrap();
+        rpc_module
+            .register_method("blob.GetProof", |params, node, _ext| {
+                let (height, _namespace, commitment): (u64, Base64Bytes, Base64Bytes) =
+                    params.parse()?;
+                let blocks = node.blocks.lock().unwrap();
+                let block = blocks.get(height as usize - 1).ok_or_else(not_found)?;
+                if !block.iter().any(|blob| blob.commitment == commitment.0) {
+                    return Err(not_found());
+                }
+                Ok(serde_json::json!([{ "start": 0, "end": 1, "nodes": [] }]))
+            })
+            .unwrap();
+
+        let server = ServerBuilder::default()
+            .http_only()
+            .build((Ipv4Addr::LOCALHOST, 0))
+            .await
+            .unwrap();
+        let local_addr = server.local_addr().unwrap();
+        let server_handle = server.start(rpc_module);
+        tokio::spawn(server_handle.stopped());
+        format!("http://{local_addr}/")
+    }
+
+    fn mock_config(api_node_url: String) -> CelestiaConfig {
+        CelestiaConfig {
+            api_node_url,
+            auth_token: Some("test".to_owned()),
+            namespace: "0xdeadbeef".to_owned(),
+            gas_price: None,
+            blob_size_limit: 1_024,
+            timeout_ms: 5_000,
+        }
+    }
+
+    #[test]
+    fn parsing_namespace() {
+        let namespace = parse_namespace("deadbeef").unwrap();
+        assert_eq!(namespace[..25], [0; 25]);
+        assert_eq!(namespace[25..], [0xde, 0xad, 0xbe, 0xef]);
+
+        parse_namespace("").unwrap_err();
+        parse_namespace(&"ff".repeat(11)).unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn dispatching_blobs() {
+        let node = Arc::<MockNode>::default();
+        let url = spawn_mock_node(node.clone()).await;
+        let client = CelestiaClient::new(mock_config(url)).unwrap();
+
+        let response = client.dispatch_blob(1, vec![1; 100]).await.unwrap();
+        let blob_id = BlobId::parse(&response.blob_id).unwrap();
+        assert_eq!(blob_id.height, 1);
+        assert_eq!(blob_id.commitment, keccak256(&[1; 100]));
+        let blocks = node.blocks.lock().unwrap().clone();
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0][0].namespace, client.namespace);
+
+        let inclusion_data = client
+            .get_inclusion_data(&response.blob_id)
+            .await
+            .unwrap()
+            .expect("no inclusion data");
+        let tokens = ethabi::decode(
+            &[
+                ethabi::ParamType::Uint(256),
+                ethabi::ParamType::Bytes,
+                ethabi::ParamType::Bytes,
+            ],
+            &inclusion_data.data,
+        )
+        .unwrap();
+        assert_eq!(tokens[0], Token::Uint(U256::one()));
+        assert_eq!(tokens[1], Token::Bytes(blob_id.commitment));
+
+        let missing_blob_id = BlobId {
+            height: 1,
+            commitment: vec![0; 32],
+        };
+        let inclusion_data = client
+            .get_inclusion_data(&missing_blob_id.to_string())
+            .await
+            .unwrap();
+        assert!(inclusion_data.is_none());
+    }
+
+    #[tokio::test]
+    async fn oversized_blobs_are_rejected() {
+        let node = Arc::<MockNode>::default();
+        let url = spawn_mock_node(node.clone()).await;
+        let client = CelestiaClient::new(mock_config(url)).unwrap();
+        assert_eq!(client.blob_size_limit(), Some(1_024));
+
+        let err = client.dispatch_blob(1, vec![1; 2_000]).await.unwrap_err();
+        assert!(!err.is_retriable());
+        assert!(node.blocks.lock().unwrap().is_empty());
+    }
+}
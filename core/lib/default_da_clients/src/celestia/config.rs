@@ -0,0 +1,55 @@
+use std::{fmt, time::Duration};
+
+use serde::Deserialize;
+use zksync_env_config::envy_load;
+
+/// Configuration of the [Celestia](https://celestia.org/) DA client.
+#[derive(Clone, Deserialize)]
+pub struct CelestiaConfig {
+    /// URL of the Celestia node JSON-RPC API.
+    pub api_node_url: String,
+    /// Authentication token for the node API with write permissions.
+    pub auth_token: Option<String>,
+    /// Hex-encoded namespace ID (up to 10 bytes). Blobs are submitted to the version 0 namespace with this ID.
+    pub namespace: String,
+    /// Gas price for blob submission transactions. If not set, the gas price is estimated by the node.
+    pub gas_price: Option<f64>,
+    /// Maximum size of a single blob in bytes.
+    #[serde(default = "CelestiaConfig::default_blob_size_limit")]
+    pub blob_size_limit: usize,
+    /// Timeout for requests to the node in milliseconds. Blob submission blocks until the blob is included,
+    /// so the timeout should be larger than the Celestia block time.
+    #[serde(default = "CelestiaConfig::default_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+impl fmt::Debug for CelestiaConfig {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("CelestiaConfig")
+            .field("api_node_url", &self.api_node_url)
+            .field("namespace", &self.namespace)
+            .field("gas_price", &self.gas_price)
+            .field("blob_size_limit", &self.blob_size_limit)
+            .field("timeout_ms", &self.timeout_ms)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CelestiaConfig {
+    const fn default_blob_size_limit() -> usize {
+        1_900_000
+    }
+
+    const fn default_timeout_ms() -> u64 {
+        60_000
+    }
+
+    pub fn from_env() -> anyhow::Result<Self> {
+        envy_load("celestia", "DA_CLIENT_CELESTIA_")
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_ms)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod client;
+pub mod config;
+mod types;
+pub mod wiring_layer;
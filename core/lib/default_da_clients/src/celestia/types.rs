@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{base64::Base64, serde_as};
+
+/// Length of a Celestia namespace (1 version byte + 28 ID bytes).
+pub(crate) const NAMESPACE_LEN: usize = 29;
+/// Maximum length of the user-specified namespace ID for version 0 namespaces.
+pub(crate) const NAMESPACE_ID_LEN: usize = 10;
+
+/// Blob as represented in the Celestia node JSON-RPC API.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Blob {
+    #[serde_as(as = "Base64")]
+    pub namespace: Vec<u8>,
+    #[serde_as(as = "Base64")]
+    pub data: Vec<u8>,
+    pub share_version: u32,
+    /// Commitment is computed by the node; it's ignored when submitting blobs.
+    #[serde_as(as = "Base64")]
+    #[serde(default)]
+    pub commitment: Vec<u8>,
+}
+
+/// Bytes serialized as a base64 string, which is how byte slices (e.g., namespaces and commitments)
+/// are represented in the Celestia node API.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub(crate) struct Base64Bytes(#[serde_as(as = "Base64")] pub Vec<u8>);
+
+/// Options for the `blob.Submit` method.
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct SubmitOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_price: Option<f64>,
+    pub is_gas_price_set: bool,
+}
+
+/// Parsed blob ID returned from `dispatch_blob()`. Has the `{height}-{hex(commitment)}` format.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BlobId {
+    pub height: u64,
+    pub commitment: Vec<u8>,
+}
+
+impl BlobId {
+    pub fn parse(blob_id: &str) -> anyhow::Result<Self> {
+        let (height, commitment) = blob_id
+            .split_once('-')
+            .ok_or_else(|| anyhow::anyhow!("invalid blob ID format: `{blob_id}`"))?;
+        Ok(Self {
+            height: height.parse()?,
+            commitment: hex::decode(commitment)?,
+        })
+    }
+}
+
+impl std::fmt::Display for BlobId {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            formatter,
+            "{}-{}",
+            self.height,
+            hex::encode(&self.commitment)
+        )
+    }
+}
+
+/// Creates a version 0 namespace from the hex-encoded ID.
+pub(crate) fn parse_namespace(namespace_id: &str) -> anyhow::Result<[u8; NAMESPACE_LEN]> {
+    let namespace_id = namespace_id.strip_prefix("0x").unwrap_or(namespace_id);
+    let namespace_id = hex::decode(namespace_id)?;
+    anyhow::ensure!(
+        !namespace_id.is_empty() && namespace_id.len() <= NAMESPACE_ID_LEN,
+        "namespace ID must have 1 to {NAMESPACE_ID_LEN} bytes, got {}",
+        namespace_id.len()
+    );
+    // The version byte and the leading ID bytes are zeros for version 0 namespaces.
+    let mut namespace = [0_u8; NAMESPACE_LEN];
+    namespace[NAMESPACE_LEN - namespace_id.len()..].copy_from_slice(&namespace_id);
+    Ok(namespace)
+}
@@ -0,0 +1,42 @@
+use zksync_da_client::DataAvailabilityClient;
+use zksync_node_framework::{
+    implementations::resources::da_client::DAClientResource,
+    wiring_layer::{WiringError, WiringLayer},
+    IntoContext,
+};
+
+use crate::celestia::{client::CelestiaClient, config::CelestiaConfig};
+
+#[derive(Debug)]
+pub struct CelestiaWiringLayer {
+    config: CelestiaConfig,
+}
+
+impl CelestiaWiringLayer {
+    pub fn new(config: CelestiaConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[derive(Debug, IntoContext)]
+pub struct Output {
+    pub client: DAClientResource,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for CelestiaWiringLayer {
+    type Input = ();
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "celestia_da_layer"
+    }
+
+    async fn wire(self, _input: Self::Input) -> Result<Self::Output, WiringError> {
+        let client: Box<dyn DataAvailabilityClient> = Box::new(CelestiaClient::new(self.config)?);
+
+        Ok(Output {
+            client: DAClientResource(client),
+        })
+    }
+}
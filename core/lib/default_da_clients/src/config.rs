@@ -0,0 +1,32 @@
+use serde::Deserialize;
+use zksync_env_config::envy_load;
+
+/// DA client used by the DA dispatcher.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum DAClientKind {
+    /// Client that doesn't send pubdata anywhere.
+    #[default]
+    NoDA,
+    /// Client storing pubdata in the object store configured via `DA_CLIENT_OBJECT_STORE_*` env vars.
+    ObjectStore,
+    /// Celestia client configured via `DA_CLIENT_CELESTIA_*` env vars.
+    Celestia,
+    /// Avail client configured via `DA_CLIENT_AVAIL_*` env vars.
+    Avail,
+}
+
+/// Selects the DA client. The client-specific configuration is loaded separately, once the client is known,
+/// so that an invalid configuration of the selected client results in an error rather than a fallback
+/// to another client.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub struct DAClientSelection {
+    /// DA client to use. If not specified, the no-DA client is used.
+    #[serde(default)]
+    pub client: DAClientKind,
+}
+
+impl DAClientSelection {
+    pub fn from_env() -> anyhow::Result<Self> {
+        envy_load("da_client", "DA_CLIENT_")
+    }
+}
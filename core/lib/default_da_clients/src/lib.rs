@@ -1,2 +1,5 @@
+pub mod avail;
+pub mod celestia;
+pub mod config;
 pub mod no_da;
 pub mod object_store;
@@ -76,6 +76,7 @@ mod tests {
                 initial_writes_cache_size_mb: Some(32),
                 latest_values_cache_size_mb: Some(256),
                 fee_history_limit: Some(100),
+                trace_filter_block_range_limit: Some(5000),
                 max_batch_request_size: Some(200),
                 max_response_body_size_mb: Some(10),
                 max_response_body_size_overrides_mb: [
@@ -86,6 +87,20 @@ mod tests {
                 .into_iter()
                 .collect(),
                 websocket_requests_per_minute_limit: Some(NonZeroU32::new(10).unwrap()),
+                compute_units_per_minute_limit: Some(NonZeroU32::new(10_000).unwrap()),
+                api_key_compute_units_per_minute: [("test_key", NonZeroU32::new(100_000).unwrap())]
+                    .into_iter()
+                    .collect(),
+                method_compute_unit_costs: [
+                    ("eth_getLogs", NonZeroU32::new(100).unwrap()),
+                    ("debug_traceCall", NonZeroU32::new(500).unwrap()),
+                ]
+                .into_iter()
+                .collect(),
+                rate_limit_trusted_proxies: vec![
+                    "10.0.0.1".parse().unwrap(),
+                    "fd00::1".parse().unwrap(),
+                ],
                 tree_api_url: None,
                 mempool_cache_update_interval: Some(50),
                 mempool_cache_size: Some(10000),
@@ -136,8 +151,13 @@ mod tests {
             API_WEB3_JSON_RPC_INITIAL_WRITES_CACHE_SIZE_MB=32
             API_WEB3_JSON_RPC_LATEST_VALUES_CACHE_SIZE_MB=256
             API_WEB3_JSON_RPC_FEE_HISTORY_LIMIT=100
+            API_WEB3_JSON_RPC_TRACE_FILTER_BLOCK_RANGE_LIMIT=5000
             API_WEB3_JSON_RPC_MAX_BATCH_REQUEST_SIZE=200
             API_WEB3_JSON_RPC_WEBSOCKET_REQUESTS_PER_MINUTE_LIMIT=10
+            API_WEB3_JSON_RPC_COMPUTE_UNITS_PER_MINUTE_LIMIT=10000
+            API_WEB3_JSON_RPC_API_KEY_COMPUTE_UNITS_PER_MINUTE="test_key=100000"
+            API_WEB3_JSON_RPC_METHOD_COMPUTE_UNIT_COSTS="eth_getLogs=100,debug_traceCall=500"
+            API_WEB3_JSON_RPC_RATE_LIMIT_TRUSTED_PROXIES="10.0.0.1,fd00::1"
             API_WEB3_JSON_RPC_MEMPOOL_CACHE_SIZE=10000
             API_WEB3_JSON_RPC_MEMPOOL_CACHE_UPDATE_INTERVAL=50
             API_CONTRACT_VERIFICATION_PORT="3070"
@@ -29,6 +29,10 @@ mod tests {
             price_fetching_max_attempts: 20,
             price_fetching_sleep_ms: 10_000,
             halt_on_error: true,
+            max_ratio_change_percent: Some(20),
+            clamp_ratio_changes: true,
+            l1_update_deviation_percent: 2,
+            l1_update_max_staleness_ms: 3_600_000,
         }
     }
 
@@ -46,6 +50,10 @@ mod tests {
             price_fetching_max_attempts: 3,
             price_fetching_sleep_ms: 5_000,
             halt_on_error: false,
+            max_ratio_change_percent: None,
+            clamp_ratio_changes: false,
+            l1_update_deviation_percent: 0,
+            l1_update_max_staleness_ms: 86_400_000,
         }
     }
 
@@ -65,6 +73,10 @@ mod tests {
             BASE_TOKEN_ADJUSTER_PRICE_FETCHING_MAX_ATTEMPTS=20
             BASE_TOKEN_ADJUSTER_PRICE_FETCHING_SLEEP_MS=10000
             BASE_TOKEN_ADJUSTER_HALT_ON_ERROR=true
+            BASE_TOKEN_ADJUSTER_MAX_RATIO_CHANGE_PERCENT=20
+            BASE_TOKEN_ADJUSTER_CLAMP_RATIO_CHANGES=true
+            BASE_TOKEN_ADJUSTER_L1_UPDATE_DEVIATION_PERCENT=2
+            BASE_TOKEN_ADJUSTER_L1_UPDATE_MAX_STALENESS_MS=3600000
         "#;
         lock.set_env(config);
 
@@ -88,6 +100,10 @@ mod tests {
             "BASE_TOKEN_ADJUSTER_PRICE_FETCHING_MAX_ATTEMPTS",
             "BASE_TOKEN_ADJUSTER_PRICE_FETCHING_SLEEP_MS",
             "BASE_TOKEN_ADJUSTER_HALT_ON_ERROR",
+            "BASE_TOKEN_ADJUSTER_MAX_RATIO_CHANGE_PERCENT",
+            "BASE_TOKEN_ADJUSTER_CLAMP_RATIO_CHANGES",
+            "BASE_TOKEN_ADJUSTER_L1_UPDATE_DEVIATION_PERCENT",
+            "BASE_TOKEN_ADJUSTER_L1_UPDATE_MAX_STALENESS_MS",
         ]);
 
         let actual = BaseTokenAdjusterConfig::from_env().unwrap();
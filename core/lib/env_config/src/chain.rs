@@ -37,7 +37,7 @@ impl FromEnv for MempoolConfig {
 #[cfg(test)]
 mod tests {
     use zksync_basic_types::{commitment::L1BatchCommitmentMode, L2ChainId};
-    use zksync_config::configs::chain::FeeModelVersion;
+    use zksync_config::configs::chain::{FeeModelVersion, MempoolOrdering};
 
     use super::*;
     use crate::test_utils::{addr, hash, EnvMutex};
@@ -105,6 +105,8 @@ mod tests {
             l1_batch_commit_data_generator_mode,
             max_circuits_per_batch: 24100,
             protective_reads_persistence_enabled: true,
+            disabled_seal_criteria: vec!["tx_encoding_size".to_owned()],
+            seal_criteria_thresholds: [("l1_tx_count", 100)].into_iter().collect(),
         }
     }
 
@@ -138,6 +140,8 @@ mod tests {
             CHAIN_STATE_KEEPER_BOOTLOADER_HASH=0x010007ede999d096c84553fb514d3d6ca76fbf39789dda76bfeda9f3ae06236e
             CHAIN_STATE_KEEPER_DEFAULT_AA_HASH=0x0100055b041eb28aff6e3a6e0f37c31fd053fc9ef142683b05e5f0aee6934066
             CHAIN_STATE_KEEPER_PROTECTIVE_READS_PERSISTENCE_ENABLED=true
+            CHAIN_STATE_KEEPER_DISABLED_SEAL_CRITERIA="tx_encoding_size"
+            CHAIN_STATE_KEEPER_SEAL_CRITERIA_THRESHOLDS="l1_tx_count=100"
             CHAIN_STATE_KEEPER_L1_BATCH_COMMIT_DATA_GENERATOR_MODE="{l1_batch_commit_data_generator_mode}"
         "#
         )
@@ -171,6 +175,8 @@ mod tests {
             stuck_tx_timeout: 10,
             remove_stuck_txs: true,
             delay_interval: 100,
+            ordering: MempoolOrdering::FeePriority,
+            replacement_fee_bump_percent: 15,
         }
     }
 
@@ -184,6 +190,8 @@ mod tests {
             CHAIN_MEMPOOL_REMOVE_STUCK_TXS="true"
             CHAIN_MEMPOOL_DELAY_INTERVAL="100"
             CHAIN_MEMPOOL_CAPACITY="1000000"
+            CHAIN_MEMPOOL_ORDERING="fee_priority"
+            CHAIN_MEMPOOL_REPLACEMENT_FEE_BUMP_PERCENT="15"
         "#;
         lock.set_env(config);
 
@@ -1,17 +1,30 @@
 use anyhow::Context as _;
 use zksync_config::{
-    configs::{eth_sender::SenderConfig, L1Secrets},
+    configs::{
+        eth_sender::{RemoteSignerConfig, SenderConfig},
+        L1Secrets,
+    },
     EthConfig, EthWatchConfig, GasAdjusterConfig,
 };
 
 use crate::{envy_load, FromEnv};
 
+const REMOTE_SIGNER_ENV_PREFIX: &str = "ETH_SENDER_REMOTE_SIGNER_";
+
 impl FromEnv for EthConfig {
     fn from_env() -> anyhow::Result<Self> {
         Ok(Self {
             sender: SenderConfig::from_env().ok(),
             gas_adjuster: GasAdjusterConfig::from_env().ok(),
             watcher: EthWatchConfig::from_env().ok(),
+            // The remote signer is optional, but a partially specified or malformed config must not be ignored.
+            remote_signer: if std::env::vars()
+                .any(|(name, _)| name.starts_with(REMOTE_SIGNER_ENV_PREFIX))
+            {
+                Some(RemoteSignerConfig::from_env()?)
+            } else {
+                None
+            },
         })
     }
 }
@@ -23,6 +36,18 @@ impl FromEnv for L1Secrets {
                 .context("ETH_CLIENT_WEB3_URL")?
                 .parse()
                 .context("ETH_CLIENT_WEB3_URL")?,
+            fallback_l1_rpc_urls: match std::env::var("ETH_CLIENT_FALLBACK_WEB3_URLS") {
+                Ok(urls) => urls
+                    .split(',')
+                    .map(|url| url.trim().parse())
+                    .collect::<Result<_, _>>()
+                    .context("ETH_CLIENT_FALLBACK_WEB3_URLS")?,
+                Err(_) => vec![],
+            },
+            l1_rpc_quorum: match std::env::var("ETH_CLIENT_WEB3_QUORUM") {
+                Ok(quorum) => Some(quorum.parse().context("ETH_CLIENT_WEB3_QUORUM")?),
+                Err(_) => None,
+            },
         })
     }
 }
@@ -39,12 +64,23 @@ impl FromEnv for GasAdjusterConfig {
     }
 }
 
+impl FromEnv for RemoteSignerConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        envy_load("eth_sender.remote_signer", REMOTE_SIGNER_ENV_PREFIX)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use zksync_config::configs::eth_sender::{ProofSendingMode, PubdataSendingMode};
+    use std::num::NonZeroUsize;
+
+    use zksync_config::configs::eth_sender::{
+        BaseFeeStrategy, PriorityFeeStrategy, ProofSendingMode, PubdataSendingMode,
+        ResendFeeStrategy,
+    };
 
     use super::*;
-    use crate::test_utils::{hash, EnvMutex};
+    use crate::test_utils::{addr, hash, EnvMutex};
 
     static MUTEX: EnvMutex = EnvMutex::new();
 
@@ -72,6 +108,10 @@ mod tests {
                     pubdata_sending_mode: PubdataSendingMode::Calldata,
                     tx_aggregation_only_prove_and_execute: false,
                     tx_aggregation_paused: false,
+                    resend_fee_strategy: ResendFeeStrategy::Exponential,
+                    resend_fee_bump_percent: 25,
+                    max_resend_fee_per_gas: Some(500_000_000_000),
+                    cancel_stuck_txs_after_blocks: Some(50),
                 }),
                 gas_adjuster: Some(GasAdjusterConfig {
                     default_priority_fee_per_gas: 20000000000,
@@ -87,14 +127,28 @@ mod tests {
                     internal_pubdata_pricing_multiplier: 1.0,
                     max_blob_base_fee: None,
                     settlement_mode: Default::default(),
+                    base_fee_strategy: BaseFeeStrategy::Eip1559Projection,
+                    priority_fee_strategy: PriorityFeeStrategy::Percentile,
+                    priority_fee_percentile: 60.0,
                 }),
                 watcher: Some(EthWatchConfig {
                     confirmations_for_eth_event: Some(0),
                     eth_node_poll_interval: 300,
                 }),
+                remote_signer: Some(RemoteSignerConfig {
+                    url: "http://127.0.0.1:9000".parse().unwrap(),
+                    operator_address: addr("de03a0B5963f75f1C8485B355fF6D30f3093BDE7"),
+                    blob_operator_address: None,
+                    request_timeout_ms: 5_000,
+                }),
             },
             L1Secrets {
                 l1_rpc_url: "http://127.0.0.1:8545".to_string().parse().unwrap(),
+                fallback_l1_rpc_urls: vec![
+                    "http://127.0.0.1:8546".parse().unwrap(),
+                    "http://127.0.0.1:8547".parse().unwrap(),
+                ],
+                l1_rpc_quorum: NonZeroUsize::new(2),
             },
         )
     }
@@ -121,6 +175,9 @@ mod tests {
             ETH_SENDER_GAS_ADJUSTER_MAX_L1_GAS_PRICE="100000000"
             ETH_SENDER_GAS_ADJUSTER_MAX_BLOB_BASE_FEE_SAMPLES="10"
             ETH_SENDER_GAS_ADJUSTER_INTERNAL_PUBDATA_PRICING_MULTIPLIER="1.0"
+            ETH_SENDER_GAS_ADJUSTER_BASE_FEE_STRATEGY="Eip1559Projection"
+            ETH_SENDER_GAS_ADJUSTER_PRIORITY_FEE_STRATEGY="Percentile"
+            ETH_SENDER_GAS_ADJUSTER_PRIORITY_FEE_PERCENTILE="60"
             ETH_SENDER_WAIT_FOR_PROOFS="false"
             ETH_SENDER_SENDER_AGGREGATED_PROOF_SIZES="1,5"
             ETH_SENDER_SENDER_MAX_AGGREGATED_BLOCKS_TO_COMMIT="3"
@@ -134,9 +191,18 @@ mod tests {
             ETH_SENDER_SENDER_L1_BATCH_MIN_AGE_BEFORE_EXECUTE_SECONDS="1000"
             ETH_SENDER_SENDER_MAX_ACCEPTABLE_PRIORITY_FEE_IN_GWEI="100000000000"
             ETH_SENDER_SENDER_PUBDATA_SENDING_MODE="Calldata"
+            ETH_SENDER_SENDER_RESEND_FEE_STRATEGY="Exponential"
+            ETH_SENDER_SENDER_RESEND_FEE_BUMP_PERCENT="25"
+            ETH_SENDER_SENDER_MAX_RESEND_FEE_PER_GAS="500000000000"
+            ETH_SENDER_SENDER_CANCEL_STUCK_TXS_AFTER_BLOCKS="50"
             ETH_WATCH_CONFIRMATIONS_FOR_ETH_EVENT="0"
             ETH_WATCH_ETH_NODE_POLL_INTERVAL="300"
             ETH_CLIENT_WEB3_URL="http://127.0.0.1:8545"
+            ETH_CLIENT_FALLBACK_WEB3_URLS="http://127.0.0.1:8546,http://127.0.0.1:8547"
+            ETH_CLIENT_WEB3_QUORUM="2"
+            ETH_SENDER_REMOTE_SIGNER_URL="http://127.0.0.1:9000"
+            ETH_SENDER_REMOTE_SIGNER_OPERATOR_ADDRESS="0xde03a0B5963f75f1C8485B355fF6D30f3093BDE7"
+            ETH_SENDER_REMOTE_SIGNER_REQUEST_TIMEOUT_MS="5000"
 
         "#;
         lock.set_env(config);
@@ -156,4 +222,24 @@ mod tests {
         let actual = L1Secrets::from_env().unwrap();
         assert_eq!(actual, expected_config().1);
     }
+
+    #[test]
+    fn remote_signer_errors_are_propagated() {
+        let mut lock = MUTEX.lock();
+        lock.remove_env(&[
+            "ETH_SENDER_REMOTE_SIGNER_URL",
+            "ETH_SENDER_REMOTE_SIGNER_OPERATOR_ADDRESS",
+            "ETH_SENDER_REMOTE_SIGNER_REQUEST_TIMEOUT_MS",
+        ]);
+        let config = EthConfig::from_env().unwrap();
+        assert_eq!(config.remote_signer, None);
+
+        lock.set_env(
+            r#"
+            ETH_SENDER_REMOTE_SIGNER_URL="http://127.0.0.1:9000"
+            ETH_SENDER_REMOTE_SIGNER_OPERATOR_ADDRESS="not an address"
+            "#,
+        );
+        EthConfig::from_env().unwrap_err();
+    }
 }
@@ -1,5 +1,6 @@
 use zksync_config::configs::{
-    external_price_api_client::ForcedPriceClientConfig, ExternalPriceApiClientConfig,
+    external_price_api_client::{AggregatedPriceClientConfig, ForcedPriceClientConfig},
+    ExternalPriceApiClientConfig,
 };
 
 use crate::{envy_load, FromEnv};
@@ -9,6 +10,7 @@ impl FromEnv for ExternalPriceApiClientConfig {
         let mut config: ExternalPriceApiClientConfig =
             envy_load("external_price_api_client", "EXTERNAL_PRICE_API_CLIENT_")?;
         config.forced = ForcedPriceClientConfig::from_env().ok();
+        config.aggregated = AggregatedPriceClientConfig::from_env().ok();
         Ok(config)
     }
 }
@@ -22,10 +24,20 @@ impl FromEnv for ForcedPriceClientConfig {
     }
 }
 
+impl FromEnv for AggregatedPriceClientConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        envy_load(
+            "external_price_api_client_aggregated",
+            "EXTERNAL_PRICE_API_CLIENT_AGGREGATED_",
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use zksync_config::configs::external_price_api_client::{
-        ExternalPriceApiClientConfig, ForcedPriceClientConfig, DEFAULT_TIMEOUT_MS,
+        AggregatedPriceClientConfig, ExternalPriceApiClientConfig, ForcedPriceClientConfig,
+        DEFAULT_TIMEOUT_MS,
     };
 
     use super::*;
@@ -44,6 +56,25 @@ mod tests {
                 denominator: Some(1),
                 fluctuation: Some(10),
             }),
+            aggregated: Some(AggregatedPriceClientConfig {
+                sources: vec!["coingecko".to_owned(), "uniswap_v3".to_owned()],
+                quorum: Some(2),
+                max_deviation_percent: 3,
+                max_quote_age_ms: 60_000,
+                coinmarketcap_api_key: None,
+                coinmarketcap_base_url: None,
+                uniswap_v3_pool_address: Some(
+                    "0x1d9a5bc2dfbd5b2e1a0bb0e4ae8a0c0d1c4a5e3f"
+                        .parse()
+                        .unwrap(),
+                ),
+                uniswap_v3_quote_token_address: Some(
+                    "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"
+                        .parse()
+                        .unwrap(),
+                ),
+                uniswap_v3_twap_interval_sec: 600,
+            }),
         }
     }
 
@@ -57,6 +88,12 @@ mod tests {
             EXTERNAL_PRICE_API_CLIENT_FORCED_NUMERATOR=100
             EXTERNAL_PRICE_API_CLIENT_FORCED_DENOMINATOR=1
             EXTERNAL_PRICE_API_CLIENT_FORCED_FLUCTUATION=10
+            EXTERNAL_PRICE_API_CLIENT_AGGREGATED_SOURCES=coingecko,uniswap_v3
+            EXTERNAL_PRICE_API_CLIENT_AGGREGATED_QUORUM=2
+            EXTERNAL_PRICE_API_CLIENT_AGGREGATED_MAX_DEVIATION_PERCENT=3
+            EXTERNAL_PRICE_API_CLIENT_AGGREGATED_MAX_QUOTE_AGE_MS=60000
+            EXTERNAL_PRICE_API_CLIENT_AGGREGATED_UNISWAP_V3_POOL_ADDRESS=0x1d9a5bc2dfbd5b2e1a0bb0e4ae8a0c0d1c4a5e3f
+            EXTERNAL_PRICE_API_CLIENT_AGGREGATED_UNISWAP_V3_QUOTE_TOKEN_ADDRESS=0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2
         "#;
         lock.set_env(config);
 
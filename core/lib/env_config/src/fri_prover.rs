@@ -50,6 +50,8 @@ mod tests {
                 },
                 max_retries: 5,
                 local_mirror_path: None,
+                compression_level: None,
+                compression_excluded_buckets: vec![],
             }),
             public_object_store: Some(ObjectStoreConfig {
                 mode: ObjectStoreMode::GCSWithCredentialFile {
@@ -58,6 +60,8 @@ mod tests {
                 },
                 max_retries: 5,
                 local_mirror_path: None,
+                compression_level: None,
+                compression_excluded_buckets: vec![],
             }),
             availability_check_interval_in_secs: Some(1_800),
             cloud_type: CloudConnectionMode::GCP,
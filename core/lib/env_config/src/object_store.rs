@@ -42,7 +42,10 @@ impl FromEnv for SnapshotsObjectStoreConfig {
 
 #[cfg(test)]
 mod tests {
-    use zksync_config::{configs::object_store::ObjectStoreMode, ObjectStoreConfig};
+    use zksync_config::{
+        configs::object_store::{ObjectStoreMode, S3AddressingStyle, S3SecretAccessKey},
+        ObjectStoreConfig,
+    };
 
     use super::*;
     use crate::test_utils::EnvMutex;
@@ -57,6 +60,8 @@ mod tests {
             },
             max_retries: 5,
             local_mirror_path: Some("/var/cache".to_owned()),
+            compression_level: None,
+            compression_excluded_buckets: vec![],
         }
     }
 
@@ -92,6 +97,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compression_config_from_env() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            OBJECT_STORE_MODE="FileBacked"
+            OBJECT_STORE_FILE_BACKED_BASE_PATH="artifacts"
+            OBJECT_STORE_COMPRESSION_LEVEL="3"
+            OBJECT_STORE_COMPRESSION_EXCLUDED_BUCKETS="storage_logs_snapshots,proofs_fri"
+        "#;
+        lock.set_env(config);
+        let actual = ObjectStoreConfig::from_env().unwrap();
+        assert_eq!(actual.compression_level, Some(3));
+        assert_eq!(
+            actual.compression_excluded_buckets,
+            ["storage_logs_snapshots", "proofs_fri"]
+        );
+    }
+
+    #[test]
+    fn s3_config_from_env() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            OBJECT_STORE_MODE="S3"
+            OBJECT_STORE_BUCKET_NAME="zksync-artifacts"
+            OBJECT_STORE_REGION="us-east-1"
+            OBJECT_STORE_ENDPOINT="http://127.0.0.1:9000"
+            OBJECT_STORE_ADDRESSING_STYLE="Path"
+            OBJECT_STORE_ACCESS_KEY_ID="minioadmin"
+            OBJECT_STORE_SECRET_ACCESS_KEY="minioadmin"
+        "#;
+        lock.set_env(config);
+        let actual = ObjectStoreConfig::from_env().unwrap();
+        assert_eq!(
+            actual.mode,
+            ObjectStoreMode::S3 {
+                bucket_name: "zksync-artifacts".to_owned(),
+                region: "us-east-1".to_owned(),
+                endpoint: Some("http://127.0.0.1:9000".to_owned()),
+                addressing_style: S3AddressingStyle::Path,
+                access_key_id: Some("minioadmin".to_owned()),
+                secret_access_key: Some(S3SecretAccessKey("minioadmin".to_owned())),
+            }
+        );
+    }
+
     #[test]
     fn public_bucket_config_from_env() {
         let mut lock = MUTEX.lock();
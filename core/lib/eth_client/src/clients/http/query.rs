@@ -297,6 +297,7 @@ async fn l1_base_fee_history<T>(
     client: &T,
     upto_block: usize,
     block_count: usize,
+    reward_percentile: Option<f32>,
 ) -> EnrichedClientResult<Vec<BaseFees>>
 where
     T: ForWeb3Network<Net = L1> + L1EthNamespaceClient + Send + Sync,
@@ -317,7 +318,7 @@ where
             .fee_history(
                 U64::from(chunk_size),
                 web3::BlockNumber::from(chunk_end),
-                None,
+                reward_percentile.map(|percentile| vec![percentile]),
             )
             .rpc_context("fee_history")
             .with_arg("chunk_size", &chunk_size)
@@ -336,15 +337,19 @@ where
             );
         }
 
-        for (base, blob) in fee_history
+        let rewards = fee_history.reward.unwrap_or_default();
+        for (i, (base, blob)) in fee_history
             .base_fee_per_gas
             .into_iter()
             .zip(fee_history.base_fee_per_blob_gas)
+            .enumerate()
         {
             let fees = BaseFees {
                 base_fee_per_gas: cast_to_u64(base, "base_fee_per_gas")?,
                 base_fee_per_blob_gas: blob,
                 l2_pubdata_price: 0.into(),
+                gas_used_ratio: fee_history.gas_used_ratio.get(i).copied(),
+                priority_fee_per_gas: block_reward(&rewards, i)?,
             };
             history.push(fees)
         }
@@ -360,8 +365,9 @@ impl EthFeeInterface for Box<DynClient<L1>> {
         &self,
         upto_block: usize,
         block_count: usize,
+        reward_percentile: Option<f32>,
     ) -> EnrichedClientResult<Vec<BaseFees>> {
-        l1_base_fee_history(self, upto_block, block_count).await
+        l1_base_fee_history(self, upto_block, block_count, reward_percentile).await
     }
 }
 
@@ -371,8 +377,9 @@ impl EthFeeInterface for MockClient<L1> {
         &self,
         upto_block: usize,
         block_count: usize,
+        reward_percentile: Option<f32>,
     ) -> EnrichedClientResult<Vec<BaseFees>> {
-        l1_base_fee_history(self, upto_block, block_count).await
+        l1_base_fee_history(self, upto_block, block_count, reward_percentile).await
     }
 }
 
@@ -380,6 +387,7 @@ async fn l2_base_fee_history<T>(
     client: &T,
     upto_block: usize,
     block_count: usize,
+    reward_percentile: Option<f32>,
 ) -> EnrichedClientResult<Vec<BaseFees>>
 where
     T: ForWeb3Network<Net = L2> + EthNamespaceClient + Send + Sync,
@@ -400,7 +408,9 @@ where
             client,
             U64::from(chunk_size),
             zksync_types::api::BlockNumber::from(chunk_end),
-            vec![],
+            reward_percentile
+                .map(|percentile| vec![percentile])
+                .unwrap_or_default(),
         )
         .rpc_context("fee_history")
         .with_arg("chunk_size", &chunk_size)
@@ -416,16 +426,20 @@ where
             );
         }
 
-        for (base, l2_pubdata_price) in fee_history
+        let rewards = fee_history.inner.reward.unwrap_or_default();
+        for (i, (base, l2_pubdata_price)) in fee_history
             .inner
             .base_fee_per_gas
             .into_iter()
             .zip(fee_history.l2_pubdata_price)
+            .enumerate()
         {
             let fees = BaseFees {
                 base_fee_per_gas: cast_to_u64(base, "base_fee_per_gas")?,
                 base_fee_per_blob_gas: 0.into(),
                 l2_pubdata_price,
+                gas_used_ratio: fee_history.inner.gas_used_ratio.get(i).copied(),
+                priority_fee_per_gas: block_reward(&rewards, i)?,
             };
             history.push(fees)
         }
@@ -441,8 +455,9 @@ impl EthFeeInterface for Box<DynClient<L2>> {
         &self,
         upto_block: usize,
         block_count: usize,
+        reward_percentile: Option<f32>,
     ) -> EnrichedClientResult<Vec<BaseFees>> {
-        l2_base_fee_history(self, upto_block, block_count).await
+        l2_base_fee_history(self, upto_block, block_count, reward_percentile).await
     }
 }
 
@@ -452,11 +467,21 @@ impl EthFeeInterface for MockClient<L2> {
         &self,
         upto_block: usize,
         block_count: usize,
+        reward_percentile: Option<f32>,
     ) -> EnrichedClientResult<Vec<BaseFees>> {
-        l2_base_fee_history(self, upto_block, block_count).await
+        l2_base_fee_history(self, upto_block, block_count, reward_percentile).await
     }
 }
 
+/// Returns the reward at the (single) requested percentile for the `i`th block in the fee history.
+fn block_reward(rewards: &[Vec<U256>], i: usize) -> EnrichedClientResult<Option<u64>> {
+    rewards
+        .get(i)
+        .and_then(|block_rewards| block_rewards.first())
+        .map(|&reward| cast_to_u64(reward, "reward"))
+        .transpose()
+}
+
 /// Non-panicking conversion to u64.
 fn cast_to_u64(value: U256, tag: &str) -> EnrichedClientResult<u64> {
     u64::try_from(value).map_err(|_| {
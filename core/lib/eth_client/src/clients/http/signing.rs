@@ -2,7 +2,7 @@ use std::{fmt, sync::Arc};
 
 use async_trait::async_trait;
 use zksync_contracts::hyperchain_contract;
-use zksync_eth_signer::{EthereumSigner, PrivateKeySigner, TransactionParameters};
+use zksync_eth_signer::{EthereumSigner, PrivateKeySigner, RemoteSigner, TransactionParameters};
 use zksync_types::{
     ethabi, web3, Address, K256PrivateKey, SLChainId, EIP_4844_TX_TYPE, H160, U256,
 };
@@ -40,6 +40,31 @@ impl PKSigningClient {
     }
 }
 
+/// HTTP-based Ethereum client, backed by a remote signing service to sign transactions.
+pub type RemoteSigningClient = SigningClient<RemoteSigner>;
+
+impl RemoteSigningClient {
+    pub fn new_raw(
+        signer: RemoteSigner,
+        diamond_proxy_addr: Address,
+        default_priority_fee_per_gas: u64,
+        chain_id: SLChainId,
+        query_client: Box<DynClient<L1>>,
+    ) -> Self {
+        let operator_address = signer.address();
+        tracing::info!("Operator address: {operator_address:?} (using remote signer)");
+        SigningClient::new(
+            query_client,
+            hyperchain_contract(),
+            operator_address,
+            signer,
+            diamond_proxy_addr,
+            default_priority_fee_per_gas.into(),
+            chain_id,
+        )
+    }
+}
+
 /// Gas limit value to be used in transaction if for some reason
 /// gas limit was not set for it.
 ///
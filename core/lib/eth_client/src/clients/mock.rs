@@ -132,6 +132,18 @@ impl MockSettlementLayerInner {
         self.executed_txs.insert(tx_hash, status);
     }
 
+    fn execute_external_tx(&mut self, confirmations: u64) {
+        let block_number = self.block_number;
+        self.block_number += confirmations;
+        self.current_nonce += 1;
+        self.pending_nonce = self.pending_nonce.max(self.current_nonce);
+        tracing::info!(
+            "Executing external tx at block {block_number}, current nonce: {}",
+            self.current_nonce
+        );
+        self.nonces.insert(block_number, self.current_nonce);
+    }
+
     fn get_transaction_count(&self, address: Address, block: web3::BlockNumber) -> U256 {
         if address != MOCK_SENDER_ACCOUNT {
             unimplemented!("Getting nonce for custom account is not supported");
@@ -408,12 +420,32 @@ fn l2_eth_fee_history(
     base_fee_history: &[BaseFees],
     block_count: U64,
     newest_block: web3::BlockNumber,
+    reward_percentiles: Option<Vec<f32>>,
 ) -> FeeHistory {
     let web3::BlockNumber::Number(from_block) = newest_block else {
         panic!("Non-numeric newest block in `eth_feeHistory`");
     };
     let from_block = from_block.as_usize();
     let start_block = from_block.saturating_sub(block_count.as_usize() - 1);
+    let blocks = &base_fee_history[start_block..=from_block];
+
+    // Only return gas used ratios if they are set for all blocks, so that fee history round-trips.
+    let gas_used_ratio = blocks
+        .iter()
+        .map(|fee| fee.gas_used_ratio)
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_default();
+    let reward = reward_percentiles
+        .filter(|percentiles| !percentiles.is_empty())
+        .map(|percentiles| {
+            blocks
+                .iter()
+                .map(|fee| {
+                    let reward = U256::from(fee.priority_fee_per_gas.unwrap_or(0));
+                    vec![reward; percentiles.len()]
+                })
+                .collect()
+        });
 
     FeeHistory {
         inner: web3::FeeHistory {
@@ -426,9 +458,9 @@ fn l2_eth_fee_history(
                 .iter()
                 .map(|fee| fee.base_fee_per_blob_gas)
                 .collect(),
-            gas_used_ratio: vec![],      // not used
+            gas_used_ratio,
             blob_gas_used_ratio: vec![], // not used
-            reward: None,
+            reward,
         },
         l2_pubdata_price: base_fee_history[start_block..=from_block]
             .iter()
@@ -447,8 +479,16 @@ impl SupportedMockSLNetwork for L1 {
             .build_client_inner(CHAIN_ID.0, CHAIN_ID.into())
             .method(
                 "eth_feeHistory",
-                move |block_count: U64, newest_block: web3::BlockNumber, _: Option<Vec<f32>>| {
-                    Ok(l2_eth_fee_history(&base_fee_history, block_count, newest_block).inner)
+                move |block_count: U64,
+                      newest_block: web3::BlockNumber,
+                      reward_percentiles: Option<Vec<f32>>| {
+                    Ok(l2_eth_fee_history(
+                        &base_fee_history,
+                        block_count,
+                        newest_block,
+                        reward_percentiles,
+                    )
+                    .inner)
                 },
             )
             .build()
@@ -465,11 +505,14 @@ impl SupportedMockSLNetwork for L2 {
             .build_client_inner(chain_id.as_u64(), chain_id.into())
             .method(
                 "eth_feeHistory",
-                move |block_count: U64, newest_block: web3::BlockNumber, _: Option<Vec<f32>>| {
+                move |block_count: U64,
+                      newest_block: web3::BlockNumber,
+                      reward_percentiles: Option<Vec<f32>>| {
                     Ok(l2_eth_fee_history(
                         &base_fee_history,
                         block_count,
                         newest_block,
+                        reward_percentiles,
                     ))
                 },
             )
@@ -574,6 +617,16 @@ impl<Net: SupportedMockSLNetwork> MockSettlementLayer<Net> {
         MockExecutedTxHandle { inner, tx_hash }
     }
 
+    /// Increments the blocks by a provided `confirmations` and executes a transaction from the sender account
+    /// that was not sent via this client (e.g., sent by another process using the same key). As a result,
+    /// the next sender nonce is consumed.
+    pub fn execute_external_tx(&self, confirmations: u64) {
+        self.inner
+            .write()
+            .unwrap()
+            .execute_external_tx(confirmations);
+    }
+
     /// Increases the block number in the network by the specified value.
     pub fn advance_block_number(&self, val: u64) -> u64 {
         let mut inner = self.inner.write().unwrap();
@@ -653,6 +706,8 @@ mod tests {
             base_fee_per_gas: block,
             base_fee_per_blob_gas: U256::from(blob),
             l2_pubdata_price: U256::from(pubdata_price),
+            gas_used_ratio: None,
+            priority_fee_per_gas: None,
         }
     }
 
@@ -708,11 +763,11 @@ mod tests {
             .build();
         client.advance_block_number(4);
 
-        let fee_history = client.client.base_fee_history(4, 4).await.unwrap();
+        let fee_history = client.client.base_fee_history(4, 4, None).await.unwrap();
         assert_eq!(fee_history, initial_fee_history[1..=4]);
-        let fee_history = client.client.base_fee_history(2, 2).await.unwrap();
+        let fee_history = client.client.base_fee_history(2, 2, None).await.unwrap();
         assert_eq!(fee_history, initial_fee_history[1..=2]);
-        let fee_history = client.client.base_fee_history(3, 2).await.unwrap();
+        let fee_history = client.client.base_fee_history(3, 2, None).await.unwrap();
         assert_eq!(fee_history, initial_fee_history[2..=3]);
     }
 
@@ -730,14 +785,46 @@ mod tests {
             .build();
         client.advance_block_number(4);
 
-        let fee_history = client.client.base_fee_history(4, 4).await.unwrap();
+        let fee_history = client.client.base_fee_history(4, 4, None).await.unwrap();
         assert_eq!(fee_history, initial_fee_history[1..=4]);
-        let fee_history = client.client.base_fee_history(2, 2).await.unwrap();
+        let fee_history = client.client.base_fee_history(2, 2, None).await.unwrap();
         assert_eq!(fee_history, initial_fee_history[1..=2]);
-        let fee_history = client.client.base_fee_history(3, 2).await.unwrap();
+        let fee_history = client.client.base_fee_history(3, 2, None).await.unwrap();
         assert_eq!(fee_history, initial_fee_history[2..=3]);
     }
 
+    #[tokio::test]
+    async fn fee_history_with_rewards() {
+        let initial_fee_history: Vec<_> = (1..=5)
+            .map(|i| BaseFees {
+                gas_used_ratio: Some(i as f64 / 10.0),
+                priority_fee_per_gas: Some(i * 100),
+                ..base_fees(i, 0, 0)
+            })
+            .collect();
+        let client = MockSettlementLayer::<L1>::builder()
+            .with_fee_history(initial_fee_history.clone())
+            .build();
+        client.advance_block_number(4);
+
+        let fee_history = client
+            .client
+            .base_fee_history(4, 4, Some(50.0))
+            .await
+            .unwrap();
+        assert_eq!(fee_history, initial_fee_history[1..=4]);
+
+        let fee_history = client.client.base_fee_history(4, 4, None).await.unwrap();
+        let expected_fee_history: Vec<_> = initial_fee_history[1..=4]
+            .iter()
+            .map(|fees| BaseFees {
+                priority_fee_per_gas: None,
+                ..fees.clone()
+            })
+            .collect();
+        assert_eq!(fee_history, expected_fee_history);
+    }
+
     #[tokio::test]
     async fn managing_transactions() {
         let client = MockSettlementLayer::<L1>::builder()
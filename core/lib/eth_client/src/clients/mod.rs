@@ -2,10 +2,12 @@
 
 mod http;
 mod mock;
+mod multi_provider;
 
 pub use zksync_web3_decl::client::{Client, DynClient, L1};
 
 pub use self::{
-    http::{PKSigningClient, SigningClient},
+    http::{PKSigningClient, RemoteSigningClient, SigningClient},
     mock::{MockSettlementLayer, MockSettlementLayerBuilder},
+    multi_provider::{MultiProviderClient, MultiProviderClientBuilder},
 };
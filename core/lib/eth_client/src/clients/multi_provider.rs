@@ -0,0 +1,690 @@
+//! Ethereum client backed by multiple RPC providers.
+
+use std::{
+    fmt,
+    future::Future,
+    num::NonZeroUsize,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use futures::future;
+use jsonrpsee::core::{
+    client::{BatchResponse, ClientT, Error},
+    params::BatchRequestBuilder,
+    traits::ToRpcParams,
+    JsonRawValue,
+};
+use serde::de::DeserializeOwned;
+use vise::{Counter, LabeledFamily, Metrics};
+use zksync_web3_decl::{
+    client::{DynClient, ForWeb3Network, TaggedClient, L1},
+    error::is_retriable,
+};
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "eth_client_multi_provider")]
+struct MultiProviderMetrics {
+    /// Number of retriable errors returned by a provider.
+    #[metrics(labels = ["provider"])]
+    provider_errors: LabeledFamily<String, Counter>,
+    /// Number of times a provider was marked as unhealthy.
+    #[metrics(labels = ["provider"])]
+    provider_marked_unhealthy: LabeledFamily<String, Counter>,
+    /// Number of reads for which the required number of providers didn't agree on the response.
+    #[metrics(labels = ["method"])]
+    quorum_failures: LabeledFamily<&'static str, Counter>,
+}
+
+#[vise::register]
+static METRICS: vise::Global<MultiProviderMetrics> = vise::Global::new();
+
+type ProviderCall<'a, T> = Pin<Box<dyn Future<Output = Result<T, Error>> + Send + 'a>>;
+
+/// Serialized request params that can be sent to multiple providers.
+#[derive(Debug, Clone)]
+struct SharedParams(Option<Box<JsonRawValue>>);
+
+impl SharedParams {
+    fn first_param_is_one_of(&self, values: &[&str]) -> bool {
+        let Some(raw) = &self.0 else {
+            return false;
+        };
+        let Ok(params) = serde_json::from_str::<Vec<serde_json::Value>>(raw.get()) else {
+            return false;
+        };
+        params
+            .first()
+            .and_then(serde_json::Value::as_str)
+            .is_some_and(|param| values.contains(&param))
+    }
+}
+
+impl ToRpcParams for SharedParams {
+    fn to_rpc_params(self) -> Result<Option<Box<JsonRawValue>>, serde_json::Error> {
+        Ok(self.0)
+    }
+}
+
+/// Routing of a request to the providers.
+#[derive(Debug, Clone, Copy)]
+enum Routing {
+    /// Sent to the first healthy provider, failing over to the next one on retriable errors.
+    Failover,
+    /// Sent to all providers; the response is only returned if at least the quorum of providers agree on it.
+    Quorum(&'static str),
+    /// Sent to all providers; succeeds if at least one provider succeeds.
+    Broadcast,
+}
+
+impl Routing {
+    fn new(method: &str, params: &SharedParams) -> Self {
+        match method {
+            "eth_sendRawTransaction" => Self::Broadcast,
+            "eth_getLogs" => Self::Quorum("eth_getLogs"),
+            "eth_getBlockByNumber" if params.first_param_is_one_of(&["finalized", "safe"]) => {
+                Self::Quorum("eth_getBlockByNumber")
+            }
+            _ => Self::Failover,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct ProviderHealth {
+    consecutive_failures: usize,
+    unhealthy_since: Option<Instant>,
+}
+
+#[derive(Debug, Clone)]
+struct Provider {
+    name: String,
+    client: Box<DynClient<L1>>,
+    /// Shared among client clones, so that all components observe the same provider health.
+    health: Arc<Mutex<ProviderHealth>>,
+}
+
+impl Provider {
+    fn is_healthy(&self, unhealthy_cooldown: Duration) -> bool {
+        let health = self.health.lock().unwrap();
+        match health.unhealthy_since {
+            Some(since) => since.elapsed() >= unhealthy_cooldown,
+            None => true,
+        }
+    }
+
+    fn report_success(&self) {
+        let mut health = self.health.lock().unwrap();
+        if health.unhealthy_since.is_some() {
+            tracing::info!("Provider `{}` is healthy again", self.name);
+        }
+        *health = ProviderHealth::default();
+    }
+
+    fn report_failure(&self, max_consecutive_failures: usize, err: &Error) {
+        METRICS.provider_errors[&self.name].inc();
+        let mut health = self.health.lock().unwrap();
+        health.consecutive_failures += 1;
+        tracing::warn!(
+            "Provider `{}` failed ({} consecutive failures): {err}",
+            self.name,
+            health.consecutive_failures
+        );
+        if health.consecutive_failures >= max_consecutive_failures {
+            if health.unhealthy_since.is_none() {
+                tracing::warn!("Marking provider `{}` as unhealthy", self.name);
+                METRICS.provider_marked_unhealthy[&self.name].inc();
+            }
+            // Each failure past the threshold restarts the cooldown.
+            health.unhealthy_since = Some(Instant::now());
+        }
+    }
+}
+
+/// Ethereum client holding several RPC providers for the same network. The client works on the JSON-RPC level,
+/// so it can be used as [`DynClient<L1>`] (e.g., as a query client for L1 components).
+///
+/// - Reads are routed to the first healthy provider (in the order the providers were added) and fail over
+///   to the next one on retriable errors. A provider is marked as unhealthy after several consecutive retriable errors
+///   and is only used as a last resort until a cooldown elapses.
+/// - Safety-critical reads (event logs and finalized / safe blocks) are sent to all providers if a quorum is configured;
+///   the response is only returned if at least the quorum of providers agree on it. Otherwise, a retriable error
+///   is returned.
+/// - Transactions are broadcast to all providers.
+#[derive(Debug, Clone)]
+pub struct MultiProviderClient {
+    providers: Vec<Provider>,
+    quorum: usize,
+    max_consecutive_failures: usize,
+    unhealthy_cooldown: Duration,
+    component_name: &'static str,
+}
+
+impl MultiProviderClient {
+    pub fn builder() -> MultiProviderClientBuilder {
+        MultiProviderClientBuilder::default()
+    }
+
+    /// Returns healthy providers followed by the unhealthy ones.
+    fn ordered_providers(&self) -> Vec<&Provider> {
+        let (mut healthy, unhealthy): (Vec<_>, Vec<_>) = self
+            .providers
+            .iter()
+            .partition(|provider| provider.is_healthy(self.unhealthy_cooldown));
+        healthy.extend(unhealthy);
+        healthy
+    }
+
+    fn report_error(&self, provider: &Provider, err: &Error) {
+        if is_retriable(err) {
+            provider.report_failure(self.max_consecutive_failures, err);
+        } else {
+            // The provider has responded, so it's healthy.
+            provider.report_success();
+        }
+    }
+
+    // `ClientT` is implemented for `Box<DynClient<_>>`, so boxed clients are passed to `call`.
+    #[allow(clippy::borrowed_box)]
+    async fn failover<'a, T>(
+        &'a self,
+        call: impl Fn(&'a Box<DynClient<L1>>) -> ProviderCall<'a, T>,
+    ) -> Result<T, Error> {
+        let mut last_error = None;
+        for provider in self.ordered_providers() {
+            match call(&provider.client).await {
+                Ok(value) => {
+                    provider.report_success();
+                    return Ok(value);
+                }
+                Err(err) => {
+                    self.report_error(provider, &err);
+                    if !is_retriable(&err) {
+                        return Err(err);
+                    }
+                    last_error = Some(err);
+                }
+            }
+        }
+        Err(last_error.expect("client has at least one provider"))
+    }
+
+    async fn quorum_request(
+        &self,
+        label: &'static str,
+        method: &str,
+        params: SharedParams,
+    ) -> Result<serde_json::Value, Error> {
+        let providers = self.ordered_providers();
+        let responses = future::join_all(providers.iter().map(|provider| {
+            ClientT::request::<serde_json::Value, _>(&provider.client, method, params.clone())
+        }))
+        .await;
+
+        let mut distinct_values: Vec<(serde_json::Value, usize)> = vec![];
+        let mut last_error = None;
+        for (provider, response) in providers.into_iter().zip(responses) {
+            match response {
+                Ok(value) => {
+                    provider.report_success();
+                    match distinct_values
+                        .iter_mut()
+                        .find(|(known, _)| *known == value)
+                    {
+                        Some((_, count)) => *count += 1,
+                        None => distinct_values.push((value, 1)),
+                    }
+                }
+                Err(err) => {
+                    self.report_error(provider, &err);
+                    last_error = Some(err);
+                }
+            }
+        }
+
+        if let Some(idx) = distinct_values
+            .iter()
+            .position(|(_, count)| *count >= self.quorum)
+        {
+            return Ok(distinct_values.swap_remove(idx).0);
+        }
+
+        METRICS.quorum_failures[&label].inc();
+        match last_error {
+            Some(err) if distinct_values.is_empty() => Err(err),
+            _ => {
+                let responded_count: usize = distinct_values.iter().map(|(_, count)| count).sum();
+                let message = format!(
+                    "quorum of {} providers not reached: {responded_count} providers returned {} distinct responses",
+                    self.quorum,
+                    distinct_values.len()
+                );
+                tracing::warn!("{method}: {message}");
+                // Providers may disagree because some of them lag behind, so the error is transient.
+                Err(Error::Transport(anyhow::anyhow!(message)))
+            }
+        }
+    }
+
+    /// Broadcasts the request to all providers. Succeeds if at least one provider has succeeded.
+    async fn broadcast_request(
+        &self,
+        method: &str,
+        params: SharedParams,
+    ) -> Result<serde_json::Value, Error> {
+        let responses = future::join_all(self.providers.iter().map(|provider| {
+            ClientT::request::<serde_json::Value, _>(&provider.client, method, params.clone())
+        }))
+        .await;
+
+        let mut response = None;
+        let mut error = None::<Error>;
+        for (provider, provider_response) in self.providers.iter().zip(responses) {
+            match provider_response {
+                Ok(value) => {
+                    provider.report_success();
+                    response = Some(value);
+                }
+                Err(err) => {
+                    tracing::info!("Provider `{}` failed `{method}`: {err}", provider.name);
+                    self.report_error(provider, &err);
+                    // If any provider returned a retriable error, the request may have been processed,
+                    // so the retriable error is reported to the caller.
+                    if !error.as_ref().is_some_and(is_retriable) {
+                        error = Some(err);
+                    }
+                }
+            }
+        }
+        match response {
+            Some(value) => Ok(value),
+            None => Err(error.expect("client has at least one provider")),
+        }
+    }
+}
+
+impl ForWeb3Network for MultiProviderClient {
+    type Net = L1;
+
+    fn network(&self) -> Self::Net {
+        self.providers[0].client.network()
+    }
+
+    fn component(&self) -> &'static str {
+        self.component_name
+    }
+}
+
+impl TaggedClient for MultiProviderClient {
+    fn set_component(&mut self, component_name: &'static str) {
+        self.component_name = component_name;
+        for provider in &mut self.providers {
+            provider.client = provider.client.clone().for_component(component_name);
+        }
+    }
+}
+
+#[async_trait]
+impl ClientT for MultiProviderClient {
+    async fn notification<Params>(&self, method: &str, params: Params) -> Result<(), Error>
+    where
+        Params: ToRpcParams + Send,
+    {
+        let params = SharedParams(params.to_rpc_params()?);
+        self.failover(|client| ClientT::notification(client, method, params.clone()))
+            .await
+    }
+
+    async fn request<R, Params>(&self, method: &str, params: Params) -> Result<R, Error>
+    where
+        R: DeserializeOwned,
+        Params: ToRpcParams + Send,
+    {
+        let params = SharedParams(params.to_rpc_params()?);
+        let raw_response = match Routing::new(method, &params) {
+            Routing::Quorum(label) if self.quorum > 1 => {
+                self.quorum_request(label, method, params).await?
+            }
+            Routing::Broadcast => self.broadcast_request(method, params).await?,
+            Routing::Failover | Routing::Quorum(_) => {
+                self.failover(|client| {
+                    ClientT::request::<serde_json::Value, _>(client, method, params.clone())
+                })
+                .await?
+            }
+        };
+        serde_json::from_value(raw_response).map_err(Error::ParseError)
+    }
+
+    async fn batch_request<'a, R>(
+        &self,
+        batch: BatchRequestBuilder<'a>,
+    ) -> Result<BatchResponse<'a, R>, Error>
+    where
+        R: DeserializeOwned + fmt::Debug + 'a,
+    {
+        // Batches are not used for L1 requests, so they are not routed based on the contained methods.
+        let requests: Vec<_> = batch.into_iter().collect();
+        self.failover(|client| {
+            let mut batch = BatchRequestBuilder::new();
+            for (method, params) in &requests {
+                batch
+                    .insert(*method, SharedParams(params.clone()))
+                    .expect("serialized params are always valid");
+            }
+            ClientT::batch_request(client, batch)
+        })
+        .await
+    }
+}
+
+/// Builder for [`MultiProviderClient`].
+pub struct MultiProviderClientBuilder {
+    providers: Vec<Provider>,
+    quorum: NonZeroUsize,
+    max_consecutive_failures: NonZeroUsize,
+    unhealthy_cooldown: Duration,
+}
+
+impl fmt::Debug for MultiProviderClientBuilder {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let provider_names: Vec<_> = self
+            .providers
+            .iter()
+            .map(|provider| &provider.name)
+            .collect();
+        formatter
+            .debug_struct("MultiProviderClientBuilder")
+            .field("providers", &provider_names)
+            .field("quorum", &self.quorum)
+            .field("max_consecutive_failures", &self.max_consecutive_failures)
+            .field("unhealthy_cooldown", &self.unhealthy_cooldown)
+            .finish()
+    }
+}
+
+impl Default for MultiProviderClientBuilder {
+    fn default() -> Self {
+        Self {
+            providers: vec![],
+            quorum: NonZeroUsize::MIN,
+            max_consecutive_failures: NonZeroUsize::new(3).unwrap(),
+            unhealthy_cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+impl MultiProviderClientBuilder {
+    /// Adds a provider. Providers are preferred for reads in the order they are added, and must be configured
+    /// for the same network. `name` is used in logs and metrics, so it should not contain sensitive data
+    /// (e.g., API keys in the provider URL).
+    pub fn with_provider(mut self, name: impl Into<String>, client: Box<DynClient<L1>>) -> Self {
+        self.providers.push(Provider {
+            name: name.into(),
+            client,
+            health: Arc::default(),
+        });
+        self
+    }
+
+    /// Sets the number of providers that must return the same response for safety-critical reads.
+    /// By default, no agreement is required.
+    pub fn with_quorum(mut self, quorum: NonZeroUsize) -> Self {
+        self.quorum = quorum;
+        self
+    }
+
+    /// Sets the number of consecutive retriable errors after which a provider is marked as unhealthy,
+    /// and the duration for which it stays unhealthy.
+    pub fn with_health_check(
+        mut self,
+        max_consecutive_failures: NonZeroUsize,
+        unhealthy_cooldown: Duration,
+    ) -> Self {
+        self.max_consecutive_failures = max_consecutive_failures;
+        self.unhealthy_cooldown = unhealthy_cooldown;
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no providers were added, or if the quorum exceeds the number of providers.
+    pub fn build(self) -> MultiProviderClient {
+        assert!(
+            !self.providers.is_empty(),
+            "at least one provider must be specified"
+        );
+        assert!(
+            self.quorum.get() <= self.providers.len(),
+            "quorum ({}) exceeds the number of providers ({})",
+            self.quorum,
+            self.providers.len()
+        );
+        tracing::info!("Creating multi-provider Ethereum client: {self:?}");
+
+        MultiProviderClient {
+            providers: self.providers,
+            quorum: self.quorum.get(),
+            max_consecutive_failures: self.max_consecutive_failures.get(),
+            unhealthy_cooldown: self.unhealthy_cooldown,
+            component_name: "",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    use assert_matches::assert_matches;
+    use jsonrpsee::core::ClientError;
+    use zksync_types::{
+        web3::{self, BlockNumber},
+        H256, U64,
+    };
+    use zksync_web3_decl::client::MockClient;
+
+    use super::*;
+    use crate::{EthInterface, RawTransactionBytes};
+
+    fn block_number_provider(
+        block_number: u64,
+        is_failing: Arc<AtomicBool>,
+        call_count: Arc<AtomicUsize>,
+    ) -> Box<DynClient<L1>> {
+        let client = MockClient::builder(L1::default())
+            .method("eth_blockNumber", move || {
+                call_count.fetch_add(1, Ordering::SeqCst);
+                if is_failing.load(Ordering::SeqCst) {
+                    Err(ClientError::RequestTimeout)
+                } else {
+                    Ok(U64::from(block_number))
+                }
+            })
+            .build();
+        Box::new(client)
+    }
+
+    fn logs_provider(logs: Vec<web3::Log>) -> Box<DynClient<L1>> {
+        let client = MockClient::builder(L1::default())
+            .method("eth_getLogs", move |_: serde_json::Value| Ok(logs.clone()))
+            .build();
+        Box::new(client)
+    }
+
+    fn block_provider(hash: H256) -> Box<DynClient<L1>> {
+        let client = MockClient::builder(L1::default())
+            .method("eth_getBlockByNumber", move |_: BlockNumber, _: bool| {
+                Ok(Some(web3::Block::<H256> {
+                    hash: Some(hash),
+                    ..web3::Block::default()
+                }))
+            })
+            .build();
+        Box::new(client)
+    }
+
+    fn test_log(block_number: u64) -> web3::Log {
+        web3::Log {
+            block_number: Some(block_number.into()),
+            ..web3::Log::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn reads_fail_over_to_healthy_provider() {
+        let first_is_failing = Arc::new(AtomicBool::new(true));
+        let first_call_count = Arc::new(AtomicUsize::new(0));
+        let second_call_count = Arc::new(AtomicUsize::new(0));
+        let client = MultiProviderClient::builder()
+            .with_provider(
+                "first",
+                block_number_provider(1, first_is_failing.clone(), first_call_count.clone()),
+            )
+            .with_provider(
+                "second",
+                block_number_provider(2, Arc::default(), second_call_count.clone()),
+            )
+            .with_health_check(NonZeroUsize::new(2).unwrap(), Duration::from_secs(3_600))
+            .build();
+        let client: Box<DynClient<L1>> = Box::new(client);
+
+        for _ in 0..2 {
+            assert_eq!(client.block_number().await.unwrap(), 2.into());
+        }
+        assert_eq!(first_call_count.load(Ordering::SeqCst), 2);
+        assert_eq!(second_call_count.load(Ordering::SeqCst), 2);
+
+        // The first provider is now unhealthy and shouldn't be queried, including by client clones.
+        first_is_failing.store(false, Ordering::SeqCst);
+        let client = client.for_component("test");
+        assert_eq!(client.block_number().await.unwrap(), 2.into());
+        assert_eq!(first_call_count.load(Ordering::SeqCst), 2);
+        assert_eq!(second_call_count.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn unhealthy_providers_are_used_as_last_resort() {
+        let first_is_failing = Arc::new(AtomicBool::new(true));
+        let second_is_failing = Arc::new(AtomicBool::new(true));
+        let client = MultiProviderClient::builder()
+            .with_provider(
+                "first",
+                block_number_provider(1, first_is_failing.clone(), Arc::default()),
+            )
+            .with_provider(
+                "second",
+                block_number_provider(2, second_is_failing.clone(), Arc::default()),
+            )
+            .with_health_check(NonZeroUsize::MIN, Duration::from_secs(3_600))
+            .build();
+
+        let err = client.block_number().await.unwrap_err();
+        assert_matches!(err.as_ref(), ClientError::RequestTimeout);
+
+        // Both providers are unhealthy, but are still queried.
+        first_is_failing.store(false, Ordering::SeqCst);
+        assert_eq!(client.block_number().await.unwrap(), 1.into());
+        // The first provider has recovered, so it should be preferred to the still unhealthy second provider.
+        second_is_failing.store(false, Ordering::SeqCst);
+        assert_eq!(client.block_number().await.unwrap(), 1.into());
+    }
+
+    #[tokio::test]
+    async fn non_retriable_errors_are_not_failed_over() {
+        let second_call_count = Arc::new(AtomicUsize::new(0));
+        let client = MultiProviderClient::builder()
+            // `eth_blockNumber` is not mocked, so the provider returns a "method not found" error.
+            .with_provider(
+                "first",
+                Box::new(MockClient::builder(L1::default()).build()),
+            )
+            .with_provider(
+                "second",
+                block_number_provider(2, Arc::default(), second_call_count.clone()),
+            )
+            .build();
+
+        let err = client.block_number().await.unwrap_err();
+        assert_matches!(err.as_ref(), ClientError::Call(_));
+        assert_eq!(second_call_count.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn logs_require_quorum() {
+        let logs = vec![test_log(1), test_log(2)];
+        let build_client = |quorum| {
+            MultiProviderClient::builder()
+                .with_provider("first", logs_provider(logs.clone()))
+                .with_provider("second", logs_provider(vec![test_log(1)]))
+                .with_provider("third", logs_provider(logs.clone()))
+                .with_quorum(NonZeroUsize::new(quorum).unwrap())
+                .build()
+        };
+
+        let filter = web3::Filter::default();
+        let client = build_client(2);
+        assert_eq!(client.logs(&filter).await.unwrap(), logs);
+
+        let client = build_client(3);
+        let err = client.logs(&filter).await.unwrap_err();
+        assert!(err.is_retriable(), "{err:?}");
+        assert_matches!(
+            err.as_ref(),
+            ClientError::Transport(err) if err.to_string().contains("quorum")
+        );
+    }
+
+    #[tokio::test]
+    async fn finalized_blocks_require_quorum() {
+        let client = MultiProviderClient::builder()
+            .with_provider("first", block_provider(H256::repeat_byte(1)))
+            .with_provider("second", block_provider(H256::repeat_byte(2)))
+            .with_quorum(NonZeroUsize::new(2).unwrap())
+            .build();
+
+        let err = client
+            .block(BlockNumber::Finalized.into())
+            .await
+            .unwrap_err();
+        assert!(err.is_retriable(), "{err:?}");
+
+        let block = client.block(BlockNumber::Latest.into()).await.unwrap();
+        assert_eq!(block.unwrap().hash, Some(H256::repeat_byte(1)));
+    }
+
+    #[tokio::test]
+    async fn transactions_are_broadcast_to_all_providers() {
+        let sent_tx_count = Arc::new(AtomicUsize::new(0));
+        let tx_hash = H256::repeat_byte(1);
+        let mut builder = MultiProviderClient::builder().with_provider(
+            "failing",
+            Box::new(
+                MockClient::builder(L1::default())
+                    .method("eth_sendRawTransaction", |_: web3::Bytes| {
+                        Err::<H256, _>(ClientError::RequestTimeout)
+                    })
+                    .build(),
+            ),
+        );
+        for name in ["first", "second"] {
+            let sent_tx_count = sent_tx_count.clone();
+            let client = MockClient::builder(L1::default())
+                .method("eth_sendRawTransaction", move |_: web3::Bytes| {
+                    sent_tx_count.fetch_add(1, Ordering::SeqCst);
+                    Ok(tx_hash)
+                })
+                .build();
+            builder = builder.with_provider(name, Box::new(client));
+        }
+        let client = builder.build();
+
+        let tx = RawTransactionBytes::new_unchecked(vec![1, 2, 3]);
+        assert_eq!(client.send_raw_tx(tx).await.unwrap(), tx_hash);
+        assert_eq!(sent_tx_count.load(Ordering::SeqCst), 2);
+    }
+}
@@ -65,13 +65,18 @@ impl Options {
 }
 
 /// Information about the base fees provided by the L1 client.
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct BaseFees {
     pub base_fee_per_gas: u64,
     // Base fee per blob gas. It is zero on networks that do not support blob transactions (e.g. L2s).
     pub base_fee_per_blob_gas: U256,
     // The price (in wei) for relaying the pubdata to L1. It is non-zero only for L2 settlement layers.
     pub l2_pubdata_price: U256,
+    // Ratio of gas used by the block to its gas limit. Not provided for the pending block
+    // (`eth_feeHistory` returns its base fee in addition to the requested blocks).
+    pub gas_used_ratio: Option<f64>,
+    // Effective priority fee per gas at the requested reward percentile. Only provided if the percentile was requested.
+    pub priority_fee_per_gas: Option<u64>,
 }
 
 /// Common Web3 interface, as seen by the core applications.
@@ -152,10 +157,14 @@ pub trait EthFeeInterface: EthInterface {
     ///
     /// Returns 1 value for each block in range, assuming that these blocks exist.
     /// Will return an error if the `from_block + block_count` is beyond the head block.
+    ///
+    /// If `reward_percentile` is specified, effective priority fees at this percentile
+    /// are returned as [`BaseFees::priority_fee_per_gas`].
     async fn base_fee_history(
         &self,
         from_block: usize,
         block_count: usize,
+        reward_percentile: Option<f32>,
     ) -> EnrichedClientResult<Vec<BaseFees>>;
 }
 
@@ -1,10 +1,14 @@
 use async_trait::async_trait;
 use zksync_types::{Address, EIP712TypedStructure, Eip712Domain, PackedEthSignature};
 
-pub use crate::{pk_signer::PrivateKeySigner, raw_ethereum_tx::TransactionParameters};
+pub use crate::{
+    pk_signer::PrivateKeySigner, raw_ethereum_tx::TransactionParameters,
+    remote_signer::RemoteSigner,
+};
 
 mod pk_signer;
 mod raw_ethereum_tx;
+mod remote_signer;
 
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
@@ -0,0 +1,277 @@
+use std::time::Duration;
+
+use jsonrpsee::{
+    core::client::ClientT,
+    http_client::{HttpClient, HttpClientBuilder},
+    rpc_params,
+};
+use serde::{Deserialize, Serialize};
+use zksync_types::{
+    url::SensitiveUrl,
+    web3::{AccessList, Bytes},
+    Address, EIP712TypedStructure, Eip712Domain, PackedEthSignature, H256, U256, U64,
+};
+
+use crate::{raw_ethereum_tx::TransactionParameters, EthereumSigner, SignerError};
+
+const LEGACY_TX_ID: u64 = 0;
+const ACCESSLISTS_TX_ID: u64 = 1;
+
+/// Transaction object accepted by the `eth_signTransaction` JSON-RPC method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SignTransactionRequest {
+    pub from: Address,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<Address>,
+    pub gas: U256,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_price: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_fee_per_gas: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_priority_fee_per_gas: Option<U256>,
+    pub value: U256,
+    pub data: Bytes,
+    pub nonce: U256,
+    pub chain_id: U64,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub transaction_type: Option<U64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access_list: Option<AccessList>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_fee_per_blob_gas: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob_versioned_hashes: Option<Vec<H256>>,
+}
+
+impl SignTransactionRequest {
+    pub(crate) fn new(from: Address, tx: TransactionParameters) -> Self {
+        // Consistently with `PrivateKeySigner`, `max_fee_per_gas` is used as the gas price for legacy transactions.
+        let is_legacy = matches!(
+            tx.transaction_type.map(|ty| ty.as_u64()),
+            None | Some(LEGACY_TX_ID | ACCESSLISTS_TX_ID)
+        );
+        let (gas_price, max_fee_per_gas, max_priority_fee_per_gas) = if is_legacy {
+            (Some(tx.max_fee_per_gas), None, None)
+        } else {
+            (
+                None,
+                Some(tx.max_fee_per_gas),
+                Some(tx.max_priority_fee_per_gas),
+            )
+        };
+
+        Self {
+            from,
+            to: tx.to,
+            gas: tx.gas,
+            gas_price,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            value: tx.value,
+            data: Bytes(tx.data),
+            nonce: tx.nonce,
+            chain_id: tx.chain_id.into(),
+            transaction_type: tx.transaction_type,
+            access_list: tx.access_list,
+            max_fee_per_blob_gas: tx.max_fee_per_blob_gas,
+            blob_versioned_hashes: tx.blob_versioned_hashes,
+        }
+    }
+}
+
+/// Signer delegating transaction signing to an external service (e.g., Web3Signer) over JSON-RPC.
+/// This allows to keep operator private keys out of the node configuration.
+///
+/// The signer is bound to a single account; the service must manage the key for this account.
+/// Only transaction signing (`eth_signTransaction`) is supported; EIP-712 typed data signing returns an error.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    client: HttpClient,
+    address: Address,
+}
+
+impl RemoteSigner {
+    /// Creates a signer for the specified account. This doesn't perform any requests to the signing service.
+    pub fn new(
+        url: &SensitiveUrl,
+        address: Address,
+        request_timeout: Duration,
+    ) -> Result<Self, SignerError> {
+        let client = HttpClientBuilder::default()
+            .request_timeout(request_timeout)
+            .build(url.expose_str())
+            .map_err(|err| {
+                SignerError::SigningFailed(format!("failed creating remote signer client: {err}"))
+            })?;
+        Ok(Self { client, address })
+    }
+
+    /// Returns the address of the account this signer is bound to.
+    pub fn address(&self) -> Address {
+        self.address
+    }
+}
+
+#[async_trait::async_trait]
+impl EthereumSigner for RemoteSigner {
+    /// Returns the configured account address.
+    async fn get_address(&self) -> Result<Address, SignerError> {
+        Ok(self.address())
+    }
+
+    async fn sign_typed_data<S: EIP712TypedStructure + Sync>(
+        &self,
+        _domain: &Eip712Domain,
+        _typed_struct: &S,
+    ) -> Result<PackedEthSignature, SignerError> {
+        Err(SignerError::SigningFailed(
+            "typed data signing is not supported by remote signer".to_owned(),
+        ))
+    }
+
+    /// Signs the transaction using the `eth_signTransaction` method and returns the RLP-encoded signed transaction.
+    async fn sign_transaction(
+        &self,
+        raw_tx: TransactionParameters,
+    ) -> Result<Vec<u8>, SignerError> {
+        let request = SignTransactionRequest::new(self.address, raw_tx);
+        let signed: Bytes = self
+            .client
+            .request("eth_signTransaction", rpc_params![request])
+            .await
+            .map_err(|err| {
+                SignerError::SigningFailed(format!("remote signer request failed: {err}"))
+            })?;
+        if signed.0.is_empty() {
+            return Err(SignerError::SigningFailed(
+                "remote signer returned an empty transaction".to_owned(),
+            ));
+        }
+        Ok(signed.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use jsonrpsee::{server::ServerBuilder, types::ErrorObjectOwned, RpcModule};
+    use zksync_types::{K256PrivateKey, EIP_4844_TX_TYPE};
+
+    use super::*;
+    use crate::PrivateKeySigner;
+
+    /// Converts the request back into transaction params to sign it locally.
+    fn to_transaction_params(request: SignTransactionRequest) -> TransactionParameters {
+        TransactionParameters {
+            nonce: request.nonce,
+            to: request.to,
+            gas: request.gas,
+            gas_price: request.gas_price,
+            value: request.value,
+            data: request.data.0,
+            chain_id: request.chain_id.as_u64(),
+            transaction_type: request.transaction_type,
+            access_list: request.access_list,
+            max_fee_per_gas: request
+                .max_fee_per_gas
+                .or(request.gas_price)
+                .unwrap_or_default(),
+            max_priority_fee_per_gas: request.max_priority_fee_per_gas.unwrap_or_default(),
+            max_fee_per_blob_gas: request.max_fee_per_blob_gas,
+            blob_versioned_hashes: request.blob_versioned_hashes,
+        }
+    }
+
+    /// Spawns a mock signing server backed by the provided signer and returns its URL.
+    async fn spawn_mock_server(signer: PrivateKeySigner) -> SensitiveUrl {
+        let mut rpc_module = RpcModule::new(signer);
+        rpc_module
+            .register_async_method("eth_signTransaction", |params, signer, _ext| async move {
+                let request: SignTransactionRequest = params.one()?;
+                let address = signer.get_address().await.unwrap();
+                if request.from != address {
+                    return Err(ErrorObjectOwned::owned(
+                        -32_000,
+                        format!("unknown account {:?}", request.from),
+                        None::<()>,
+                    ));
+                }
+                let signed = signer
+                    .sign_transaction(to_transaction_params(request))
+                    .await
+                    .unwrap();
+                Ok(Bytes(signed))
+            })
+            .unwrap();
+
+        let server = ServerBuilder::default()
+            .http_only()
+            .build((Ipv4Addr::LOCALHOST, 0))
+            .await
+            .unwrap();
+        let local_addr = server.local_addr().unwrap();
+        let server_handle = server.start(rpc_module);
+        // The server is stopped once the test runtime is dropped.
+        tokio::spawn(server_handle.stopped());
+        format!("http://{local_addr}/").parse().unwrap()
+    }
+
+    fn create_transactions() -> Vec<TransactionParameters> {
+        let base_tx = TransactionParameters {
+            nonce: 3.into(),
+            to: Some(Address::repeat_byte(0x11)),
+            gas: 100_000.into(),
+            value: 1_000.into(),
+            data: vec![1, 2, 3],
+            chain_id: 270,
+            max_fee_per_gas: 2_000.into(),
+            max_priority_fee_per_gas: 100.into(),
+            ..TransactionParameters::default()
+        };
+        vec![
+            base_tx.clone(),
+            TransactionParameters {
+                transaction_type: Some(2.into()),
+                ..base_tx.clone()
+            },
+            TransactionParameters {
+                transaction_type: Some(EIP_4844_TX_TYPE.into()),
+                max_fee_per_blob_gas: Some(10.into()),
+                blob_versioned_hashes: Some(vec![H256::repeat_byte(1)]),
+                ..base_tx
+            },
+        ]
+    }
+
+    #[tokio::test]
+    async fn signing_transactions_via_remote_signer() {
+        let local_signer =
+            PrivateKeySigner::new(K256PrivateKey::from_bytes(H256::repeat_byte(5)).unwrap());
+        let address = local_signer.get_address().await.unwrap();
+        let url = spawn_mock_server(local_signer.clone()).await;
+        let remote_signer = RemoteSigner::new(&url, address, Duration::from_secs(5)).unwrap();
+        assert_eq!(remote_signer.get_address().await.unwrap(), address);
+
+        for tx in create_transactions() {
+            let expected = local_signer.sign_transaction(tx.clone()).await.unwrap();
+            let actual = remote_signer.sign_transaction(tx.clone()).await.unwrap();
+            assert_eq!(actual, expected, "{tx:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn remote_signer_errors() {
+        let local_signer =
+            PrivateKeySigner::new(K256PrivateKey::from_bytes(H256::repeat_byte(5)).unwrap());
+        let url = spawn_mock_server(local_signer).await;
+        let remote_signer =
+            RemoteSigner::new(&url, Address::repeat_byte(1), Duration::from_secs(5)).unwrap();
+
+        let tx = create_transactions().pop().unwrap();
+        let err = remote_signer.sign_transaction(tx).await.unwrap_err();
+        assert!(err.to_string().contains("unknown account"), "{err}");
+    }
+}
@@ -0,0 +1,347 @@
+use std::{cmp::Ordering, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::future;
+use serde::Serialize;
+use zksync_config::configs::external_price_api_client::AggregatedPriceClientConfig;
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+use zksync_types::{base_token_ratio::BaseTokenAPIRatio, Address};
+
+use crate::{
+    metrics::{QuoteStatus, SourceQuoteLabels, METRICS},
+    PriceAPIClient,
+};
+
+/// Health details of a price source whose latest quote wasn't accepted.
+#[derive(Debug, Serialize)]
+struct SourceHealthDetails {
+    status: QuoteStatus,
+    error: String,
+}
+
+/// Named price source queried by [`AggregatedPriceAPIClient`].
+#[derive(Debug)]
+pub struct PriceSource {
+    name: &'static str,
+    client: Arc<dyn PriceAPIClient>,
+    health_updater: HealthUpdater,
+}
+
+impl PriceSource {
+    /// Creates a source with the specified name. The name is used in logs, metrics and as the name
+    /// of the source health check.
+    pub fn new(name: &'static str, client: Arc<dyn PriceAPIClient>) -> Self {
+        Self {
+            name,
+            client,
+            health_updater: ReactiveHealthCheck::new(name).1,
+        }
+    }
+
+    /// Returns a health check for this source. The check is [`HealthStatus::Affected`] if the latest quote
+    /// from the source has failed, was stale or was rejected as an outlier.
+    pub fn health_check(&self) -> ReactiveHealthCheck {
+        self.health_updater.subscribe()
+    }
+
+    fn report_accepted_quote(&self) {
+        METRICS.source_quotes[&SourceQuoteLabels {
+            source: self.name,
+            status: QuoteStatus::Accepted,
+        }]
+            .inc();
+        self.health_updater
+            .update(Health::from(HealthStatus::Ready));
+    }
+
+    fn report_rejected_quote(&self, status: QuoteStatus, error: String) {
+        METRICS.source_quotes[&SourceQuoteLabels {
+            source: self.name,
+            status,
+        }]
+            .inc();
+        let health = Health::from(HealthStatus::Affected)
+            .with_details(SourceHealthDetails { status, error });
+        self.health_updater.update(health);
+    }
+}
+
+/// Price client aggregating quotes from several sources.
+///
+/// All sources are queried concurrently. Quotes that are older than the configured max age, or deviate from
+/// the median of all fresh quotes by more than the configured percentage are discarded. The client returns
+/// the median of the remaining quotes (for an even number of quotes, the lower median is used so that
+/// the returned ratio is always one of the source quotes). If fewer quotes than the configured quorum remain,
+/// the client returns an error rather than a potentially manipulated ratio.
+#[derive(Debug)]
+pub struct AggregatedPriceAPIClient {
+    sources: Vec<PriceSource>,
+    quorum: usize,
+    max_deviation: f64,
+    max_quote_age: chrono::Duration,
+}
+
+impl AggregatedPriceAPIClient {
+    pub fn new(
+        config: &AggregatedPriceClientConfig,
+        sources: Vec<PriceSource>,
+    ) -> anyhow::Result<Self> {
+        let quorum = config.quorum();
+        anyhow::ensure!(
+            quorum > 0 && quorum <= sources.len(),
+            "quorum ({quorum}) must be positive and not exceed the number of price sources ({})",
+            sources.len()
+        );
+        Ok(Self {
+            sources,
+            quorum,
+            max_deviation: f64::from(config.max_deviation_percent) / 100.0,
+            max_quote_age: chrono::Duration::from_std(config.max_quote_age())?,
+        })
+    }
+
+    /// Returns health checks for all price sources.
+    pub fn health_checks(&self) -> Vec<ReactiveHealthCheck> {
+        self.sources.iter().map(PriceSource::health_check).collect()
+    }
+
+    /// Filters out stale and outlying quotes.
+    fn accepted_quotes<'a>(
+        &self,
+        quotes: Vec<(&'a PriceSource, BaseTokenAPIRatio)>,
+    ) -> Vec<(&'a PriceSource, BaseTokenAPIRatio)> {
+        let now = Utc::now();
+        let (fresh_quotes, stale_quotes): (Vec<_>, _) = quotes
+            .into_iter()
+            .partition(|(_, quote)| now - quote.ratio_timestamp <= self.max_quote_age);
+        for (source, quote) in stale_quotes {
+            let error = format!("stale quote obtained at {}", quote.ratio_timestamp);
+            tracing::warn!("Discarding {error} from price source `{}`", source.name);
+            source.report_rejected_quote(QuoteStatus::Stale, error);
+        }
+
+        let Some(median) = lower_median(&fresh_quotes) else {
+            return vec![];
+        };
+        let median = ratio_to_f64(&median);
+        let (accepted_quotes, outliers): (Vec<_>, _) =
+            fresh_quotes.into_iter().partition(|(_, quote)| {
+                (ratio_to_f64(quote) - median).abs() <= median * self.max_deviation
+            });
+        for (source, quote) in outliers {
+            let error = format!(
+                "outlying quote {}; median quote is {median}",
+                ratio_to_f64(&quote)
+            );
+            tracing::warn!("Discarding {error} from price source `{}`", source.name);
+            source.report_rejected_quote(QuoteStatus::Outlier, error);
+        }
+        for (source, _) in &accepted_quotes {
+            source.report_accepted_quote();
+        }
+        accepted_quotes
+    }
+}
+
+#[async_trait]
+impl PriceAPIClient for AggregatedPriceAPIClient {
+    async fn fetch_ratio(&self, token_address: Address) -> anyhow::Result<BaseTokenAPIRatio> {
+        let quote_futures = self.sources.iter().map(|source| async move {
+            let latency = METRICS.source_latency[&source.name].start();
+            let result = source.client.fetch_ratio(token_address).await;
+            latency.observe();
+            (source, result)
+        });
+        let results = future::join_all(quote_futures).await;
+
+        let mut quotes = Vec::with_capacity(results.len());
+        for (source, result) in results {
+            match result {
+                Ok(quote) => {
+                    METRICS.source_ratio[&source.name].set(ratio_to_f64(&quote));
+                    quotes.push((source, quote));
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed fetching quote from price source `{}`: {err:#}",
+                        source.name
+                    );
+                    source.report_rejected_quote(QuoteStatus::Failed, format!("{err:#}"));
+                }
+            }
+        }
+
+        let accepted_quotes = self.accepted_quotes(quotes);
+        if accepted_quotes.len() < self.quorum {
+            METRICS.quorum_failures.inc();
+            anyhow::bail!(
+                "only {} price sources out of {} returned fresh agreeing quotes; quorum is {}",
+                accepted_quotes.len(),
+                self.sources.len(),
+                self.quorum
+            );
+        }
+        METRICS.agreeing_sources.set(accepted_quotes.len());
+        Ok(lower_median(&accepted_quotes).expect("no accepted quotes"))
+    }
+}
+
+fn ratio_to_f64(ratio: &BaseTokenAPIRatio) -> f64 {
+    ratio.numerator.get() as f64 / ratio.denominator.get() as f64
+}
+
+fn lower_median(quotes: &[(&PriceSource, BaseTokenAPIRatio)]) -> Option<BaseTokenAPIRatio> {
+    let mut quotes: Vec<_> = quotes.iter().map(|(_, quote)| *quote).collect();
+    quotes.sort_unstable_by(|x, y| {
+        ratio_to_f64(x)
+            .partial_cmp(&ratio_to_f64(y))
+            .unwrap_or(Ordering::Equal)
+    });
+    let median_idx = quotes.len().checked_sub(1)? / 2;
+    Some(quotes[median_idx])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+
+    use zksync_health_check::CheckHealth;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct MockPriceClient(Option<BaseTokenAPIRatio>);
+
+    #[async_trait]
+    impl PriceAPIClient for MockPriceClient {
+        async fn fetch_ratio(&self, _token_address: Address) -> anyhow::Result<BaseTokenAPIRatio> {
+            self.0.ok_or_else(|| anyhow::anyhow!("source is down"))
+        }
+    }
+
+    fn quote(numerator: u64, age_secs: i64) -> Option<BaseTokenAPIRatio> {
+        Some(BaseTokenAPIRatio {
+            numerator: NonZeroU64::new(numerator).unwrap(),
+            denominator: NonZeroU64::new(100).unwrap(),
+            ratio_timestamp: Utc::now() - chrono::Duration::seconds(age_secs),
+        })
+    }
+
+    fn mock_config(quorum: u32) -> AggregatedPriceClientConfig {
+        AggregatedPriceClientConfig {
+            sources: vec![],
+            quorum: Some(quorum),
+            max_deviation_percent: 5,
+            max_quote_age_ms: 60_000,
+            coinmarketcap_api_key: None,
+            coinmarketcap_base_url: None,
+            uniswap_v3_pool_address: None,
+            uniswap_v3_quote_token_address: None,
+            uniswap_v3_twap_interval_sec: 600,
+        }
+    }
+
+    fn mock_client(
+        quorum: u32,
+        quotes: Vec<Option<BaseTokenAPIRatio>>,
+    ) -> AggregatedPriceAPIClient {
+        const NAMES: [&str; 5] = ["a", "b", "c", "d", "e"];
+
+        let sources = quotes
+            .into_iter()
+            .zip(NAMES)
+            .map(|(quote, name)| PriceSource::new(name, Arc::new(MockPriceClient(quote))))
+            .collect();
+        AggregatedPriceAPIClient::new(&mock_config(quorum), sources).unwrap()
+    }
+
+    #[tokio::test]
+    async fn aggregating_quotes() {
+        let client = mock_client(2, vec![quote(101, 0), quote(99, 0), quote(100, 0)]);
+        let ratio = client.fetch_ratio(Address::zero()).await.unwrap();
+        assert_eq!(ratio.numerator.get(), 100);
+
+        // Lower median is used for an even number of quotes.
+        let client = mock_client(
+            2,
+            vec![quote(101, 0), quote(99, 0), quote(100, 0), quote(102, 0)],
+        );
+        let ratio = client.fetch_ratio(Address::zero()).await.unwrap();
+        assert_eq!(ratio.numerator.get(), 100);
+    }
+
+    #[tokio::test]
+    async fn discarding_failed_stale_and_outlying_quotes() {
+        let client = mock_client(
+            2,
+            vec![
+                quote(100, 0),
+                None,
+                quote(50, 3_600),
+                quote(150, 0),
+                quote(102, 0),
+            ],
+        );
+        let ratio = client.fetch_ratio(Address::zero()).await.unwrap();
+        assert_eq!(ratio.numerator.get(), 100);
+
+        let client = mock_client(
+            3,
+            vec![
+                quote(100, 0),
+                None,
+                quote(50, 3_600),
+                quote(150, 0),
+                quote(102, 0),
+            ],
+        );
+        let err = client.fetch_ratio(Address::zero()).await.unwrap_err();
+        assert!(err.to_string().contains("quorum is 3"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn source_health_checks() {
+        let client = mock_client(
+            2,
+            vec![
+                quote(100, 0),
+                None,
+                quote(50, 3_600),
+                quote(150, 0),
+                quote(102, 0),
+            ],
+        );
+        let health_checks = client.health_checks();
+        for health_check in &health_checks {
+            let health = health_check.check_health().await;
+            assert_eq!(health.status(), HealthStatus::NotReady);
+        }
+
+        client.fetch_ratio(Address::zero()).await.unwrap();
+        let mut statuses = vec![];
+        for health_check in &health_checks {
+            let health = health_check.check_health().await;
+            let quote_status = health
+                .details()
+                .map(|details| details["status"].as_str().unwrap().to_owned());
+            statuses.push((health.status(), quote_status));
+        }
+        assert_eq!(
+            statuses,
+            [
+                (HealthStatus::Ready, None),
+                (HealthStatus::Affected, Some("failed".to_owned())),
+                (HealthStatus::Affected, Some("stale".to_owned())),
+                (HealthStatus::Affected, Some("outlier".to_owned())),
+                (HealthStatus::Ready, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn quorum_cannot_exceed_number_of_sources() {
+        let sources = vec![PriceSource::new("a", Arc::new(MockPriceClient(None)))];
+        AggregatedPriceAPIClient::new(&mock_config(2), sources).unwrap_err();
+    }
+}
@@ -0,0 +1,151 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use url::Url;
+use zksync_config::configs::ExternalPriceApiClientConfig;
+use zksync_types::{base_token_ratio::BaseTokenAPIRatio, Address};
+
+use crate::{address_to_string, utils::get_fraction, PriceAPIClient};
+
+const DEFAULT_CMC_API_URL: &str = "https://pro-api.coinmarketcap.com";
+const CMC_AUTH_HEADER: &str = "x-cmc_pro_api_key";
+const ETH_SYMBOL: &str = "ETH";
+
+/// Client fetching base token prices from the CoinMarketCap API.
+#[derive(Debug)]
+pub struct CmcPriceAPIClient {
+    base_url: Url,
+    client: reqwest::Client,
+    /// CoinMarketCap IDs of tokens resolved by their L1 address.
+    token_ids: Mutex<HashMap<Address, u64>>,
+}
+
+impl CmcPriceAPIClient {
+    pub fn new(config: ExternalPriceApiClientConfig) -> Self {
+        let aggregated_config = config.aggregated.as_ref();
+        let api_key = aggregated_config.and_then(|config| config.coinmarketcap_api_key.as_ref());
+        let mut client_builder = reqwest::Client::builder().timeout(config.client_timeout());
+        if let Some(api_key) = api_key {
+            let mut headers = reqwest::header::HeaderMap::new();
+            headers.insert(
+                reqwest::header::HeaderName::from_static(CMC_AUTH_HEADER),
+                reqwest::header::HeaderValue::from_str(api_key)
+                    .expect("Failed to create header value"),
+            );
+            client_builder = client_builder.default_headers(headers);
+        }
+        let client = client_builder
+            .build()
+            .expect("Failed to build reqwest client");
+
+        let base_url = aggregated_config
+            .and_then(|config| config.coinmarketcap_base_url.as_deref())
+            .unwrap_or(DEFAULT_CMC_API_URL);
+
+        Self {
+            base_url: Url::parse(base_url).expect("Failed to parse CoinMarketCap URL"),
+            client,
+            token_ids: Mutex::default(),
+        }
+    }
+
+    async fn get<T: serde::de::DeserializeOwned>(&self, url: Url) -> anyhow::Result<T> {
+        let response = self.client.get(url).send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!(
+                "Http error while fetching data from CoinMarketCap. Status: {}, msg: {}",
+                response.status(),
+                response.text().await.unwrap_or(String::new())
+            ));
+        }
+        Ok(response.json::<T>().await?)
+    }
+
+    async fn get_token_id(&self, address: Address) -> anyhow::Result<u64> {
+        if let Some(&id) = self.token_ids.lock().unwrap().get(&address) {
+            return Ok(id);
+        }
+
+        let address_str = address_to_string(&address);
+        let info_url = self
+            .base_url
+            .join(format!("/v1/cryptocurrency/info?address={address_str}").as_str())
+            .expect("failed to join URL path");
+        let info = self.get::<CmcInfoResponse>(info_url).await?;
+        let id = info
+            .data
+            .values()
+            .next()
+            .with_context(|| format!("Token not found on CoinMarketCap: {address_str}"))?
+            .id;
+        self.token_ids.lock().unwrap().insert(address, id);
+        Ok(id)
+    }
+
+    async fn get_token_price_by_address(
+        &self,
+        address: Address,
+    ) -> anyhow::Result<(f64, DateTime<Utc>)> {
+        let id = self.get_token_id(address).await?;
+        let quotes_url = self
+            .base_url
+            .join(format!("/v2/cryptocurrency/quotes/latest?id={id}&convert={ETH_SYMBOL}").as_str())
+            .expect("failed to join URL path");
+        let response = self.get::<CmcQuotesResponse>(quotes_url).await?;
+        let quote = response
+            .data
+            .get(&id.to_string())
+            .and_then(|token| token.quote.get(ETH_SYMBOL))
+            .with_context(|| {
+                format!("Price not found for token: {}", address_to_string(&address))
+            })?;
+
+        let timestamp = DateTime::parse_from_rfc3339(&quote.last_updated)
+            .with_context(|| format!("invalid quote timestamp: {}", quote.last_updated))?;
+        Ok((quote.price, timestamp.with_timezone(&Utc)))
+    }
+}
+
+#[async_trait]
+impl PriceAPIClient for CmcPriceAPIClient {
+    async fn fetch_ratio(&self, token_address: Address) -> anyhow::Result<BaseTokenAPIRatio> {
+        let (base_token_in_eth, ratio_timestamp) =
+            self.get_token_price_by_address(token_address).await?;
+        let (numerator, denominator) = get_fraction(base_token_in_eth);
+
+        Ok(BaseTokenAPIRatio {
+            numerator,
+            denominator,
+            ratio_timestamp,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CmcInfoResponse {
+    data: HashMap<String, CmcTokenInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CmcTokenInfo {
+    id: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct CmcQuotesResponse {
+    data: HashMap<String, CmcTokenQuotes>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CmcTokenQuotes {
+    quote: HashMap<String, CmcQuote>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CmcQuote {
+    price: f64,
+    last_updated: String,
+}
@@ -1,5 +1,9 @@
+pub mod aggregated_client;
+pub mod cmc_api;
 pub mod coingecko_api;
 pub mod forced_price_client;
+mod metrics;
+pub mod uniswap_v3;
 mod utils;
 
 use std::fmt;
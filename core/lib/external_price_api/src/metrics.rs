@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use serde::Serialize;
+use vise::{
+    Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, LabeledFamily,
+    Metrics, Unit,
+};
+
+/// Outcome of querying a single source by the aggregated price client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, Serialize)]
+#[metrics(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum QuoteStatus {
+    /// Quote was used to compute the aggregated ratio.
+    Accepted,
+    /// Source has returned an error.
+    Failed,
+    /// Quote is older than the configured max age.
+    Stale,
+    /// Quote deviates from the median of all quotes by more than allowed.
+    Outlier,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelSet)]
+pub(crate) struct SourceQuoteLabels {
+    pub source: &'static str,
+    pub status: QuoteStatus,
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "external_price_api")]
+pub(crate) struct ExternalPriceApiMetrics {
+    /// Number of quotes returned by aggregated price sources, grouped by the quote status.
+    pub source_quotes: Family<SourceQuoteLabels, Counter>,
+    /// Latest ETH / base token ratio returned by each aggregated price source.
+    #[metrics(labels = ["source"])]
+    pub source_ratio: LabeledFamily<&'static str, Gauge<f64>>,
+    /// Latency of querying each aggregated price source.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds, labels = ["source"])]
+    pub source_latency: LabeledFamily<&'static str, Histogram<Duration>>,
+    /// Number of sources that agreed on the latest aggregated ratio.
+    pub agreeing_sources: Gauge<usize>,
+    /// Number of aggregated quotes that failed because of a lack of the quorum.
+    pub quorum_failures: Counter,
+}
+
+#[vise::register]
+pub(crate) static METRICS: vise::Global<ExternalPriceApiMetrics> = vise::Global::new();
@@ -0,0 +1,283 @@
+use std::time::Duration;
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use chrono::Utc;
+use zksync_eth_client::{
+    clients::{DynClient, L1},
+    CallFunctionArgs,
+};
+use zksync_types::{
+    base_token_ratio::BaseTokenAPIRatio,
+    ethabi::{self, Token},
+    web3::contract::{Detokenize, Error as DetokenizeError, Tokenize},
+    Address, U256,
+};
+
+use crate::{utils::get_fraction, PriceAPIClient};
+
+/// Subset of the Uniswap v3 pool and ERC-20 ABIs used by the client.
+const UNISWAP_V3_ABI: &str = r#"[
+    {
+        "type": "function",
+        "name": "token0",
+        "inputs": [],
+        "outputs": [{ "name": "", "type": "address" }],
+        "stateMutability": "view"
+    },
+    {
+        "type": "function",
+        "name": "token1",
+        "inputs": [],
+        "outputs": [{ "name": "", "type": "address" }],
+        "stateMutability": "view"
+    },
+    {
+        "type": "function",
+        "name": "observe",
+        "inputs": [{ "name": "secondsAgos", "type": "uint32[]" }],
+        "outputs": [
+            { "name": "tickCumulatives", "type": "int56[]" },
+            { "name": "secondsPerLiquidityCumulativeX128s", "type": "uint160[]" }
+        ],
+        "stateMutability": "view"
+    },
+    {
+        "type": "function",
+        "name": "decimals",
+        "inputs": [],
+        "outputs": [{ "name": "", "type": "uint8" }],
+        "stateMutability": "view"
+    }
+]"#;
+
+/// Client computing the base token price as a time-weighted average price (TWAP) of a Uniswap v3
+/// base token / WETH pool on L1.
+#[derive(Debug)]
+pub struct UniswapV3TwapClient {
+    eth_client: Box<DynClient<L1>>,
+    pool_address: Address,
+    /// Address of the token (WETH) that the base token must be paired with in the pool.
+    quote_token_address: Address,
+    twap_interval: Duration,
+    abi: ethabi::Contract,
+}
+
+impl UniswapV3TwapClient {
+    pub fn new(
+        eth_client: Box<DynClient<L1>>,
+        pool_address: Address,
+        quote_token_address: Address,
+        twap_interval: Duration,
+    ) -> Self {
+        Self {
+            eth_client: eth_client.for_component("uniswap_v3_price_client"),
+            pool_address,
+            quote_token_address,
+            twap_interval,
+            abi: ethabi::Contract::load(UNISWAP_V3_ABI.as_bytes()).expect("invalid Uniswap v3 ABI"),
+        }
+    }
+
+    async fn call<T: Detokenize>(
+        &self,
+        contract_address: Address,
+        function: &str,
+        params: impl Tokenize,
+    ) -> anyhow::Result<T> {
+        CallFunctionArgs::new(function, params)
+            .for_contract(contract_address, &self.abi)
+            .call(&self.eth_client)
+            .await
+            .with_context(|| format!("failed calling `{function}` on {contract_address:?}"))
+    }
+
+    async fn decimals(&self, token_address: Address) -> anyhow::Result<i32> {
+        let decimals: U256 = self.call(token_address, "decimals", ()).await?;
+        anyhow::ensure!(
+            decimals <= U256::from(u8::MAX),
+            "invalid token decimals: {decimals}"
+        );
+        Ok(decimals.as_u32() as i32)
+    }
+
+    /// Returns the arithmetic mean tick of the pool over the TWAP interval.
+    async fn mean_tick(&self) -> anyhow::Result<i64> {
+        let interval_secs =
+            u32::try_from(self.twap_interval.as_secs()).context("TWAP interval is too large")?;
+        anyhow::ensure!(interval_secs > 0, "TWAP interval must be positive");
+        let seconds_agos = Token::Array(vec![
+            Token::Uint(interval_secs.into()),
+            Token::Uint(0.into()),
+        ]);
+        let observation: PoolObservation = self
+            .call(self.pool_address, "observe", seconds_agos)
+            .await?;
+        Ok(observation.mean_tick(interval_secs))
+    }
+}
+
+#[async_trait]
+impl PriceAPIClient for UniswapV3TwapClient {
+    async fn fetch_ratio(&self, token_address: Address) -> anyhow::Result<BaseTokenAPIRatio> {
+        let token0: Address = self.call(self.pool_address, "token0", ()).await?;
+        let token1: Address = self.call(self.pool_address, "token1", ()).await?;
+        check_pool_tokens((token0, token1), token_address, self.quote_token_address)
+            .with_context(|| format!("invalid Uniswap v3 pool {:?}", self.pool_address))?;
+        let decimals0 = self.decimals(token0).await?;
+        let decimals1 = self.decimals(token1).await?;
+        let mean_tick = self.mean_tick().await?;
+
+        let base_token_in_eth =
+            base_token_price(mean_tick, decimals0, decimals1, token_address == token0);
+        anyhow::ensure!(
+            base_token_in_eth.is_finite() && base_token_in_eth > 0.0,
+            "invalid TWAP price: {base_token_in_eth}"
+        );
+        let (numerator, denominator) = get_fraction(base_token_in_eth);
+        Ok(BaseTokenAPIRatio {
+            numerator,
+            denominator,
+            ratio_timestamp: Utc::now(),
+        })
+    }
+}
+
+/// Checks that the pool pairs the base token with the quote token; otherwise, the pool price isn't denominated in ETH.
+fn check_pool_tokens(
+    (token0, token1): (Address, Address),
+    base_token_address: Address,
+    quote_token_address: Address,
+) -> anyhow::Result<()> {
+    let other_token = if base_token_address == token0 {
+        token1
+    } else if base_token_address == token1 {
+        token0
+    } else {
+        anyhow::bail!("pool doesn't contain base token {base_token_address:?}");
+    };
+    anyhow::ensure!(
+        other_token == quote_token_address,
+        "pool pairs base token with {other_token:?} rather than with quote token {quote_token_address:?}"
+    );
+    Ok(())
+}
+
+/// Output of the `observe()` pool method; only tick cumulatives are used.
+#[derive(Debug)]
+struct PoolObservation {
+    tick_cumulatives: Vec<i64>,
+}
+
+impl PoolObservation {
+    fn mean_tick(&self, interval_secs: u32) -> i64 {
+        let delta = self.tick_cumulatives[1] - self.tick_cumulatives[0];
+        // Round towards negative infinity, same as Uniswap's `OracleLibrary.consult()`.
+        delta.div_euclid(interval_secs.into())
+    }
+}
+
+impl Detokenize for PoolObservation {
+    fn from_tokens(tokens: Vec<Token>) -> Result<Self, DetokenizeError> {
+        let Some(Token::Array(tick_cumulatives)) = tokens.into_iter().next() else {
+            return Err(DetokenizeError::InvalidOutputType(
+                "expected array of tick cumulatives".to_owned(),
+            ));
+        };
+        let tick_cumulatives = tick_cumulatives
+            .into_iter()
+            .map(|token| match token {
+                Token::Int(value) => Ok(int256_to_i64(value)),
+                other => Err(DetokenizeError::InvalidOutputType(format!(
+                    "expected int56, got {other:?}"
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if tick_cumulatives.len() != 2 {
+            return Err(DetokenizeError::InvalidOutputType(format!(
+                "expected 2 tick cumulatives, got {}",
+                tick_cumulatives.len()
+            )));
+        }
+        Ok(Self { tick_cumulatives })
+    }
+}
+
+/// Converts a two's complement 256-bit integer to `i64`. Values are expected to fit into `int56`.
+fn int256_to_i64(value: U256) -> i64 {
+    if value.bit(255) {
+        let abs_value = (!value).overflowing_add(U256::one()).0;
+        -(abs_value.low_u64() as i64)
+    } else {
+        value.low_u64() as i64
+    }
+}
+
+/// Computes the base token price in ETH from the pool tick. The tick determines the price of `token0` in `token1`
+/// in the smallest token units.
+fn base_token_price(tick: i64, decimals0: i32, decimals1: i32, is_base_token0: bool) -> f64 {
+    let token0_price = 1.0001_f64.powf(tick as f64) * 10_f64.powi(decimals0 - decimals1);
+    if is_base_token0 {
+        token0_price
+    } else {
+        token0_price.recip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converting_signed_ints() {
+        assert_eq!(int256_to_i64(U256::from(42)), 42);
+        assert_eq!(int256_to_i64(U256::MAX), -1);
+        assert_eq!(int256_to_i64(U256::MAX - 41), -42);
+    }
+
+    #[test]
+    fn checking_pool_tokens() {
+        let base_token = Address::repeat_byte(1);
+        let weth = Address::repeat_byte(2);
+        let other_token = Address::repeat_byte(3);
+
+        check_pool_tokens((base_token, weth), base_token, weth).unwrap();
+        check_pool_tokens((weth, base_token), base_token, weth).unwrap();
+        let err = check_pool_tokens((base_token, other_token), base_token, weth).unwrap_err();
+        assert!(
+            err.to_string().contains("rather than with quote token"),
+            "{err}"
+        );
+        let err = check_pool_tokens((other_token, weth), base_token, weth).unwrap_err();
+        assert!(
+            err.to_string().contains("doesn't contain base token"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn computing_mean_tick() {
+        let observation = PoolObservation {
+            tick_cumulatives: vec![-1_000, -7_000],
+        };
+        assert_eq!(observation.mean_tick(600), -10);
+        let observation = PoolObservation {
+            tick_cumulatives: vec![0, -6_001],
+        };
+        assert_eq!(observation.mean_tick(600), -11);
+    }
+
+    #[test]
+    fn computing_base_token_price() {
+        let price = base_token_price(0, 18, 18, true);
+        assert!((price - 1.0).abs() < 1e-9, "{price}");
+        // tick = ln(2) / ln(1.0001) ≈ 6932
+        let price = base_token_price(6_932, 18, 18, true);
+        assert!((price - 2.0).abs() < 1e-3, "{price}");
+        let price = base_token_price(6_932, 18, 18, false);
+        assert!((price - 0.5).abs() < 1e-3, "{price}");
+        // Base token with 6 decimals (e.g., a stablecoin) as `token0`.
+        let price = base_token_price(0, 6, 18, true);
+        assert!((price - 1e-12).abs() < 1e-20, "{price}");
+    }
+}
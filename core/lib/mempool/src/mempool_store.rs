@@ -1,7 +1,8 @@
 use std::collections::{hash_map, BTreeSet, HashMap, HashSet};
 
+use zksync_config::configs::chain::MempoolOrdering;
 use zksync_types::{
-    l1::L1Tx, l2::L2Tx, Address, ExecuteTransactionCommon, Nonce, PriorityOpId, Transaction,
+    l1::L1Tx, l2::L2Tx, Address, ExecuteTransactionCommon, Nonce, PriorityOpId, Transaction, H256,
 };
 
 use crate::types::{AccountTransactions, L2TxFilter, MempoolScore};
@@ -10,6 +11,8 @@ use crate::types::{AccountTransactions, L2TxFilter, MempoolScore};
 pub struct MempoolInfo {
     pub stashed_accounts: Vec<Address>,
     pub purged_accounts: Vec<Address>,
+    /// Hashes of individual transactions evicted because the mempool has reached its capacity.
+    pub evicted_transactions: Vec<H256>,
 }
 
 #[derive(Debug)]
@@ -33,6 +36,7 @@ pub struct MempoolStore {
     /// Number of L2 transactions in the mempool.
     size: u64,
     capacity: u64,
+    ordering: MempoolOrdering,
 }
 
 impl MempoolStore {
@@ -45,9 +49,16 @@ impl MempoolStore {
             stashed_accounts: vec![],
             size: 0,
             capacity,
+            ordering: MempoolOrdering::default(),
         }
     }
 
+    /// Sets the ordering of L2 transactions. The ordering is also used to choose transactions for eviction.
+    pub fn with_ordering(mut self, ordering: MempoolOrdering) -> Self {
+        self.ordering = ordering;
+        self
+    }
+
     /// Inserts batch of new transactions to mempool
     /// `initial_nonces` provides current committed nonce information to mempool
     /// variable is used only if account is not present in mempool yet and we have to bootstrap it
@@ -107,7 +118,7 @@ impl MempoolStore {
             hash_map::Entry::Vacant(entry) => {
                 let account_nonce = initial_nonces.get(&account).cloned().unwrap_or(Nonce(0));
                 entry
-                    .insert(AccountTransactions::new(account_nonce))
+                    .insert(AccountTransactions::new(account_nonce, self.ordering))
                     .insert(transaction)
             }
         };
@@ -206,9 +217,21 @@ impl MempoolStore {
     }
 
     pub fn get_mempool_info(&mut self) -> MempoolInfo {
+        let (purged_accounts, evicted_transactions) = match self.ordering {
+            MempoolOrdering::Fifo => {
+                let purged_accounts = self.gc();
+                (purged_accounts, self.evict())
+            }
+            // Evict the cheapest transactions first, so that GC doesn't purge whole accounts regardless of their fees.
+            MempoolOrdering::FeePriority => {
+                let evicted_transactions = self.evict();
+                (self.gc(), evicted_transactions)
+            }
+        };
         MempoolInfo {
             stashed_accounts: std::mem::take(&mut self.stashed_accounts),
-            purged_accounts: self.gc(),
+            purged_accounts,
+            evicted_transactions,
         }
     }
 
@@ -240,4 +263,38 @@ impl MempoolStore {
         }
         vec![]
     }
+
+    /// Evicts transactions with the lowest priority until the mempool size drops below its capacity.
+    /// Only the last transaction of an account can be evicted, so that no nonce gaps are created.
+    fn evict(&mut self) -> Vec<H256> {
+        let mut evicted = vec![];
+        if self.size < self.capacity {
+            return evicted;
+        }
+
+        let mut candidates: BTreeSet<_> = self
+            .l2_transactions_per_account
+            .values()
+            .filter_map(AccountTransactions::last_score)
+            .collect();
+        while self.size >= self.capacity {
+            let Some(score) = candidates.pop_first() else {
+                break;
+            };
+            let account_transactions = self
+                .l2_transactions_per_account
+                .get_mut(&score.account)
+                .expect("mempool: dangling pointer in eviction queue");
+            let (transaction, is_next) = account_transactions.evict_last();
+            if is_next {
+                self.l2_priority_queue.remove(&score);
+            }
+            if let Some(score) = account_transactions.last_score() {
+                candidates.insert(score);
+            }
+            self.size -= 1;
+            evicted.push(transaction.hash());
+        }
+        evicted
+    }
 }
@@ -3,6 +3,7 @@ use std::{
     iter::FromIterator,
 };
 
+use zksync_config::configs::chain::MempoolOrdering;
 use zksync_types::{
     fee::Fee,
     helpers::unix_timestamp_ms,
@@ -365,6 +366,161 @@ fn mempool_capacity() {
     );
 }
 
+#[test]
+fn fee_priority_ordering() {
+    let mut mempool =
+        MempoolStore::new(PriorityOpId(0), 100).with_ordering(MempoolOrdering::FeePriority);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let account2 = Address::random();
+    let now = unix_timestamp_ms();
+    mempool.insert(
+        vec![
+            gen_l2_tx_with_fee(account0, Nonce(0), now, 100),
+            gen_l2_tx_with_fee(account1, Nonce(0), now + 1, 300),
+            gen_l2_tx_with_fee(account1, Nonce(1), now + 2, 50),
+            gen_l2_tx_with_fee(account2, Nonce(0), now + 3, 100),
+        ],
+        HashMap::new(),
+    );
+
+    // The highest-paying transaction goes first; the cheap follow-up of `account1` is scored on its own.
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account1, 0)
+    );
+    // Transactions with equal fees are ordered by the time they were received.
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account0, 0)
+    );
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account2, 0)
+    );
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account1, 1)
+    );
+    assert_eq!(mempool.next_transaction(&L2TxFilter::default()), None);
+}
+
+#[test]
+fn fifo_ordering_ignores_fees() {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let now = unix_timestamp_ms();
+    mempool.insert(
+        vec![
+            gen_l2_tx_with_fee(account0, Nonce(0), now, 100),
+            gen_l2_tx_with_fee(account1, Nonce(0), now + 1, 300),
+        ],
+        HashMap::new(),
+    );
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account0, 0)
+    );
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account1, 0)
+    );
+}
+
+#[test]
+fn replacement_is_not_limited_by_fee() {
+    // Replacement fee rules are enforced when the transaction is submitted via API. The mempool must always
+    // accept replacements, so that it stays in sync with the transactions in the storage.
+    let mut mempool =
+        MempoolStore::new(PriorityOpId(0), 100).with_ordering(MempoolOrdering::FeePriority);
+    let account = Address::random();
+    let now = unix_timestamp_ms();
+    mempool.insert(
+        vec![gen_l2_tx_with_fee(account, Nonce(0), now, 100)],
+        HashMap::new(),
+    );
+    mempool.insert(
+        vec![gen_l2_tx_with_fee(account, Nonce(0), now + 1, 50)],
+        HashMap::new(),
+    );
+    assert_eq!(mempool.stats().l2_transaction_count, 1);
+    let tx = mempool.next_transaction(&L2TxFilter::default()).unwrap();
+    assert_eq!(tx.received_timestamp_ms, now + 1);
+    assert_eq!(mempool.next_transaction(&L2TxFilter::default()), None);
+}
+
+#[test]
+fn fee_aware_eviction() {
+    let mut mempool =
+        MempoolStore::new(PriorityOpId(0), 4).with_ordering(MempoolOrdering::FeePriority);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let now = unix_timestamp_ms();
+    let transactions = vec![
+        gen_l2_tx_with_fee(account0, Nonce(0), now, 500),
+        gen_l2_tx_with_fee(account0, Nonce(1), now, 50),
+        gen_l2_tx_with_fee(account0, Nonce(2), now, 400),
+        gen_l2_tx_with_fee(account1, Nonce(0), now, 100),
+        gen_l2_tx_with_fee(account1, Nonce(1), now, 200),
+    ];
+    let hashes: Vec<_> = transactions.iter().map(Transaction::hash).collect();
+    mempool.insert(transactions, HashMap::new());
+
+    let info = mempool.get_mempool_info();
+    assert!(info.purged_accounts.is_empty());
+    // Only the last transaction of an account can be evicted, so the cheapest tail (`account1`, nonce 1)
+    // is evicted first, followed by the cheapest remaining tail (`account1`, nonce 0).
+    assert_eq!(info.evicted_transactions, [hashes[4], hashes[3]]);
+    assert_eq!(mempool.stats().l2_transaction_count, 3);
+    assert_eq!(mempool.stats().l2_priority_queue_size, 1);
+
+    for nonce in 0..3 {
+        assert_eq!(
+            view(mempool.next_transaction(&L2TxFilter::default())),
+            (account0, nonce)
+        );
+    }
+    assert_eq!(mempool.next_transaction(&L2TxFilter::default()), None);
+}
+
+#[test]
+fn fee_aware_eviction_precedes_gc() {
+    let mut mempool =
+        MempoolStore::new(PriorityOpId(0), 3).with_ordering(MempoolOrdering::FeePriority);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let now = unix_timestamp_ms();
+    // `account0` has a nonce gap, so it's not in the priority queue and would be purged by GC.
+    let transactions = vec![
+        gen_l2_tx_with_fee(account0, Nonce(1), now, 1_000),
+        gen_l2_tx_with_fee(account0, Nonce(2), now, 1_000),
+        gen_l2_tx_with_fee(account1, Nonce(0), now, 10),
+        gen_l2_tx_with_fee(account1, Nonce(1), now, 10),
+        gen_l2_tx_with_fee(account1, Nonce(2), now, 10),
+    ];
+    let hashes: Vec<_> = transactions.iter().map(Transaction::hash).collect();
+    mempool.insert(transactions, HashMap::new());
+
+    let info = mempool.get_mempool_info();
+    assert!(info.purged_accounts.is_empty());
+    assert_eq!(info.evicted_transactions, [hashes[4], hashes[3], hashes[2]]);
+    assert_eq!(mempool.stats().l2_transaction_count, 2);
+
+    // Transactions of the high-fee account are retained once the nonce gap is filled.
+    mempool.insert(
+        vec![gen_l2_tx_with_fee(account0, Nonce(0), now, 1_000)],
+        HashMap::new(),
+    );
+    for nonce in 0..3 {
+        assert_eq!(
+            view(mempool.next_transaction(&L2TxFilter::default())),
+            (account0, nonce)
+        );
+    }
+    assert_eq!(mempool.next_transaction(&L2TxFilter::default()), None);
+}
+
 fn gen_l2_tx(address: Address, nonce: Nonce) -> Transaction {
     gen_l2_tx_with_timestamp(address, nonce, unix_timestamp_ms())
 }
@@ -384,6 +540,23 @@ fn gen_l2_tx_with_timestamp(address: Address, nonce: Nonce, received_at_ms: u64)
     txn.into()
 }
 
+fn gen_l2_tx_with_fee(
+    address: Address,
+    nonce: Nonce,
+    received_at_ms: u64,
+    max_fee_per_gas: u64,
+) -> Transaction {
+    let mut txn = gen_l2_tx_with_timestamp(address, nonce, received_at_ms);
+    match &mut txn.common_data {
+        ExecuteTransactionCommon::L2(data) => {
+            data.fee.max_fee_per_gas = max_fee_per_gas.into();
+            data.set_input(vec![], H256::random());
+        }
+        _ => unreachable!(),
+    }
+    txn
+}
+
 fn gen_l1_tx(priority_id: PriorityOpId) -> Transaction {
     let execute = Execute {
         contract_address: Address::repeat_byte(0x11),
@@ -1,5 +1,6 @@
-use std::{cmp::Ordering, collections::HashMap};
+use std::{cmp::Ordering, collections::BTreeMap};
 
+use zksync_config::configs::chain::MempoolOrdering;
 use zksync_types::{
     fee::Fee, fee_model::BatchFeeInput, l2::L2Tx, Address, Nonce, Transaction, U256,
 };
@@ -8,17 +9,19 @@ use zksync_types::{
 #[derive(Debug)]
 pub(crate) struct AccountTransactions {
     /// transactions that belong to given account keyed by transaction nonce
-    transactions: HashMap<Nonce, L2Tx>,
+    transactions: BTreeMap<Nonce, L2Tx>,
     /// account nonce in mempool
     /// equals to committed nonce in db + number of transactions sent to state keeper
     nonce: Nonce,
+    ordering: MempoolOrdering,
 }
 
 impl AccountTransactions {
-    pub fn new(nonce: Nonce) -> Self {
+    pub fn new(nonce: Nonce, ordering: MempoolOrdering) -> Self {
         Self {
-            transactions: HashMap::new(),
+            transactions: BTreeMap::new(),
             nonce,
+            ordering,
         }
     }
 
@@ -30,11 +33,11 @@ impl AccountTransactions {
         if nonce < self.nonce {
             return metadata;
         }
-        let new_score = Self::score_for_transaction(&transaction);
+        let new_score = self.score_for_transaction(&transaction);
         let previous_score = self
             .transactions
             .insert(nonce, transaction)
-            .map(|tx| Self::score_for_transaction(&tx));
+            .map(|tx| self.score_for_transaction(&tx));
         metadata.is_new = previous_score.is_none();
         if nonce == self.nonce {
             metadata.new_score = Some(new_score);
@@ -54,7 +57,7 @@ impl AccountTransactions {
         let score = self
             .transactions
             .get(&self.nonce)
-            .map(Self::score_for_transaction);
+            .map(|tx| self.score_for_transaction(tx));
         (transaction, score)
     }
 
@@ -67,28 +70,55 @@ impl AccountTransactions {
         self.nonce = self.nonce.min(tx_nonce);
         self.transactions
             .get(&(tx_nonce + 1))
-            .map(Self::score_for_transaction)
+            .map(|tx| self.score_for_transaction(tx))
+    }
+
+    /// Returns the score of the transaction with the highest nonce, which is the only transaction
+    /// that can be evicted without creating a nonce gap.
+    pub fn last_score(&self) -> Option<MempoolScore> {
+        let (_, transaction) = self.transactions.last_key_value()?;
+        Some(self.score_for_transaction(transaction))
+    }
+
+    /// Removes the transaction with the highest nonce. Returns the removed transaction and a flag
+    /// whether it was the next transaction for the account (i.e., it is referenced by the priority queue).
+    /// Panics if the account has no transactions.
+    pub fn evict_last(&mut self) -> (L2Tx, bool) {
+        let (nonce, transaction) = self
+            .transactions
+            .pop_last()
+            .expect("no transactions to evict");
+        (transaction, nonce == self.nonce)
     }
 
     pub fn len(&self) -> usize {
         self.transactions.len()
     }
 
-    fn score_for_transaction(transaction: &L2Tx) -> MempoolScore {
+    fn score_for_transaction(&self, transaction: &L2Tx) -> MempoolScore {
+        let fee_priority = match self.ordering {
+            MempoolOrdering::Fifo => U256::zero(),
+            MempoolOrdering::FeePriority => transaction.common_data.fee.max_fee_per_gas,
+        };
         MempoolScore {
             account: transaction.initiator_account(),
             received_at_ms: transaction.received_timestamp_ms,
+            fee_priority,
             fee_data: transaction.common_data.fee.clone(),
         }
     }
 }
 
-/// Mempool score of transaction. Used to prioritize L2 transactions in mempool
-/// Currently trivial ordering is used based on received at timestamp
+/// Mempool score of transaction. Used to prioritize L2 transactions in mempool.
+/// Transactions are ordered by `fee_priority` and then by received at timestamp.
 #[derive(Eq, PartialEq, Clone, Debug, Hash)]
 pub struct MempoolScore {
     pub account: Address,
     pub received_at_ms: u64,
+    /// Fee-based priority of the transaction; always zero for [`MempoolOrdering::Fifo`].
+    /// For [`MempoolOrdering::FeePriority`], this is `max_fee_per_gas`. Since the fair L2 gas price is the same
+    /// for all transactions in a batch, ordering by `max_fee_per_gas` is equivalent to ordering by the effective tip.
+    pub fee_priority: U256,
     // Not used for actual scoring, but state keeper would request
     // transactions that have acceptable fee values (so transactions
     // with fee too low would be ignored until prices go down).
@@ -105,6 +135,10 @@ impl MempoolScore {
 
 impl Ord for MempoolScore {
     fn cmp(&self, other: &MempoolScore) -> Ordering {
+        match self.fee_priority.cmp(&other.fee_priority) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
         match self.received_at_ms.cmp(&other.received_at_ms).reverse() {
             Ordering::Equal => {}
             ordering => return ordering,
@@ -160,6 +194,7 @@ mod tests {
         let score = MempoolScore {
             account: Address::random(),
             received_at_ms: Default::default(), // Not important
+            fee_priority: Default::default(),   // Not important
             fee_data: Fee {
                 gas_limit: Default::default(), // Not important
                 max_fee_per_gas: U256::from(MAX_FEE_PER_GAS),
@@ -1,7 +1,7 @@
 pub use self::{
     call_tracer::CallTracer,
     multivm_dispatcher::TracerDispatcher,
-    prestate_tracer::PrestateTracer,
+    prestate_tracer::{Account, PrestateTracer},
     storage_invocation::StorageInvocations,
     validator::{
         ValidationError, ValidationTracer, ValidationTracerParams, ViolatedValidationRule,
@@ -7,10 +7,14 @@ use zksync_types::{
 };
 use zksync_utils::{address_to_h256, h256_to_u256};
 
-use crate::interface::storage::{StoragePtr, WriteStorage};
+use crate::{
+    glue::tracers::IntoOldVmTracer,
+    interface::storage::{StoragePtr, WriteStorage},
+};
 
 pub mod vm_1_4_1;
 pub mod vm_1_4_2;
+pub mod vm_boojum_integration;
 pub mod vm_latest;
 pub mod vm_refunds_enhancement;
 pub mod vm_virtual_blocks;
@@ -67,6 +71,8 @@ impl PrestateTracer {
     }
 }
 
+impl IntoOldVmTracer for PrestateTracer {}
+
 #[derive(Debug, Clone)]
 pub struct PrestateTracerConfig {
     diff_mode: bool,
@@ -415,6 +415,8 @@ impl<S: ReadStorage> Vm<S> {
 }
 
 impl<S: ReadStorage> VmInterface for Vm<S> {
+    // Tracers (in particular, the call tracer) are not supported; `vm2` doesn't expose execution hooks
+    // at the currently used revision.
     type TracerDispatcher = ();
 
     fn push_transaction(&mut self, tx: zksync_types::Transaction) {
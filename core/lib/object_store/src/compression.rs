@@ -0,0 +1,235 @@
+//! Object store wrapper compressing and checksumming stored objects.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+
+use crate::raw::{Bucket, ObjectMetadata, ObjectStore, ObjectStoreError};
+
+/// Header prepended to all objects written by [`CompressedObjectStore`]. The last byte is the format version.
+/// Objects not starting with this header are considered legacy uncompressed objects. The first byte is chosen
+/// so that the header cannot be confused with gzip or protobuf data, and is unlikely to occur in `bincode` data.
+const HEADER: [u8; 8] = [0xff, b'Z', b'K', b'S', b'O', b'B', b'J', 1];
+const CHECKSUM_LEN: usize = 32;
+
+/// [`ObjectStore`] wrapper that compresses stored objects with zstd and appends a SHA-256 checksum of the original
+/// contents to them. The checksum is verified when fetching objects; on mismatch, an [`ObjectStoreError::Corrupted`]
+/// error is returned.
+///
+/// Objects that were stored without the wrapper (or in buckets excluded from compression) are returned as-is,
+/// so the wrapper can be enabled for an existing store without migrating it.
+#[derive(Debug)]
+pub struct CompressedObjectStore<S> {
+    inner: S,
+    level: i32,
+    excluded_buckets: HashSet<Bucket>,
+}
+
+impl<S: ObjectStore> CompressedObjectStore<S> {
+    /// Wraps the provided store using the specified zstd compression `level`. `0` means the default zstd level.
+    pub fn new(inner: S, level: i32) -> Self {
+        Self {
+            inner,
+            level,
+            excluded_buckets: HashSet::new(),
+        }
+    }
+
+    /// Excludes the specified buckets from compression. Objects in these buckets are stored as-is.
+    #[must_use]
+    pub fn with_excluded_buckets(mut self, buckets: impl IntoIterator<Item = Bucket>) -> Self {
+        self.excluded_buckets.extend(buckets);
+        self
+    }
+
+    fn pack(level: i32, value: &[u8]) -> Result<Vec<u8>, ObjectStoreError> {
+        let compressed = zstd::encode_all(value, level).map_err(|err| {
+            ObjectStoreError::Serialization(format!("failed compressing object: {err}").into())
+        })?;
+        let checksum = Sha256::digest(value);
+
+        let mut packed = Vec::with_capacity(HEADER.len() + compressed.len() + CHECKSUM_LEN);
+        packed.extend_from_slice(&HEADER);
+        packed.extend_from_slice(&compressed);
+        packed.extend_from_slice(&checksum);
+        Ok(packed)
+    }
+
+    fn unpack(bucket: Bucket, key: &str, value: Vec<u8>) -> Result<Vec<u8>, ObjectStoreError> {
+        let Some(body) = value.strip_prefix(&HEADER) else {
+            // Legacy object stored without compression.
+            return Ok(value);
+        };
+        let corrupted = |message: String| {
+            ObjectStoreError::Corrupted(
+                format!("object `{key}` in bucket {bucket}: {message}").into(),
+            )
+        };
+
+        if body.len() < CHECKSUM_LEN {
+            return Err(corrupted(format!(
+                "object is too short ({} bytes) to contain a checksum",
+                value.len()
+            )));
+        }
+        let (compressed, expected_checksum) = body.split_at(body.len() - CHECKSUM_LEN);
+        let decompressed = zstd::decode_all(compressed)
+            .map_err(|err| corrupted(format!("failed decompressing object: {err}")))?;
+        let checksum = Sha256::digest(&decompressed);
+        if checksum.as_slice() != expected_checksum {
+            return Err(corrupted(format!(
+                "checksum mismatch: expected {}, got {}",
+                hex::encode(expected_checksum),
+                hex::encode(checksum)
+            )));
+        }
+        Ok(decompressed)
+    }
+}
+
+#[async_trait]
+impl<S: ObjectStore> ObjectStore for CompressedObjectStore<S> {
+    async fn get_raw(&self, bucket: Bucket, key: &str) -> Result<Vec<u8>, ObjectStoreError> {
+        let value = self.inner.get_raw(bucket, key).await?;
+        if !value.starts_with(&HEADER) {
+            return Ok(value);
+        }
+        // Decompression may be CPU-heavy for large objects (e.g., witness inputs), so we run it on a blocking thread.
+        let owned_key = key.to_owned();
+        tokio::task::spawn_blocking(move || Self::unpack(bucket, &owned_key, value))
+            .await
+            .map_err(|err| ObjectStoreError::Other {
+                is_retriable: false,
+                source: err.into(),
+            })?
+    }
+
+    async fn put_raw(
+        &self,
+        bucket: Bucket,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), ObjectStoreError> {
+        if self.excluded_buckets.contains(&bucket) {
+            return self.inner.put_raw(bucket, key, value).await;
+        }
+
+        let level = self.level;
+        let packed = tokio::task::spawn_blocking(move || Self::pack(level, &value))
+            .await
+            .map_err(|err| ObjectStoreError::Other {
+                is_retriable: false,
+                source: err.into(),
+            })??;
+        self.inner.put_raw(bucket, key, packed).await
+    }
+
+    async fn remove_raw(&self, bucket: Bucket, key: &str) -> Result<(), ObjectStoreError> {
+        self.inner.remove_raw(bucket, key).await
+    }
+
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+    ) -> Result<Vec<ObjectMetadata>, ObjectStoreError> {
+        self.inner.list_raw(bucket, prefix).await
+    }
+
+    fn storage_prefix_raw(&self, bucket: Bucket) -> String {
+        self.inner.storage_prefix_raw(bucket)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+    use crate::MockObjectStore;
+
+    #[tokio::test]
+    async fn compressing_objects() {
+        let store = CompressedObjectStore::new(MockObjectStore::default(), 0);
+        let object = vec![42_u8; 10_000];
+        store
+            .put_raw(Bucket::WitnessInput, "test", object.clone())
+            .await
+            .unwrap();
+
+        let stored_object = store
+            .inner
+            .get_raw(Bucket::WitnessInput, "test")
+            .await
+            .unwrap();
+        assert!(stored_object.starts_with(&HEADER));
+        assert!(stored_object.len() < object.len());
+
+        let fetched_object = store.get_raw(Bucket::WitnessInput, "test").await.unwrap();
+        assert_eq!(fetched_object, object);
+    }
+
+    #[tokio::test]
+    async fn reading_legacy_objects() {
+        let store = CompressedObjectStore::new(MockObjectStore::default(), 0);
+        store
+            .inner
+            .put_raw(Bucket::WitnessInput, "legacy", vec![1, 2, 3])
+            .await
+            .unwrap();
+        let object = store.get_raw(Bucket::WitnessInput, "legacy").await.unwrap();
+        assert_eq!(object, [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn excluded_buckets_are_not_compressed() {
+        let store = CompressedObjectStore::new(MockObjectStore::default(), 0)
+            .with_excluded_buckets([Bucket::StorageSnapshot]);
+        store
+            .put_raw(Bucket::StorageSnapshot, "test", vec![1, 2, 3])
+            .await
+            .unwrap();
+        let stored_object = store
+            .inner
+            .get_raw(Bucket::StorageSnapshot, "test")
+            .await
+            .unwrap();
+        assert_eq!(stored_object, [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn detecting_corrupted_objects() {
+        let store = CompressedObjectStore::new(MockObjectStore::default(), 0);
+        store
+            .put_raw(Bucket::ProofsFri, "test", vec![5; 1_000])
+            .await
+            .unwrap();
+        let mut stored_object = store
+            .inner
+            .get_raw(Bucket::ProofsFri, "test")
+            .await
+            .unwrap();
+
+        // Corrupt the checksum.
+        *stored_object.last_mut().unwrap() ^= 1;
+        store
+            .inner
+            .put_raw(Bucket::ProofsFri, "test", stored_object.clone())
+            .await
+            .unwrap();
+        let err = store.get_raw(Bucket::ProofsFri, "test").await.unwrap_err();
+        assert_matches!(err, ObjectStoreError::Corrupted(_));
+        assert!(err.to_string().contains("checksum mismatch"), "{err}");
+
+        // Truncate the object.
+        stored_object.truncate(HEADER.len() + 10);
+        store
+            .inner
+            .put_raw(Bucket::ProofsFri, "test", stored_object)
+            .await
+            .unwrap();
+        let err = store.get_raw(Bucket::ProofsFri, "test").await.unwrap_err();
+        assert_matches!(err, ObjectStoreError::Corrupted(_));
+    }
+}
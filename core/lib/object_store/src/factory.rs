@@ -1,15 +1,17 @@
-use std::sync::Arc;
+use std::{future, sync::Arc};
 
 use anyhow::Context as _;
 use tokio::sync::OnceCell;
 use zksync_config::configs::object_store::{ObjectStoreConfig, ObjectStoreMode};
 
 use crate::{
+    compression::CompressedObjectStore,
     file::FileBackedObjectStore,
     gcs::{GoogleCloudStore, GoogleCloudStoreAuthMode},
     mirror::MirroringObjectStore,
-    raw::{ObjectStore, ObjectStoreError},
+    raw::{Bucket, ObjectStore, ObjectStoreError},
     retries::StoreWithRetries,
+    s3::{S3Credentials, S3Store, S3StoreAuthMode},
 };
 
 /// Factory of [`ObjectStore`]s that caches the store instance once it's created. Used mainly for legacy reasons.
@@ -70,7 +72,7 @@ impl ObjectStoreFactory {
                     )
                 })
                 .await?;
-                Self::wrap_mirroring(store, config.local_mirror_path.as_ref()).await
+                Self::wrap_mirroring(store, config).await
             }
             ObjectStoreMode::GCSWithCredentialFile {
                 bucket_base_url,
@@ -85,7 +87,7 @@ impl ObjectStoreFactory {
                     )
                 })
                 .await?;
-                Self::wrap_mirroring(store, config.local_mirror_path.as_ref()).await
+                Self::wrap_mirroring(store, config).await
             }
             ObjectStoreMode::GCSAnonymousReadOnly { bucket_base_url } => {
                 let store = StoreWithRetries::try_new(config.max_retries, || {
@@ -95,7 +97,41 @@ impl ObjectStoreFactory {
                     )
                 })
                 .await?;
-                Self::wrap_mirroring(store, config.local_mirror_path.as_ref()).await
+                Self::wrap_mirroring(store, config).await
+            }
+            ObjectStoreMode::S3 {
+                bucket_name,
+                region,
+                endpoint,
+                addressing_style,
+                access_key_id,
+                secret_access_key,
+            } => {
+                let auth_mode = match (access_key_id, secret_access_key) {
+                    (Some(access_key_id), Some(secret_access_key)) => S3StoreAuthMode::Static(
+                        S3Credentials::new(access_key_id.clone(), secret_access_key.0.clone()),
+                    ),
+                    (None, None) => S3StoreAuthMode::Environment,
+                    _ => {
+                        return Err(ObjectStoreError::Initialization {
+                            source:
+                                "`access_key_id` and `secret_access_key` must be specified together"
+                                    .into(),
+                            is_retriable: false,
+                        });
+                    }
+                };
+                let store = StoreWithRetries::try_new(config.max_retries, || {
+                    future::ready(S3Store::new(
+                        auth_mode.clone(),
+                        bucket_name,
+                        region.clone(),
+                        endpoint.as_deref(),
+                        *addressing_style,
+                    ))
+                })
+                .await?;
+                Self::wrap_mirroring(store, config).await
             }
 
             ObjectStoreMode::FileBacked {
@@ -109,19 +145,44 @@ impl ObjectStoreFactory {
                 if let Some(mirror_path) = &config.local_mirror_path {
                     tracing::warn!("Mirroring doesn't make sense with file-backed object store; ignoring mirror path `{mirror_path}`");
                 }
-                Ok(Arc::new(store))
+                Self::wrap_compression(store, config)
             }
         }
     }
 
     async fn wrap_mirroring(
         store: impl ObjectStore,
-        mirror_path: Option<&String>,
+        config: &ObjectStoreConfig,
     ) -> Result<Arc<dyn ObjectStore>, ObjectStoreError> {
-        Ok(if let Some(mirror_path) = mirror_path {
-            Arc::new(MirroringObjectStore::new(store, mirror_path.clone()).await?)
+        if let Some(mirror_path) = &config.local_mirror_path {
+            let store = MirroringObjectStore::new(store, mirror_path.clone()).await?;
+            Self::wrap_compression(store, config)
         } else {
-            Arc::new(store)
-        })
+            Self::wrap_compression(store, config)
+        }
+    }
+
+    fn wrap_compression(
+        store: impl ObjectStore,
+        config: &ObjectStoreConfig,
+    ) -> Result<Arc<dyn ObjectStore>, ObjectStoreError> {
+        let Some(level) = config.compression_level else {
+            return Ok(Arc::new(store));
+        };
+        let excluded_buckets = config
+            .compression_excluded_buckets
+            .iter()
+            .map(|name| name.parse::<Bucket>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| ObjectStoreError::Initialization {
+                source: err.into(),
+                is_retriable: false,
+            })?;
+        tracing::info!(
+            "Enabling compression with level {level} for store {store:?}; excluded buckets: {excluded_buckets:?}"
+        );
+        Ok(Arc::new(
+            CompressedObjectStore::new(store, level).with_excluded_buckets(excluded_buckets),
+        ))
     }
 }
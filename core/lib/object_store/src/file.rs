@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use async_trait::async_trait;
 use tokio::{fs, io};
 
-use crate::raw::{Bucket, ObjectStore, ObjectStoreError};
+use crate::raw::{Bucket, ObjectMetadata, ObjectStore, ObjectStoreError};
 
 impl From<io::Error> for ObjectStoreError {
     fn from(err: io::Error) -> Self {
@@ -77,6 +77,38 @@ impl ObjectStore for FileBackedObjectStore {
         fs::remove_file(filename).await.map_err(From::from)
     }
 
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+    ) -> Result<Vec<ObjectMetadata>, ObjectStoreError> {
+        let bucket_path = self.storage_prefix_raw(bucket);
+        let mut entries = match fs::read_dir(&bucket_path).await {
+            Ok(entries) => entries,
+            // Not all buckets are created on initialization; a missing bucket directory is equivalent to an empty bucket.
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut objects = vec![];
+        while let Some(entry) = entries.next_entry().await? {
+            let metadata = entry.metadata().await?;
+            if !metadata.is_file() {
+                continue;
+            }
+            let Ok(key) = entry.file_name().into_string() else {
+                continue; // Cannot be created via `put_raw()`
+            };
+            if key.starts_with(prefix) {
+                objects.push(ObjectMetadata {
+                    key,
+                    size: metadata.len(),
+                });
+            }
+        }
+        Ok(objects)
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         format!("{}/{}", self.base_dir, bucket)
     }
@@ -131,4 +163,32 @@ mod test {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_list() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.into_path().into_os_string().into_string().unwrap();
+        let object_store = FileBackedObjectStore::new(path).await.unwrap();
+        for key in ["proof_1.bin", "proof_2.bin", "other.bin"] {
+            object_store
+                .put_raw(Bucket::ProofsFri, key, vec![0; 10])
+                .await
+                .unwrap();
+        }
+
+        let mut objects = object_store
+            .list_raw(Bucket::ProofsFri, "proof_")
+            .await
+            .unwrap();
+        objects.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+        let keys: Vec<_> = objects.iter().map(|obj| obj.key.as_str()).collect();
+        assert_eq!(keys, ["proof_1.bin", "proof_2.bin"]);
+        assert!(objects.iter().all(|obj| obj.size == 10));
+
+        let objects = object_store
+            .list_raw(Bucket::DataAvailability, "")
+            .await
+            .unwrap();
+        assert!(objects.is_empty());
+    }
 }
@@ -11,6 +11,7 @@ use google_cloud_storage::{
             delete::DeleteObjectRequest,
             download::Range,
             get::GetObjectRequest,
+            list::ListObjectsRequest,
             upload::{Media, UploadObjectRequest, UploadType},
         },
         Error as HttpError,
@@ -18,7 +19,7 @@ use google_cloud_storage::{
 };
 use http::StatusCode;
 
-use crate::raw::{Bucket, ObjectStore, ObjectStoreError};
+use crate::raw::{Bucket, ObjectMetadata, ObjectStore, ObjectStoreError};
 
 /// [`ObjectStore`] implementation based on GCS.
 pub struct GoogleCloudStore {
@@ -96,7 +97,7 @@ impl From<AuthError> for ObjectStoreError {
     }
 }
 
-fn is_retriable_http_error(err: &reqwest::Error) -> bool {
+pub(crate) fn is_retriable_http_error(err: &reqwest::Error) -> bool {
     err.is_timeout()
         || err.is_connect()
         // Not all request errors are logically transient, but a significant part of them are (e.g.,
@@ -215,6 +216,44 @@ impl ObjectStore for GoogleCloudStore {
         Ok(())
     }
 
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+    ) -> Result<Vec<ObjectMetadata>, ObjectStoreError> {
+        let bucket_dir = Self::filename(bucket.as_str(), "");
+        tracing::trace!(
+            "Listing objects in GCS with prefix {bucket_dir}{prefix} from bucket {}",
+            self.bucket_prefix
+        );
+
+        let mut objects = vec![];
+        let mut page_token = None;
+        loop {
+            let request = ListObjectsRequest {
+                bucket: self.bucket_prefix.clone(),
+                prefix: Some(format!("{bucket_dir}{prefix}")),
+                page_token: page_token.take(),
+                ..ListObjectsRequest::default()
+            };
+            let response = self.client.list_objects(&request).await?;
+            let items = response.items.unwrap_or_default();
+            objects.extend(items.into_iter().filter_map(|object| {
+                let key = object.name.strip_prefix(&bucket_dir)?.to_owned();
+                Some(ObjectMetadata {
+                    key,
+                    size: u64::try_from(object.size).unwrap_or(0),
+                })
+            }));
+
+            page_token = response.next_page_token;
+            if page_token.is_none() {
+                break;
+            }
+        }
+        Ok(objects)
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         format!(
             "https://storage.googleapis.com/{}/{}",
@@ -3,8 +3,11 @@
 //!
 //! - [File-backed store](FileBackedObjectStore) saving blobs as separate files in the local filesystem
 //! - [GCS-based store](GoogleCloudStore)
+//! - [S3-compatible store](S3Store)
 //! - [Mock in-memory store](MockObjectStore)
 //!
+//! Stores can be wrapped in a [compressing store](CompressedObjectStore) that compresses and checksums stored objects.
+//!
 //! Normally, these implementations are not used directly. Instead, a store trait object (`Arc<dyn ObjectStore>`)
 //! can be constructed using an [`ObjectStoreFactory`] based on the configuration.
 //! This trait object is what should be used for dependency injection.
@@ -23,6 +26,7 @@
     clippy::doc_markdown
 )]
 
+mod compression;
 mod factory;
 mod file;
 mod gcs;
@@ -32,6 +36,7 @@ mod mock;
 mod objects;
 mod raw;
 mod retries;
+mod s3;
 
 // Re-export `bincode` crate so that client binaries can conveniently use it.
 pub use bincode;
@@ -42,10 +47,12 @@ pub mod _reexports {
 }
 
 pub use self::{
+    compression::CompressedObjectStore,
     factory::ObjectStoreFactory,
     file::FileBackedObjectStore,
     gcs::{GoogleCloudStore, GoogleCloudStoreAuthMode},
     mock::MockObjectStore,
     objects::StoredObject,
-    raw::{Bucket, ObjectStore, ObjectStoreError},
+    raw::{Bucket, ObjectMetadata, ObjectStore, ObjectStoreError},
+    s3::{S3Credentials, S3Store, S3StoreAuthMode},
 };
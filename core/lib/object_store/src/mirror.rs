@@ -2,7 +2,11 @@
 
 use async_trait::async_trait;
 
-use crate::{file::FileBackedObjectStore, raw::ObjectStore, Bucket, ObjectStoreError};
+use crate::{
+    file::FileBackedObjectStore,
+    raw::{ObjectMetadata, ObjectStore},
+    Bucket, ObjectStoreError,
+};
 
 #[derive(Debug)]
 pub(crate) struct MirroringObjectStore<S> {
@@ -85,6 +89,14 @@ impl<S: ObjectStore> ObjectStore for MirroringObjectStore<S> {
         Ok(())
     }
 
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+    ) -> Result<Vec<ObjectMetadata>, ObjectStoreError> {
+        self.inner.list_raw(bucket, prefix).await
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         self.inner.storage_prefix_raw(bucket)
     }
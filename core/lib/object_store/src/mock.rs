@@ -5,7 +5,7 @@ use std::{collections::HashMap, sync::Arc};
 use async_trait::async_trait;
 use tokio::sync::Mutex;
 
-use crate::raw::{Bucket, ObjectStore, ObjectStoreError};
+use crate::raw::{Bucket, ObjectMetadata, ObjectStore, ObjectStoreError};
 
 type BucketMap = HashMap<String, Vec<u8>>;
 
@@ -54,6 +54,25 @@ impl ObjectStore for MockObjectStore {
         Ok(())
     }
 
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+    ) -> Result<Vec<ObjectMetadata>, ObjectStoreError> {
+        let lock = self.inner.lock().await;
+        let Some(bucket_map) = lock.get(&bucket) else {
+            return Ok(vec![]);
+        };
+        let objects = bucket_map
+            .iter()
+            .filter(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| ObjectMetadata {
+                key: key.clone(),
+                size: value.len() as u64,
+            });
+        Ok(objects.collect())
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         bucket.to_string()
     }
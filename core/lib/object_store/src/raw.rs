@@ -1,4 +1,4 @@
-use std::{error, fmt};
+use std::{error, fmt, str::FromStr};
 
 use async_trait::async_trait;
 
@@ -23,7 +23,8 @@ pub enum Bucket {
 }
 
 impl Bucket {
-    pub(crate) fn as_str(self) -> &'static str {
+    /// Returns the bucket name as used in object paths and metrics.
+    pub fn as_str(self) -> &'static str {
         match self {
             Self::ProverJobs => "prover_jobs",
             Self::WitnessInput => "witness_inputs",
@@ -43,6 +44,30 @@ impl Bucket {
     }
 }
 
+impl FromStr for Bucket {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "prover_jobs" => Self::ProverJobs,
+            "witness_inputs" => Self::WitnessInput,
+            "leaf_aggregation_witness_jobs" => Self::LeafAggregationWitnessJobs,
+            "node_aggregation_witness_jobs" => Self::NodeAggregationWitnessJobs,
+            "scheduler_witness_jobs" => Self::SchedulerWitnessJobs,
+            "prover_jobs_fri" => Self::ProverJobsFri,
+            "leaf_aggregation_witness_jobs_fri" => Self::LeafAggregationWitnessJobsFri,
+            "node_aggregation_witness_jobs_fri" => Self::NodeAggregationWitnessJobsFri,
+            "scheduler_witness_jobs_fri" => Self::SchedulerWitnessJobsFri,
+            "proofs_fri" => Self::ProofsFri,
+            "proofs_tee" => Self::ProofsTee,
+            "storage_logs_snapshots" => Self::StorageSnapshot,
+            "data_availability" => Self::DataAvailability,
+            "tee_verifier_inputs" => Self::TeeVerifierInput,
+            _ => return Err(format!("unknown bucket: `{s}`")),
+        })
+    }
+}
+
 impl fmt::Display for Bucket {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         formatter.write_str(self.as_str())
@@ -65,6 +90,8 @@ pub enum ObjectStoreError {
     KeyNotFound(BoxedError),
     /// Object (de)serialization failed.
     Serialization(BoxedError),
+    /// Stored object is corrupted (e.g., its checksum doesn't match its contents).
+    Corrupted(BoxedError),
     /// Other error has occurred when accessing the store (e.g., a network error).
     Other {
         source: BoxedError,
@@ -79,7 +106,7 @@ impl ObjectStoreError {
             Self::Initialization { is_retriable, .. } | Self::Other { is_retriable, .. } => {
                 *is_retriable
             }
-            Self::KeyNotFound(_) | Self::Serialization(_) => false,
+            Self::KeyNotFound(_) | Self::Serialization(_) | Self::Corrupted(_) => false,
         }
     }
 }
@@ -99,6 +126,7 @@ impl fmt::Display for ObjectStoreError {
             }
             Self::KeyNotFound(err) => write!(formatter, "key not found: {err}"),
             Self::Serialization(err) => write!(formatter, "serialization error: {err}"),
+            Self::Corrupted(err) => write!(formatter, "corrupted object: {err}"),
             Self::Other {
                 source,
                 is_retriable,
@@ -116,11 +144,22 @@ impl error::Error for ObjectStoreError {
             Self::Initialization { source, .. } | Self::Other { source, .. } => {
                 Some(source.as_ref())
             }
-            Self::KeyNotFound(err) | Self::Serialization(err) => Some(err.as_ref()),
+            Self::KeyNotFound(err) | Self::Serialization(err) | Self::Corrupted(err) => {
+                Some(err.as_ref())
+            }
         }
     }
 }
 
+/// Metadata of an object returned by [`ObjectStore::list_raw()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectMetadata {
+    /// Object key relative to the bucket, i.e., the same key that is used in [`ObjectStore::get_raw()`].
+    pub key: String,
+    /// Size of the stored object in bytes.
+    pub size: u64,
+}
+
 /// Functionality to fetch and store byte blobs from an object store (AWS S3, Google Cloud Storage,
 /// Azure Blobstore etc).
 ///
@@ -157,5 +196,26 @@ pub trait ObjectStore: 'static + fmt::Debug + Send + Sync {
     /// Returns an error if removal fails.
     async fn remove_raw(&self, bucket: Bucket, key: &str) -> Result<(), ObjectStoreError>;
 
+    /// Lists objects in the given bucket with keys starting with the specified `prefix`.
+    /// The order of returned objects is unspecified.
+    ///
+    /// The default implementation returns an error; stores that can enumerate their contents should override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if listing fails or is not supported by the store.
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+    ) -> Result<Vec<ObjectMetadata>, ObjectStoreError> {
+        let _ = prefix;
+        Err(ObjectStoreError::Other {
+            is_retriable: false,
+            source: format!("listing objects in bucket {bucket} is not supported by {self:?}")
+                .into(),
+        })
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String;
 }
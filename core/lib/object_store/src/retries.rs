@@ -5,7 +5,7 @@ use rand::Rng;
 
 use crate::{
     metrics::OBJECT_STORE_METRICS,
-    raw::{Bucket, ObjectStore, ObjectStoreError},
+    raw::{Bucket, ObjectMetadata, ObjectStore, ObjectStoreError},
 };
 
 /// Information about request added to logs.
@@ -16,6 +16,7 @@ enum Request<'a> {
     Get(Bucket, &'a str),
     Put(Bucket, &'a str),
     Remove(Bucket, &'a str),
+    List(Bucket, &'a str),
 }
 
 impl Request<'_> {
@@ -127,6 +128,18 @@ impl<S: ObjectStore> ObjectStore for StoreWithRetries<S> {
             .await
     }
 
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+    ) -> Result<Vec<ObjectMetadata>, ObjectStoreError> {
+        Request::List(bucket, prefix)
+            .retry(&self.inner, self.max_retries, || {
+                self.inner.list_raw(bucket, prefix)
+            })
+            .await
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         self.inner.storage_prefix_raw(bucket)
     }
@@ -1,4 +1,4 @@
-use std::num::NonZeroUsize;
+use std::num::{NonZeroU32, NonZeroUsize};
 
 use anyhow::Context as _;
 use zksync_config::configs::{api, ApiConfig};
@@ -30,6 +30,30 @@ impl ProtoRepr for proto::Api {
     }
 }
 
+fn read_compute_units(entries: &[proto::ComputeUnits]) -> anyhow::Result<api::ComputeUnitsByName> {
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let name = required(&entry.name).with_context(|| format!("[{i}].name"))?;
+            let units = required(&entry.compute_units)
+                .and_then(|&units| NonZeroU32::new(units).context("cannot be zero"))
+                .with_context(|| format!("[{i}].compute_units"))?;
+            Ok((name.clone(), units))
+        })
+        .collect()
+}
+
+fn build_compute_units(units: &api::ComputeUnitsByName) -> Vec<proto::ComputeUnits> {
+    units
+        .iter()
+        .map(|(name, units)| proto::ComputeUnits {
+            name: Some(name.to_owned()),
+            compute_units: Some(units.get()),
+        })
+        .collect()
+}
+
 impl ProtoRepr for proto::Web3JsonRpc {
     type Type = api::Web3JsonRpcConfig;
 
@@ -113,6 +137,7 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .transpose()
                 .context("latest_values_cache_size_mb")?,
             fee_history_limit: self.fee_history_limit,
+            trace_filter_block_range_limit: self.trace_filter_block_range_limit,
             max_batch_request_size: self
                 .max_batch_request_size
                 .map(|x| x.try_into())
@@ -129,6 +154,24 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .map(|x| x.try_into())
                 .transpose()
                 .context("websocket_requests_per_minute_limit")?,
+            compute_units_per_minute_limit: self
+                .compute_units_per_minute_limit
+                .map(|x| x.try_into())
+                .transpose()
+                .context("compute_units_per_minute_limit")?,
+            api_key_compute_units_per_minute: read_compute_units(
+                &self.api_key_compute_units_per_minute,
+            )
+            .context("api_key_compute_units_per_minute")?,
+            method_compute_unit_costs: read_compute_units(&self.method_compute_unit_costs)
+                .context("method_compute_unit_costs")?,
+            rate_limit_trusted_proxies: self
+                .rate_limit_trusted_proxies
+                .iter()
+                .enumerate()
+                .map(|(i, ip)| ip.parse().with_context(|| format!("[{i}]")))
+                .collect::<anyhow::Result<_>>()
+                .context("rate_limit_trusted_proxies")?,
             tree_api_url: self.tree_api_url.clone(),
             mempool_cache_update_interval: self.mempool_cache_update_interval,
             mempool_cache_size: self
@@ -182,6 +225,7 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .latest_values_cache_size_mb
                 .map(|x| x.try_into().unwrap()),
             fee_history_limit: this.fee_history_limit,
+            trace_filter_block_range_limit: this.trace_filter_block_range_limit,
             max_batch_request_size: this.max_batch_request_size.map(|x| x.try_into().unwrap()),
             max_response_body_size_mb: this
                 .max_response_body_size_mb
@@ -201,6 +245,16 @@ impl ProtoRepr for proto::Web3JsonRpc {
             websocket_requests_per_minute_limit: this
                 .websocket_requests_per_minute_limit
                 .map(|x| x.into()),
+            compute_units_per_minute_limit: this.compute_units_per_minute_limit.map(|x| x.into()),
+            api_key_compute_units_per_minute: build_compute_units(
+                &this.api_key_compute_units_per_minute,
+            ),
+            method_compute_unit_costs: build_compute_units(&this.method_compute_unit_costs),
+            rate_limit_trusted_proxies: this
+                .rate_limit_trusted_proxies
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
             tree_api_url: this.tree_api_url.clone(),
             whitelisted_tokens_for_aa: this
                 .whitelisted_tokens_for_aa
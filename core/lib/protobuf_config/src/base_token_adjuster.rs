@@ -42,6 +42,14 @@ impl ProtoRepr for proto::BaseTokenAdjuster {
             l1_tx_sending_sleep_ms: self
                 .l1_tx_sending_sleep_ms
                 .unwrap_or(Self::Type::default_l1_tx_sending_sleep_ms()),
+            max_ratio_change_percent: self.max_ratio_change_percent,
+            clamp_ratio_changes: self.clamp_ratio_changes.unwrap_or_default(),
+            l1_update_deviation_percent: self
+                .l1_update_deviation_percent
+                .unwrap_or(Self::Type::default_l1_update_deviation_percent()),
+            l1_update_max_staleness_ms: self
+                .l1_update_max_staleness_ms
+                .unwrap_or(Self::Type::default_l1_update_max_staleness_ms()),
         })
     }
 
@@ -59,6 +67,10 @@ impl ProtoRepr for proto::BaseTokenAdjuster {
             default_priority_fee_per_gas: Some(this.default_priority_fee_per_gas),
             max_acceptable_priority_fee_in_gwei: Some(this.max_acceptable_priority_fee_in_gwei),
             halt_on_error: Some(this.halt_on_error),
+            max_ratio_change_percent: this.max_ratio_change_percent,
+            clamp_ratio_changes: Some(this.clamp_ratio_changes),
+            l1_update_deviation_percent: Some(this.l1_update_deviation_percent),
+            l1_update_max_staleness_ms: Some(this.l1_update_max_staleness_ms),
         }
     }
 }
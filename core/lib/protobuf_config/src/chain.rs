@@ -22,6 +22,52 @@ impl proto::FeeModelVersion {
     }
 }
 
+impl proto::MempoolOrdering {
+    fn new(n: &configs::chain::MempoolOrdering) -> Self {
+        use configs::chain::MempoolOrdering as From;
+        match n {
+            From::Fifo => Self::Fifo,
+            From::FeePriority => Self::FeePriority,
+        }
+    }
+
+    fn parse(&self) -> configs::chain::MempoolOrdering {
+        use configs::chain::MempoolOrdering as To;
+        match self {
+            Self::Fifo => To::Fifo,
+            Self::FeePriority => To::FeePriority,
+        }
+    }
+}
+
+fn read_seal_criteria_thresholds(
+    entries: &[proto::SealCriterionThreshold],
+) -> anyhow::Result<configs::chain::SealCriteriaThresholds> {
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let criterion =
+                required(&entry.criterion).with_context(|| format!("[{i}].criterion"))?;
+            let threshold =
+                *required(&entry.threshold).with_context(|| format!("[{i}].threshold"))?;
+            Ok((criterion.clone(), threshold))
+        })
+        .collect()
+}
+
+fn build_seal_criteria_thresholds(
+    thresholds: &configs::chain::SealCriteriaThresholds,
+) -> Vec<proto::SealCriterionThreshold> {
+    thresholds
+        .iter()
+        .map(|(criterion, threshold)| proto::SealCriterionThreshold {
+            criterion: Some(criterion.to_owned()),
+            threshold: Some(threshold),
+        })
+        .collect()
+}
+
 impl ProtoRepr for proto::StateKeeper {
     type Type = configs::chain::StateKeeperConfig;
     fn read(&self) -> anyhow::Result<Self::Type> {
@@ -81,6 +127,9 @@ impl ProtoRepr for proto::StateKeeper {
             protective_reads_persistence_enabled: self
                 .protective_reads_persistence_enabled
                 .unwrap_or_default(),
+            disabled_seal_criteria: self.disabled_seal_criteria.clone(),
+            seal_criteria_thresholds: read_seal_criteria_thresholds(&self.seal_criteria_thresholds)
+                .context("seal_criteria_thresholds")?,
 
             // We need these values only for instantiating configs from environmental variables, so it's not
             // needed during the initialization from files
@@ -119,6 +168,10 @@ impl ProtoRepr for proto::StateKeeper {
             save_call_traces: Some(this.save_call_traces),
             max_circuits_per_batch: Some(this.max_circuits_per_batch.try_into().unwrap()),
             protective_reads_persistence_enabled: Some(this.protective_reads_persistence_enabled),
+            disabled_seal_criteria: this.disabled_seal_criteria.clone(),
+            seal_criteria_thresholds: build_seal_criteria_thresholds(
+                &this.seal_criteria_thresholds,
+            ),
         }
     }
 }
@@ -150,6 +203,15 @@ impl ProtoRepr for proto::Mempool {
             stuck_tx_timeout: *required(&self.stuck_tx_timeout).context("stuck_tx_timeout")?,
             remove_stuck_txs: *required(&self.remove_stuck_txs).context("remove_stuck_txs")?,
             delay_interval: *required(&self.delay_interval).context("delay_interval")?,
+            ordering: self
+                .ordering
+                .map(proto::MempoolOrdering::try_from)
+                .transpose()
+                .context("ordering")?
+                .map_or_else(Default::default, |ordering| ordering.parse()),
+            replacement_fee_bump_percent: self.replacement_fee_bump_percent.unwrap_or_else(
+                configs::chain::MempoolConfig::default_replacement_fee_bump_percent,
+            ),
         })
     }
 
@@ -161,6 +223,8 @@ impl ProtoRepr for proto::Mempool {
             stuck_tx_timeout: Some(this.stuck_tx_timeout),
             remove_stuck_txs: Some(this.remove_stuck_txs),
             delay_interval: Some(this.delay_interval),
+            ordering: Some(proto::MempoolOrdering::new(&this.ordering).into()),
+            replacement_fee_bump_percent: Some(this.replacement_fee_bump_percent),
         }
     }
 }
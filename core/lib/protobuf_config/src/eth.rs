@@ -1,8 +1,11 @@
+use std::str::FromStr;
+
 use anyhow::Context as _;
+use zksync_basic_types::url::SensitiveUrl;
 use zksync_config::configs::{self};
 use zksync_protobuf::{required, ProtoRepr};
 
-use crate::{proto::eth as proto, read_optional_repr};
+use crate::{parse_h160, proto::eth as proto, read_optional_repr};
 
 impl proto::ProofSendingMode {
     fn new(x: &configs::eth_sender::ProofSendingMode) -> Self {
@@ -46,6 +49,60 @@ impl proto::PubdataSendingMode {
     }
 }
 
+impl proto::ResendFeeStrategy {
+    fn new(x: &configs::eth_sender::ResendFeeStrategy) -> Self {
+        use configs::eth_sender::ResendFeeStrategy as From;
+        match x {
+            From::Minimal => Self::Minimal,
+            From::Exponential => Self::Exponential,
+        }
+    }
+
+    fn parse(&self) -> configs::eth_sender::ResendFeeStrategy {
+        use configs::eth_sender::ResendFeeStrategy as To;
+        match self {
+            Self::Minimal => To::Minimal,
+            Self::Exponential => To::Exponential,
+        }
+    }
+}
+
+impl proto::BaseFeeStrategy {
+    fn new(x: &configs::eth_sender::BaseFeeStrategy) -> Self {
+        use configs::eth_sender::BaseFeeStrategy as From;
+        match x {
+            From::Median => Self::Median,
+            From::Eip1559Projection => Self::Eip1559Projection,
+        }
+    }
+
+    fn parse(&self) -> configs::eth_sender::BaseFeeStrategy {
+        use configs::eth_sender::BaseFeeStrategy as To;
+        match self {
+            Self::Median => To::Median,
+            Self::Eip1559Projection => To::Eip1559Projection,
+        }
+    }
+}
+
+impl proto::PriorityFeeStrategy {
+    fn new(x: &configs::eth_sender::PriorityFeeStrategy) -> Self {
+        use configs::eth_sender::PriorityFeeStrategy as From;
+        match x {
+            From::Fixed => Self::Fixed,
+            From::Percentile => Self::Percentile,
+        }
+    }
+
+    fn parse(&self) -> configs::eth_sender::PriorityFeeStrategy {
+        use configs::eth_sender::PriorityFeeStrategy as To;
+        match self {
+            Self::Fixed => To::Fixed,
+            Self::Percentile => To::Percentile,
+        }
+    }
+}
+
 impl ProtoRepr for proto::Eth {
     type Type = configs::eth_sender::EthConfig;
 
@@ -54,6 +111,12 @@ impl ProtoRepr for proto::Eth {
             sender: read_optional_repr(&self.sender),
             gas_adjuster: read_optional_repr(&self.gas_adjuster),
             watcher: read_optional_repr(&self.watcher),
+            remote_signer: self
+                .remote_signer
+                .as_ref()
+                .map(ProtoRepr::read)
+                .transpose()
+                .context("remote_signer")?,
         })
     }
 
@@ -62,6 +125,38 @@ impl ProtoRepr for proto::Eth {
             sender: this.sender.as_ref().map(ProtoRepr::build),
             gas_adjuster: this.gas_adjuster.as_ref().map(ProtoRepr::build),
             watcher: this.watcher.as_ref().map(ProtoRepr::build),
+            remote_signer: this.remote_signer.as_ref().map(ProtoRepr::build),
+        }
+    }
+}
+
+impl ProtoRepr for proto::RemoteSigner {
+    type Type = configs::eth_sender::RemoteSignerConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            url: SensitiveUrl::from_str(required(&self.url).context("url")?).context("url")?,
+            operator_address: parse_h160(
+                required(&self.operator_address).context("operator_address")?,
+            )
+            .context("operator_address")?,
+            blob_operator_address: self
+                .blob_operator_address
+                .as_deref()
+                .map(parse_h160)
+                .transpose()
+                .context("blob_operator_address")?,
+            request_timeout_ms: *required(&self.request_timeout_ms)
+                .context("request_timeout_ms")?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            url: Some(this.url.expose_str().to_string()),
+            operator_address: Some(format!("{:?}", this.operator_address)),
+            blob_operator_address: this.blob_operator_address.map(|addr| format!("{addr:?}")),
+            request_timeout_ms: Some(this.request_timeout_ms),
         }
     }
 }
@@ -115,6 +210,17 @@ impl ProtoRepr for proto::Sender {
                 .parse(),
             tx_aggregation_only_prove_and_execute: self.tx_aggregation_paused.unwrap_or(false),
             tx_aggregation_paused: self.tx_aggregation_only_prove_and_execute.unwrap_or(false),
+            resend_fee_strategy: self
+                .resend_fee_strategy
+                .map(proto::ResendFeeStrategy::try_from)
+                .transpose()
+                .context("resend_fee_strategy")?
+                .map_or_else(Default::default, |x| x.parse()),
+            resend_fee_bump_percent: self
+                .resend_fee_bump_percent
+                .unwrap_or(Self::Type::default_resend_fee_bump_percent()),
+            max_resend_fee_per_gas: self.max_resend_fee_per_gas,
+            cancel_stuck_txs_after_blocks: self.cancel_stuck_txs_after_blocks,
         })
     }
 
@@ -147,6 +253,12 @@ impl ProtoRepr for proto::Sender {
             ),
             tx_aggregation_only_prove_and_execute: Some(this.tx_aggregation_only_prove_and_execute),
             tx_aggregation_paused: Some(this.tx_aggregation_paused),
+            resend_fee_strategy: Some(
+                proto::ResendFeeStrategy::new(&this.resend_fee_strategy).into(),
+            ),
+            resend_fee_bump_percent: Some(this.resend_fee_bump_percent),
+            max_resend_fee_per_gas: this.max_resend_fee_per_gas,
+            cancel_stuck_txs_after_blocks: this.cancel_stuck_txs_after_blocks,
         }
     }
 }
@@ -182,6 +294,21 @@ impl ProtoRepr for proto::GasAdjuster {
             max_blob_base_fee: self.max_blob_base_fee,
             // TODO(EVM-676): support this field
             settlement_mode: Default::default(),
+            base_fee_strategy: self
+                .base_fee_strategy
+                .map(proto::BaseFeeStrategy::try_from)
+                .transpose()
+                .context("base_fee_strategy")?
+                .map_or_else(Default::default, |x| x.parse()),
+            priority_fee_strategy: self
+                .priority_fee_strategy
+                .map(proto::PriorityFeeStrategy::try_from)
+                .transpose()
+                .context("priority_fee_strategy")?
+                .map_or_else(Default::default, |x| x.parse()),
+            priority_fee_percentile: self
+                .priority_fee_percentile
+                .unwrap_or(Self::Type::default_priority_fee_percentile()),
         })
     }
 
@@ -203,6 +330,11 @@ impl ProtoRepr for proto::GasAdjuster {
             ),
             internal_pubdata_pricing_multiplier: Some(this.internal_pubdata_pricing_multiplier),
             max_blob_base_fee: this.max_blob_base_fee,
+            base_fee_strategy: Some(proto::BaseFeeStrategy::new(&this.base_fee_strategy).into()),
+            priority_fee_strategy: Some(
+                proto::PriorityFeeStrategy::new(&this.priority_fee_strategy).into(),
+            ),
+            priority_fee_percentile: Some(this.priority_fee_percentile),
         }
     }
 }
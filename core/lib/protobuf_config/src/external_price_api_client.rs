@@ -1,7 +1,11 @@
-use zksync_config::configs::{self, external_price_api_client::ForcedPriceClientConfig};
+use anyhow::Context as _;
+use zksync_config::configs::{
+    self,
+    external_price_api_client::{AggregatedPriceClientConfig, ForcedPriceClientConfig},
+};
 use zksync_protobuf::ProtoRepr;
 
-use crate::proto::external_price_api_client as proto;
+use crate::{parse_h160, proto::external_price_api_client as proto};
 
 impl ProtoRepr for proto::ExternalPriceApiClient {
     type Type = configs::external_price_api_client::ExternalPriceApiClientConfig;
@@ -18,6 +22,12 @@ impl ProtoRepr for proto::ExternalPriceApiClient {
                     denominator: self.forced_denominator,
                     fluctuation: self.forced_fluctuation,
                 }),
+                aggregated: self
+                    .aggregated
+                    .as_ref()
+                    .map(ProtoRepr::read)
+                    .transpose()
+                    .context("aggregated")?,
             },
         )
     }
@@ -35,6 +45,57 @@ impl ProtoRepr for proto::ExternalPriceApiClient {
             forced_numerator: numerator,
             forced_denominator: denominator,
             forced_fluctuation: fluctuation,
+            aggregated: this.aggregated.as_ref().map(ProtoRepr::build),
+        }
+    }
+}
+
+impl ProtoRepr for proto::AggregatedPriceClient {
+    type Type = AggregatedPriceClientConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(AggregatedPriceClientConfig {
+            sources: self.sources.clone(),
+            quorum: self.quorum,
+            max_deviation_percent: self
+                .max_deviation_percent
+                .unwrap_or_else(AggregatedPriceClientConfig::default_max_deviation_percent),
+            max_quote_age_ms: self
+                .max_quote_age_ms
+                .unwrap_or_else(AggregatedPriceClientConfig::default_max_quote_age_ms),
+            coinmarketcap_api_key: self.coinmarketcap_api_key.clone(),
+            coinmarketcap_base_url: self.coinmarketcap_base_url.clone(),
+            uniswap_v3_pool_address: self
+                .uniswap_v3_pool_address
+                .as_deref()
+                .map(parse_h160)
+                .transpose()
+                .context("uniswap_v3_pool_address")?,
+            uniswap_v3_quote_token_address: self
+                .uniswap_v3_quote_token_address
+                .as_deref()
+                .map(parse_h160)
+                .transpose()
+                .context("uniswap_v3_quote_token_address")?,
+            uniswap_v3_twap_interval_sec: self
+                .uniswap_v3_twap_interval_sec
+                .unwrap_or_else(AggregatedPriceClientConfig::default_uniswap_v3_twap_interval_sec),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            sources: this.sources.clone(),
+            quorum: this.quorum,
+            max_deviation_percent: Some(this.max_deviation_percent),
+            max_quote_age_ms: Some(this.max_quote_age_ms),
+            coinmarketcap_api_key: this.coinmarketcap_api_key.clone(),
+            coinmarketcap_base_url: this.coinmarketcap_base_url.clone(),
+            uniswap_v3_pool_address: this.uniswap_v3_pool_address.map(|addr| format!("{addr:?}")),
+            uniswap_v3_quote_token_address: this
+                .uniswap_v3_quote_token_address
+                .map(|addr| format!("{addr:?}")),
+            uniswap_v3_twap_interval_sec: Some(this.uniswap_v3_twap_interval_sec),
         }
     }
 }
@@ -34,6 +34,7 @@ impl ProtoRepr for proto::GeneralConfig {
                 &self.basic_witness_input_producer,
             ),
             core_object_store: read_optional_repr(&self.core_object_store),
+            object_store_pruning: read_optional_repr(&self.object_store_pruning),
             base_token_adjuster: read_optional_repr(&self.base_token_adjuster),
             commitment_generator: read_optional_repr(&self.commitment_generator),
             pruning: read_optional_repr(&self.pruning),
@@ -89,6 +90,7 @@ impl ProtoRepr for proto::GeneralConfig {
             snapshot_recovery: this.snapshot_recovery.as_ref().map(ProtoRepr::build),
             pruning: this.pruning.as_ref().map(ProtoRepr::build),
             core_object_store: this.core_object_store.as_ref().map(ProtoRepr::build),
+            object_store_pruning: this.object_store_pruning.as_ref().map(ProtoRepr::build),
             base_token_adjuster: this.base_token_adjuster.as_ref().map(ProtoRepr::build),
             external_price_api_client: this
                 .external_price_api_client_config
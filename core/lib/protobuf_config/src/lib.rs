@@ -21,6 +21,7 @@ mod general;
 mod genesis;
 mod house_keeper;
 mod object_store;
+mod object_store_pruning;
 mod observability;
 mod proof_data_handler;
 pub mod proto;
@@ -1,9 +1,27 @@
 use anyhow::Context as _;
-use zksync_config::configs::object_store::{ObjectStoreConfig, ObjectStoreMode};
+use zksync_config::configs::object_store::{
+    ObjectStoreConfig, ObjectStoreMode, S3AddressingStyle, S3SecretAccessKey,
+};
 use zksync_protobuf::{repr::ProtoRepr, required};
 
 use crate::proto::object_store as proto;
 
+impl proto::S3AddressingStyle {
+    fn new(x: S3AddressingStyle) -> Self {
+        match x {
+            S3AddressingStyle::VirtualHosted => Self::VirtualHosted,
+            S3AddressingStyle::Path => Self::Path,
+        }
+    }
+
+    fn parse(self) -> S3AddressingStyle {
+        match self {
+            Self::VirtualHosted => S3AddressingStyle::VirtualHosted,
+            Self::Path => S3AddressingStyle::Path,
+        }
+    }
+}
+
 impl ProtoRepr for proto::ObjectStore {
     type Type = ObjectStoreConfig;
 
@@ -37,6 +55,19 @@ impl ProtoRepr for proto::ObjectStore {
                     .context("file_backed_base_path")?
                     .clone(),
             },
+            proto::object_store::Mode::S3(mode) => ObjectStoreMode::S3 {
+                bucket_name: required(&mode.bucket_name).context("bucket_name")?.clone(),
+                region: required(&mode.region).context("region")?.clone(),
+                endpoint: mode.endpoint.clone(),
+                addressing_style: mode
+                    .addressing_style
+                    .map(proto::S3AddressingStyle::try_from)
+                    .transpose()
+                    .context("addressing_style")?
+                    .map_or_else(S3AddressingStyle::default, proto::S3AddressingStyle::parse),
+                access_key_id: mode.access_key_id.clone(),
+                secret_access_key: mode.secret_access_key.clone().map(S3SecretAccessKey),
+            },
         };
 
         Ok(Self::Type {
@@ -45,6 +76,8 @@ impl ProtoRepr for proto::ObjectStore {
                 .and_then(|x| Ok((*x).try_into()?))
                 .context("max_retries")?,
             local_mirror_path: self.local_mirror_path.clone(),
+            compression_level: self.compression_level,
+            compression_excluded_buckets: self.compression_excluded_buckets.clone(),
         })
     }
 
@@ -76,12 +109,29 @@ impl ProtoRepr for proto::ObjectStore {
             } => proto::object_store::Mode::FileBacked(proto::object_store::FileBacked {
                 file_backed_base_path: Some(file_backed_base_path.clone()),
             }),
+            ObjectStoreMode::S3 {
+                bucket_name,
+                region,
+                endpoint,
+                addressing_style,
+                access_key_id,
+                secret_access_key,
+            } => proto::object_store::Mode::S3(proto::object_store::S3 {
+                bucket_name: Some(bucket_name.clone()),
+                region: Some(region.clone()),
+                endpoint: endpoint.clone(),
+                addressing_style: Some(proto::S3AddressingStyle::new(*addressing_style).into()),
+                access_key_id: access_key_id.clone(),
+                secret_access_key: secret_access_key.as_ref().map(|key| key.0.clone()),
+            }),
         };
 
         Self {
             mode: Some(mode),
             max_retries: Some(this.max_retries.into()),
             local_mirror_path: this.local_mirror_path.clone(),
+            compression_level: this.compression_level,
+            compression_excluded_buckets: this.compression_excluded_buckets.clone(),
         }
     }
 }
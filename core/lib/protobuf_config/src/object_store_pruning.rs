@@ -0,0 +1,55 @@
+use anyhow::Context as _;
+use zksync_config::configs::object_store_pruning::{
+    ObjectRetentionPolicy, ObjectStorePruningConfig,
+};
+use zksync_protobuf::{repr::ProtoRepr, required};
+
+use crate::proto::object_store_pruning as proto;
+
+impl ProtoRepr for proto::RetentionPolicy {
+    type Type = ObjectRetentionPolicy;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            bucket: required(&self.bucket).context("bucket")?.clone(),
+            max_age_sec: self.max_age_sec,
+            remove_executed: self.remove_executed.unwrap_or_default(),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            bucket: Some(this.bucket.clone()),
+            max_age_sec: this.max_age_sec,
+            remove_executed: Some(this.remove_executed),
+        }
+    }
+}
+
+impl ProtoRepr for proto::ObjectStorePruning {
+    type Type = ObjectStorePruningConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            interval_sec: self
+                .interval_sec
+                .unwrap_or_else(ObjectStorePruningConfig::default_interval_sec),
+            dry_run: self.dry_run.unwrap_or_default(),
+            policies: self
+                .policies
+                .iter()
+                .enumerate()
+                .map(|(i, policy)| policy.read().context(i))
+                .collect::<Result<_, _>>()
+                .context("policies")?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            interval_sec: Some(this.interval_sec),
+            dry_run: Some(this.dry_run),
+            policies: this.policies.iter().map(ProtoRepr::build).collect(),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{num::NonZeroUsize, str::FromStr};
 
 use anyhow::Context;
 use secrecy::ExposeSecret;
@@ -77,12 +77,32 @@ impl ProtoRepr for proto::L1Secrets {
     fn read(&self) -> anyhow::Result<Self::Type> {
         Ok(Self::Type {
             l1_rpc_url: SensitiveUrl::from_str(required(&self.l1_rpc_url).context("l1_rpc_url")?)?,
+            fallback_l1_rpc_urls: self
+                .fallback_l1_rpc_urls
+                .iter()
+                .enumerate()
+                .map(|(i, url)| {
+                    SensitiveUrl::from_str(url)
+                        .with_context(|| format!("fallback_l1_rpc_urls[{i}]"))
+                })
+                .collect::<anyhow::Result<_>>()?,
+            l1_rpc_quorum: self
+                .l1_rpc_quorum
+                .map(|quorum| NonZeroUsize::new(quorum.try_into()?).context("cannot be zero"))
+                .transpose()
+                .context("l1_rpc_quorum")?,
         })
     }
 
     fn build(this: &Self::Type) -> Self {
         Self {
             l1_rpc_url: Some(this.l1_rpc_url.expose_str().to_string()),
+            fallback_l1_rpc_urls: this
+                .fallback_l1_rpc_urls
+                .iter()
+                .map(|url| url.expose_str().to_string())
+                .collect(),
+            l1_rpc_quorum: this.l1_rpc_quorum.map(|quorum| quorum.get() as u64),
         }
     }
 }
@@ -49,6 +49,7 @@ fn test_encoding() {
     test_encode_all_formats::<ReprConv<proto::snapshot_recovery::Postgres>>(rng);
     test_encode_all_formats::<ReprConv<proto::snapshot_recovery::SnapshotRecovery>>(rng);
     test_encode_all_formats::<ReprConv<proto::pruning::Pruning>>(rng);
+    test_encode_all_formats::<ReprConv<proto::object_store_pruning::ObjectStorePruning>>(rng);
     test_encode_all_formats::<ReprConv<proto::base_token_adjuster::BaseTokenAdjuster>>(rng);
     test_encode_all_formats::<ReprConv<proto::external_price_api_client::ExternalPriceApiClient>>(
         rng,
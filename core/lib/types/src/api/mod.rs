@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
@@ -12,10 +14,14 @@ use zksync_contracts::BaseSystemContractsHashes;
 pub use crate::transaction_request::{
     Eip712Meta, SerializationTransactionError, TransactionRequest,
 };
-use crate::{protocol_version::L1VerifierConfig, Address, L2BlockNumber, ProtocolVersionId};
+use crate::{
+    protocol_version::L1VerifierConfig, transaction_request::CallRequest, Address, L2BlockNumber,
+    ProtocolVersionId,
+};
 
 pub mod en;
 pub mod state_override;
+pub mod trace;
 
 /// Block Number
 #[derive(Copy, Clone, Debug, PartialEq, Display)]
@@ -621,6 +627,21 @@ pub struct DebugCall {
     pub error: Option<String>,
     pub revert_reason: Option<String>,
     pub calls: Vec<DebugCall>,
+    /// Logs emitted by the call itself (i.e., not by subcalls). Only populated if `withLog` is enabled
+    /// in the call tracer config.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub logs: Vec<DebugCallLog>,
+}
+
+/// Log emitted during a call, as reported by the call tracer with `withLog` enabled.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DebugCallLog {
+    pub address: Address,
+    pub topics: Vec<H256>,
+    pub data: Bytes,
+    /// Number of subcalls made by the call before emitting the log.
+    pub position: U64,
 }
 
 // TODO (PLA-965): remove deprecated fields from the struct. It is currently in a "migration" phase
@@ -700,24 +721,207 @@ impl ProtocolVersion {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize
+)]
 #[serde(rename_all = "camelCase")]
 pub enum SupportedTracers {
     CallTracer,
+    PrestateTracer,
+    #[serde(rename = "4byteTracer")]
+    FourByteTracer,
+    /// Runs several other tracers in a single execution.
+    MuxTracer,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct CallTracerConfig {
+    #[serde(default)]
     pub only_top_call: bool,
+    #[serde(default)]
+    pub with_log: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct TracerConfig {
-    pub tracer: SupportedTracers,
+pub struct PrestateTracerConfig {
     #[serde(default)]
-    pub tracer_config: CallTracerConfig,
+    pub diff_mode: bool,
+}
+
+/// Tracer used by `debug_trace*` methods together with its configuration. (De)serialized in the Geth format,
+/// i.e. as an object with `tracer` and optional `tracerConfig` fields.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TracerConfig {
+    CallTracer(CallTracerConfig),
+    PrestateTracer(PrestateTracerConfig),
+    FourByteTracer,
+    /// Configurations of the tracers to run. Tracers cannot be nested, i.e. there are no `MuxTracer` configs.
+    MuxTracer(BTreeMap<SupportedTracers, TracerConfig>),
+}
+
+impl Default for TracerConfig {
+    fn default() -> Self {
+        Self::CallTracer(CallTracerConfig::default())
+    }
+}
+
+impl TracerConfig {
+    pub fn tracer(&self) -> SupportedTracers {
+        match self {
+            Self::CallTracer(_) => SupportedTracers::CallTracer,
+            Self::PrestateTracer(_) => SupportedTracers::PrestateTracer,
+            Self::FourByteTracer => SupportedTracers::FourByteTracer,
+            Self::MuxTracer(_) => SupportedTracers::MuxTracer,
+        }
+    }
+
+    /// Iterates over all tracers that need to run, flattening `MuxTracer` configs.
+    pub fn leaf_tracers(&self) -> impl Iterator<Item = &Self> + '_ {
+        match self {
+            Self::MuxTracer(tracers) => Box::new(tracers.values()) as Box<dyn Iterator<Item = _>>,
+            _ => Box::new(std::iter::once(self)),
+        }
+    }
+
+    fn config_value(&self) -> Result<Value, serde_json::Error> {
+        match self {
+            Self::CallTracer(config) => serde_json::to_value(config),
+            Self::PrestateTracer(config) => serde_json::to_value(config),
+            Self::FourByteTracer => Ok(Value::Null),
+            Self::MuxTracer(tracers) => {
+                let configs = tracers
+                    .iter()
+                    .map(|(tracer, config)| Ok((*tracer, config.config_value()?)))
+                    .collect::<Result<BTreeMap<_, _>, serde_json::Error>>()?;
+                serde_json::to_value(configs)
+            }
+        }
+    }
+
+    fn from_value(tracer: SupportedTracers, config: Value) -> Result<Self, serde_json::Error> {
+        fn parse_config<T: Default + de::DeserializeOwned>(
+            config: Value,
+        ) -> Result<T, serde_json::Error> {
+            if config.is_null() {
+                Ok(T::default())
+            } else {
+                serde_json::from_value(config)
+            }
+        }
+
+        Ok(match tracer {
+            SupportedTracers::CallTracer => Self::CallTracer(parse_config(config)?),
+            SupportedTracers::PrestateTracer => Self::PrestateTracer(parse_config(config)?),
+            SupportedTracers::FourByteTracer => Self::FourByteTracer,
+            SupportedTracers::MuxTracer => {
+                let configs: BTreeMap<SupportedTracers, Value> = parse_config(config)?;
+                let configs = configs
+                    .into_iter()
+                    .map(|(tracer, config)| {
+                        if tracer == SupportedTracers::MuxTracer {
+                            return Err(de::Error::custom("`muxTracer` cannot be nested"));
+                        }
+                        Ok((tracer, Self::from_value(tracer, config)?))
+                    })
+                    .collect::<Result<_, serde_json::Error>>()?;
+                Self::MuxTracer(configs)
+            }
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawTracerConfig {
+    tracer: SupportedTracers,
+    #[serde(default)]
+    tracer_config: Value,
+}
+
+impl Serialize for TracerConfig {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::Error as _;
+
+        RawTracerConfig {
+            tracer: self.tracer(),
+            tracer_config: self.config_value().map_err(S::Error::custom)?,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TracerConfig {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawTracerConfig::deserialize(deserializer)?;
+        Self::from_value(raw.tracer, raw.tracer_config).map_err(de::Error::custom)
+    }
+}
+
+/// Account state reported by the prestate tracer.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrestateAccount {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance: Option<U256>,
+    /// Transaction nonce of the account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<U256>,
+    /// Versioned hash of the deployed bytecode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_hash: Option<H256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<Bytes>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub storage: BTreeMap<H256, H256>,
+}
+
+/// Output of the prestate tracer in the diff mode.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PrestateDiff {
+    pub pre: BTreeMap<Address, PrestateAccount>,
+    pub post: BTreeMap<Address, PrestateAccount>,
+}
+
+/// Output of a tracer for a single transaction or call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TracerResult {
+    CallTrace(DebugCall),
+    /// Number of calls keyed by `{selector}-{calldata size without selector}`, e.g. `0x27dc297e-128`.
+    FourByte(BTreeMap<String, u64>),
+    PrestateDiff(PrestateDiff),
+    Prestate(BTreeMap<Address, PrestateAccount>),
+    Mux(BTreeMap<SupportedTracers, TracerResult>),
+}
+
+impl TracerResult {
+    /// Returns the call trace if this is a call tracer result.
+    pub fn into_call_trace(self) -> Option<DebugCall> {
+        match self {
+            Self::CallTrace(call) => Some(call),
+            _ => None,
+        }
+    }
+}
+
+/// Output of a tracer for a transaction in a block.
+/// For some reasons geth returns result as {result: ...}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TracerBlockResult {
+    pub result: TracerResult,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -831,6 +1035,143 @@ pub struct FeeHistory {
     pub l2_pubdata_price: Vec<U256>,
 }
 
+/// The access list type returned from `eth_createAccessList` call.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessListResult {
+    /// Accounts and storage slots accessed during the call.
+    pub access_list: AccessList,
+    /// Gas used by the call.
+    pub gas_used: U256,
+    /// Error message if the call has reverted or halted. The access list is still returned in this case.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Request for the `eth_simulateV1` call.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatePayload {
+    /// L2 blocks to simulate, in order. All blocks are simulated in a single L1 batch.
+    pub block_state_calls: Vec<SimulateBlock>,
+}
+
+/// L2 block simulated as a part of [`SimulatePayload`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulateBlock {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_overrides: Option<SimulateBlockOverrides>,
+    /// State overrides applied before executing the block calls. Only supported for the first simulated block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_overrides: Option<state_override::StateOverride>,
+    /// Calls to execute in the block. Each call observes state changes made by all preceding calls.
+    #[serde(default)]
+    pub calls: Vec<CallRequest>,
+}
+
+/// Overrides for a simulated L2 block.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulateBlockOverrides {
+    /// Block timestamp. Must be greater than the timestamp of the previous simulated block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<U64>,
+    /// Base fee per gas enforced for all calls. Since simulated blocks share the same L1 batch,
+    /// this override is only supported for the first simulated block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_fee_per_gas: Option<U256>,
+}
+
+/// Simulated L2 block returned from `eth_simulateV1`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedBlock {
+    pub number: U64,
+    pub timestamp: U64,
+    /// Total gas used by all calls in the block.
+    pub gas_used: U256,
+    pub calls: Vec<SimulatedCall>,
+}
+
+/// Outcome of a single call in [`SimulatedBlock`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedCall {
+    /// 1 if the call has succeeded, 0 otherwise.
+    pub status: U64,
+    pub return_data: Bytes,
+    pub gas_used: U256,
+    pub logs: Vec<Log>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<SimulatedCallError>,
+}
+
+/// Error of a failed simulated call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulatedCallError {
+    pub code: i64,
+    pub message: String,
+}
+
+/// Mempool summary returned by `txpool_status`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolStatus {
+    /// Number of transactions that can be executed given the current account nonces.
+    pub pending: U64,
+    /// Number of transactions that cannot be executed because of nonce gaps.
+    pub queued: U64,
+    /// Stats of the state keeper mempool. Note, that this field is L2-specific and only provided by the main node
+    /// if the API server runs together with the state keeper.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mempool: Option<MempoolStats>,
+}
+
+/// Stats of the in-memory mempool maintained by the state keeper.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MempoolStats {
+    pub l1_transaction_count: U64,
+    pub l2_transaction_count: U64,
+    /// Number of accounts with a transaction that can be executed next.
+    pub l2_priority_queue_size: U64,
+}
+
+/// Mempool contents returned by `txpool_content` and `txpool_inspect`, grouped by the initiator address and nonce.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolContent<T> {
+    pub pending: BTreeMap<Address, BTreeMap<u64, T>>,
+    pub queued: BTreeMap<Address, BTreeMap<u64, T>>,
+    /// Set if the number of transactions in the mempool exceeds the server limit, so only a part of them is returned.
+    /// This field is L2-specific and is omitted if the contents are complete.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+}
+
+// Implemented manually to not require `T: Default`.
+impl<T> Default for TxpoolContent<T> {
+    fn default() -> Self {
+        Self {
+            pending: BTreeMap::new(),
+            queued: BTreeMap::new(),
+            truncated: false,
+        }
+    }
+}
+
+/// Mempool contents for a single account returned by `txpool_contentFrom`, grouped by nonce.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolContentFrom {
+    pub pending: BTreeMap<u64, Transaction>,
+    pub queued: BTreeMap<u64, Transaction>,
+    /// Set if the number of account transactions exceeds the server limit; see [`TxpoolContent::truncated`].
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -864,4 +1205,85 @@ mod tests {
         serde_json::from_str::<OldProtocolVersion>(&serde_json::to_string(&new_version).unwrap())
             .unwrap();
     }
+
+    #[test]
+    fn tracer_config_serialization() {
+        let config: TracerConfig = serde_json::from_str(r#"{ "tracer": "callTracer" }"#).unwrap();
+        assert_eq!(config, TracerConfig::default());
+
+        let config: TracerConfig = serde_json::from_str(
+            r#"{ "tracer": "callTracer", "tracerConfig": { "onlyTopCall": true, "withLog": true } }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            TracerConfig::CallTracer(CallTracerConfig {
+                only_top_call: true,
+                with_log: true,
+            })
+        );
+
+        let config: TracerConfig = serde_json::from_str(
+            r#"{
+                "tracer": "muxTracer",
+                "tracerConfig": {
+                    "prestateTracer": { "diffMode": true },
+                    "4byteTracer": null,
+                    "callTracer": {}
+                }
+            }"#,
+        )
+        .unwrap();
+        let TracerConfig::MuxTracer(tracers) = &config else {
+            panic!("Unexpected config: {config:?}");
+        };
+        assert_eq!(
+            tracers[&SupportedTracers::PrestateTracer],
+            TracerConfig::PrestateTracer(PrestateTracerConfig { diff_mode: true })
+        );
+        assert_eq!(
+            tracers[&SupportedTracers::FourByteTracer],
+            TracerConfig::FourByteTracer
+        );
+        assert_eq!(config.leaf_tracers().count(), 3);
+
+        let roundtrip: TracerConfig =
+            serde_json::from_value(serde_json::to_value(&config).unwrap()).unwrap();
+        assert_eq!(roundtrip, config);
+
+        let err = serde_json::from_str::<TracerConfig>(
+            r#"{ "tracer": "muxTracer", "tracerConfig": { "muxTracer": {} } }"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("nested"), "{err}");
+    }
+
+    #[test]
+    fn tracer_result_serialization() {
+        let call = TracerResult::CallTrace(DebugCall {
+            to: Address::repeat_byte(1),
+            ..DebugCall::default()
+        });
+        let four_byte = TracerResult::FourByte(BTreeMap::from([("0x27dc297e-128".to_owned(), 1)]));
+        let prestate = TracerResult::Prestate(BTreeMap::from([(
+            Address::repeat_byte(2),
+            PrestateAccount {
+                balance: Some(100.into()),
+                ..PrestateAccount::default()
+            },
+        )]));
+        let prestate_diff = TracerResult::PrestateDiff(PrestateDiff::default());
+        let mux = TracerResult::Mux(BTreeMap::from([
+            (SupportedTracers::CallTracer, call.clone()),
+            (SupportedTracers::FourByteTracer, four_byte.clone()),
+        ]));
+
+        let serialized = serde_json::to_value(&call).unwrap();
+        assert!(serialized.get("logs").is_none(), "{serialized}");
+        for result in [call, four_byte, prestate, prestate_diff, mux] {
+            let serialized = serde_json::to_value(&result).unwrap();
+            let deserialized: TracerResult = serde_json::from_value(serialized).unwrap();
+            assert_eq!(deserialized, result);
+        }
+    }
 }
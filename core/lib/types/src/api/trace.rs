@@ -0,0 +1,206 @@
+//! Types used by the Parity-compatible `trace` namespace.
+
+use serde::{Deserialize, Serialize};
+use zksync_basic_types::{web3::Bytes, H256, U256, U64};
+
+use crate::{api::BlockNumber, Address};
+
+/// Filter for `trace_filter` requests.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceFilter {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_block: Option<BlockNumber>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_block: Option<BlockNumber>,
+    /// If specified, only traces with `from` address in this list are returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_address: Option<Vec<Address>>,
+    /// If specified, only traces with `to` address (for calls) or the created contract address (for creations)
+    /// in this list are returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_address: Option<Vec<Address>>,
+    /// Number of matching traces to skip.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<usize>,
+    /// Maximum number of traces to return.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<usize>,
+}
+
+impl TraceFilter {
+    /// Checks whether a trace with the specified addresses matches this filter.
+    pub fn matches(&self, from: Address, to: Address) -> bool {
+        let from_matches = self.from_address.as_ref().map_or(true, |addresses| {
+            addresses.is_empty() || addresses.contains(&from)
+        });
+        let to_matches = self.to_address.as_ref().map_or(true, |addresses| {
+            addresses.is_empty() || addresses.contains(&to)
+        });
+        from_matches && to_matches
+    }
+}
+
+/// Type of the traced call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TraceCallType {
+    Call,
+    DelegateCall,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TraceType {
+    Call,
+    Create,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceCallAction {
+    pub call_type: TraceCallType,
+    pub from: Address,
+    pub to: Address,
+    pub gas: U256,
+    pub input: Bytes,
+    pub value: U256,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceCreateAction {
+    pub from: Address,
+    pub gas: U256,
+    pub init: Bytes,
+    pub value: U256,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TraceAction {
+    Call(TraceCallAction),
+    Create(TraceCreateAction),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceCallResult {
+    pub gas_used: U256,
+    pub output: Bytes,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceCreateResult {
+    pub address: Address,
+    pub code: Bytes,
+    pub gas_used: U256,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TraceOutput {
+    Create(TraceCreateResult),
+    Call(TraceCallResult),
+}
+
+/// Single call in a transaction trace together with its location in the chain, in the format used by Parity / OpenEthereum.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizedTrace {
+    pub action: TraceAction,
+    /// Output of the call; `None` if the call has failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<TraceOutput>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Number of direct subcalls.
+    pub subtraces: usize,
+    /// Path to the call in the call tree, e.g. `[]` for the top-level call, `[1, 0]` for the first subcall
+    /// of the second subcall of the top-level call.
+    pub trace_address: Vec<usize>,
+    pub block_hash: H256,
+    pub block_number: U64,
+    pub transaction_hash: H256,
+    pub transaction_position: U64,
+    pub r#type: TraceType,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_filter_matching() {
+        let filter: TraceFilter = serde_json::from_str(
+            r#"{
+                "fromBlock": "0x1",
+                "toBlock": "latest",
+                "toAddress": ["0x2222222222222222222222222222222222222222"],
+                "after": 1,
+                "count": 10
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(filter.from_block, Some(BlockNumber::Number(1.into())));
+        assert_eq!(filter.to_block, Some(BlockNumber::Latest));
+        assert_eq!(filter.after, Some(1));
+        assert_eq!(filter.count, Some(10));
+
+        assert!(filter.matches(Address::repeat_byte(1), Address::repeat_byte(0x22)));
+        assert!(!filter.matches(Address::repeat_byte(0x22), Address::repeat_byte(1)));
+        assert!(TraceFilter::default().matches(Address::zero(), Address::zero()));
+    }
+
+    #[test]
+    fn localized_trace_serialization() {
+        let trace = LocalizedTrace {
+            action: TraceAction::Call(TraceCallAction {
+                call_type: TraceCallType::DelegateCall,
+                from: Address::repeat_byte(1),
+                to: Address::repeat_byte(2),
+                gas: 100.into(),
+                input: vec![1, 2, 3].into(),
+                value: 0.into(),
+            }),
+            result: None,
+            error: Some("Reverted".to_owned()),
+            subtraces: 0,
+            trace_address: vec![0, 1],
+            block_hash: H256::repeat_byte(3),
+            block_number: 5.into(),
+            transaction_hash: H256::repeat_byte(4),
+            transaction_position: 2.into(),
+            r#type: TraceType::Call,
+        };
+        let serialized = serde_json::to_value(&trace).unwrap();
+        assert_eq!(serialized["action"]["callType"], "delegatecall");
+        assert_eq!(serialized["traceAddress"], serde_json::json!([0, 1]));
+        assert_eq!(serialized["type"], "call");
+        assert!(serialized.get("result").is_none(), "{serialized}");
+
+        let deserialized: LocalizedTrace = serde_json::from_value(serialized).unwrap();
+        assert_eq!(deserialized, trace);
+
+        let create_trace = LocalizedTrace {
+            action: TraceAction::Create(TraceCreateAction {
+                from: Address::repeat_byte(1),
+                gas: 100.into(),
+                init: vec![1, 2, 3].into(),
+                value: 0.into(),
+            }),
+            result: Some(TraceOutput::Create(TraceCreateResult {
+                address: Address::repeat_byte(5),
+                code: vec![].into(),
+                gas_used: 50.into(),
+            })),
+            error: None,
+            r#type: TraceType::Create,
+            ..trace
+        };
+        let serialized = serde_json::to_value(&create_trace).unwrap();
+        let deserialized: LocalizedTrace = serde_json::from_value(serialized).unwrap();
+        assert_eq!(deserialized, create_trace);
+    }
+}
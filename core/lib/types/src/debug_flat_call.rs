@@ -115,6 +115,7 @@ mod test {
             error: None,
             revert_reason: None,
             calls: new_testing_trace(),
+            logs: vec![],
         }
     }
 
@@ -93,3 +93,13 @@ pub struct TxHistoryToSend {
     pub signed_raw_tx: Vec<u8>,
     pub nonce: Nonce,
 }
+
+/// Zero-value self-transfer sent by the main operator to cancel a stuck transaction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TxCancellation {
+    pub nonce: Nonce,
+    pub tx_hash: H256,
+    pub base_fee_per_gas: u64,
+    pub priority_fee_per_gas: u64,
+    pub sent_at_block: u32,
+}
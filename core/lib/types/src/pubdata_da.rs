@@ -38,3 +38,15 @@ pub struct DataAvailabilityBlob {
     pub inclusion_data: Option<Vec<u8>>,
     pub sent_at: DateTime<Utc>,
 }
+
+/// Represents a part of the L1 batch pubdata dispatched to the data availability layer as a separate blob.
+/// Used if the pubdata exceeds the blob size limit of the DA client.
+#[derive(Debug, Clone)]
+pub struct DataAvailabilityBlobPart {
+    pub l1_batch_number: L1BatchNumber,
+    /// Zero-based index of the part in the pubdata.
+    pub part_index: u32,
+    pub blob_id: String,
+    pub inclusion_data: Option<Vec<u8>>,
+    pub sent_at: DateTime<Utc>,
+}
@@ -37,8 +37,12 @@ pub enum Web3Error {
     FilterNotFound,
     #[error("Query returned more than {0} results. Try with this block range [{1:#x}, {2:#x}].")]
     LogsLimitExceeded(usize, u32, u32),
+    #[error("Block range is too large; at most {0} blocks can be queried at once")]
+    BlockRangeLimitExceeded(u32),
     #[error("invalid filter: if blockHash is supplied fromBlock and toBlock must not be")]
     InvalidFilterBlockHash,
+    #[error("invalid simulation request: {0}")]
+    InvalidSimulation(String),
     /// Weaker form of a "method not found" error; the method implementation is technically present,
     /// but the node configuration prevents the method from functioning.
     #[error("Method not implemented")]
@@ -87,15 +91,20 @@ impl EnrichedClientError {
 
     /// Whether the error should be considered retriable.
     pub fn is_retriable(&self) -> bool {
-        match self.as_ref() {
-            ClientError::Transport(_) | ClientError::RequestTimeout => true,
-            ClientError::Call(err) => {
-                // At least some RPC providers use "internal error" in case of the server being overloaded
-                err.code() == ErrorCode::ServerIsBusy.code()
-                    || err.code() == ErrorCode::InternalError.code()
-            }
-            _ => false,
+        is_retriable(self.as_ref())
+    }
+}
+
+/// Whether the client error should be considered retriable.
+pub fn is_retriable(err: &ClientError) -> bool {
+    match err {
+        ClientError::Transport(_) | ClientError::RequestTimeout => true,
+        ClientError::Call(err) => {
+            // At least some RPC providers use "internal error" in case of the server being overloaded
+            err.code() == ErrorCode::ServerIsBusy.code()
+                || err.code() == ErrorCode::InternalError.code()
         }
+        _ => false,
     }
 }
 
@@ -2,7 +2,7 @@
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use zksync_types::{
-    api::{BlockId, BlockNumber, DebugCall, ResultDebugCall, TracerConfig},
+    api::{BlockId, BlockNumber, TracerBlockResult, TracerConfig, TracerResult},
     debug_flat_call::DebugCallFlat,
     transaction_request::CallRequest,
 };
@@ -26,7 +26,7 @@ pub trait DebugNamespace {
         &self,
         block: BlockNumber,
         options: Option<TracerConfig>,
-    ) -> RpcResult<Vec<ResultDebugCall>>;
+    ) -> RpcResult<Vec<TracerBlockResult>>;
 
     #[method(name = "traceBlockByNumber.callFlatTracer")]
     async fn trace_block_by_number_flat(
@@ -40,7 +40,7 @@ pub trait DebugNamespace {
         &self,
         hash: H256,
         options: Option<TracerConfig>,
-    ) -> RpcResult<Vec<ResultDebugCall>>;
+    ) -> RpcResult<Vec<TracerBlockResult>>;
 
     #[method(name = "traceCall")]
     async fn trace_call(
@@ -48,12 +48,12 @@ pub trait DebugNamespace {
         request: CallRequest,
         block: Option<BlockId>,
         options: Option<TracerConfig>,
-    ) -> RpcResult<DebugCall>;
+    ) -> RpcResult<TracerResult>;
 
     #[method(name = "traceTransaction")]
     async fn trace_transaction(
         &self,
         tx_hash: H256,
         options: Option<TracerConfig>,
-    ) -> RpcResult<Option<DebugCall>>;
+    ) -> RpcResult<Option<TracerResult>>;
 }
@@ -3,8 +3,8 @@ use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use zksync_types::{
     api::{
-        state_override::StateOverride, BlockId, BlockIdVariant, BlockNumber, FeeHistory,
-        Transaction, TransactionVariant,
+        state_override::StateOverride, AccessListResult, BlockId, BlockIdVariant, BlockNumber,
+        FeeHistory, SimulatePayload, SimulatedBlock, Transaction, TransactionVariant,
     },
     transaction_request::CallRequest,
     Address, H256,
@@ -48,6 +48,21 @@ pub trait EthNamespace {
         state_override: Option<StateOverride>,
     ) -> RpcResult<U256>;
 
+    #[method(name = "createAccessList")]
+    async fn create_access_list(
+        &self,
+        req: CallRequest,
+        block: Option<BlockIdVariant>,
+        state_override: Option<StateOverride>,
+    ) -> RpcResult<AccessListResult>;
+
+    #[method(name = "simulateV1")]
+    async fn simulate_v1(
+        &self,
+        payload: SimulatePayload,
+        block: Option<BlockIdVariant>,
+    ) -> RpcResult<Vec<SimulatedBlock>>;
+
     #[method(name = "gasPrice")]
     async fn gas_price(&self) -> RpcResult<U256>;
 
@@ -0,0 +1,32 @@
+#[cfg_attr(not(feature = "server"), allow(unused_imports))]
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+use zksync_types::{
+    api::{
+        trace::{LocalizedTrace, TraceFilter},
+        BlockNumber,
+    },
+    H256,
+};
+
+use crate::client::{ForWeb3Network, L2};
+
+/// Parity-compatible methods returning flattened call traces persisted by the server.
+#[cfg_attr(
+    feature = "server",
+    rpc(server, client, namespace = "trace", client_bounds(Self: ForWeb3Network<Net = L2>))
+)]
+#[cfg_attr(
+    not(feature = "server"),
+    rpc(client, namespace = "trace", client_bounds(Self: ForWeb3Network<Net = L2>))
+)]
+pub trait TraceNamespace {
+    #[method(name = "block")]
+    async fn block(&self, block: BlockNumber) -> RpcResult<Vec<LocalizedTrace>>;
+
+    #[method(name = "transaction")]
+    async fn transaction(&self, tx_hash: H256) -> RpcResult<Option<Vec<LocalizedTrace>>>;
+
+    #[method(name = "filter")]
+    async fn filter(&self, filter: TraceFilter) -> RpcResult<Vec<LocalizedTrace>>;
+}
@@ -0,0 +1,35 @@
+#[cfg_attr(not(feature = "server"), allow(unused_imports))]
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+use zksync_types::{
+    api::{Transaction, TxpoolContent, TxpoolContentFrom, TxpoolStatus},
+    Address,
+};
+
+use crate::client::{ForWeb3Network, L2};
+
+/// Mempool introspection methods compatible with the Geth `txpool` namespace.
+///
+/// Transactions are considered *pending* if they can be executed given the current account nonces, and *queued*
+/// if there are nonce gaps before them.
+#[cfg_attr(
+    feature = "server",
+    rpc(server, client, namespace = "txpool", client_bounds(Self: ForWeb3Network<Net = L2>))
+)]
+#[cfg_attr(
+    not(feature = "server"),
+    rpc(client, namespace = "txpool", client_bounds(Self: ForWeb3Network<Net = L2>))
+)]
+pub trait TxpoolNamespace {
+    #[method(name = "status")]
+    async fn status(&self) -> RpcResult<TxpoolStatus>;
+
+    #[method(name = "content")]
+    async fn content(&self) -> RpcResult<TxpoolContent<Transaction>>;
+
+    #[method(name = "contentFrom")]
+    async fn content_from(&self, address: Address) -> RpcResult<TxpoolContentFrom>;
+
+    #[method(name = "inspect")]
+    async fn inspect(&self) -> RpcResult<TxpoolContent<String>>;
+}
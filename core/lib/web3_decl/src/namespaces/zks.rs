@@ -139,4 +139,9 @@ pub trait ZksNamespace {
         &self,
         tx_bytes: Bytes,
     ) -> RpcResult<TransactionDetailedResult>;
+
+    /// Submits a transaction that is not exposed via API (e.g., in pending transaction subscriptions
+    /// or `eth_getTransactionByHash`) until it is included into an L2 block.
+    #[method(name = "sendPrivateRawTransaction")]
+    async fn send_private_raw_transaction(&self, tx_bytes: Bytes) -> RpcResult<H256>;
 }
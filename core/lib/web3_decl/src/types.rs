@@ -16,8 +16,10 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 pub use zksync_types::{
     api::{Block, BlockNumber, Log, TransactionReceipt, TransactionRequest},
     ethabi,
-    web3::{BlockHeader, Bytes, CallRequest, FeeHistory, Index, SyncState, TraceFilter, Work},
-    Address, Transaction, H160, H256, H64, U256, U64,
+    web3::{
+        BlockHeader, Bytes, CallRequest, FeeHistory, Index, SyncInfo, SyncState, TraceFilter, Work,
+    },
+    Address, L1BatchNumber, L2BlockNumber, Transaction, H160, H256, H64, U256, U64,
 };
 
 /// Token in the ZKsync network
@@ -246,10 +248,19 @@ impl FilterBuilder {
 
 #[derive(Default, Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct PubSubFilter {
+    /// For `logs` subscriptions, filters logs by the emitting contract address. For `zks_txStatus` subscriptions,
+    /// filters transactions by the initiator address.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub address: Option<ValueOrArray<H160>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub topics: Option<Vec<Option<ValueOrArray<H256>>>>,
+    /// Filters transactions by hash; only used by `zks_txStatus` subscriptions.
+    #[serde(
+        default,
+        rename = "transactionHashes",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub transaction_hashes: Option<ValueOrArray<H256>>,
 }
 
 impl PubSubFilter {
@@ -274,6 +285,21 @@ impl PubSubFilter {
         }
         true
     }
+
+    /// Checks whether a transaction status notification matches this filter.
+    pub fn matches_tx_status(&self, notification: &TxStatusNotification) -> bool {
+        if let Some(addresses) = &self.address {
+            if !addresses.0.contains(&notification.initiator_address) {
+                return false;
+            }
+        }
+        if let Some(hashes) = &self.transaction_hashes {
+            if !hashes.0.contains(&notification.transaction_hash) {
+                return false;
+            }
+        }
+        true
+    }
 }
 
 #[derive(Default, Clone)]
@@ -339,10 +365,77 @@ fn topic_to_option<T>(topic: ethabi::Topic<T>) -> Option<Vec<T>> {
 pub enum PubSubResult {
     Header(BlockHeader),
     Log(Log),
+    TxStatus(TxStatusNotification),
+    L1Batch(L1BatchNotification),
     TxHash(H256),
+    SyncProgress(SyncProgress),
     Syncing(bool),
 }
 
+/// Sync progress notification sent to `syncing` subscribers while the node is catching up.
+/// Once the node is synced, a `false` notification is sent instead (i.e., [`PubSubResult::Syncing`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncProgress {
+    /// Always `true`; used to distinguish this notification from the final `false` one.
+    pub syncing: bool,
+    pub status: SyncInfo,
+}
+
+/// Stage of the transaction lifecycle reported to `zks_txStatus` subscribers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TxLifecycleStage {
+    /// Transaction is included into a sealed L2 block.
+    Included,
+    /// L1 batch with the transaction is committed on L1.
+    Committed,
+    /// L1 batch with the transaction is proven on L1.
+    Proved,
+    /// L1 batch with the transaction is executed on L1.
+    Executed,
+}
+
+/// Notification sent to `zks_txStatus` subscribers when a transaction advances to the next lifecycle stage.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxStatusNotification {
+    pub transaction_hash: H256,
+    pub initiator_address: Address,
+    pub stage: TxLifecycleStage,
+    pub block_number: L2BlockNumber,
+    /// L1 batch containing the transaction. Not set for the [`TxLifecycleStage::Included`] stage.
+    pub l1_batch_number: Option<L1BatchNumber>,
+    /// Hash of the L1 transaction that has committed, proved or executed the batch.
+    pub eth_tx_hash: Option<H256>,
+}
+
+/// Stage of the L1 batch lifecycle reported to `zks_l1Batches` subscribers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum L1BatchStage {
+    /// L1 batch is sealed by the state keeper.
+    Sealed,
+    /// L1 batch is committed on L1.
+    Committed,
+    /// L1 batch is proven on L1.
+    Proved,
+    /// L1 batch is executed on L1, i.e., is final.
+    Executed,
+}
+
+/// Notification sent to `zks_l1Batches` subscribers when an L1 batch advances to the next lifecycle stage.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1BatchNotification {
+    pub l1_batch_number: L1BatchNumber,
+    pub stage: L1BatchStage,
+    pub timestamp: u64,
+    /// State root hash of the batch. May be not computed yet for the [`L1BatchStage::Sealed`] stage.
+    pub root_hash: Option<H256>,
+    /// Hash of the L1 transaction that has committed, proved or executed the batch.
+    pub eth_tx_hash: Option<H256>,
+}
+
 #[cfg(test)]
 mod tests {
     use zksync_types::api::{BlockId, BlockIdVariant};
@@ -437,4 +530,33 @@ mod tests {
         let restored_value: ValueOrArray<Address> = serde_json::from_value(json).unwrap();
         assert_eq!(restored_value, value);
     }
+
+    #[test]
+    fn filtering_tx_status_notifications() {
+        let filter: PubSubFilter = serde_json::from_value(serde_json::json!({
+            "address": "0x1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f",
+            "transactionHashes": ["0x2323232323232323232323232323232323232323232323232323232323232323"],
+        }))
+        .unwrap();
+        let mut notification = TxStatusNotification {
+            transaction_hash: H256::repeat_byte(0x23),
+            initiator_address: Address::repeat_byte(0x1f),
+            stage: TxLifecycleStage::Committed,
+            block_number: L2BlockNumber(1),
+            l1_batch_number: Some(L1BatchNumber(1)),
+            eth_tx_hash: Some(H256::repeat_byte(1)),
+        };
+        assert!(filter.matches_tx_status(&notification));
+        assert!(PubSubFilter::default().matches_tx_status(&notification));
+
+        notification.initiator_address = Address::repeat_byte(0x20);
+        assert!(!filter.matches_tx_status(&notification));
+        notification.initiator_address = Address::repeat_byte(0x1f);
+        notification.transaction_hash = H256::repeat_byte(0x24);
+        assert!(!filter.matches_tx_status(&notification));
+
+        let json = serde_json::to_value(PubSubResult::TxStatus(notification)).unwrap();
+        assert_eq!(json["stage"], "committed");
+        assert_eq!(json["l1BatchNumber"], 1);
+    }
 }
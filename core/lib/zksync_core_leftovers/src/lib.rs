@@ -66,6 +66,8 @@ pub enum Component {
     ExternalProofIntegrationApi,
     /// VM runner-based component that allows to test experimental VM features. Doesn't save any data to Postgres.
     VmPlayground,
+    /// Component removing stale objects from object store buckets according to retention policies.
+    ObjectStorePruner,
 }
 
 #[derive(Debug)]
@@ -114,6 +116,7 @@ impl FromStr for Components {
             "external_proof_integration_api" => {
                 Ok(Components(vec![Component::ExternalProofIntegrationApi]))
             }
+            "object_store_pruner" => Ok(Components(vec![Component::ObjectStorePruner])),
             other => Err(format!("{} is not a valid component name", other)),
         }
     }
@@ -41,13 +41,37 @@ use zksync_utils::{h256_to_u256, time::seconds_since_epoch, u256_to_h256};
 
 use super::{
     vm_metrics::{self, SandboxStage, SANDBOX_METRICS},
-    ApiTracer, BlockArgs, OneshotExecutor, TxExecutionArgs, TxSetupArgs,
+    ApiTracer, BlockArgs, OneshotExecutor, ReplayedTransaction, SimulatedL2Block,
+    SimulatedL2BlockOutput, TxExecutionArgs, TxSetupArgs,
 };
 
 pub(super) async fn prepare_env_and_storage(
+    connection: Connection<'static, Core>,
+    setup_args: TxSetupArgs,
+    block_args: &BlockArgs,
+) -> anyhow::Result<(OneshotEnv, PostgresStorage<'static>)> {
+    prepare_env_and_storage_inner(connection, setup_args, block_args, false).await
+}
+
+/// Same as [`prepare_env_and_storage()`], but the returned storage corresponds to the state *before* the sealed L2 block
+/// referenced by `block_args`. Used to re-execute transactions from this block.
+pub(super) async fn prepare_env_and_storage_for_replay(
+    connection: Connection<'static, Core>,
+    setup_args: TxSetupArgs,
+    block_args: &BlockArgs,
+) -> anyhow::Result<(OneshotEnv, PostgresStorage<'static>)> {
+    anyhow::ensure!(
+        !block_args.is_pending_l2_block(),
+        "cannot replay pending L2 block"
+    );
+    prepare_env_and_storage_inner(connection, setup_args, block_args, true).await
+}
+
+async fn prepare_env_and_storage_inner(
     mut connection: Connection<'static, Core>,
     setup_args: TxSetupArgs,
     block_args: &BlockArgs,
+    before_block: bool,
 ) -> anyhow::Result<(OneshotEnv, PostgresStorage<'static>)> {
     let initialization_stage = SANDBOX_METRICS.sandbox[&SandboxStage::Initialization].start();
 
@@ -75,10 +99,17 @@ pub(super) async fn prepare_env_and_storage(
     )
     .await?;
 
+    let storage_l2_block_number = if before_block {
+        let block_number = resolved_block_info.state_l2_block_number;
+        let prev_block_number = block_number.0.checked_sub(1);
+        L2BlockNumber(prev_block_number.context("cannot replay genesis L2 block")?)
+    } else {
+        resolved_block_info.state_l2_block_number
+    };
     let storage = PostgresStorage::new_async(
         Handle::current(),
         connection,
-        resolved_block_info.state_l2_block_number,
+        storage_l2_block_number,
         false,
     )
     .await
@@ -267,25 +298,7 @@ impl<S: ReadStorage> VmSandbox<S> {
         current_balance += execution_args.added_balance;
         storage_view.set_value(balance_key, u256_to_h256(current_balance));
 
-        // Reset L2 block info if necessary.
-        if let Some(current_block) = current_block {
-            let l2_block_info_key = StorageKey::new(
-                AccountTreeId::new(SYSTEM_CONTEXT_ADDRESS),
-                SYSTEM_CONTEXT_CURRENT_L2_BLOCK_INFO_POSITION,
-            );
-            let l2_block_info =
-                pack_block_info(current_block.number.into(), current_block.timestamp);
-            storage_view.set_value(l2_block_info_key, u256_to_h256(l2_block_info));
-
-            let l2_block_txs_rolling_hash_key = StorageKey::new(
-                AccountTreeId::new(SYSTEM_CONTEXT_ADDRESS),
-                SYSTEM_CONTEXT_CURRENT_TX_ROLLING_HASH_POSITION,
-            );
-            storage_view.set_value(
-                l2_block_txs_rolling_hash_key,
-                current_block.txs_rolling_hash,
-            );
-        }
+        Self::reset_l2_block_info(storage_view, current_block);
 
         let storage_view_setup_time = storage_view_setup_started_at.elapsed();
         // We don't want to emit too many logs.
@@ -294,6 +307,32 @@ impl<S: ReadStorage> VmSandbox<S> {
         }
     }
 
+    /// Resets L2 block info if necessary.
+    fn reset_l2_block_info(
+        storage_view: &mut StorageView<S>,
+        current_block: Option<StoredL2BlockEnv>,
+    ) {
+        let Some(current_block) = current_block else {
+            return;
+        };
+
+        let l2_block_info_key = StorageKey::new(
+            AccountTreeId::new(SYSTEM_CONTEXT_ADDRESS),
+            SYSTEM_CONTEXT_CURRENT_L2_BLOCK_INFO_POSITION,
+        );
+        let l2_block_info = pack_block_info(current_block.number.into(), current_block.timestamp);
+        storage_view.set_value(l2_block_info_key, u256_to_h256(l2_block_info));
+
+        let l2_block_txs_rolling_hash_key = StorageKey::new(
+            AccountTreeId::new(SYSTEM_CONTEXT_ADDRESS),
+            SYSTEM_CONTEXT_CURRENT_TX_ROLLING_HASH_POSITION,
+        );
+        storage_view.set_value(
+            l2_block_txs_rolling_hash_key,
+            current_block.txs_rolling_hash,
+        );
+    }
+
     fn wrap_tracers(
         tracers: Vec<ApiTracer>,
         env: &OneshotEnv,
@@ -333,6 +372,104 @@ impl<S: ReadStorage> VmSandbox<S> {
     }
 }
 
+/// Executes transactions in one or more consecutive L2 blocks using a single VM instance.
+///
+/// This method is blocking.
+fn simulate_l2_blocks<S: ReadStorage>(
+    storage: S,
+    env: OneshotEnv,
+    blocks: Vec<SimulatedL2Block>,
+    missed_storage_invocation_limit: usize,
+) -> Vec<SimulatedL2BlockOutput> {
+    let mut storage_view = StorageView::new(storage);
+    VmSandbox::reset_l2_block_info(&mut storage_view, env.current_block);
+    let storage_view = storage_view.to_rc_ptr();
+
+    let protocol_version = env.system.version;
+    let mut block_env = env.l1_batch.first_l2_block;
+    let mut vm = Box::new(VmInstance::new_with_specific_version(
+        env.l1_batch,
+        env.system,
+        storage_view,
+        protocol_version.into_api_vm_version(),
+    ));
+
+    let execution_latency = SANDBOX_METRICS.sandbox[&SandboxStage::Execution].start();
+    let mut outputs = Vec::with_capacity(blocks.len());
+    let mut prev_block_hash = None;
+    for block in blocks {
+        if let Some(prev_block_hash) = prev_block_hash {
+            block_env = block.next_l2_block_env(&block_env, prev_block_hash);
+            vm.start_new_l2_block(block_env);
+        }
+
+        // The VM checks that the previous block hash provided when starting a new block is correct,
+        // so we need to track block hashes in the same way the bootloader does.
+        let mut block_hasher = L2BlockHasher::new(
+            L2BlockNumber(block_env.number),
+            block_env.timestamp,
+            block_env.prev_block_hash,
+        );
+        let results = block
+            .transactions
+            .into_iter()
+            .map(|transaction| {
+                block_hasher.push_tx_hash(transaction.hash());
+                let tracers: Vec<MultiVmTracerPointer<_, HistoryDisabled>> =
+                    vec![StorageInvocations::new(missed_storage_invocation_limit)
+                        .into_tracer_pointer()];
+                vm.push_transaction(transaction);
+                vm.inspect(tracers.into(), VmExecutionMode::OneTx)
+            })
+            .collect();
+        prev_block_hash = Some(block_hasher.finalize(protocol_version));
+        outputs.push(SimulatedL2BlockOutput {
+            env: block_env,
+            results,
+        });
+    }
+    execution_latency.observe();
+    outputs
+}
+
+/// Re-executes transactions from a sealed L2 block using a single VM instance, so that each transaction
+/// observes changes made by the preceding ones.
+///
+/// This method is blocking.
+fn replay_l2_block<S: ReadStorage>(
+    storage: S,
+    env: OneshotEnv,
+    transactions: Vec<ReplayedTransaction>,
+) -> Vec<VmExecutionResultAndLogs> {
+    let mut storage_view = StorageView::new(storage);
+    VmSandbox::reset_l2_block_info(&mut storage_view, env.current_block);
+    let storage_view = storage_view.to_rc_ptr();
+
+    let protocol_version = env.system.version;
+    let mut vm = Box::new(VmInstance::new_with_specific_version(
+        env.l1_batch,
+        env.system,
+        storage_view,
+        protocol_version.into_api_vm_version(),
+    ));
+
+    let execution_latency = SANDBOX_METRICS.sandbox[&SandboxStage::Execution].start();
+    let results = transactions
+        .into_iter()
+        .map(|replayed| {
+            let tracers: Vec<MultiVmTracerPointer<_, HistoryDisabled>> = replayed
+                .tracers
+                .into_iter()
+                .map(|tracer| tracer.into_boxed(protocol_version))
+                .collect();
+            vm.push_transaction(replayed.transaction);
+            vm.inspect(tracers.into(), VmExecutionMode::OneTx)
+        })
+        .collect();
+    execution_latency.observe();
+    results
+}
+
 /// Main [`OneshotExecutor`] implementation used by the API server.
 #[derive(Debug, Default)]
 pub struct MainOneshotExecutor {
@@ -411,6 +548,33 @@ where
         .await
         .context("VM execution panicked")
     }
+
+    async fn simulate_transactions(
+        &self,
+        storage: S,
+        env: OneshotEnv,
+        blocks: Vec<SimulatedL2Block>,
+    ) -> anyhow::Result<Vec<SimulatedL2BlockOutput>> {
+        // Simulations are never used for transaction validation, so storage accesses are always limited.
+        let missed_storage_invocation_limit = self.missed_storage_invocation_limit;
+        tokio::task::spawn_blocking(move || {
+            simulate_l2_blocks(storage, env, blocks, missed_storage_invocation_limit)
+        })
+        .await
+        .context("VM execution panicked")
+    }
+
+    async fn replay_transactions(
+        &self,
+        storage: S,
+        env: OneshotEnv,
+        transactions: Vec<ReplayedTransaction>,
+    ) -> anyhow::Result<Vec<VmExecutionResultAndLogs>> {
+        // Replayed transactions were already executed by the state keeper, so storage accesses are not limited.
+        tokio::task::spawn_blocking(move || replay_l2_block(storage, env, transactions))
+            .await
+            .context("VM execution panicked")
+    }
 }
 
 async fn read_stored_l2_block(
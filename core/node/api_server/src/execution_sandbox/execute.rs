@@ -3,12 +3,12 @@
 use async_trait::async_trait;
 use zksync_dal::{Connection, Core};
 use zksync_multivm::interface::{
-    storage::ReadStorage, BytecodeCompressionError, OneshotEnv, TransactionExecutionMetrics,
-    VmExecutionResultAndLogs,
+    storage::ReadStorage, BytecodeCompressionError, L2BlockEnv, OneshotEnv,
+    TransactionExecutionMetrics, VmExecutionResultAndLogs,
 };
 use zksync_types::{
     api::state_override::StateOverride, l2::L2Tx, ExecuteTransactionCommon, Nonce,
-    PackedEthSignature, Transaction, U256,
+    PackedEthSignature, Transaction, H256, U256,
 };
 
 use super::{
@@ -79,6 +79,59 @@ impl TxExecutionArgs {
     }
 }
 
+/// L2 block executed as a part of a multi-transaction simulation.
+#[derive(Debug)]
+pub(crate) struct SimulatedL2Block {
+    /// Block timestamp. If not specified, the first block uses the timestamp from the sandbox environment,
+    /// and each following block uses the previous block timestamp + 1.
+    pub timestamp: Option<u64>,
+    /// Transactions / calls to execute in the block, in order.
+    pub transactions: Vec<Transaction>,
+}
+
+impl SimulatedL2Block {
+    pub fn for_eth_calls(timestamp: Option<u64>, calls: Vec<L2Tx>) -> Self {
+        let transactions = calls
+            .into_iter()
+            .map(|call| TxExecutionArgs::for_eth_call(call).transaction)
+            .collect();
+        Self {
+            timestamp,
+            transactions,
+        }
+    }
+
+    pub(super) fn next_l2_block_env(
+        &self,
+        prev_block: &L2BlockEnv,
+        prev_block_hash: H256,
+    ) -> L2BlockEnv {
+        L2BlockEnv {
+            number: prev_block.number + 1,
+            timestamp: self.timestamp.unwrap_or(prev_block.timestamp + 1),
+            prev_block_hash,
+            max_virtual_blocks_to_create: 1,
+        }
+    }
+}
+
+/// Historical transaction re-executed as a part of an L2 block replay.
+#[derive(Debug)]
+pub(crate) struct ReplayedTransaction {
+    pub transaction: Transaction,
+    /// Tracers to run for this transaction. Usually empty for all transactions except for the traced ones.
+    pub tracers: Vec<ApiTracer>,
+}
+
+/// Output of executing a [`SimulatedL2Block`].
+#[derive(Debug)]
+pub(crate) struct SimulatedL2BlockOutput {
+    /// Environment the block was executed in.
+    pub env: L2BlockEnv,
+    /// VM outputs for each of the block transactions.
+    pub results: Vec<VmExecutionResultAndLogs>,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct TransactionExecutionOutput {
     /// Output of the VM.
@@ -135,6 +188,51 @@ impl TransactionExecutor {
             are_published_bytecodes_ok: published_bytecodes.is_ok(),
         })
     }
+
+    /// Executes transactions in one or more consecutive L2 blocks in a single VM, so that each transaction
+    /// observes changes made by the preceding ones. All blocks belong to the same L1 batch.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn simulate_in_sandbox(
+        &self,
+        vm_permit: VmPermit,
+        setup_args: TxSetupArgs,
+        connection: Connection<'static, Core>,
+        block_args: BlockArgs,
+        state_override: Option<StateOverride>,
+        blocks: Vec<SimulatedL2Block>,
+    ) -> anyhow::Result<Vec<SimulatedL2BlockOutput>> {
+        let (mut env, storage) =
+            apply::prepare_env_and_storage(connection, setup_args, &block_args).await?;
+        if let Some(timestamp) = blocks.first().and_then(|block| block.timestamp) {
+            env.l1_batch.timestamp = timestamp;
+            env.l1_batch.first_l2_block.timestamp = timestamp;
+        }
+        let state_override = state_override.unwrap_or_default();
+        let storage = StorageWithOverrides::new(storage, &state_override);
+
+        let outputs = self.simulate_transactions(storage, env, blocks).await?;
+        drop(vm_permit);
+        Ok(outputs)
+    }
+
+    /// Re-executes transactions from a sealed L2 block referenced by `block_args` on top of the state before the block.
+    /// Transactions must be a prefix of the block transactions in the execution order; they are executed in a single VM,
+    /// so that each transaction observes changes made by the preceding ones.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn replay_in_sandbox(
+        &self,
+        vm_permit: VmPermit,
+        setup_args: TxSetupArgs,
+        connection: Connection<'static, Core>,
+        block_args: BlockArgs,
+        transactions: Vec<ReplayedTransaction>,
+    ) -> anyhow::Result<Vec<VmExecutionResultAndLogs>> {
+        let (env, storage) =
+            apply::prepare_env_and_storage_for_replay(connection, setup_args, &block_args).await?;
+        let results = self.replay_transactions(storage, env, transactions).await?;
+        drop(vm_permit);
+        Ok(results)
+    }
 }
 
 #[async_trait]
@@ -184,4 +282,36 @@ where
             }
         }
     }
+
+    async fn simulate_transactions(
+        &self,
+        storage: S,
+        env: OneshotEnv,
+        blocks: Vec<SimulatedL2Block>,
+    ) -> anyhow::Result<Vec<SimulatedL2BlockOutput>> {
+        match self {
+            Self::Real(executor) => executor.simulate_transactions(storage, env, blocks).await,
+            Self::Mock(executor) => executor.simulate_transactions(storage, env, blocks).await,
+        }
+    }
+
+    async fn replay_transactions(
+        &self,
+        storage: S,
+        env: OneshotEnv,
+        transactions: Vec<ReplayedTransaction>,
+    ) -> anyhow::Result<Vec<VmExecutionResultAndLogs>> {
+        match self {
+            Self::Real(executor) => {
+                executor
+                    .replay_transactions(storage, env, transactions)
+                    .await
+            }
+            Self::Mock(executor) => {
+                executor
+                    .replay_transactions(storage, env, transactions)
+                    .await
+            }
+        }
+    }
 }
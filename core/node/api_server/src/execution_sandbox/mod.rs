@@ -19,8 +19,11 @@ use zksync_types::{
 use self::vm_metrics::SandboxStage;
 pub(super) use self::{
     error::SandboxExecutionError,
-    execute::{TransactionExecutor, TxExecutionArgs},
-    tracers::ApiTracer,
+    execute::{
+        ReplayedTransaction, SimulatedL2Block, SimulatedL2BlockOutput, TransactionExecutor,
+        TxExecutionArgs,
+    },
+    tracers::{ApiTracer, PrestateResult},
     validate::ValidationError,
     vm_metrics::{SubmitTxStage, SANDBOX_METRICS},
 };
@@ -441,4 +444,18 @@ trait OneshotExecutor<S: ReadStorage> {
         Result<(), BytecodeCompressionError>,
         VmExecutionResultAndLogs,
     )>;
+
+    async fn simulate_transactions(
+        &self,
+        storage: S,
+        env: OneshotEnv,
+        blocks: Vec<SimulatedL2Block>,
+    ) -> anyhow::Result<Vec<SimulatedL2BlockOutput>>;
+
+    async fn replay_transactions(
+        &self,
+        storage: S,
+        env: OneshotEnv,
+        transactions: Vec<ReplayedTransaction>,
+    ) -> anyhow::Result<Vec<VmExecutionResultAndLogs>>;
 }
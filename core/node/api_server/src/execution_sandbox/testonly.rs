@@ -7,9 +7,12 @@ use zksync_multivm::interface::{
     storage::ReadStorage, BytecodeCompressionError, OneshotEnv, TxExecutionMode,
     VmExecutionResultAndLogs,
 };
-use zksync_types::Transaction;
+use zksync_types::{Transaction, H256};
 
-use super::{execute::TransactionExecutor, OneshotExecutor, TxExecutionArgs};
+use super::{
+    execute::TransactionExecutor, OneshotExecutor, ReplayedTransaction, SimulatedL2Block,
+    SimulatedL2BlockOutput, TxExecutionArgs,
+};
 
 type TxResponseFn = dyn Fn(&Transaction, &OneshotEnv) -> VmExecutionResultAndLogs + Send + Sync;
 
@@ -76,6 +79,14 @@ impl MockOneshotExecutor {
         )
     }
 
+    #[cfg(test)]
+    pub(crate) fn set_call_responses_with_logs<F>(&mut self, responses: F)
+    where
+        F: Fn(&Transaction, &OneshotEnv) -> VmExecutionResultAndLogs + 'static + Send + Sync,
+    {
+        self.call_responses = Box::new(responses);
+    }
+
     #[cfg(test)]
     pub(crate) fn set_tx_responses_with_logs<F>(&mut self, responses: F)
     where
@@ -84,11 +95,15 @@ impl MockOneshotExecutor {
         self.tx_responses = Box::new(responses);
     }
 
-    fn mock_inspect(&self, env: OneshotEnv, args: TxExecutionArgs) -> VmExecutionResultAndLogs {
+    fn mock_inspect(
+        &self,
+        env: &OneshotEnv,
+        transaction: &Transaction,
+    ) -> VmExecutionResultAndLogs {
         match env.system.execution_mode {
-            TxExecutionMode::EthCall => (self.call_responses)(&args.transaction, &env),
+            TxExecutionMode::EthCall => (self.call_responses)(transaction, env),
             TxExecutionMode::VerifyExecute | TxExecutionMode::EstimateFee => {
-                (self.tx_responses)(&args.transaction, &env)
+                (self.tx_responses)(transaction, env)
             }
         }
     }
@@ -108,7 +123,7 @@ where
         args: TxExecutionArgs,
         (): Self::Tracers,
     ) -> anyhow::Result<VmExecutionResultAndLogs> {
-        Ok(self.mock_inspect(env, args))
+        Ok(self.mock_inspect(&env, &args.transaction))
     }
 
     async fn inspect_transaction_with_bytecode_compression(
@@ -121,7 +136,49 @@ where
         Result<(), BytecodeCompressionError>,
         VmExecutionResultAndLogs,
     )> {
-        Ok((Ok(()), self.mock_inspect(env, args)))
+        Ok((Ok(()), self.mock_inspect(&env, &args.transaction)))
+    }
+
+    /// Responses for each transaction are returned according to the configured call / transaction responses.
+    /// Unlike with the real executor, transactions don't observe changes made by the preceding transactions;
+    /// the passed environment has `first_l2_block` set to the simulated block.
+    async fn simulate_transactions(
+        &self,
+        _storage: S,
+        mut env: OneshotEnv,
+        blocks: Vec<SimulatedL2Block>,
+    ) -> anyhow::Result<Vec<SimulatedL2BlockOutput>> {
+        let mut outputs = Vec::with_capacity(blocks.len());
+        for (i, block) in blocks.into_iter().enumerate() {
+            if i > 0 {
+                let prev_block = env.l1_batch.first_l2_block;
+                env.l1_batch.first_l2_block = block.next_l2_block_env(&prev_block, H256::zero());
+            }
+            let results = block
+                .transactions
+                .iter()
+                .map(|transaction| self.mock_inspect(&env, transaction))
+                .collect();
+            outputs.push(SimulatedL2BlockOutput {
+                env: env.l1_batch.first_l2_block,
+                results,
+            });
+        }
+        Ok(outputs)
+    }
+
+    /// Responses for each transaction are returned according to the configured transaction responses.
+    /// Tracers are not run.
+    async fn replay_transactions(
+        &self,
+        _storage: S,
+        env: OneshotEnv,
+        transactions: Vec<ReplayedTransaction>,
+    ) -> anyhow::Result<Vec<VmExecutionResultAndLogs>> {
+        Ok(transactions
+            .iter()
+            .map(|replayed| self.mock_inspect(&env, &replayed.transaction))
+            .collect())
     }
 }
 
@@ -1,18 +1,29 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use once_cell::sync::OnceCell;
 use zksync_multivm::{
     interface::{storage::WriteStorage, Call},
-    tracers::{CallTracer, ValidationTracer, ValidationTracerParams, ViolatedValidationRule},
+    tracers::{
+        Account, CallTracer, PrestateTracer, ValidationTracer, ValidationTracerParams,
+        ViolatedValidationRule,
+    },
     vm_latest::HistoryDisabled,
     MultiVMTracer, MultiVmTracerPointer,
 };
-use zksync_types::ProtocolVersionId;
+use zksync_types::{Address, ProtocolVersionId};
+
+/// Pre- and post-execution states of accounts touched by a transaction, as reported by [`PrestateTracer`].
+pub(crate) type PrestateResult = (HashMap<Address, Account>, HashMap<Address, Account>);
 
 /// Custom tracers supported by the API sandbox.
 #[derive(Debug)]
 pub(crate) enum ApiTracer {
     CallTracer(Arc<OnceCell<Vec<Call>>>),
+    /// Prestate tracer. In the non-diff mode, collects all accounts read by the transaction.
+    Prestate {
+        diff_mode: bool,
+        result: Arc<OnceCell<PrestateResult>>,
+    },
     Validation {
         params: ValidationTracerParams,
         result: Arc<OnceCell<ViolatedValidationRule>>,
@@ -40,6 +51,9 @@ impl ApiTracer {
     {
         match self {
             Self::CallTracer(traces) => CallTracer::new(traces).into_tracer_pointer(),
+            Self::Prestate { diff_mode, result } => {
+                PrestateTracer::new(diff_mode, result).into_tracer_pointer()
+            }
             Self::Validation { params, result } => {
                 let (mut tracer, _) =
                     ValidationTracer::<HistoryDisabled>::new(params, protocol_version.into());
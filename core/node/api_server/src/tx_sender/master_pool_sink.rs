@@ -1,10 +1,13 @@
 use std::collections::hash_map::{Entry, HashMap};
 
 use tokio::sync::Mutex;
-use zksync_dal::{transactions_dal::L2TxSubmissionResult, ConnectionPool, Core, CoreDal};
+use zksync_dal::{
+    transactions_dal::{L2TxSubmissionResult, TransactionsDal},
+    ConnectionPool, Core, CoreDal,
+};
 use zksync_multivm::interface::TransactionExecutionMetrics;
 use zksync_shared_metrics::{TxStage, APP_METRICS};
-use zksync_types::{l2::L2Tx, Address, Nonce, H256};
+use zksync_types::{l2::L2Tx, Address, Nonce, H256, U256};
 
 use super::{tx_sink::TxSink, SubmitTxError};
 use crate::web3::metrics::API_METRICS;
@@ -14,6 +17,7 @@ use crate::web3::metrics::API_METRICS;
 pub struct MasterPoolSink {
     master_pool: ConnectionPool<Core>,
     inflight_requests: Mutex<HashMap<(Address, Nonce), H256>>,
+    replacement_fee_bump_percent: u64,
 }
 
 impl MasterPoolSink {
@@ -21,16 +25,74 @@ impl MasterPoolSink {
         Self {
             master_pool,
             inflight_requests: Mutex::new(HashMap::new()),
+            replacement_fee_bump_percent: 0,
         }
     }
-}
 
-#[async_trait::async_trait]
-impl TxSink for MasterPoolSink {
-    async fn submit_tx(
+    /// Sets the minimum bump of `max_fee_per_gas` (in percent) required for a transaction to replace a pending
+    /// transaction with the same initiator and nonce. By default, no bump is required.
+    pub fn with_replacement_fee_bump_percent(mut self, percent: u64) -> Self {
+        self.replacement_fee_bump_percent = percent;
+        self
+    }
+
+    /// Checks that the transaction pays enough to replace a pending transaction with the same initiator and nonce
+    /// (if any). Concurrent submissions for the same initiator and nonce via this sink are prevented
+    /// by `inflight_requests`, so the pending transaction cannot be replaced by this sink between the check
+    /// and the insertion.
+    async fn check_replacement_fee(
+        &self,
+        dal: &mut TransactionsDal<'_, '_>,
+        tx: &L2Tx,
+    ) -> Result<(), SubmitTxError> {
+        if self.replacement_fee_bump_percent == 0 {
+            return Ok(());
+        }
+        let pending_max_fee = dal
+            .get_pending_l2_tx_max_fee_per_gas(tx.initiator_account(), tx.nonce())
+            .await
+            .map_err(|err| err.generalize())?;
+        let Some(pending_max_fee) = pending_max_fee else {
+            return Ok(());
+        };
+        let min_max_fee = pending_max_fee
+            .saturating_mul(U256::from(100 + self.replacement_fee_bump_percent))
+            / 100;
+        if tx.common_data.fee.max_fee_per_gas < min_max_fee {
+            return Err(SubmitTxError::ReplacementUnderpriced(min_max_fee));
+        }
+        Ok(())
+    }
+
+    async fn insert_tx_to_storage(
         &self,
         tx: &L2Tx,
         execution_metrics: TransactionExecutionMetrics,
+        is_private: bool,
+    ) -> Result<L2TxSubmissionResult, SubmitTxError> {
+        let mut connection = self
+            .master_pool
+            .connection_tagged("api")
+            .await
+            .map_err(|err| err.generalize())?;
+        let mut dal = connection.transactions_dal();
+        self.check_replacement_fee(&mut dal, tx).await?;
+        let insertion_result = if is_private {
+            dal.insert_private_transaction_l2(tx, execution_metrics)
+                .await
+        } else {
+            dal.insert_transaction_l2(tx, execution_metrics).await
+        };
+        let submission_res_handle = insertion_result.map_err(|err| err.generalize())?;
+        APP_METRICS.processed_txs[&TxStage::Mempool(submission_res_handle)].inc();
+        Ok(submission_res_handle)
+    }
+
+    async fn insert_tx(
+        &self,
+        tx: &L2Tx,
+        execution_metrics: TransactionExecutionMetrics,
+        is_private: bool,
     ) -> Result<L2TxSubmissionResult, SubmitTxError> {
         let address_and_nonce = (tx.initiator_account(), tx.nonce());
 
@@ -52,17 +114,9 @@ impl TxSink for MasterPoolSink {
         };
         drop(lock);
 
-        let result = match self.master_pool.connection_tagged("api").await {
-            Ok(mut connection) => connection
-                .transactions_dal()
-                .insert_transaction_l2(tx, execution_metrics)
-                .await
-                .inspect(|submission_res_handle| {
-                    APP_METRICS.processed_txs[&TxStage::Mempool(*submission_res_handle)].inc();
-                })
-                .map_err(|err| err.generalize().into()),
-            Err(err) => Err(err.generalize().into()),
-        };
+        let result = self
+            .insert_tx_to_storage(tx, execution_metrics, is_private)
+            .await;
 
         self.inflight_requests
             .lock()
@@ -73,3 +127,22 @@ impl TxSink for MasterPoolSink {
         result
     }
 }
+
+#[async_trait::async_trait]
+impl TxSink for MasterPoolSink {
+    async fn submit_tx(
+        &self,
+        tx: &L2Tx,
+        execution_metrics: TransactionExecutionMetrics,
+    ) -> Result<L2TxSubmissionResult, SubmitTxError> {
+        self.insert_tx(tx, execution_metrics, false).await
+    }
+
+    async fn submit_private_tx(
+        &self,
+        tx: &L2Tx,
+        execution_metrics: TransactionExecutionMetrics,
+    ) -> Result<L2TxSubmissionResult, SubmitTxError> {
+        self.insert_tx(tx, execution_metrics, true).await
+    }
+}
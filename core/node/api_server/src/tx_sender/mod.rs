@@ -1,8 +1,13 @@
 //! Helper module to submit transactions into the ZKsync Network.
 
-use std::{sync::Arc, time::Instant};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    time::Instant,
+};
 
 use anyhow::Context as _;
+use once_cell::sync::OnceCell;
 use tokio::sync::RwLock;
 use zksync_config::configs::{api::Web3JsonRpcConfig, chain::StateKeeperConfig};
 use zksync_contracts::BaseSystemContracts;
@@ -10,7 +15,9 @@ use zksync_dal::{
     transactions_dal::L2TxSubmissionResult, Connection, ConnectionPool, Core, CoreDal,
 };
 use zksync_multivm::{
-    interface::{TransactionExecutionMetrics, TxExecutionMode, VmExecutionResultAndLogs},
+    interface::{
+        ExecutionResult, TransactionExecutionMetrics, TxExecutionMode, VmExecutionResultAndLogs,
+    },
     utils::{
         adjust_pubdata_price_for_tx, derive_base_fee_and_gas_per_pubdata, derive_overhead,
         get_max_batch_gas_limit,
@@ -24,7 +31,7 @@ use zksync_state_keeper::{
     SequencerSealer,
 };
 use zksync_types::{
-    api::state_override::StateOverride,
+    api::{state_override::StateOverride, AccessListResult},
     fee::Fee,
     fee_model::BatchFeeInput,
     get_code_key, get_intrinsic_constants,
@@ -32,6 +39,7 @@ use zksync_types::{
     transaction_request::CallOverrides,
     utils::storage_key_for_eth_balance,
     vm::VmVersion,
+    web3::AccessListItem,
     AccountTreeId, Address, ExecuteTransactionCommon, L2ChainId, Nonce, PackedEthSignature,
     ProtocolVersionId, Transaction, H160, H256, MAX_L2_TX_GAS_LIMIT, MAX_NEW_FACTORY_DEPS, U256,
 };
@@ -41,8 +49,9 @@ pub(super) use self::result::SubmitTxError;
 use self::{master_pool_sink::MasterPoolSink, tx_sink::TxSink};
 use crate::{
     execution_sandbox::{
-        BlockArgs, SubmitTxStage, TransactionExecutor, TxExecutionArgs, TxSetupArgs,
-        VmConcurrencyBarrier, VmConcurrencyLimiter, VmPermit, SANDBOX_METRICS,
+        ApiTracer, BlockArgs, SimulatedL2Block, SimulatedL2BlockOutput, SubmitTxStage,
+        TransactionExecutor, TxExecutionArgs, TxSetupArgs, VmConcurrencyBarrier,
+        VmConcurrencyLimiter, VmPermit, SANDBOX_METRICS,
     },
     tx_sender::result::ApiCallResult,
 };
@@ -63,7 +72,7 @@ pub async fn build_tx_sender(
     batch_fee_model_input_provider: Arc<dyn BatchFeeModelInputProvider>,
     storage_caches: PostgresStorageCaches,
 ) -> anyhow::Result<(TxSender, VmConcurrencyBarrier)> {
-    let sequencer_sealer = SequencerSealer::new(state_keeper_config.clone());
+    let sequencer_sealer = SequencerSealer::new(state_keeper_config.clone())?;
     let master_pool_sink = MasterPoolSink::new(master_pool);
     let tx_sender_builder = TxSenderBuilder::new(
         tx_sender_config.clone(),
@@ -110,6 +119,22 @@ pub struct MultiVMBaseSystemContracts {
 }
 
 impl MultiVMBaseSystemContracts {
+    /// Uses the same contracts for all protocol versions. This is useful if the contracts are known to match
+    /// the protocol version used for execution, e.g. if they are loaded for a specific L2 block.
+    pub(crate) fn uniform(contracts: BaseSystemContracts) -> Self {
+        Self {
+            pre_virtual_blocks: contracts.clone(),
+            post_virtual_blocks: contracts.clone(),
+            post_virtual_blocks_finish_upgrade_fix: contracts.clone(),
+            post_boojum: contracts.clone(),
+            post_allowlist_removal: contracts.clone(),
+            post_1_4_1: contracts.clone(),
+            post_1_4_2: contracts.clone(),
+            vm_1_5_0_small_memory: contracts.clone(),
+            vm_1_5_0_increased_memory: contracts,
+        }
+    }
+
     pub fn get_by_protocol_version(self, version: ProtocolVersionId) -> BaseSystemContracts {
         match version {
             ProtocolVersionId::Version0
@@ -358,10 +383,27 @@ impl TxSender {
             .context("failed acquiring connection to replica DB")
     }
 
-    #[tracing::instrument(level = "debug", skip_all, fields(tx.hash = ?tx.hash()))]
     pub async fn submit_tx(
         &self,
         tx: L2Tx,
+    ) -> Result<(L2TxSubmissionResult, VmExecutionResultAndLogs), SubmitTxError> {
+        self.submit_tx_impl(tx, false).await
+    }
+
+    /// Submits a private transaction. Private transactions are validated in the same way as ordinary ones,
+    /// but are not exposed via the API until they are included into an L2 block.
+    pub async fn submit_private_tx(
+        &self,
+        tx: L2Tx,
+    ) -> Result<(L2TxSubmissionResult, VmExecutionResultAndLogs), SubmitTxError> {
+        self.submit_tx_impl(tx, true).await
+    }
+
+    #[tracing::instrument(level = "debug", skip_all, fields(tx.hash = ?tx.hash(), is_private = is_private))]
+    async fn submit_tx_impl(
+        &self,
+        tx: L2Tx,
+        is_private: bool,
     ) -> Result<(L2TxSubmissionResult, VmExecutionResultAndLogs), SubmitTxError> {
         let tx_hash = tx.hash();
         let stage_latency = SANDBOX_METRICS.start_tx_submit_stage(tx_hash, SubmitTxStage::Validate);
@@ -425,11 +467,14 @@ impl TxSender {
         let mut stage_latency =
             SANDBOX_METRICS.start_tx_submit_stage(tx_hash, SubmitTxStage::DbInsert);
         self.ensure_tx_executable(&tx.clone().into(), &execution_output.metrics, true)?;
-        let submission_res_handle = self
-            .0
-            .tx_sink
-            .submit_tx(&tx, execution_output.metrics)
-            .await?;
+        let tx_sink = &self.0.tx_sink;
+        let submission_res_handle = if is_private {
+            tx_sink
+                .submit_private_tx(&tx, execution_output.metrics)
+                .await?
+        } else {
+            tx_sink.submit_tx(&tx, execution_output.metrics).await?
+        };
 
         match submission_res_handle {
             L2TxSubmissionResult::AlreadyExecuted => {
@@ -468,6 +513,21 @@ impl TxSender {
         &self,
         tx: &L2Tx,
         call_overrides: Option<&CallOverrides>,
+    ) -> anyhow::Result<TxSetupArgs> {
+        if let Some(overrides) = call_overrides {
+            self.setup_args(TxExecutionMode::EthCall, overrides.enforced_base_fee)
+                .await
+        } else {
+            let enforced_base_fee = Some(tx.common_data.fee.max_fee_per_gas.as_u64());
+            self.setup_args(TxExecutionMode::VerifyExecute, enforced_base_fee)
+                .await
+        }
+    }
+
+    async fn setup_args(
+        &self,
+        execution_mode: TxExecutionMode,
+        enforced_base_fee: Option<u64>,
     ) -> anyhow::Result<TxSetupArgs> {
         let fee_input = self
             .0
@@ -476,11 +536,7 @@ impl TxSender {
             .await
             .context("cannot get batch fee input")?;
         Ok(TxSetupArgs {
-            execution_mode: if call_overrides.is_some() {
-                TxExecutionMode::EthCall
-            } else {
-                TxExecutionMode::VerifyExecute
-            },
+            execution_mode,
             operator_account: AccountTreeId::new(self.0.sender_config.fee_account_addr),
             fee_input,
             base_system_contracts: self.0.api_contracts.eth_call.clone(),
@@ -491,11 +547,7 @@ impl TxSender {
                 .validation_computational_gas_limit,
             chain_id: self.0.sender_config.chain_id,
             whitelisted_tokens_for_aa: self.read_whitelisted_tokens_for_aa_cache().await,
-            enforced_base_fee: if let Some(overrides) = call_overrides {
-                overrides.enforced_base_fee
-            } else {
-                Some(tx.common_data.fee.max_fee_per_gas.as_u64())
-            },
+            enforced_base_fee,
         })
     }
 
@@ -1030,6 +1082,102 @@ impl TxSender {
         result.vm.into_api_call_result()
     }
 
+    /// Executes a call and collects all accounts and storage slots accessed by it. Unlike with `eth_call`,
+    /// a reverted call is not an error; the revert reason is returned together with the access list instead.
+    pub(super) async fn create_access_list(
+        &self,
+        block_args: BlockArgs,
+        call_overrides: CallOverrides,
+        tx: L2Tx,
+        state_override: Option<StateOverride>,
+    ) -> Result<AccessListResult, SubmitTxError> {
+        let vm_permit = self.0.vm_concurrency_limiter.acquire().await;
+        let vm_permit = vm_permit.ok_or(SubmitTxError::ServerShuttingDown)?;
+
+        let prestate = Arc::new(OnceCell::default());
+        let connection = self.acquire_replica_connection().await?;
+        let result = self
+            .0
+            .executor
+            .execute_tx_in_sandbox(
+                vm_permit,
+                self.call_args(&tx, Some(&call_overrides)).await?,
+                TxExecutionArgs::for_eth_call(tx),
+                connection,
+                block_args,
+                state_override,
+                vec![ApiTracer::Prestate {
+                    diff_mode: false,
+                    result: prestate.clone(),
+                }],
+            )
+            .await?;
+
+        // The prestate tracer reports all accounts read during execution, including ones without
+        // accessed storage slots. Slots themselves (both read and written) are taken from storage logs.
+        let mut accessed_slots = BTreeMap::<Address, BTreeSet<H256>>::new();
+        if let Some((_, post)) = prestate.get() {
+            for address in post.keys() {
+                accessed_slots.entry(*address).or_default();
+            }
+        }
+        for log in &result.vm.logs.storage_logs {
+            let key = &log.log.key;
+            accessed_slots
+                .entry(*key.address())
+                .or_default()
+                .insert(*key.key());
+        }
+        let access_list = accessed_slots
+            .into_iter()
+            .map(|(address, slots)| AccessListItem {
+                address,
+                storage_keys: slots.into_iter().collect(),
+            })
+            .collect();
+
+        let error = match &result.vm.result {
+            ExecutionResult::Success { .. } => None,
+            ExecutionResult::Revert { output } => Some(output.to_user_friendly_string()),
+            ExecutionResult::Halt { reason } => Some(reason.to_string()),
+        };
+        Ok(AccessListResult {
+            access_list,
+            gas_used: result.vm.statistics.gas_used.into(),
+            error,
+        })
+    }
+
+    /// Simulates calls in one or more consecutive L2 blocks. Each call observes changes made by the preceding calls.
+    pub(super) async fn simulate(
+        &self,
+        block_args: BlockArgs,
+        call_overrides: CallOverrides,
+        state_override: Option<StateOverride>,
+        blocks: Vec<SimulatedL2Block>,
+    ) -> Result<Vec<SimulatedL2BlockOutput>, SubmitTxError> {
+        let vm_permit = self.0.vm_concurrency_limiter.acquire().await;
+        let vm_permit = vm_permit.ok_or(SubmitTxError::ServerShuttingDown)?;
+
+        let setup_args = self
+            .setup_args(TxExecutionMode::EthCall, call_overrides.enforced_base_fee)
+            .await?;
+        let connection = self.acquire_replica_connection().await?;
+        let outputs = self
+            .0
+            .executor
+            .simulate_in_sandbox(
+                vm_permit,
+                setup_args,
+                connection,
+                block_args,
+                state_override,
+                blocks,
+            )
+            .await?;
+        Ok(outputs)
+    }
+
     pub async fn gas_price(&self) -> anyhow::Result<u64> {
         let mut connection = self.acquire_replica_connection().await?;
         let protocol_version = connection
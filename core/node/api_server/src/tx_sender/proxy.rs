@@ -1,5 +1,5 @@
 use std::{
-    collections::{BTreeSet, HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     sync::Arc,
     time::Duration,
 };
@@ -17,7 +17,7 @@ use zksync_types::{api, l2::L2Tx, Address, Nonce, H256, U256};
 use zksync_web3_decl::{
     client::{DynClient, L2},
     error::{ClientRpcContext, EnrichedClientResult, Web3Error},
-    namespaces::EthNamespaceClient,
+    namespaces::{EthNamespaceClient, TxpoolNamespaceClient, ZksNamespaceClient},
 };
 
 use super::{tx_sink::TxSink, SubmitTxError};
@@ -233,6 +233,18 @@ impl TxProxy {
             .await
     }
 
+    async fn submit_private_tx_impl(&self, tx: &L2Tx) -> EnrichedClientResult<H256> {
+        let input_data = tx.common_data.input_data().expect("raw tx is absent");
+        let raw_tx = zksync_types::web3::Bytes(input_data.to_vec());
+        let tx_hash = tx.hash();
+        tracing::info!("Proxying private tx {tx_hash:?}");
+        self.client
+            .send_private_raw_transaction(raw_tx)
+            .rpc_context("send_private_raw_transaction")
+            .with_arg("tx_hash", &tx_hash)
+            .await
+    }
+
     async fn find_tx(
         &self,
         storage: &mut Connection<'_, Core>,
@@ -323,6 +335,17 @@ impl TxSink for TxProxy {
         Ok(L2TxSubmissionResult::Proxied)
     }
 
+    async fn submit_private_tx(
+        &self,
+        tx: &L2Tx,
+        _execution_metrics: TransactionExecutionMetrics,
+    ) -> Result<L2TxSubmissionResult, SubmitTxError> {
+        // Unlike with public transactions, we don't save the transaction to cache, so that it's not exposed via the API.
+        self.submit_private_tx_impl(tx).await?;
+        APP_METRICS.processed_txs[&TxStage::Proxied].inc();
+        Ok(L2TxSubmissionResult::Proxied)
+    }
+
     async fn lookup_pending_nonce(
         &self,
         account_address: Address,
@@ -379,12 +402,49 @@ impl TxSink for TxProxy {
         }
         Ok(None)
     }
+
+    async fn lookup_txpool_content(
+        &self,
+        initiator_address: Option<Address>,
+    ) -> Result<Option<api::TxpoolContent<api::Transaction>>, Web3Error> {
+        // EN doesn't have a mempool; transactions are stored on the main node.
+        let content = if let Some(address) = initiator_address {
+            let content = self
+                .client
+                .content_from(address)
+                .rpc_context("txpool_contentFrom")
+                .with_arg("address", &address)
+                .await?;
+            let mut pending = BTreeMap::new();
+            if !content.pending.is_empty() {
+                pending.insert(address, content.pending);
+            }
+            let mut queued = BTreeMap::new();
+            if !content.queued.is_empty() {
+                queued.insert(address, content.queued);
+            }
+            api::TxpoolContent {
+                pending,
+                queued,
+                truncated: content.truncated,
+            }
+        } else {
+            self.client.content().rpc_context("txpool_content").await?
+        };
+        Ok(Some(content))
+    }
+
+    async fn lookup_txpool_status(&self) -> Result<Option<api::TxpoolStatus>, Web3Error> {
+        let status = self.client.status().rpc_context("txpool_status").await?;
+        Ok(Some(status))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::sync::atomic::{AtomicBool, Ordering};
 
+    use assert_matches::assert_matches;
     use test_casing::test_casing;
     use zksync_node_genesis::{insert_genesis_batch, mock_genesis_config, GenesisParams};
     use zksync_node_test_utils::{create_l2_block, create_l2_transaction};
@@ -445,6 +505,72 @@ mod tests {
         assert_eq!(tx_details.initiator_address, tx.initiator_account());
     }
 
+    #[tokio::test]
+    async fn private_txs_are_not_cached() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        let params = GenesisParams::load_genesis_params(mock_genesis_config()).unwrap();
+        insert_genesis_batch(&mut storage, &params).await.unwrap();
+
+        let tx = create_l2_transaction(10, 100);
+        let send_tx_called = Arc::new(AtomicBool::new(false));
+        let main_node_client = MockClient::builder(L2::default())
+            .method("zks_sendPrivateRawTransaction", {
+                let send_tx_called = send_tx_called.clone();
+                let tx = tx.clone();
+                move |bytes: Bytes| {
+                    assert_eq!(bytes.0, tx.common_data.input_data().unwrap());
+                    send_tx_called.store(true, Ordering::Relaxed);
+                    Ok(tx.hash())
+                }
+            })
+            .build();
+
+        let proxy = TxProxy::new(Box::new(main_node_client));
+        let submission_result = proxy
+            .submit_private_tx(&tx, TransactionExecutionMetrics::default())
+            .await
+            .unwrap();
+        assert_matches!(submission_result, L2TxSubmissionResult::Proxied);
+        assert!(send_tx_called.load(Ordering::Relaxed));
+
+        assert!(proxy.tx_cache.get(tx.hash()).await.is_none());
+        let found_tx = proxy
+            .lookup_tx(&mut storage, api::TransactionId::Hash(tx.hash()))
+            .await
+            .unwrap();
+        assert!(found_tx.is_none(), "{found_tx:?}");
+    }
+
+    #[tokio::test]
+    async fn proxying_txpool_content() {
+        let tx = create_l2_transaction(10, 100);
+        let initiator = tx.initiator_account();
+        let main_node_client = MockClient::builder(L2::default())
+            .method("txpool_contentFrom", {
+                let tx = tx.clone();
+                move |address: Address| {
+                    assert_eq!(address, initiator);
+                    Ok(api::TxpoolContentFrom {
+                        pending: BTreeMap::from([(0, api::Transaction::from(tx.clone()))]),
+                        queued: BTreeMap::new(),
+                        truncated: false,
+                    })
+                }
+            })
+            .build();
+        let proxy = TxProxy::new(Box::new(main_node_client));
+
+        let content = proxy
+            .lookup_txpool_content(Some(initiator))
+            .await
+            .unwrap()
+            .expect("no content");
+        assert!(content.queued.is_empty());
+        assert_eq!(content.pending.len(), 1);
+        assert_eq!(content.pending[&initiator][&0].hash, tx.hash());
+    }
+
     #[tokio::test]
     async fn low_level_transaction_cache_operations() {
         let tx_cache = TxCache::default();
@@ -155,3 +155,39 @@ async fn submitting_tx_requires_one_connection() {
         .unwrap()
         .expect("transaction is not persisted");
 }
+
+#[tokio::test]
+async fn replacing_tx_requires_fee_bump() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+    drop(storage);
+
+    let sink = MasterPoolSink::new(pool.clone()).with_replacement_fee_bump_percent(10);
+    let tx = create_l2_transaction(100, 50);
+    let submission_result = sink
+        .submit_tx(&tx, TransactionExecutionMetrics::default())
+        .await
+        .unwrap();
+    assert_matches!(submission_result, L2TxSubmissionResult::Added);
+
+    let mut underpriced_tx = tx.clone();
+    underpriced_tx.common_data.fee.max_fee_per_gas = 105.into();
+    underpriced_tx.set_input(H256::random().0.to_vec(), H256::random());
+    let err = sink
+        .submit_tx(&underpriced_tx, TransactionExecutionMetrics::default())
+        .await
+        .unwrap_err();
+    assert_matches!(err, SubmitTxError::ReplacementUnderpriced(fee) if fee == 110.into());
+
+    let mut replacing_tx = tx.clone();
+    replacing_tx.common_data.fee.max_fee_per_gas = 110.into();
+    replacing_tx.set_input(H256::random().0.to_vec(), H256::random());
+    let submission_result = sink
+        .submit_tx(&replacing_tx, TransactionExecutionMetrics::default())
+        .await
+        .unwrap();
+    assert_matches!(submission_result, L2TxSubmissionResult::Replaced);
+}
@@ -1,7 +1,7 @@
 use zksync_dal::{transactions_dal::L2TxSubmissionResult, Connection, Core};
 use zksync_multivm::interface::TransactionExecutionMetrics;
 use zksync_types::{
-    api::{Transaction, TransactionDetails, TransactionId},
+    api::{Transaction, TransactionDetails, TransactionId, TxpoolContent, TxpoolStatus},
     l2::L2Tx,
     Address, Nonce, H256,
 };
@@ -30,6 +30,15 @@ pub trait TxSink: std::fmt::Debug + Send + Sync + 'static {
         execution_metrics: TransactionExecutionMetrics,
     ) -> Result<L2TxSubmissionResult, SubmitTxError>;
 
+    /// Ensures that a private transaction is propagated to the mempool. Unlike transactions submitted
+    /// via [`Self::submit_tx()`], private transactions must not be exposed via the API (e.g., in pending transaction
+    /// subscriptions or `eth_getTransactionByHash`) until they are included into an L2 block.
+    async fn submit_private_tx(
+        &self,
+        tx: &L2Tx,
+        execution_metrics: TransactionExecutionMetrics,
+    ) -> Result<L2TxSubmissionResult, SubmitTxError>;
+
     /// Attempts to look up the pending nonce for the account in the sink-specific storage.
     /// By default, returns `Ok(None)`.
     async fn lookup_pending_nonce(
@@ -59,4 +68,20 @@ pub trait TxSink: std::fmt::Debug + Send + Sync + 'static {
     ) -> Result<Option<TransactionDetails>, Web3Error> {
         Ok(None)
     }
+
+    /// Attempts to look up the mempool contents in the sink-specific storage. If `initiator_address` is specified,
+    /// only transactions from this account should be returned.
+    /// By default, returns `Ok(None)`, meaning that the contents are loaded from Postgres.
+    async fn lookup_txpool_content(
+        &self,
+        _initiator_address: Option<Address>,
+    ) -> Result<Option<TxpoolContent<Transaction>>, Web3Error> {
+        Ok(None)
+    }
+
+    /// Attempts to look up the mempool status in the sink-specific storage.
+    /// By default, returns `Ok(None)`, meaning that the status is computed based on Postgres data.
+    async fn lookup_txpool_status(&self) -> Result<Option<TxpoolStatus>, Web3Error> {
+        Ok(None)
+    }
 }
@@ -35,6 +35,7 @@ use crate::web3::metrics::{ObservedRpcParams, API_METRICS};
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
 #[metrics(label = "transport", rename_all = "snake_case")]
 pub(crate) enum Transport {
+    Http,
     Ws,
 }
 
@@ -7,17 +7,22 @@ use zksync_web3_decl::{
     jsonrpsee::types::{error::ErrorCode, ErrorObjectOwned},
 };
 
+pub use self::rate_limit::ComputeUnitLimiter;
+#[cfg(test)]
+pub(crate) use self::rate_limit::RATE_LIMIT_ERROR_CODE;
 pub(crate) use self::{
     metadata::{MethodMetadata, MethodTracer},
     middleware::{
         CorrelationMiddleware, LimitMiddleware, MetadataLayer, ShutdownMiddleware, TrafficTracker,
     },
+    rate_limit::{ComputeUnitMiddleware, RateLimitKeyLayer, RemoteAddr},
 };
 use crate::tx_sender::SubmitTxError;
 
 mod metadata;
 mod middleware;
 pub mod namespaces;
+mod rate_limit;
 #[cfg(test)]
 pub(crate) mod testonly;
 
@@ -39,7 +44,9 @@ impl MethodTracer {
             | Web3Error::TooManyTopics
             | Web3Error::FilterNotFound
             | Web3Error::InvalidFilterBlockHash
-            | Web3Error::LogsLimitExceeded(_, _, _) => ErrorCode::InvalidParams.code(),
+            | Web3Error::InvalidSimulation(_)
+            | Web3Error::LogsLimitExceeded(_, _, _)
+            | Web3Error::BlockRangeLimitExceeded(_) => ErrorCode::InvalidParams.code(),
             Web3Error::SubmitTransactionError(_, _)
             | Web3Error::SerializationError(_)
             | Web3Error::ProxyError(_) => 3,
@@ -1,7 +1,8 @@
 use zksync_types::{
     api::{
-        state_override::StateOverride, Block, BlockId, BlockIdVariant, BlockNumber, FeeHistory,
-        Log, Transaction, TransactionId, TransactionReceipt, TransactionVariant,
+        state_override::StateOverride, AccessListResult, Block, BlockId, BlockIdVariant,
+        BlockNumber, FeeHistory, Log, SimulatePayload, SimulatedBlock, Transaction, TransactionId,
+        TransactionReceipt, TransactionVariant,
     },
     transaction_request::CallRequest,
     web3::{Bytes, Index, SyncState},
@@ -49,6 +50,27 @@ impl EthNamespaceServer for EthNamespace {
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn create_access_list(
+        &self,
+        req: CallRequest,
+        block: Option<BlockIdVariant>,
+        state_override: Option<StateOverride>,
+    ) -> RpcResult<AccessListResult> {
+        self.create_access_list_impl(req, block.map(Into::into), state_override)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn simulate_v1(
+        &self,
+        payload: SimulatePayload,
+        block: Option<BlockIdVariant>,
+    ) -> RpcResult<Vec<SimulatedBlock>> {
+        self.simulate_v1_impl(payload, block.map(Into::into))
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn gas_price(&self) -> RpcResult<U256> {
         self.gas_price_impl()
             .await
@@ -0,0 +1,34 @@
+use zksync_types::{
+    api::{
+        trace::{LocalizedTrace, TraceFilter},
+        BlockNumber,
+    },
+    H256,
+};
+use zksync_web3_decl::{
+    jsonrpsee::core::{async_trait, RpcResult},
+    namespaces::TraceNamespaceServer,
+};
+
+use crate::web3::namespaces::TraceNamespace;
+
+#[async_trait]
+impl TraceNamespaceServer for TraceNamespace {
+    async fn block(&self, block: BlockNumber) -> RpcResult<Vec<LocalizedTrace>> {
+        self.trace_block_impl(block)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn transaction(&self, tx_hash: H256) -> RpcResult<Option<Vec<LocalizedTrace>>> {
+        self.trace_transaction_impl(tx_hash)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn filter(&self, filter: TraceFilter) -> RpcResult<Vec<LocalizedTrace>> {
+        self.trace_filter_impl(filter)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+}
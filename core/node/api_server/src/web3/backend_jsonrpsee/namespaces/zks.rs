@@ -223,6 +223,12 @@ impl ZksNamespaceServer for ZksNamespace {
             })
             .map_err(|err| self.current_method().map_err(err))
     }
+
+    async fn send_private_raw_transaction(&self, tx_bytes: web3::Bytes) -> RpcResult<H256> {
+        self.send_private_raw_transaction_impl(tx_bytes)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
 }
 
 fn map_event(vm_event: &VmEvent) -> Log {
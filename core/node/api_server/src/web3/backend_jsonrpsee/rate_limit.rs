@@ -0,0 +1,629 @@
+//! Compute unit-based rate limiting shared by HTTP and WebSocket servers.
+//!
+//! Each RPC method has a cost in compute units (CUs); clients have a budget of CUs per minute. Clients are identified
+//! by an API key (if it's recognized), or by their IP address. Identification happens on the HTTP level
+//! (see [`RateLimitKeyLayer`]), and the resulting [`RateLimitKey`] is propagated to RPC calls via request extensions,
+//! where it's checked by [`ComputeUnitMiddleware`].
+//!
+//! The client IP address is the address of the connection peer (see [`RemoteAddr`]), unless the peer is one
+//! of the configured trusted proxies; in the latter case, the address is taken from `x-forwarded-for` / `x-real-ip` headers.
+
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fmt,
+    net::{IpAddr, SocketAddr},
+    num::NonZeroU32,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use governor::{
+    clock::{Clock, DefaultClock},
+    middleware::NoOpMiddleware,
+    state::{keyed::DefaultKeyedStateStore, InMemoryState, NotKeyed},
+    NegativeMultiDecision, Quota, RateLimiter,
+};
+use tokio::sync::watch;
+use vise::{Counter, EncodeLabelSet, Family, Metrics};
+use zksync_config::configs::api::ComputeUnitLimits;
+use zksync_web3_decl::jsonrpsee::{
+    server::middleware::rpc::{layer::ResponseFuture, RpcServiceT},
+    types::{ErrorObject, Request},
+    MethodResponse,
+};
+
+use super::middleware::Transport;
+
+/// JSON-RPC error code returned for rate-limited calls. This is the de-facto standard code used by Ethereum node providers.
+pub(crate) const RATE_LIMIT_ERROR_CODE: i32 = -32005;
+/// Header to read API keys from.
+const API_KEY_HEADER: &str = "x-api-key";
+/// Cost of methods not mentioned in [`DEFAULT_METHOD_COSTS`] or in config overrides.
+const DEFAULT_COST: NonZeroU32 = NonZeroU32::MIN;
+/// Default costs of RPC methods. Methods not mentioned here have [`DEFAULT_COST`].
+const DEFAULT_METHOD_COSTS: &[(&str, u32)] = &[
+    ("eth_call", 20),
+    ("eth_estimateGas", 50),
+    ("eth_createAccessList", 50),
+    ("eth_simulateV1", 200),
+    ("eth_getLogs", 75),
+    ("eth_getFilterLogs", 75),
+    ("eth_getBlockReceipts", 50),
+    ("eth_sendRawTransaction", 50),
+    ("zks_getProof", 50),
+    ("debug_traceCall", 200),
+    ("debug_traceTransaction", 100),
+    ("debug_traceBlockByNumber", 500),
+    ("debug_traceBlockByHash", 500),
+];
+/// Interval between removing stale per-IP rate limiting state.
+const HOUSEKEEPING_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EncodeLabelSet)]
+struct ComputeUnitLabels {
+    transport: Transport,
+    key: Cow<'static, str>,
+    method: &'static str,
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "api_jsonrpc_backend_compute_units")]
+struct ComputeUnitMetrics {
+    /// Number of compute units consumed by successfully admitted calls.
+    consumed: Family<ComputeUnitLabels, Counter>,
+    /// Number of calls rejected because of exceeding the compute unit budget.
+    rejected_calls: Family<ComputeUnitLabels, Counter>,
+}
+
+#[vise::register]
+static METRICS: vise::Global<ComputeUnitMetrics> = vise::Global::new();
+
+/// Address of the connection peer. Inserted into HTTP request extensions by the server when accepting a connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RemoteAddr(pub SocketAddr);
+
+/// Client identity used for rate limiting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum RateLimitKey {
+    /// One of the configured API keys.
+    ApiKey(Arc<str>),
+    /// Client IP address.
+    Ip(IpAddr),
+    /// Client couldn't be identified. All such clients share a single budget.
+    Unknown,
+}
+
+/// Reason for rejecting an RPC call.
+#[derive(Debug, PartialEq)]
+pub(crate) enum RateLimitError {
+    /// Budget is exhausted; the call may succeed after the specified delay.
+    Exhausted { retry_after: Duration },
+    /// Call cost exceeds the entire budget, so it can never succeed.
+    InsufficientCapacity { cost: NonZeroU32 },
+}
+
+impl RateLimitError {
+    fn into_error_object(self) -> ErrorObject<'static> {
+        match self {
+            Self::Exhausted { retry_after } => {
+                let retry_after_ms = u64::try_from(retry_after.as_millis()).unwrap_or(u64::MAX);
+                ErrorObject::owned(
+                    RATE_LIMIT_ERROR_CODE,
+                    "Rate limit exceeded",
+                    Some(serde_json::json!({ "retryAfterMs": retry_after_ms })),
+                )
+            }
+            Self::InsufficientCapacity { cost } => ErrorObject::owned(
+                RATE_LIMIT_ERROR_CODE,
+                format!("Rate limit exceeded: call cost ({cost} compute units) exceeds the per-minute budget"),
+                None::<()>,
+            ),
+        }
+    }
+}
+
+type DirectLimiter = RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>;
+type KeyedLimiter = RateLimiter<
+    Option<IpAddr>,
+    DefaultKeyedStateStore<Option<IpAddr>>,
+    DefaultClock,
+    NoOpMiddleware,
+>;
+
+struct ApiKeyBudget {
+    limiter: DirectLimiter,
+    /// Masked API key used in metric labels.
+    label: Cow<'static, str>,
+}
+
+struct LimiterInner {
+    method_costs: HashMap<String, NonZeroU32>,
+    api_keys: HashMap<Arc<str>, ApiKeyBudget>,
+    per_client: Option<KeyedLimiter>,
+    trusted_proxies: HashSet<IpAddr>,
+    /// Set once housekeeping is started, so that it's run once for all servers sharing the limiter.
+    housekeeping_started: AtomicBool,
+}
+
+/// Compute unit limiter. Cloning the limiter is cheap and produces a handle to the same budgets; thus, a single limiter
+/// can be shared among multiple servers (e.g., HTTP and WS ones).
+#[derive(Clone)]
+pub struct ComputeUnitLimiter {
+    inner: Arc<LimiterInner>,
+}
+
+impl fmt::Debug for ComputeUnitLimiter {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("ComputeUnitLimiter")
+            .field("method_costs", &self.inner.method_costs)
+            .field("api_keys", &self.inner.api_keys.len())
+            .field("has_per_client_limit", &self.inner.per_client.is_some())
+            .field("trusted_proxies", &self.inner.trusted_proxies)
+            .finish()
+    }
+}
+
+impl ComputeUnitLimiter {
+    /// Creates a limiter based on the provided config.
+    pub fn new(limits: &ComputeUnitLimits) -> Self {
+        let mut method_costs: HashMap<_, _> = DEFAULT_METHOD_COSTS
+            .iter()
+            .map(|&(name, cost)| (name.to_owned(), NonZeroU32::new(cost).unwrap()))
+            .collect();
+        method_costs.extend(
+            limits
+                .method_costs
+                .iter()
+                .map(|(name, cost)| (name.to_owned(), cost)),
+        );
+
+        let api_keys = limits
+            .per_api_key
+            .iter()
+            .map(|(key, limit)| {
+                let budget = ApiKeyBudget {
+                    limiter: RateLimiter::direct(Quota::per_minute(limit)),
+                    label: Cow::Owned(Self::mask_api_key(key)),
+                };
+                (Arc::from(key), budget)
+            })
+            .collect();
+
+        Self {
+            inner: Arc::new(LimiterInner {
+                method_costs,
+                api_keys,
+                per_client: limits
+                    .per_client
+                    .map(|limit| RateLimiter::keyed(Quota::per_minute(limit))),
+                trusted_proxies: limits
+                    .trusted_proxies
+                    .iter()
+                    .map(IpAddr::to_canonical)
+                    .collect(),
+                housekeeping_started: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    fn mask_api_key(key: &str) -> String {
+        let prefix: String = key.chars().take(4).collect();
+        format!("{prefix}***")
+    }
+
+    fn method_cost(&self, method_name: &str) -> NonZeroU32 {
+        self.inner
+            .method_costs
+            .get(method_name)
+            .copied()
+            .unwrap_or(DEFAULT_COST)
+    }
+
+    /// Identifies the client sending the specified HTTP request.
+    ///
+    /// API keys are taken from the `x-api-key` header or the first segment of the URL path; unrecognized keys are ignored.
+    /// The IP address is the connection peer address (see [`RemoteAddr`]). If the peer is a trusted proxy,
+    /// the address is taken from the `x-forwarded-for` or `x-real-ip` headers set by the proxy instead.
+    pub(crate) fn extract_key<B>(&self, request: &http::Request<B>) -> RateLimitKey {
+        let headers = request.headers();
+        let header_key = headers
+            .get(API_KEY_HEADER)
+            .and_then(|value| value.to_str().ok());
+        let path_key = request
+            .uri()
+            .path()
+            .trim_start_matches('/')
+            .split('/')
+            .next()
+            .filter(|segment| !segment.is_empty());
+        for candidate in [header_key, path_key].into_iter().flatten() {
+            if let Some((key, _)) = self.inner.api_keys.get_key_value(candidate) {
+                return RateLimitKey::ApiKey(key.clone());
+            }
+        }
+
+        let Some(RemoteAddr(remote_addr)) = request.extensions().get::<RemoteAddr>() else {
+            return RateLimitKey::Unknown;
+        };
+        let remote_ip = remote_addr.ip().to_canonical();
+        if !self.inner.trusted_proxies.contains(&remote_ip) {
+            return RateLimitKey::Ip(remote_ip);
+        }
+        RateLimitKey::Ip(self.forwarded_ip(headers).unwrap_or(remote_ip))
+    }
+
+    /// Extracts the client IP address from headers set by a trusted proxy. For `x-forwarded-for`, the client is
+    /// the rightmost address not belonging to a trusted proxy; addresses to the left of it can be spoofed by the client.
+    fn forwarded_ip(&self, headers: &http::HeaderMap) -> Option<IpAddr> {
+        let forwarded_for = headers
+            .get("x-forwarded-for")
+            .and_then(|value| value.to_str().ok());
+        if let Some(forwarded_for) = forwarded_for {
+            let mut forwarded_ips = forwarded_for
+                .split(',')
+                .map(|ip| ip.trim().parse::<IpAddr>().map(|ip| ip.to_canonical()));
+            let mut client_ip = None;
+            // Traverse proxies from the closest one; stop at the first malformed or untrusted address.
+            while let Some(Ok(ip)) = forwarded_ips.next_back() {
+                client_ip = Some(ip);
+                if !self.inner.trusted_proxies.contains(&ip) {
+                    break;
+                }
+            }
+            return client_ip;
+        }
+
+        headers
+            .get("x-real-ip")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|ip| ip.trim().parse::<IpAddr>().ok())
+            .map(|ip| ip.to_canonical())
+    }
+
+    /// Charges the client identified by `key` for a call of the specified method.
+    pub(crate) fn check(
+        &self,
+        key: &RateLimitKey,
+        method_name: &str,
+    ) -> Result<NonZeroU32, RateLimitError> {
+        let cost = self.method_cost(method_name);
+        let result = match key {
+            RateLimitKey::ApiKey(api_key) => match self.inner.api_keys.get(api_key) {
+                Some(budget) => budget.limiter.check_n(cost),
+                None => Ok(()),
+            },
+            RateLimitKey::Ip(ip) => self.check_client(Some(*ip), cost),
+            RateLimitKey::Unknown => self.check_client(None, cost),
+        };
+
+        result.map(|()| cost).map_err(|err| match err {
+            NegativeMultiDecision::BatchNonConforming(_, not_until) => RateLimitError::Exhausted {
+                retry_after: not_until.wait_time_from(DefaultClock::default().now()),
+            },
+            NegativeMultiDecision::InsufficientCapacity(_) => {
+                RateLimitError::InsufficientCapacity { cost }
+            }
+        })
+    }
+
+    fn check_client(
+        &self,
+        ip: Option<IpAddr>,
+        cost: NonZeroU32,
+    ) -> Result<(), NegativeMultiDecision<governor::NotUntil<<DefaultClock as Clock>::Instant>>>
+    {
+        match &self.inner.per_client {
+            Some(limiter) => limiter.check_key_n(&ip, cost),
+            None => Ok(()),
+        }
+    }
+
+    fn metric_key(&self, key: &RateLimitKey) -> Cow<'static, str> {
+        match key {
+            RateLimitKey::ApiKey(api_key) => self
+                .inner
+                .api_keys
+                .get(api_key)
+                .map_or(Cow::Borrowed("unknown"), |budget| budget.label.clone()),
+            RateLimitKey::Ip(_) => Cow::Borrowed("ip"),
+            RateLimitKey::Unknown => Cow::Borrowed("unknown"),
+        }
+    }
+
+    /// Periodically removes stale per-IP rate limiting state so that it doesn't grow indefinitely.
+    /// Housekeeping is performed only by the first caller; other calls (e.g., from other servers sharing the limiter)
+    /// return immediately.
+    pub(crate) async fn run_housekeeping(
+        self,
+        mut stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        let Some(per_client) = &self.inner.per_client else {
+            return Ok(());
+        };
+        if self
+            .inner
+            .housekeeping_started
+            .swap(true, Ordering::Relaxed)
+        {
+            tracing::debug!("Compute unit limiter housekeeping is already running");
+            return Ok(());
+        }
+        while !*stop_receiver.borrow() {
+            per_client.retain_recent();
+            per_client.shrink_to_fit();
+            if tokio::time::timeout(HOUSEKEEPING_INTERVAL, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+        tracing::info!("Stop signal received, compute unit limiter housekeeping is shutting down");
+        Ok(())
+    }
+}
+
+/// [`tower`] HTTP middleware layer identifying clients for rate limiting purposes. The client identity is placed
+/// into HTTP request extensions, which are then propagated to RPC calls by `jsonrpsee`. For WS connections, the layer
+/// is applied to the upgrade request, so all calls in the connection are attributed to the same client.
+#[derive(Debug, Clone)]
+pub(crate) struct RateLimitKeyLayer {
+    limiter: ComputeUnitLimiter,
+}
+
+impl RateLimitKeyLayer {
+    pub fn new(limiter: ComputeUnitLimiter) -> Self {
+        Self { limiter }
+    }
+}
+
+impl<S> tower::Layer<S> for RateLimitKeyLayer {
+    type Service = RateLimitKeyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitKeyService {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+/// HTTP service produced by [`RateLimitKeyLayer`].
+#[derive(Debug, Clone)]
+pub(crate) struct RateLimitKeyService<S> {
+    inner: S,
+    limiter: ComputeUnitLimiter,
+}
+
+impl<S, B> tower::Service<http::Request<B>> for RateLimitKeyService<S>
+where
+    S: tower::Service<http::Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<B>) -> Self::Future {
+        let key = self.limiter.extract_key(&request);
+        request.extensions_mut().insert(key);
+        self.inner.call(request)
+    }
+}
+
+/// RPC-level middleware charging compute units for each call (including each call in a batch).
+#[derive(Debug)]
+pub(crate) struct ComputeUnitMiddleware<S> {
+    inner: S,
+    limiter: ComputeUnitLimiter,
+    registered_method_names: Arc<HashSet<&'static str>>,
+    transport: Transport,
+}
+
+impl<S> ComputeUnitMiddleware<S> {
+    pub(crate) fn new(
+        inner: S,
+        limiter: ComputeUnitLimiter,
+        registered_method_names: Arc<HashSet<&'static str>>,
+        is_http: bool,
+    ) -> Self {
+        Self {
+            inner,
+            limiter,
+            registered_method_names,
+            transport: if is_http {
+                Transport::Http
+            } else {
+                Transport::Ws
+            },
+        }
+    }
+}
+
+impl<'a, S> RpcServiceT<'a> for ComputeUnitMiddleware<S>
+where
+    S: Send + Sync + RpcServiceT<'a>,
+{
+    type Future = ResponseFuture<S::Future>;
+
+    fn call(&self, request: Request<'a>) -> Self::Future {
+        // Normalize the method name the same way as `MetadataMiddleware` does to bound metric label cardinality.
+        let method = self
+            .registered_method_names
+            .get(request.method_name())
+            .copied()
+            .unwrap_or("");
+        let key = request
+            .extensions()
+            .get::<RateLimitKey>()
+            .cloned()
+            .unwrap_or(RateLimitKey::Unknown);
+        let labels = ComputeUnitLabels {
+            transport: self.transport,
+            key: self.limiter.metric_key(&key),
+            method,
+        };
+
+        match self.limiter.check(&key, method) {
+            Ok(cost) => {
+                METRICS.consumed[&labels].inc_by(cost.get().into());
+                ResponseFuture::future(self.inner.call(request))
+            }
+            Err(err) => {
+                tracing::debug!("Rejected `{method}` call from `{}`: {err:?}", labels.key);
+                METRICS.rejected_calls[&labels].inc();
+                let rp = MethodResponse::error(request.id, err.into_error_object());
+                ResponseFuture::ready(rp)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_config::configs::api::ComputeUnitsByName;
+
+    use super::*;
+
+    fn limits(per_client: u32) -> ComputeUnitLimits {
+        ComputeUnitLimits {
+            per_client: NonZeroU32::new(per_client),
+            per_api_key: [("secret", NonZeroU32::new(1_000).unwrap())]
+                .into_iter()
+                .collect(),
+            method_costs: [("eth_getLogs", NonZeroU32::new(30).unwrap())]
+                .into_iter()
+                .collect(),
+            trusted_proxies: vec![[10, 0, 0, 100].into(), [10, 0, 0, 101].into()],
+        }
+    }
+
+    #[test]
+    fn extracting_rate_limit_key() {
+        let limiter = ComputeUnitLimiter::new(&limits(100));
+        let request = |remote_ip: [u8; 4], uri: &str, headers: &[(&str, &str)]| {
+            let mut builder = http::Request::post(uri)
+                .extension(RemoteAddr(SocketAddr::from((remote_ip, 12_345))));
+            for &(name, value) in headers {
+                builder = builder.header(name, value);
+            }
+            builder.body(()).unwrap()
+        };
+        let client_ip = [1, 2, 3, 4];
+        let proxy_ip = [10, 0, 0, 100];
+
+        let key = limiter.extract_key(&request(client_ip, "/", &[("x-api-key", "secret")]));
+        assert_eq!(key, RateLimitKey::ApiKey("secret".into()));
+        let key = limiter.extract_key(&request(client_ip, "/secret", &[]));
+        assert_eq!(key, RateLimitKey::ApiKey("secret".into()));
+
+        // Forwarding headers from untrusted peers are ignored.
+        let key = limiter.extract_key(&request(
+            client_ip,
+            "/other",
+            &[("x-api-key", "wrong"), ("x-forwarded-for", "10.0.0.1")],
+        ));
+        assert_eq!(key, RateLimitKey::Ip(client_ip.into()));
+        let key = limiter.extract_key(&request(client_ip, "/", &[("x-real-ip", "::1")]));
+        assert_eq!(key, RateLimitKey::Ip(client_ip.into()));
+
+        // Forwarding headers from trusted proxies are respected.
+        let key = limiter.extract_key(&request(
+            proxy_ip,
+            "/",
+            &[("x-forwarded-for", "10.0.0.1, 192.168.0.1")],
+        ));
+        assert_eq!(key, RateLimitKey::Ip([192, 168, 0, 1].into()));
+        let key = limiter.extract_key(&request(
+            proxy_ip,
+            "/",
+            &[("x-forwarded-for", "10.0.0.1, 192.168.0.1, 10.0.0.101")],
+        ));
+        assert_eq!(key, RateLimitKey::Ip([192, 168, 0, 1].into()));
+        let key = limiter.extract_key(&request(
+            proxy_ip,
+            "/",
+            &[("x-forwarded-for", "10.0.0.1, garbage, 10.0.0.101")],
+        ));
+        assert_eq!(key, RateLimitKey::Ip([10, 0, 0, 101].into()));
+        let key = limiter.extract_key(&request(proxy_ip, "/", &[("x-real-ip", "::1")]));
+        assert_eq!(key, RateLimitKey::Ip("::1".parse().unwrap()));
+        let key = limiter.extract_key(&request(proxy_ip, "/", &[]));
+        assert_eq!(key, RateLimitKey::Ip(proxy_ip.into()));
+
+        let request_without_addr = http::Request::post("/").body(()).unwrap();
+        let key = limiter.extract_key(&request_without_addr);
+        assert_eq!(key, RateLimitKey::Unknown);
+    }
+
+    #[test]
+    fn charging_compute_units() {
+        let limiter = ComputeUnitLimiter::new(&limits(100));
+        let ip = RateLimitKey::Ip([10, 0, 0, 1].into());
+
+        // Default costs and overrides
+        assert_eq!(limiter.check(&ip, "eth_chainId").unwrap().get(), 1);
+        assert_eq!(limiter.check(&ip, "eth_call").unwrap().get(), 20);
+        assert_eq!(limiter.check(&ip, "eth_getLogs").unwrap().get(), 30);
+        assert_eq!(limiter.check(&ip, "eth_getLogs").unwrap().get(), 30);
+        let err = limiter.check(&ip, "eth_getLogs").unwrap_err();
+        assert!(
+            matches!(err, RateLimitError::Exhausted { retry_after } if retry_after > Duration::ZERO),
+            "{err:?}"
+        );
+        let err = limiter.check(&ip, "debug_traceBlockByNumber").unwrap_err();
+        assert_eq!(
+            err,
+            RateLimitError::InsufficientCapacity {
+                cost: NonZeroU32::new(500).unwrap()
+            }
+        );
+
+        // Other clients have independent budgets.
+        let other_ip = RateLimitKey::Ip([10, 0, 0, 2].into());
+        limiter.check(&other_ip, "eth_getLogs").unwrap();
+        limiter
+            .check(&RateLimitKey::Unknown, "eth_getLogs")
+            .unwrap();
+        let api_key = RateLimitKey::ApiKey("secret".into());
+        limiter.check(&api_key, "debug_traceBlockByNumber").unwrap();
+        limiter.check(&api_key, "debug_traceBlockByNumber").unwrap();
+        limiter
+            .check(&api_key, "debug_traceBlockByNumber")
+            .unwrap_err();
+    }
+
+    #[test]
+    fn clients_without_per_client_limit_are_not_limited() {
+        let limiter = ComputeUnitLimiter::new(&ComputeUnitLimits {
+            per_client: None,
+            per_api_key: ComputeUnitsByName::empty(),
+            method_costs: ComputeUnitsByName::empty(),
+            trusted_proxies: vec![],
+        });
+        let ip = RateLimitKey::Ip([10, 0, 0, 1].into());
+        for _ in 0..100 {
+            limiter.check(&ip, "debug_traceBlockByNumber").unwrap();
+        }
+    }
+
+    #[test]
+    fn rate_limit_error_object() {
+        let err = RateLimitError::Exhausted {
+            retry_after: Duration::from_millis(1_500),
+        };
+        let err = err.into_error_object();
+        assert_eq!(err.code(), RATE_LIMIT_ERROR_CODE);
+        let data: serde_json::Value = serde_json::from_str(err.data().unwrap().get()).unwrap();
+        assert_eq!(data, serde_json::json!({ "retryAfterMs": 1_500 }));
+    }
+}
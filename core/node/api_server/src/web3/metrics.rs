@@ -169,7 +169,9 @@ enum Web3ErrorKind {
     TooManyTopics,
     FilterNotFound,
     LogsLimitExceeded,
+    BlockRangeLimitExceeded,
     InvalidFilterBlockHash,
+    InvalidSimulation,
     TreeApiUnavailable,
     Internal,
 }
@@ -185,7 +187,9 @@ impl Web3ErrorKind {
             Web3Error::TooManyTopics => Self::TooManyTopics,
             Web3Error::FilterNotFound => Self::FilterNotFound,
             Web3Error::LogsLimitExceeded(..) => Self::LogsLimitExceeded,
+            Web3Error::BlockRangeLimitExceeded(_) => Self::BlockRangeLimitExceeded,
             Web3Error::InvalidFilterBlockHash => Self::InvalidFilterBlockHash,
+            Web3Error::InvalidSimulation(_) => Self::InvalidSimulation,
             Web3Error::TreeApiUnavailable => Self::TreeApiUnavailable,
             Web3Error::InternalError(_) | Web3Error::MethodNotImplemented => Self::Internal,
         }
@@ -421,6 +425,9 @@ pub enum SubscriptionType {
     Blocks,
     Txs,
     Logs,
+    Syncing,
+    TxStatuses,
+    L1Batches,
 }
 
 #[derive(Debug, Metrics)]
@@ -3,43 +3,49 @@ use std::{collections::HashSet, net::SocketAddr, num::NonZeroU32, sync::Arc, tim
 use anyhow::Context as _;
 use chrono::NaiveDateTime;
 use futures::future;
+use hyper::body::Incoming;
 use serde::Deserialize;
 use tokio::{
+    net::TcpListener,
     sync::{mpsc, oneshot, watch, Mutex},
     task::JoinHandle,
 };
+use tower::Service as _;
 use tower_http::{cors::CorsLayer, metrics::InFlightRequestsLayer};
 use zksync_config::configs::api::{MaxResponseSize, MaxResponseSizeOverrides};
 use zksync_dal::{helpers::wait_for_l1_batch, ConnectionPool, Core};
 use zksync_health_check::{HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_metadata_calculator::api_server::TreeApiClient;
 use zksync_node_sync::SyncState;
+use zksync_state_keeper::MempoolGuard;
 use zksync_types::L2BlockNumber;
 use zksync_web3_decl::{
     jsonrpsee::{
         server::{
-            middleware::rpc::either::Either, BatchRequestConfig, RpcServiceBuilder, ServerBuilder,
+            middleware::rpc::either::Either, serve_with_graceful_shutdown, stop_channel,
+            BatchRequestConfig, RpcServiceBuilder, ServerBuilder,
         },
         MethodCallback, Methods, RpcModule,
     },
     namespaces::{
         DebugNamespaceServer, EnNamespaceServer, EthNamespaceServer, EthPubSubServer,
-        NetNamespaceServer, SnapshotsNamespaceServer, UnstableNamespaceServer, Web3NamespaceServer,
-        ZksNamespaceServer,
+        NetNamespaceServer, SnapshotsNamespaceServer, TraceNamespaceServer, TxpoolNamespaceServer,
+        UnstableNamespaceServer, Web3NamespaceServer, ZksNamespaceServer,
     },
     types::Filter,
 };
 
 use self::{
     backend_jsonrpsee::{
-        CorrelationMiddleware, LimitMiddleware, MetadataLayer, MethodTracer, ShutdownMiddleware,
+        ComputeUnitLimiter, ComputeUnitMiddleware, CorrelationMiddleware, LimitMiddleware,
+        MetadataLayer, MethodTracer, RateLimitKeyLayer, RemoteAddr, ShutdownMiddleware,
         TrafficTracker,
     },
     mempool_cache::MempoolCache,
     metrics::API_METRICS,
     namespaces::{
         DebugNamespace, EnNamespace, EthNamespace, NetNamespace, SnapshotsNamespace,
-        UnstableNamespace, Web3Namespace, ZksNamespace,
+        TraceNamespace, TxpoolNamespace, UnstableNamespace, Web3Namespace, ZksNamespace,
     },
     pubsub::{EthSubscribe, EthSubscriptionIdProvider, PubSubEvent},
     state::{Filters, InternalApiConfig, RpcState, SealedL2BlockNumber},
@@ -100,6 +106,8 @@ pub enum Namespace {
     Pubsub,
     Snapshots,
     Unstable,
+    Txpool,
+    Trace,
 }
 
 impl Namespace {
@@ -132,8 +140,10 @@ struct OptionalApiParams {
     batch_request_size_limit: Option<usize>,
     response_body_size_limit: Option<MaxResponseSize>,
     websocket_requests_per_minute_limit: Option<NonZeroU32>,
+    compute_unit_limiter: Option<ComputeUnitLimiter>,
     tree_api: Option<Arc<dyn TreeApiClient>>,
     mempool_cache: Option<MempoolCache>,
+    mempool: Option<MempoolGuard>,
     extended_tracing: bool,
     pub_sub_events_sender: Option<mpsc::UnboundedSender<PubSubEvent>>,
 }
@@ -249,6 +259,13 @@ impl ApiBuilder {
         self
     }
 
+    /// Sets the compute unit limiter. The same limiter can be shared among HTTP and WS servers, so that clients
+    /// have a single budget across both transports.
+    pub fn with_compute_unit_limiter(mut self, limiter: ComputeUnitLimiter) -> Self {
+        self.optional.compute_unit_limiter = Some(limiter);
+        self
+    }
+
     pub fn with_sync_state(mut self, sync_state: SyncState) -> Self {
         self.optional.sync_state = Some(sync_state);
         self
@@ -280,6 +297,13 @@ impl ApiBuilder {
         self
     }
 
+    /// Provides access to the state keeper mempool. Only applicable to the main node if the API server
+    /// runs together with the state keeper; used to report mempool stats in the `txpool` namespace.
+    pub fn with_mempool(mut self, mempool: MempoolGuard) -> Self {
+        self.optional.mempool = Some(mempool);
+        self
+    }
+
     pub fn with_extended_tracing(mut self, extended_tracing: bool) -> Self {
         self.optional.extended_tracing = extended_tracing;
         self
@@ -363,6 +387,7 @@ impl ApiServer {
             api_config: self.config,
             start_info,
             mempool_cache: self.optional.mempool_cache,
+            mempool: self.optional.mempool,
             last_sealed_l2_block,
             tree_api: self.optional.tree_api,
         })
@@ -412,6 +437,14 @@ impl ApiServer {
             rpc.merge(SnapshotsNamespace::new(rpc_state.clone()).into_rpc())
                 .context("cannot merge snapshots namespace")?;
         }
+        if namespaces.contains(&Namespace::Txpool) {
+            rpc.merge(TxpoolNamespace::new(rpc_state.clone()).into_rpc())
+                .context("cannot merge txpool namespace")?;
+        }
+        if namespaces.contains(&Namespace::Trace) {
+            rpc.merge(TraceNamespace::new(rpc_state.clone()).into_rpc())
+                .context("cannot merge trace namespace")?;
+        }
         if namespaces.contains(&Namespace::Unstable) {
             rpc.merge(UnstableNamespace::new(rpc_state).into_rpc())
                 .context("cannot merge unstable namespace")?;
@@ -488,6 +521,11 @@ impl ApiServer {
         );
 
         let mut tasks = vec![tokio::spawn(sealed_l2_block_update_task)];
+        if let Some(limiter) = &self.optional.compute_unit_limiter {
+            tasks.push(tokio::spawn(
+                limiter.clone().run_housekeeping(stop_receiver.clone()),
+            ));
+        }
         let pub_sub = if matches!(transport, ApiTransport::WebSocket(_))
             && self.namespaces.contains(&Namespace::Pubsub)
         {
@@ -495,6 +533,9 @@ impl ApiServer {
             if let Some(sender) = &self.optional.pub_sub_events_sender {
                 pub_sub.set_events_sender(sender.clone());
             }
+            if let Some(sync_state) = &self.optional.sync_state {
+                pub_sub.set_sync_state(sync_state.clone());
+            }
 
             tasks.extend(pub_sub.spawn_notifiers(
                 self.pool.clone(),
@@ -630,6 +671,7 @@ impl ApiServer {
                 (u32::MAX, MaxResponseSizeOverrides::empty())
             };
         let websocket_requests_per_minute_limit = self.optional.websocket_requests_per_minute_limit;
+        let compute_unit_limiter = self.optional.compute_unit_limiter.clone();
         let subscriptions_limit = self.optional.subscriptions_limit;
         let vm_barrier = self.optional.vm_barrier.clone();
         let health_updater = self.health_updater.clone();
@@ -668,7 +710,8 @@ impl ApiServer {
         // Assemble server middleware.
         let middleware = tower::ServiceBuilder::new()
             .layer(in_flight_requests)
-            .option_layer(cors);
+            .option_layer(cors)
+            .option_layer(compute_unit_limiter.clone().map(RateLimitKeyLayer::new));
 
         // Settings shared by HTTP and WS servers.
         let max_connections = !is_http
@@ -676,6 +719,17 @@ impl ApiServer {
             .flatten()
             .unwrap_or(5_000);
 
+        let compute_unit_layer = compute_unit_limiter.map(|limiter| {
+            let registered_method_names = registered_method_names.clone();
+            tower::layer::layer_fn(move |svc| {
+                ComputeUnitMiddleware::new(
+                    svc,
+                    limiter.clone(),
+                    registered_method_names.clone(),
+                    is_http,
+                )
+            })
+        });
         let metadata_layer = MetadataLayer::new(registered_method_names, method_tracer);
         let metadata_layer = if extended_tracing {
             Either::Left(metadata_layer.with_param_tracing())
@@ -701,7 +755,9 @@ impl ApiServer {
                 tower::layer::layer_fn(move |svc| {
                     LimitMiddleware::new(svc, websocket_requests_per_minute_limit)
                 })
-            }));
+            }))
+            // Compute units are charged only for calls admitted by `LimitMiddleware`.
+            .option_layer(compute_unit_layer);
 
         let server_builder = ServerBuilder::default()
             .max_connections(max_connections as u32)
@@ -710,26 +766,57 @@ impl ApiServer {
             .set_batch_request_config(batch_request_config)
             .set_rpc_middleware(rpc_middleware);
 
-        let (local_addr, server_handle) = if is_http {
+        let server_builder = if is_http {
             // HTTP-specific settings
-            let server = server_builder
-                .http_only()
-                .build(addr)
-                .await
-                .context("Failed building HTTP JSON-RPC server")?;
-            (server.local_addr(), server.start(rpc))
+            server_builder.http_only()
         } else {
             // WS-specific settings
-            let server = server_builder
-                .set_id_provider(EthSubscriptionIdProvider)
-                .build(addr)
-                .await
-                .context("Failed building WS JSON-RPC server")?;
-            (server.local_addr(), server.start(rpc))
+            server_builder.set_id_provider(EthSubscriptionIdProvider)
         };
-        let local_addr = local_addr.with_context(|| {
+        let listener = TcpListener::bind(addr)
+            .await
+            .with_context(|| format!("Failed building {transport_str} JSON-RPC server"))?;
+        let local_addr = listener.local_addr().with_context(|| {
             format!("Failed getting local address for {transport_str} JSON-RPC server")
         })?;
+        let (stop_handle, server_handle) = stop_channel();
+        let service_builder = server_builder.to_service_builder();
+        // Connections are accepted manually (rather than via `Server::start()`) so that the connection peer address
+        // is available to the HTTP middleware; it is used to identify clients for rate limiting.
+        tokio::spawn(async move {
+            loop {
+                let (socket, remote_addr) = tokio::select! {
+                    res = listener.accept() => match res {
+                        Ok(conn) => conn,
+                        Err(err) => {
+                            tracing::warn!("Failed accepting connection to {transport_str} JSON-RPC server: {err}");
+                            continue;
+                        }
+                    },
+                    () = stop_handle.clone().shutdown() => break,
+                };
+
+                let service_builder = service_builder.clone();
+                let methods = rpc.clone();
+                let conn_stop_handle = stop_handle.clone();
+                let service = tower::service_fn(move |mut request: http::Request<Incoming>| {
+                    request.extensions_mut().insert(RemoteAddr(remote_addr));
+                    let mut service = service_builder
+                        .clone()
+                        .build(methods.clone(), conn_stop_handle.clone());
+                    async move { service.call(request).await }
+                });
+                let connection =
+                    serve_with_graceful_shutdown(socket, service, stop_handle.clone().shutdown());
+                tokio::spawn(async move {
+                    if let Err(err) = connection.await {
+                        tracing::debug!(
+                            "Error serving {transport_str} connection from {remote_addr}: {err}"
+                        );
+                    }
+                });
+            }
+        });
         tracing::info!("Initialized {transport_str} API on {local_addr:?}");
         local_addr_sender.send(local_addr).ok();
         health_updater.update(HealthStatus::Ready.into());
@@ -1,29 +1,92 @@
-use std::sync::Arc;
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    sync::Arc,
+};
 
 use anyhow::Context as _;
 use once_cell::sync::OnceCell;
-use zksync_dal::{CoreDal, DalError};
+use zksync_dal::{Connection, Core, CoreDal, DalError};
 use zksync_multivm::{
     interface::{Call, CallType, ExecutionResult, TxExecutionMode},
     vm_latest::constants::BATCH_COMPUTATIONAL_GAS_LIMIT,
 };
-use zksync_system_constants::MAX_ENCODED_TX_SIZE;
+use zksync_system_constants::{BOOTLOADER_ADDRESS, EVENT_WRITER_ADDRESS, MAX_ENCODED_TX_SIZE};
 use zksync_types::{
-    api::{BlockId, BlockNumber, DebugCall, DebugCallType, ResultDebugCall, TracerConfig},
+    api::{
+        self, BlockId, BlockNumber, DebugCall, DebugCallLog, DebugCallType, PrestateAccount,
+        PrestateDiff, ResultDebugCall, TracerBlockResult, TracerConfig, TracerResult,
+    },
     debug_flat_call::{flatten_debug_calls, DebugCallFlat},
     fee_model::BatchFeeInput,
     l2::L2Tx,
     transaction_request::CallRequest,
-    web3, AccountTreeId, H256, U256,
+    utils::decompose_full_nonce,
+    web3, AccountTreeId, Address, L2BlockNumber, Transaction, H256, U256,
 };
+use zksync_utils::u256_to_h256;
 use zksync_web3_decl::error::Web3Error;
 
 use crate::{
-    execution_sandbox::{ApiTracer, TxExecutionArgs, TxSetupArgs},
-    tx_sender::{ApiContracts, TxSenderConfig},
+    execution_sandbox::{
+        ApiTracer, PrestateResult, ReplayedTransaction, TxExecutionArgs, TxSetupArgs,
+    },
+    tx_sender::{ApiContracts, MultiVMBaseSystemContracts, TxSenderConfig},
     web3::{backend_jsonrpsee::MethodTracer, state::RpcState},
 };
 
+/// Prestates of accounts before and after the transaction execution.
+type PrestateState = (
+    BTreeMap<Address, PrestateAccount>,
+    BTreeMap<Address, PrestateAccount>,
+);
+
+/// Data that needs to be collected in order to produce results for the requested tracers.
+#[derive(Debug, Clone, Copy, Default)]
+struct TracingNeeds {
+    /// Whether the full call tree is required; otherwise, only the top-level call is needed.
+    full_call_trace: bool,
+    logs: bool,
+    /// Diff mode of the prestate tracer, if it's requested.
+    prestate: Option<bool>,
+}
+
+impl TracingNeeds {
+    fn new(config: &TracerConfig) -> Self {
+        let mut needs = Self::default();
+        for tracer in config.leaf_tracers() {
+            match tracer {
+                TracerConfig::CallTracer(config) => {
+                    needs.full_call_trace |= !config.only_top_call || config.with_log;
+                    needs.logs |= config.with_log;
+                }
+                TracerConfig::FourByteTracer => needs.full_call_trace = true,
+                TracerConfig::PrestateTracer(config) => needs.prestate = Some(config.diff_mode),
+                TracerConfig::MuxTracer(_) => unreachable!("mux tracers are flattened"),
+            }
+        }
+        needs
+    }
+}
+
+/// Raw outputs of tracing a single transaction or call, from which results for the requested tracers are built.
+#[derive(Debug)]
+struct TraceOutputs {
+    /// Full call tree.
+    call: DebugCall,
+    /// All logs emitted by the transaction in the emission order.
+    logs: Vec<DebugCallLog>,
+    prestate: Option<PrestateState>,
+}
+
+fn map_log(log: api::Log) -> DebugCallLog {
+    DebugCallLog {
+        address: log.address,
+        topics: log.topics,
+        data: log.data,
+        position: 0.into(),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct DebugNamespace {
     batch_fee_input: BatchFeeInput,
@@ -77,6 +140,7 @@ impl DebugNamespace {
             error: call.error,
             revert_reason: call.revert_reason,
             calls,
+            logs: vec![],
         }
     }
 
@@ -88,22 +152,245 @@ impl DebugNamespace {
         &self.state.current_method
     }
 
-    pub async fn debug_trace_block_impl(
+    /// Attaches logs emitted during the transaction execution to the calls that emitted them.
+    /// Logs are emitted via far calls to the event writer system contract; such calls are replaced with the log
+    /// at the corresponding position in the parent call.
+    ///
+    /// Returns `false` if logs cannot be unambiguously matched to calls, in which case the call trace may be partially modified.
+    fn attach_logs(call: &mut DebugCall, logs: &mut VecDeque<DebugCallLog>) -> bool {
+        let mut position = 0_u64;
+        for child in &mut call.calls {
+            if child.to == EVENT_WRITER_ADDRESS {
+                let Some(mut log) = logs.pop_front() else {
+                    return false;
+                };
+                if log.address != child.from {
+                    return false;
+                }
+                log.position = position.into();
+                call.logs.push(log);
+                continue;
+            }
+
+            position += 1;
+            // Logs emitted by reverted calls are discarded.
+            let is_reverted = child.error.is_some() || child.revert_reason.is_some();
+            if !is_reverted && !Self::attach_logs(child, logs) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn count_selectors(call: &DebugCall, counts: &mut BTreeMap<String, u64>) {
+        // Calls to system contracts (e.g., the bootloader or the event writer) and calls from the bootloader
+        // (e.g., account validation) are implementation details of zkSync; they are not counted.
+        let is_system_call = call.to.as_bytes()[..18].iter().all(|&byte| byte == 0)
+            || call.from == BOOTLOADER_ADDRESS;
+        // Like in Geth, all calls executing code are counted (including delegate and static calls); contract creations are not.
+        let is_countable =
+            !is_system_call && call.r#type != DebugCallType::Create && call.input.0.len() >= 4;
+        if is_countable {
+            let (selector, args) = call.input.0.split_at(4);
+            let key = format!("0x{}-{}", hex::encode(selector), args.len());
+            *counts.entry(key).or_default() += 1;
+        }
+        for child in &call.calls {
+            Self::count_selectors(child, counts);
+        }
+    }
+
+    fn build_tracer_result(config: &TracerConfig, outputs: &TraceOutputs) -> TracerResult {
+        match config {
+            TracerConfig::CallTracer(config) => {
+                let mut call = outputs.call.clone();
+                if config.with_log {
+                    let mut logs: VecDeque<_> = outputs.logs.iter().cloned().collect();
+                    // The top-level call corresponds to the bootloader, so we always descend into it even if it's reverted.
+                    let is_matched = Self::attach_logs(&mut call, &mut logs) && logs.is_empty();
+                    if !is_matched {
+                        tracing::debug!(
+                            "Cannot match logs to calls in the trace; returning trace without logs"
+                        );
+                        call = outputs.call.clone();
+                    }
+                }
+                if config.only_top_call {
+                    call.calls = vec![];
+                }
+                TracerResult::CallTrace(call)
+            }
+            TracerConfig::FourByteTracer => {
+                let mut counts = BTreeMap::new();
+                Self::count_selectors(&outputs.call, &mut counts);
+                TracerResult::FourByte(counts)
+            }
+            TracerConfig::PrestateTracer(config) => {
+                let (pre, post) = outputs.prestate.clone().unwrap_or_default();
+                if config.diff_mode {
+                    TracerResult::PrestateDiff(PrestateDiff { pre, post })
+                } else {
+                    TracerResult::Prestate(post)
+                }
+            }
+            TracerConfig::MuxTracer(configs) => TracerResult::Mux(
+                configs
+                    .iter()
+                    .map(|(tracer, config)| (*tracer, Self::build_tracer_result(config, outputs)))
+                    .collect(),
+            ),
+        }
+    }
+
+    async fn map_prestate(
+        connection: &mut Connection<'_, Core>,
+        (pre, post): PrestateResult,
+        bytecodes: &mut HashMap<U256, Option<web3::Bytes>>,
+    ) -> Result<PrestateState, Web3Error> {
+        let mut mapped = [BTreeMap::new(), BTreeMap::new()];
+        for (state, mapped) in [pre, post].into_iter().zip(&mut mapped) {
+            for (address, account) in state {
+                let code = match account.code {
+                    Some(hash) if !hash.is_zero() => {
+                        if !bytecodes.contains_key(&hash) {
+                            let bytecode = connection
+                                .factory_deps_dal()
+                                .get_sealed_factory_dep(u256_to_h256(hash))
+                                .await
+                                .map_err(DalError::generalize)?;
+                            bytecodes.insert(hash, bytecode.map(web3::Bytes));
+                        }
+                        bytecodes[&hash].clone()
+                    }
+                    _ => None,
+                };
+                let account = PrestateAccount {
+                    balance: account.balance,
+                    nonce: account.nonce.map(|nonce| decompose_full_nonce(nonce).0),
+                    code_hash: account.code.map(u256_to_h256),
+                    code,
+                    storage: account.storage.unwrap_or_default().into_iter().collect(),
+                };
+                mapped.insert(address, account);
+            }
+        }
+        let [pre, post] = mapped;
+        Ok((pre, post))
+    }
+
+    /// Re-executes a prefix of transactions in a sealed L2 block running the prestate tracer for each of the transactions
+    /// starting from `first_traced_index`. Returns prestates for traced transactions.
+    async fn replay_with_prestate(
         &self,
+        block_number: L2BlockNumber,
+        mut transactions: Vec<Transaction>,
+        first_traced_index: usize,
+        diff_mode: bool,
+    ) -> Result<Vec<PrestateState>, Web3Error> {
+        let mut connection = self.state.acquire_connection().await?;
+        let block_args = self
+            .state
+            .resolve_block_args(
+                &mut connection,
+                BlockId::Number(BlockNumber::Number(block_number.0.into())),
+            )
+            .await?;
+        let setup_args = self.replay_args(&mut connection, block_number).await?;
+        drop(connection);
+
+        let prestate_results: Vec<_> = (first_traced_index..transactions.len())
+            .map(|_| Arc::new(OnceCell::new()))
+            .collect();
+        let traced_transactions = transactions.split_off(first_traced_index);
+        let transactions = transactions
+            .into_iter()
+            .map(|transaction| ReplayedTransaction {
+                transaction,
+                tracers: vec![],
+            })
+            .chain(traced_transactions.into_iter().zip(&prestate_results).map(
+                |(transaction, result)| ReplayedTransaction {
+                    transaction,
+                    tracers: vec![ApiTracer::Prestate {
+                        diff_mode,
+                        result: result.clone(),
+                    }],
+                },
+            ))
+            .collect();
+
+        let vm_permit = self
+            .state
+            .tx_sender
+            .vm_concurrency_limiter()
+            .acquire()
+            .await;
+        let vm_permit = vm_permit.context("cannot acquire VM permit")?;
+        let connection = self.state.acquire_connection().await?;
+        let executor = &self.state.tx_sender.0.executor;
+        executor
+            .replay_in_sandbox(vm_permit, setup_args, connection, block_args, transactions)
+            .await?;
+
+        let mut connection = self.state.acquire_connection().await?;
+        let mut bytecodes = HashMap::new();
+        let mut prestates = Vec::with_capacity(prestate_results.len());
+        for result in prestate_results {
+            // The tracer isn't run if the transaction cannot be executed (e.g., by the mock executor).
+            let result = Arc::try_unwrap(result)
+                .ok()
+                .and_then(OnceCell::into_inner)
+                .unwrap_or_default();
+            prestates.push(Self::map_prestate(&mut connection, result, &mut bytecodes).await?);
+        }
+        Ok(prestates)
+    }
+
+    /// Returns sandbox args to replay transactions from a sealed L2 block in the same way as they were executed
+    /// by the state keeper, i.e., using the same execution mode, base system contracts and fee account.
+    async fn replay_args(
+        &self,
+        connection: &mut Connection<'_, Core>,
+        block_number: L2BlockNumber,
+    ) -> Result<TxSetupArgs, Web3Error> {
+        let header = connection
+            .blocks_dal()
+            .get_l2_block_header(block_number)
+            .await
+            .map_err(DalError::generalize)?
+            .with_context(|| format!("L2 block #{block_number} disappeared from storage"))?;
+        let contract_hashes = header.base_system_contracts_hashes;
+        let base_system_contracts = connection
+            .factory_deps_dal()
+            .get_base_system_contracts(contract_hashes.bootloader, contract_hashes.default_aa)
+            .await
+            .with_context(|| {
+                format!("failed loading base system contracts for L2 block #{block_number}")
+            })?;
+
+        Ok(TxSetupArgs {
+            execution_mode: TxExecutionMode::VerifyExecute,
+            operator_account: AccountTreeId::new(header.fee_account_address),
+            base_system_contracts: MultiVMBaseSystemContracts::uniform(base_system_contracts),
+            validation_computational_gas_limit: self
+                .sender_config()
+                .validation_computational_gas_limit,
+            ..self.call_args(None).await
+        })
+    }
+
+    async fn block_call_traces(
+        &self,
+        connection: &mut Connection<'_, Core>,
         block_id: BlockId,
-        options: Option<TracerConfig>,
-    ) -> Result<Vec<ResultDebugCall>, Web3Error> {
+    ) -> Result<Option<(L2BlockNumber, Vec<DebugCall>)>, Web3Error> {
         self.current_method().set_block_id(block_id);
         if matches!(block_id, BlockId::Number(BlockNumber::Pending)) {
             // See `EthNamespace::get_block_impl()` for an explanation why this check is needed.
-            return Ok(vec![]);
+            return Ok(None);
         }
 
-        let only_top_call = options
-            .map(|options| options.tracer_config.only_top_call)
-            .unwrap_or(false);
-        let mut connection = self.state.acquire_connection().await?;
-        let block_number = self.state.resolve_block(&mut connection, block_id).await?;
+        let block_number = self.state.resolve_block(connection, block_id).await?;
         self.current_method()
             .set_block_diff(self.state.last_sealed_l2_block.diff(block_number));
 
@@ -112,14 +399,89 @@ impl DebugNamespace {
             .get_traces_for_l2_block(block_number)
             .await
             .map_err(DalError::generalize)?;
-        let call_trace = call_traces
+        let call_traces = call_traces
             .into_iter()
-            .map(|call_trace| {
-                let result = Self::map_call(call_trace, only_top_call);
-                ResultDebugCall { result }
+            .map(|call_trace| Self::map_call(call_trace, false))
+            .collect();
+        Ok(Some((block_number, call_traces)))
+    }
+
+    pub async fn debug_trace_block_impl(
+        &self,
+        block_id: BlockId,
+        options: Option<TracerConfig>,
+    ) -> Result<Vec<TracerBlockResult>, Web3Error> {
+        let config = options.unwrap_or_default();
+        let needs = TracingNeeds::new(&config);
+        let mut connection = self.state.acquire_connection().await?;
+        let Some((block_number, call_traces)) =
+            self.block_call_traces(&mut connection, block_id).await?
+        else {
+            return Ok(vec![]);
+        };
+        if call_traces.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut logs = vec![vec![]; call_traces.len()];
+        let mut prestates = vec![None; call_traces.len()];
+        if needs.logs || needs.prestate.is_some() {
+            let transactions = connection
+                .transactions_dal()
+                .get_l2_block_transactions(block_number)
+                .await
+                .map_err(DalError::generalize)?;
+            if transactions.len() != call_traces.len() {
+                let err = anyhow::anyhow!(
+                    "number of call traces ({}) for L2 block #{block_number} doesn't match the number of transactions ({})",
+                    call_traces.len(),
+                    transactions.len()
+                );
+                return Err(Web3Error::InternalError(err));
+            }
+
+            if needs.logs {
+                let hashes: Vec<_> = transactions.iter().map(Transaction::hash).collect();
+                let receipts = connection
+                    .transactions_web3_dal()
+                    .get_transaction_receipts(&hashes)
+                    .await
+                    .map_err(DalError::generalize)?;
+                let mut logs_by_hash: HashMap<_, _> = receipts
+                    .into_iter()
+                    .map(|receipt| (receipt.transaction_hash, receipt.logs))
+                    .collect();
+                for (hash, tx_logs) in hashes.iter().zip(&mut logs) {
+                    let receipt_logs = logs_by_hash.remove(hash).unwrap_or_default();
+                    *tx_logs = receipt_logs.into_iter().map(map_log).collect();
+                }
+            }
+            drop(connection);
+
+            if let Some(diff_mode) = needs.prestate {
+                let block_prestates = self
+                    .replay_with_prestate(block_number, transactions, 0, diff_mode)
+                    .await?;
+                prestates = block_prestates.into_iter().map(Some).collect();
+            }
+        }
+
+        let results = call_traces
+            .into_iter()
+            .zip(logs)
+            .zip(prestates)
+            .map(|((call, logs), prestate)| {
+                let outputs = TraceOutputs {
+                    call,
+                    logs,
+                    prestate,
+                };
+                TracerBlockResult {
+                    result: Self::build_tracer_result(&config, &outputs),
+                }
             })
             .collect();
-        Ok(call_trace)
+        Ok(results)
     }
 
     pub async fn debug_trace_block_flat_impl(
@@ -127,8 +489,27 @@ impl DebugNamespace {
         block_id: BlockId,
         options: Option<TracerConfig>,
     ) -> Result<Vec<DebugCallFlat>, Web3Error> {
-        let call_trace = self.debug_trace_block_impl(block_id, options).await?;
-        let call_trace_flat = flatten_debug_calls(call_trace);
+        // Only the call tracer is supported by the flat format; configs for other tracers are ignored.
+        let only_top_call = match options {
+            Some(TracerConfig::CallTracer(config)) => config.only_top_call,
+            _ => false,
+        };
+        let mut connection = self.state.acquire_connection().await?;
+        let call_traces = self
+            .block_call_traces(&mut connection, block_id)
+            .await?
+            .map(|(_, call_traces)| call_traces)
+            .unwrap_or_default();
+        let call_traces = call_traces
+            .into_iter()
+            .map(|mut result| {
+                if only_top_call {
+                    result.calls = vec![];
+                }
+                ResultDebugCall { result }
+            })
+            .collect();
+        let call_trace_flat = flatten_debug_calls(call_traces);
         Ok(call_trace_flat)
     }
 
@@ -136,17 +517,65 @@ impl DebugNamespace {
         &self,
         tx_hash: H256,
         options: Option<TracerConfig>,
-    ) -> Result<Option<DebugCall>, Web3Error> {
-        let only_top_call = options
-            .map(|options| options.tracer_config.only_top_call)
-            .unwrap_or(false);
+    ) -> Result<Option<TracerResult>, Web3Error> {
+        let config = options.unwrap_or_default();
+        let needs = TracingNeeds::new(&config);
         let mut connection = self.state.acquire_connection().await?;
         let call_trace = connection
             .transactions_dal()
             .get_call_trace(tx_hash)
             .await
             .map_err(DalError::generalize)?;
-        Ok(call_trace.map(|call_trace| Self::map_call(call_trace, only_top_call)))
+        let Some(call_trace) = call_trace else {
+            return Ok(None);
+        };
+        let call = Self::map_call(call_trace, false);
+
+        let mut logs = vec![];
+        let mut prestate = None;
+        if needs.logs || needs.prestate.is_some() {
+            let receipt = connection
+                .transactions_web3_dal()
+                .get_transaction_receipts(&[tx_hash])
+                .await
+                .map_err(DalError::generalize)?
+                .pop();
+            // Transaction may be reverted on the server in the meantime
+            let Some(receipt) = receipt else {
+                return Ok(None);
+            };
+            let block_number = L2BlockNumber(receipt.block_number.as_u32());
+            let tx_index = receipt.transaction_index.as_usize();
+            logs = receipt.logs.into_iter().map(map_log).collect();
+
+            if let Some(diff_mode) = needs.prestate {
+                let mut transactions = connection
+                    .transactions_dal()
+                    .get_l2_block_transactions(block_number)
+                    .await
+                    .map_err(DalError::generalize)?;
+                drop(connection);
+
+                if transactions.get(tx_index).map(Transaction::hash) != Some(tx_hash) {
+                    let err = anyhow::anyhow!(
+                        "transaction {tx_hash:?} is not found at index {tx_index} in L2 block #{block_number}"
+                    );
+                    return Err(Web3Error::InternalError(err));
+                }
+                transactions.truncate(tx_index + 1);
+                prestate = self
+                    .replay_with_prestate(block_number, transactions, tx_index, diff_mode)
+                    .await?
+                    .pop();
+            }
+        }
+
+        let outputs = TraceOutputs {
+            call,
+            logs,
+            prestate,
+        };
+        Ok(Some(Self::build_tracer_result(&config, &outputs)))
     }
 
     pub async fn debug_trace_call_impl(
@@ -154,13 +583,12 @@ impl DebugNamespace {
         mut request: CallRequest,
         block_id: Option<BlockId>,
         options: Option<TracerConfig>,
-    ) -> Result<DebugCall, Web3Error> {
+    ) -> Result<TracerResult, Web3Error> {
         let block_id = block_id.unwrap_or(BlockId::Number(BlockNumber::Pending));
         self.current_method().set_block_id(block_id);
 
-        let only_top_call = options
-            .map(|options| options.tracer_config.only_top_call)
-            .unwrap_or(false);
+        let config = options.unwrap_or_default();
+        let needs = TracingNeeds::new(&config);
 
         let mut connection = self.state.acquire_connection().await?;
         let block_args = self
@@ -191,11 +619,17 @@ impl DebugNamespace {
 
         // We don't need properly trace if we only need top call
         let call_tracer_result = Arc::new(OnceCell::default());
-        let custom_tracers = if only_top_call {
-            vec![]
-        } else {
-            vec![ApiTracer::CallTracer(call_tracer_result.clone())]
-        };
+        let prestate_result = Arc::new(OnceCell::default());
+        let mut custom_tracers = vec![];
+        if needs.full_call_trace {
+            custom_tracers.push(ApiTracer::CallTracer(call_tracer_result.clone()));
+        }
+        if let Some(diff_mode) = needs.prestate {
+            custom_tracers.push(ApiTracer::Prestate {
+                diff_mode,
+                result: prestate_result.clone(),
+            });
+        }
 
         let connection = self.state.acquire_connection().await?;
         let executor = &self.state.tx_sender.0.executor;
@@ -237,7 +671,29 @@ impl DebugNamespace {
             revert_reason,
             trace,
         );
-        Ok(Self::map_call(call, false))
+
+        let prestate = if let Some(prestate) = Arc::try_unwrap(prestate_result).unwrap().take() {
+            let mut connection = self.state.acquire_connection().await?;
+            Some(Self::map_prestate(&mut connection, prestate, &mut HashMap::new()).await?)
+        } else {
+            None
+        };
+        let outputs = TraceOutputs {
+            call: Self::map_call(call, false),
+            logs: result
+                .logs
+                .events
+                .into_iter()
+                .map(|event| DebugCallLog {
+                    address: event.address,
+                    topics: event.indexed_topics,
+                    data: event.value.into(),
+                    position: 0.into(),
+                })
+                .collect(),
+            prestate,
+        };
+        Ok(Self::build_tracer_result(&config, &outputs))
     }
 
     async fn call_args(&self, enforced_base_fee: Option<u64>) -> TxSetupArgs {
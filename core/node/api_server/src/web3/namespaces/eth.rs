@@ -1,13 +1,15 @@
 use anyhow::Context as _;
 use zksync_dal::{CoreDal, DalError};
+use zksync_multivm::interface::ExecutionResult;
 use zksync_system_constants::DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE;
 use zksync_types::{
     api::{
-        state_override::StateOverride, BlockId, BlockNumber, FeeHistory, GetLogsFilter,
+        state_override::StateOverride, AccessListResult, BlockId, BlockNumber, FeeHistory,
+        GetLogsFilter, SimulatePayload, SimulatedBlock, SimulatedCall, SimulatedCallError,
         Transaction, TransactionId, TransactionReceipt, TransactionVariant,
     },
     l2::{L2Tx, TransactionType},
-    transaction_request::CallRequest,
+    transaction_request::{CallOverrides, CallRequest},
     utils::decompose_full_nonce,
     web3::{self, Bytes, SyncInfo, SyncState},
     AccountTreeId, L2BlockNumber, StorageKey, H256, L2_BASE_TOKEN_ADDRESS, U256,
@@ -19,12 +21,15 @@ use zksync_web3_decl::{
 };
 
 use crate::{
+    execution_sandbox::{BlockArgs, SimulatedL2Block, SimulatedL2BlockOutput},
     utils::open_readonly_transaction,
     web3::{backend_jsonrpsee::MethodTracer, metrics::API_METRICS, state::RpcState, TypedFilter},
 };
 
 pub const EVENT_TOPIC_NUMBER_LIMIT: usize = 4;
 pub const PROTOCOL_VERSION: &str = "zks/1";
+/// Maximum number of L2 blocks in a single `eth_simulateV1` request.
+pub const MAX_SIMULATED_BLOCKS: usize = 256;
 
 #[derive(Debug)]
 pub(crate) struct EthNamespace {
@@ -51,12 +56,12 @@ impl EthNamespace {
         Ok(block_number.0.into())
     }
 
-    pub async fn call_impl(
+    /// Resolves the block and converts a call request into a transaction for `eth_call`-like methods.
+    async fn prepare_call(
         &self,
         mut request: CallRequest,
         block_id: Option<BlockId>,
-        state_override: Option<StateOverride>,
-    ) -> Result<Bytes, Web3Error> {
+    ) -> Result<(BlockArgs, CallOverrides, L2Tx), Web3Error> {
         let block_id = block_id.unwrap_or(BlockId::Number(BlockNumber::Pending));
         self.current_method().set_block_id(block_id);
 
@@ -77,6 +82,16 @@ impl EthNamespace {
 
         let call_overrides = request.get_call_overrides()?;
         let tx = L2Tx::from_request(request.into(), self.state.api_config.max_tx_size)?;
+        Ok((block_args, call_overrides, tx))
+    }
+
+    pub async fn call_impl(
+        &self,
+        request: CallRequest,
+        block_id: Option<BlockId>,
+        state_override: Option<StateOverride>,
+    ) -> Result<Bytes, Web3Error> {
+        let (block_args, call_overrides, tx) = self.prepare_call(request, block_id).await?;
 
         // It is assumed that the previous checks has already enforced that the `max_fee_per_gas` is at most u64.
         let call_result: Vec<u8> = self
@@ -87,6 +102,229 @@ impl EthNamespace {
         Ok(call_result.into())
     }
 
+    pub async fn create_access_list_impl(
+        &self,
+        request: CallRequest,
+        block_id: Option<BlockId>,
+        state_override: Option<StateOverride>,
+    ) -> Result<AccessListResult, Web3Error> {
+        let (block_args, call_overrides, tx) = self.prepare_call(request, block_id).await?;
+        let access_list = self
+            .state
+            .tx_sender
+            .create_access_list(block_args, call_overrides, tx, state_override)
+            .await?;
+        Ok(access_list)
+    }
+
+    pub async fn simulate_v1_impl(
+        &self,
+        mut payload: SimulatePayload,
+        block_id: Option<BlockId>,
+    ) -> Result<Vec<SimulatedBlock>, Web3Error> {
+        let block_id = block_id.unwrap_or(BlockId::Number(BlockNumber::Pending));
+        self.current_method().set_block_id(block_id);
+        self.validate_simulation(&payload)?;
+
+        let mut connection = self.state.acquire_connection().await?;
+        let block_args = self
+            .state
+            .resolve_block_args(&mut connection, block_id)
+            .await?;
+        self.current_method().set_block_diff(
+            self.state
+                .last_sealed_l2_block
+                .diff_with_block_args(&block_args),
+        );
+        let default_gas = block_args.default_eth_call_gas(&mut connection).await?;
+        drop(connection);
+
+        // Only the first block may contain state and fee overrides; this is checked during validation.
+        let first_block = &mut payload.block_state_calls[0];
+        let state_override = first_block.state_overrides.take();
+        let enforced_base_fee = first_block
+            .block_overrides
+            .as_ref()
+            .and_then(|overrides| overrides.base_fee_per_gas)
+            .map(|base_fee| {
+                u64::try_from(base_fee).map_err(|_| {
+                    Web3Error::InvalidSimulation("`baseFeePerGas` does not fit into u64".to_owned())
+                })
+            })
+            .transpose()?;
+
+        let blocks = payload
+            .block_state_calls
+            .into_iter()
+            .map(|block| {
+                let timestamp = block.block_overrides.and_then(|overrides| overrides.time);
+                let calls = block
+                    .calls
+                    .into_iter()
+                    .map(|mut request| {
+                        request.gas.get_or_insert(default_gas);
+                        L2Tx::from_request(request.into(), self.state.api_config.max_tx_size)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Ok(SimulatedL2Block::for_eth_calls(
+                    timestamp.map(|time| time.as_u64()),
+                    calls,
+                ))
+            })
+            .collect::<Result<Vec<_>, Web3Error>>()?;
+        let tx_hashes: Vec<Vec<H256>> = blocks
+            .iter()
+            .map(|block| block.transactions.iter().map(|tx| tx.hash()).collect())
+            .collect();
+
+        let outputs = self
+            .state
+            .tx_sender
+            .simulate(
+                block_args,
+                CallOverrides { enforced_base_fee },
+                state_override,
+                blocks,
+            )
+            .await?;
+        Ok(outputs
+            .into_iter()
+            .zip(tx_hashes)
+            .map(|(output, tx_hashes)| Self::map_simulated_block(output, &tx_hashes))
+            .collect())
+    }
+
+    fn validate_simulation(&self, payload: &SimulatePayload) -> Result<(), Web3Error> {
+        let blocks = &payload.block_state_calls;
+        if blocks.is_empty() {
+            return Err(Web3Error::InvalidSimulation(
+                "no blocks to simulate".to_owned(),
+            ));
+        }
+        if blocks.len() > MAX_SIMULATED_BLOCKS {
+            return Err(Web3Error::InvalidSimulation(format!(
+                "too many blocks to simulate: {}, max {MAX_SIMULATED_BLOCKS}",
+                blocks.len()
+            )));
+        }
+        let call_count: usize = blocks.iter().map(|block| block.calls.len()).sum();
+        let call_limit = self.state.api_config.req_entities_limit;
+        if call_count > call_limit {
+            return Err(Web3Error::InvalidSimulation(format!(
+                "too many calls to simulate: {call_count}, max {call_limit}"
+            )));
+        }
+
+        // All blocks are simulated in a single L1 batch using a single VM, so state and fee overrides
+        // can only be applied at the start, and block timestamps must increase.
+        let mut prev_timestamp = blocks[0]
+            .block_overrides
+            .as_ref()
+            .and_then(|overrides| overrides.time);
+        for (i, block) in blocks.iter().enumerate().skip(1) {
+            if block.state_overrides.is_some() {
+                return Err(Web3Error::InvalidSimulation(format!(
+                    "state overrides are only supported for the first block, but are specified for block #{i}"
+                )));
+            }
+            let overrides = block.block_overrides.clone().unwrap_or_default();
+            if overrides.base_fee_per_gas.is_some() {
+                return Err(Web3Error::InvalidSimulation(format!(
+                    "`baseFeePerGas` override is only supported for the first block, but is specified for block #{i}"
+                )));
+            }
+
+            prev_timestamp = match (overrides.time, prev_timestamp) {
+                (Some(timestamp), Some(prev)) if timestamp <= prev => {
+                    return Err(Web3Error::InvalidSimulation(format!(
+                        "timestamp of block #{i} ({timestamp}) is not greater than the previous block timestamp ({prev})"
+                    )));
+                }
+                (Some(_), None) => {
+                    return Err(Web3Error::InvalidSimulation(format!(
+                        "timestamp is overridden for block #{i}, but not for the first block"
+                    )));
+                }
+                (Some(timestamp), Some(_)) => Some(timestamp),
+                (None, prev) => prev.map(|prev| prev + 1),
+            };
+        }
+        Ok(())
+    }
+
+    fn map_simulated_block(output: SimulatedL2BlockOutput, tx_hashes: &[H256]) -> SimulatedBlock {
+        let block_number = U64::from(output.env.number);
+        let block_timestamp = U64::from(output.env.timestamp);
+        let mut log_index = 0_u64;
+        let mut block_gas_used = U256::zero();
+        let calls = output
+            .results
+            .into_iter()
+            .zip(tx_hashes)
+            .enumerate()
+            .map(|(tx_index, (result, &tx_hash))| {
+                let logs = result
+                    .logs
+                    .events
+                    .iter()
+                    .map(|event| {
+                        let log = Log {
+                            address: event.address,
+                            topics: event.indexed_topics.clone(),
+                            data: Bytes(event.value.clone()),
+                            block_hash: None,
+                            block_number: Some(block_number),
+                            l1_batch_number: Some(U64::from(event.location.0 .0)),
+                            transaction_hash: Some(tx_hash),
+                            transaction_index: Some(tx_index.into()),
+                            log_index: Some(log_index.into()),
+                            transaction_log_index: None,
+                            log_type: None,
+                            removed: Some(false),
+                            block_timestamp: Some(block_timestamp),
+                        };
+                        log_index += 1;
+                        log
+                    })
+                    .collect();
+
+                let gas_used = U256::from(result.statistics.gas_used);
+                block_gas_used += gas_used;
+                let (status, return_data, error) = match result.result {
+                    ExecutionResult::Success { output } => (1_u64, output, None),
+                    ExecutionResult::Revert { output } => {
+                        let error = SimulatedCallError {
+                            code: 3,
+                            message: output.to_user_friendly_string(),
+                        };
+                        (0, output.encoded_data(), Some(error))
+                    }
+                    ExecutionResult::Halt { reason } => {
+                        let error = SimulatedCallError {
+                            code: -32015,
+                            message: reason.to_string(),
+                        };
+                        (0, vec![], Some(error))
+                    }
+                };
+                SimulatedCall {
+                    status: U64::from(status),
+                    return_data: return_data.into(),
+                    gas_used,
+                    logs,
+                    error,
+                }
+            })
+            .collect();
+
+        SimulatedBlock {
+            number: block_number,
+            timestamp: block_timestamp,
+            gas_used: block_gas_used,
+            calls,
+        }
+    }
+
     pub async fn estimate_gas_impl(
         &self,
         request: CallRequest,
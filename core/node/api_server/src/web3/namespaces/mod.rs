@@ -6,12 +6,14 @@ mod en;
 pub(crate) mod eth;
 mod net;
 mod snapshots;
+mod trace;
+mod txpool;
 mod unstable;
 mod web3;
 mod zks;
 
 pub(super) use self::{
     debug::DebugNamespace, en::EnNamespace, eth::EthNamespace, net::NetNamespace,
-    snapshots::SnapshotsNamespace, unstable::UnstableNamespace, web3::Web3Namespace,
-    zks::ZksNamespace,
+    snapshots::SnapshotsNamespace, trace::TraceNamespace, txpool::TxpoolNamespace,
+    unstable::UnstableNamespace, web3::Web3Namespace, zks::ZksNamespace,
 };
@@ -0,0 +1,256 @@
+use zksync_dal::{blocks_web3_dal::TransactionCallTrace, CoreDal, DalError};
+use zksync_multivm::interface::{Call, CallType};
+use zksync_types::{
+    api::{
+        trace::{
+            LocalizedTrace, TraceAction, TraceCallAction, TraceCallResult, TraceCallType,
+            TraceCreateAction, TraceCreateResult, TraceFilter, TraceOutput, TraceType,
+        },
+        BlockId, BlockNumber,
+    },
+    zk_evm_types::FarCallOpcode,
+    Address, L2BlockNumber, H256,
+};
+use zksync_web3_decl::error::Web3Error;
+
+use crate::web3::{backend_jsonrpsee::MethodTracer, state::RpcState};
+
+/// Maximum number of L2 blocks for which call traces are loaded from the storage at once by `trace_filter`.
+const FILTER_BLOCK_CHUNK_SIZE: u32 = 100;
+
+#[derive(Debug)]
+pub(crate) struct TraceNamespace {
+    state: RpcState,
+}
+
+impl TraceNamespace {
+    pub fn new(state: RpcState) -> Self {
+        Self { state }
+    }
+
+    pub(crate) fn current_method(&self) -> &MethodTracer {
+        &self.state.current_method
+    }
+
+    /// Flattens the call trace of a transaction in the depth-first order.
+    fn flatten_transaction_trace(mut trace: TransactionCallTrace) -> Vec<LocalizedTrace> {
+        let call = std::mem::take(&mut trace.call);
+        let mut traces = vec![];
+        Self::flatten_call(call, &trace, &mut vec![], &mut traces);
+        traces
+    }
+
+    fn flatten_call(
+        call: Call,
+        location: &TransactionCallTrace,
+        trace_address: &mut Vec<usize>,
+        traces: &mut Vec<LocalizedTrace>,
+    ) {
+        let error = match (call.error, call.revert_reason) {
+            (Some(error), _) => Some(error),
+            (None, Some(_)) => Some("Reverted".to_owned()),
+            (None, None) => None,
+        };
+        let (r#type, action, result) = match call.r#type {
+            CallType::Create => {
+                let action = TraceAction::Create(TraceCreateAction {
+                    from: call.from,
+                    gas: call.gas.into(),
+                    init: call.input.into(),
+                    value: call.value,
+                });
+                let result = TraceOutput::Create(TraceCreateResult {
+                    address: call.to,
+                    code: call.output.into(),
+                    gas_used: call.gas_used.into(),
+                });
+                (TraceType::Create, action, result)
+            }
+            CallType::Call(opcode) => {
+                let call_type = match opcode {
+                    FarCallOpcode::Delegate => TraceCallType::DelegateCall,
+                    FarCallOpcode::Normal | FarCallOpcode::Mimic => TraceCallType::Call,
+                };
+                let action = TraceAction::Call(TraceCallAction {
+                    call_type,
+                    from: call.from,
+                    to: call.to,
+                    gas: call.gas.into(),
+                    input: call.input.into(),
+                    value: call.value,
+                });
+                let result = TraceOutput::Call(TraceCallResult {
+                    gas_used: call.gas_used.into(),
+                    output: call.output.into(),
+                });
+                (TraceType::Call, action, result)
+            }
+            CallType::NearCall => unreachable!("We have to filter our near calls before"),
+        };
+
+        traces.push(LocalizedTrace {
+            action,
+            result: error.is_none().then_some(result),
+            error,
+            subtraces: call.calls.len(),
+            trace_address: trace_address.clone(),
+            block_hash: location.l2_block_hash,
+            block_number: location.l2_block_number.0.into(),
+            transaction_hash: location.tx_hash,
+            transaction_position: location.tx_index_in_l2_block.into(),
+            r#type,
+        });
+        for (index, child) in call.calls.into_iter().enumerate() {
+            trace_address.push(index);
+            Self::flatten_call(child, location, trace_address, traces);
+            trace_address.pop();
+        }
+    }
+
+    /// Returns `(from, to)` addresses of the trace used in filtering.
+    fn trace_addresses(trace: &LocalizedTrace) -> (Address, Address) {
+        match (&trace.action, &trace.result) {
+            (TraceAction::Call(action), _) => (action.from, action.to),
+            (TraceAction::Create(action), Some(TraceOutput::Create(result))) => {
+                (action.from, result.address)
+            }
+            // Failed contract creations don't have an associated address.
+            (TraceAction::Create(action), _) => (action.from, Address::zero()),
+        }
+    }
+
+    pub async fn trace_block_impl(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<Vec<LocalizedTrace>, Web3Error> {
+        let block_id = BlockId::Number(block_number);
+        self.current_method().set_block_id(block_id);
+        if matches!(block_number, BlockNumber::Pending) {
+            // See `EthNamespace::get_block_impl()` for an explanation why this check is needed.
+            return Ok(vec![]);
+        }
+
+        let mut connection = self.state.acquire_connection().await?;
+        let block_number = self.state.resolve_block(&mut connection, block_id).await?;
+        self.current_method()
+            .set_block_diff(self.state.last_sealed_l2_block.diff(block_number));
+
+        let tx_traces = connection
+            .blocks_web3_dal()
+            .get_traces_for_l2_block_range(block_number, block_number)
+            .await
+            .map_err(DalError::generalize)?;
+        Ok(tx_traces
+            .into_iter()
+            .flat_map(Self::flatten_transaction_trace)
+            .collect())
+    }
+
+    pub async fn trace_transaction_impl(
+        &self,
+        tx_hash: H256,
+    ) -> Result<Option<Vec<LocalizedTrace>>, Web3Error> {
+        let mut connection = self.state.acquire_connection().await?;
+        let call_trace = connection
+            .transactions_dal()
+            .get_call_trace(tx_hash)
+            .await
+            .map_err(DalError::generalize)?;
+        let Some(call) = call_trace else {
+            return Ok(None);
+        };
+        let receipt = connection
+            .transactions_web3_dal()
+            .get_transaction_receipts(&[tx_hash])
+            .await
+            .map_err(DalError::generalize)?
+            .pop();
+        // Transaction may be reverted on the server in the meantime
+        let Some(receipt) = receipt else {
+            return Ok(None);
+        };
+
+        let trace = TransactionCallTrace {
+            l2_block_number: L2BlockNumber(receipt.block_number.as_u32()),
+            l2_block_hash: receipt.block_hash,
+            tx_hash,
+            tx_index_in_l2_block: receipt.transaction_index.as_u32(),
+            call,
+        };
+        Ok(Some(Self::flatten_transaction_trace(trace)))
+    }
+
+    pub async fn trace_filter_impl(
+        &self,
+        filter: TraceFilter,
+    ) -> Result<Vec<LocalizedTrace>, Web3Error> {
+        let from_block = self
+            .state
+            .resolve_filter_block_number(filter.from_block)
+            .await?;
+        let to_block = self
+            .state
+            .resolve_filter_block_number(filter.to_block)
+            .await?;
+        // There are no traces after the latest sealed block, so there's no point in scanning the range after it.
+        let latest_block = self
+            .state
+            .resolve_filter_block_number(Some(BlockNumber::Latest))
+            .await?;
+        let scanned_to_block = to_block.min(latest_block);
+        let block_range_limit = self.state.api_config.trace_filter_block_range_limit;
+        if scanned_to_block.0.saturating_sub(from_block.0) >= block_range_limit {
+            return Err(Web3Error::BlockRangeLimitExceeded(block_range_limit));
+        }
+
+        let limit = self.state.api_config.req_entities_limit;
+        let count = filter.count.unwrap_or(usize::MAX);
+        let mut skipped_count = 0;
+        let mut traces = vec![];
+        let mut chunk_start = from_block;
+        while chunk_start <= scanned_to_block {
+            let chunk_end = chunk_start.0.saturating_add(FILTER_BLOCK_CHUNK_SIZE - 1);
+            let chunk_end = L2BlockNumber(chunk_end).min(scanned_to_block);
+            let mut connection = self.state.acquire_connection().await?;
+            let tx_traces = connection
+                .blocks_web3_dal()
+                .get_traces_for_l2_block_range(chunk_start, chunk_end)
+                .await
+                .map_err(DalError::generalize)?;
+            drop(connection);
+
+            for tx_trace in tx_traces {
+                let block_number = tx_trace.l2_block_number;
+                let matching_traces = Self::flatten_transaction_trace(tx_trace)
+                    .into_iter()
+                    .filter(|trace| {
+                        let (from, to) = Self::trace_addresses(trace);
+                        filter.matches(from, to)
+                    });
+                for trace in matching_traces {
+                    if skipped_count < filter.after.unwrap_or(0) {
+                        skipped_count += 1;
+                        continue;
+                    }
+                    if traces.len() == count {
+                        return Ok(traces);
+                    }
+                    // Same as for `eth_getLogs`, the limit is not enforced if the range consists of a single block.
+                    if traces.len() == limit && from_block != to_block {
+                        return Err(Web3Error::LogsLimitExceeded(
+                            limit,
+                            from_block.0,
+                            from_block.0.max(block_number.0.saturating_sub(1)),
+                        ));
+                    }
+                    traces.push(trace);
+                }
+            }
+            let Some(next_chunk_start) = chunk_end.0.checked_add(1) else {
+                break;
+            };
+            chunk_start = L2BlockNumber(next_chunk_start);
+        }
+        Ok(traces)
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::{BTreeMap, HashMap};
+
+use zksync_dal::{CoreDal, DalError};
+use zksync_types::{
+    api::{MempoolStats, Transaction, TxpoolContent, TxpoolContentFrom, TxpoolStatus},
+    Address, Nonce,
+};
+use zksync_web3_decl::error::Web3Error;
+
+use crate::web3::{backend_jsonrpsee::MethodTracer, state::RpcState};
+
+#[derive(Debug)]
+pub(crate) struct TxpoolNamespace {
+    state: RpcState,
+}
+
+impl TxpoolNamespace {
+    pub fn new(state: RpcState) -> Self {
+        Self { state }
+    }
+
+    pub(crate) fn current_method(&self) -> &MethodTracer {
+        &self.state.current_method
+    }
+
+    pub async fn status_impl(&self) -> Result<TxpoolStatus, Web3Error> {
+        // On the external node, transactions are proxied to the main node, so are its mempool contents.
+        let tx_sink = self.state.tx_sink();
+        if let Some(status) = tx_sink.lookup_txpool_status().await? {
+            return Ok(status);
+        }
+
+        let mut storage = self.state.acquire_connection().await?;
+        let counts = storage
+            .transactions_web3_dal()
+            .get_pending_l2_transaction_counts()
+            .await
+            .map_err(DalError::generalize)?;
+        let addresses: Vec<_> = counts
+            .iter()
+            .map(|counts| counts.initiator_address)
+            .collect();
+        let stored_nonces = storage
+            .storage_web3_dal()
+            .get_nonces_for_addresses(&addresses)
+            .await
+            .map_err(DalError::generalize)?;
+        drop(storage);
+
+        let (mut pending, mut queued) = (0_u64, 0_u64);
+        for counts in &counts {
+            let stored_nonce = stored_nonces
+                .get(&counts.initiator_address)
+                .copied()
+                .unwrap_or(Nonce(0));
+            // Consistent with `split_by_nonces()`: transactions are pending only if they start from the stored nonce.
+            let account_pending = if counts.min_nonce == stored_nonce {
+                counts.consecutive
+            } else {
+                0
+            };
+            pending += account_pending;
+            queued += counts.total - account_pending;
+        }
+
+        let mempool = self.state.mempool.as_ref().map(|mempool| {
+            let stats = mempool.stats();
+            MempoolStats {
+                l1_transaction_count: (stats.l1_transaction_count as u64).into(),
+                l2_transaction_count: stats.l2_transaction_count.into(),
+                l2_priority_queue_size: (stats.l2_priority_queue_size as u64).into(),
+            }
+        });
+        Ok(TxpoolStatus {
+            pending: pending.into(),
+            queued: queued.into(),
+            mempool,
+        })
+    }
+
+    pub async fn content_impl(&self) -> Result<TxpoolContent<Transaction>, Web3Error> {
+        self.load_content(None).await
+    }
+
+    pub async fn content_from_impl(
+        &self,
+        address: Address,
+    ) -> Result<TxpoolContentFrom, Web3Error> {
+        let mut content = self.load_content(Some(address)).await?;
+        Ok(TxpoolContentFrom {
+            pending: content.pending.remove(&address).unwrap_or_default(),
+            queued: content.queued.remove(&address).unwrap_or_default(),
+            truncated: content.truncated,
+        })
+    }
+
+    pub async fn inspect_impl(&self) -> Result<TxpoolContent<String>, Web3Error> {
+        let content = self.load_content(None).await?;
+        let summarize = |txs: BTreeMap<Address, BTreeMap<u64, Transaction>>| {
+            txs.into_iter()
+                .map(|(address, txs)| {
+                    let txs = txs
+                        .into_iter()
+                        .map(|(nonce, tx)| (nonce, Self::summarize_transaction(&tx)))
+                        .collect();
+                    (address, txs)
+                })
+                .collect()
+        };
+        Ok(TxpoolContent {
+            pending: summarize(content.pending),
+            queued: summarize(content.queued),
+            truncated: content.truncated,
+        })
+    }
+
+    /// Summarizes a transaction in the same format as Geth.
+    fn summarize_transaction(tx: &Transaction) -> String {
+        let recipient = match tx.to {
+            Some(to) => format!("{to:?}"),
+            None => "contract creation".to_owned(),
+        };
+        let gas_price = tx.max_fee_per_gas.or(tx.gas_price).unwrap_or_default();
+        format!(
+            "{recipient}: {} wei + {} gas × {gas_price} wei",
+            tx.value, tx.gas
+        )
+    }
+
+    async fn load_content(
+        &self,
+        initiator_address: Option<Address>,
+    ) -> Result<TxpoolContent<Transaction>, Web3Error> {
+        // On the external node, transactions are proxied to the main node, so are its mempool contents.
+        let tx_sink = self.state.tx_sink();
+        if let Some(content) = tx_sink.lookup_txpool_content(initiator_address).await? {
+            return Ok(content);
+        }
+
+        let limit = self.state.api_config.req_entities_limit;
+        let mut storage = self.state.acquire_connection().await?;
+        // Load an extra transaction to detect whether the contents are truncated.
+        let mut transactions = storage
+            .transactions_web3_dal()
+            .get_pending_l2_transactions(
+                initiator_address,
+                limit + 1,
+                self.state.api_config.l2_chain_id,
+            )
+            .await
+            .map_err(DalError::generalize)?;
+        let truncated = transactions.len() > limit;
+        transactions.truncate(limit);
+        let mut addresses: Vec<_> = transactions.iter().filter_map(|tx| tx.from).collect();
+        addresses.dedup(); // transactions are sorted by the initiator address
+        let stored_nonces = storage
+            .storage_web3_dal()
+            .get_nonces_for_addresses(&addresses)
+            .await
+            .map_err(DalError::generalize)?;
+        drop(storage);
+
+        let mut content = Self::split_by_nonces(transactions, &stored_nonces);
+        content.truncated = truncated;
+        Ok(content)
+    }
+
+    /// Splits transactions into pending and queued ones. A transaction is pending if all nonces between
+    /// the stored account nonce and the transaction nonce are occupied by other pending transactions.
+    /// `transactions` must be sorted by the initiator address and nonce.
+    fn split_by_nonces(
+        transactions: Vec<Transaction>,
+        stored_nonces: &HashMap<Address, Nonce>,
+    ) -> TxpoolContent<Transaction> {
+        let mut content = TxpoolContent::default();
+        let mut current_account: Option<(Address, u64)> = None;
+        for tx in transactions {
+            let Some(address) = tx.from else {
+                continue;
+            };
+            let nonce = tx.nonce.as_u64();
+            if current_account.map_or(true, |(prev_address, _)| prev_address != address) {
+                let stored_nonce = stored_nonces.get(&address).map_or(0, |nonce| nonce.0);
+                current_account = Some((address, stored_nonce.into()));
+            }
+            let (_, next_nonce) = current_account.as_mut().unwrap();
+
+            let txs = if nonce == *next_nonce {
+                *next_nonce += 1;
+                &mut content.pending
+            } else {
+                &mut content.queued
+            };
+            txs.entry(address).or_default().insert(nonce, tx);
+        }
+        content
+    }
+}
@@ -590,4 +590,20 @@ impl ZksNamespace {
             err.into()
         })
     }
+
+    #[tracing::instrument(skip(self, tx_bytes))]
+    pub async fn send_private_raw_transaction_impl(
+        &self,
+        tx_bytes: Bytes,
+    ) -> Result<H256, Web3Error> {
+        let (mut tx, hash) = self.state.parse_transaction_bytes(&tx_bytes.0)?;
+        tx.set_input(tx_bytes.0, hash);
+
+        let submit_result = self.state.tx_sender.submit_private_tx(tx).await;
+        submit_result.map(|_| hash).map_err(|err| {
+            tracing::debug!("Send private raw transaction error: {err}");
+            API_METRICS.submit_tx_error[&err.prom_error_code()].inc();
+            err.into()
+        })
+    }
 }
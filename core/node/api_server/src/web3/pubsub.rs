@@ -1,5 +1,7 @@
 //! (Largely) backend-agnostic logic for dealing with Web3 subscriptions.
 
+use std::{collections::HashMap, ops};
+
 use chrono::NaiveDateTime;
 use futures::FutureExt;
 use tokio::{
@@ -8,8 +10,9 @@ use tokio::{
     time::{interval, Duration},
 };
 use tracing::Instrument as _;
-use zksync_dal::{ConnectionPool, Core, CoreDal};
-use zksync_types::{L2BlockNumber, H128, H256};
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_node_sync::{SyncProgressReceiver, SyncState};
+use zksync_types::{api, web3::SyncInfo, L1BatchNumber, L2BlockNumber, H128, H256};
 use zksync_web3_decl::{
     jsonrpsee::{
         core::{server::SubscriptionMessage, SubscriptionResult},
@@ -18,7 +21,10 @@ use zksync_web3_decl::{
         PendingSubscriptionSink, SendTimeoutError, SubscriptionSink,
     },
     namespaces::EthPubSubServer,
-    types::{BlockHeader, Log, PubSubFilter, PubSubResult},
+    types::{
+        BlockHeader, L1BatchNotification, L1BatchStage, Log, PubSubFilter, PubSubResult,
+        SyncProgress, TxLifecycleStage, TxStatusNotification,
+    },
 };
 
 use super::{
@@ -28,6 +34,9 @@ use super::{
 
 const BROADCAST_CHANNEL_CAPACITY: usize = 1024;
 const SUBSCRIPTION_SINK_SEND_TIMEOUT: Duration = Duration::from_secs(1);
+/// Minimum interval between sync progress notifications sent to a single `syncing` subscriber.
+/// The sync state may be updated on each sealed L2 block, which is too frequent to forward as is.
+const SYNC_PROGRESS_NOTIFY_INTERVAL: Duration = Duration::from_secs(1);
 
 #[derive(Debug, Clone, Copy)]
 pub struct EthSubscriptionIdProvider;
@@ -47,6 +56,85 @@ pub enum PubSubEvent {
     L2BlockAdvanced(SubscriptionType, L2BlockNumber),
 }
 
+/// Last L1 batches that have reached each stage of the L1 batch lifecycle.
+#[derive(Debug, Clone, Copy)]
+struct L1BatchCursor {
+    sealed: L1BatchNumber,
+    committed: L1BatchNumber,
+    proven: L1BatchNumber,
+    executed: L1BatchNumber,
+}
+
+impl L1BatchCursor {
+    /// Loads the current state of the chain. Stages without any L1 batches are set to the L1 batch preceding
+    /// the first locally stored one, so that batches missing in the storage (e.g., after snapshot recovery)
+    /// are never reported.
+    async fn load(storage: &mut Connection<'_, Core>) -> anyhow::Result<Self> {
+        let earliest_l1_batch = storage.blocks_dal().get_earliest_l1_batch_number().await?;
+        let floor = if let Some(number) = earliest_l1_batch {
+            L1BatchNumber(number.0.saturating_sub(1))
+        } else {
+            let snapshot_recovery = storage
+                .snapshot_recovery_dal()
+                .get_applied_snapshot_status()
+                .await?;
+            snapshot_recovery.map_or(L1BatchNumber(0), |recovery| recovery.l1_batch_number)
+        };
+
+        let mut blocks_dal = storage.blocks_dal();
+        let sealed = blocks_dal.get_sealed_l1_batch_number().await?;
+        let committed = blocks_dal
+            .get_number_of_last_l1_batch_committed_on_eth()
+            .await?;
+        let proven = blocks_dal
+            .get_number_of_last_l1_batch_proven_on_eth()
+            .await?;
+        let executed = blocks_dal
+            .get_number_of_last_l1_batch_executed_on_eth()
+            .await?;
+        Ok(Self {
+            sealed: sealed.unwrap_or(floor).max(floor),
+            committed: committed.unwrap_or(floor).max(floor),
+            proven: proven.unwrap_or(floor).max(floor),
+            executed: executed.unwrap_or(floor).max(floor),
+        })
+    }
+
+    /// Returns ranges of L1 batches that have reached each stage since the `prev` cursor.
+    fn new_batches(
+        &self,
+        prev: &Self,
+    ) -> impl Iterator<Item = (L1BatchStage, ops::RangeInclusive<L1BatchNumber>)> {
+        let stages = [
+            (L1BatchStage::Sealed, prev.sealed, self.sealed),
+            (L1BatchStage::Committed, prev.committed, self.committed),
+            (L1BatchStage::Proved, prev.proven, self.proven),
+            (L1BatchStage::Executed, prev.executed, self.executed),
+        ];
+        stages
+            .into_iter()
+            .filter(|(_, prev, current)| current > prev)
+            .map(|(stage, prev, current)| (stage, prev + 1..=current))
+    }
+}
+
+fn eth_tx_hash(details: &api::L1BatchDetails, stage: L1BatchStage) -> Option<H256> {
+    match stage {
+        L1BatchStage::Sealed => None,
+        L1BatchStage::Committed => details.base.commit_tx_hash,
+        L1BatchStage::Proved => details.base.prove_tx_hash,
+        L1BatchStage::Executed => details.base.execute_tx_hash,
+    }
+}
+
+/// Position of the `zks_txStatus` notifier in the chain, i.e. the last L2 block for which transactions were reported
+/// as included, and the last L1 batches for which transactions were reported as committed, proved and executed.
+#[derive(Debug, Clone, Copy)]
+struct TxStatusCursor {
+    last_l2_block: L2BlockNumber,
+    l1_batches: L1BatchCursor,
+}
+
 /// Manager of notifications for a certain type of subscriptions.
 #[derive(Debug)]
 struct PubSubNotifier {
@@ -185,6 +273,243 @@ impl PubSubNotifier {
             .map_err(Into::into)
     }
 
+    async fn notify_tx_statuses(
+        self,
+        mut stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        let Some(last_block_number) = self
+            .get_starting_l2_block_number(&mut stop_receiver)
+            .await?
+        else {
+            tracing::info!("Stop signal received, pubsub_tx_status_notifier is shutting down");
+            return Ok(());
+        };
+        let mut cursor = self.tx_status_cursor(last_block_number).await?;
+        let mut is_cursor_stale = false;
+
+        let mut timer = interval(self.polling_interval);
+        loop {
+            if *stop_receiver.borrow() {
+                tracing::info!("Stop signal received, pubsub_tx_status_notifier is shutting down");
+                break;
+            }
+            timer.tick().await;
+
+            // Loading statuses is relatively expensive, so we skip it if there are no subscribers. Once a subscriber
+            // appears, the cursor is reset to the current chain state so that it doesn't receive stale notifications.
+            if self.sender.receiver_count() == 0 {
+                is_cursor_stale = true;
+                self.emit_event(PubSubEvent::NotifyIterationFinished(
+                    SubscriptionType::TxStatuses,
+                ));
+                continue;
+            }
+
+            let db_latency = PUB_SUB_METRICS.db_poll_latency[&SubscriptionType::TxStatuses].start();
+            if is_cursor_stale {
+                let mut storage = self.connection_pool.connection_tagged("api").await?;
+                let last_block_number = storage
+                    .blocks_dal()
+                    .get_sealed_l2_block_number()
+                    .await?
+                    .unwrap_or(cursor.last_l2_block);
+                drop(storage);
+                cursor = self.tx_status_cursor(last_block_number).await?;
+                is_cursor_stale = false;
+            }
+            let new_statuses = self.new_tx_statuses(&mut cursor).await?;
+            db_latency.observe();
+
+            if !new_statuses.is_empty() {
+                let new_statuses = new_statuses
+                    .into_iter()
+                    .map(PubSubResult::TxStatus)
+                    .collect();
+                self.send_pub_sub_results(new_statuses, SubscriptionType::TxStatuses);
+            }
+            self.emit_event(PubSubEvent::L2BlockAdvanced(
+                SubscriptionType::TxStatuses,
+                cursor.last_l2_block,
+            ));
+            self.emit_event(PubSubEvent::NotifyIterationFinished(
+                SubscriptionType::TxStatuses,
+            ));
+        }
+        Ok(())
+    }
+
+    async fn tx_status_cursor(
+        &self,
+        last_l2_block: L2BlockNumber,
+    ) -> anyhow::Result<TxStatusCursor> {
+        let mut storage = self.connection_pool.connection_tagged("api").await?;
+        Ok(TxStatusCursor {
+            last_l2_block,
+            l1_batches: L1BatchCursor::load(&mut storage).await?,
+        })
+    }
+
+    /// Loads transaction status changes since the `cursor` position and advances the cursor.
+    async fn new_tx_statuses(
+        &self,
+        cursor: &mut TxStatusCursor,
+    ) -> anyhow::Result<Vec<TxStatusNotification>> {
+        let mut storage = self.connection_pool.connection_tagged("api").await?;
+        let mut statuses = vec![];
+
+        let sealed_l2_block = storage.blocks_dal().get_sealed_l2_block_number().await?;
+        if let Some(sealed_l2_block) = sealed_l2_block.filter(|&n| n > cursor.last_l2_block) {
+            let txs = storage
+                .transactions_web3_dal()
+                .get_tx_initiators_for_l2_blocks(cursor.last_l2_block + 1..=sealed_l2_block)
+                .await?;
+            statuses.extend(txs.into_iter().map(|(tx_hash, initiator, l2_block)| {
+                TxStatusNotification {
+                    transaction_hash: tx_hash,
+                    initiator_address: initiator,
+                    stage: TxLifecycleStage::Included,
+                    block_number: l2_block,
+                    l1_batch_number: None,
+                    eth_tx_hash: None,
+                }
+            }));
+            cursor.last_l2_block = sealed_l2_block;
+        }
+
+        let l1_batches = L1BatchCursor::load(&mut storage).await?;
+        for (stage, batches) in l1_batches.new_batches(&cursor.l1_batches) {
+            if stage == L1BatchStage::Sealed {
+                continue;
+            }
+            statuses.extend(Self::l1_tx_statuses(&mut storage, stage, batches).await?);
+        }
+        cursor.l1_batches = l1_batches;
+        Ok(statuses)
+    }
+
+    async fn l1_tx_statuses(
+        storage: &mut Connection<'_, Core>,
+        stage: L1BatchStage,
+        l1_batches: ops::RangeInclusive<L1BatchNumber>,
+    ) -> anyhow::Result<Vec<TxStatusNotification>> {
+        let tx_stage = match stage {
+            L1BatchStage::Sealed => {
+                unreachable!("sealed L1 batches are not reported for transactions")
+            }
+            L1BatchStage::Committed => TxLifecycleStage::Committed,
+            L1BatchStage::Proved => TxLifecycleStage::Proved,
+            L1BatchStage::Executed => TxLifecycleStage::Executed,
+        };
+        let mut eth_tx_hashes = HashMap::new();
+        for number in l1_batches.start().0..=l1_batches.end().0 {
+            let number = L1BatchNumber(number);
+            let details = storage
+                .blocks_web3_dal()
+                .get_l1_batch_details(number)
+                .await?;
+            let tx_hash = details.and_then(|details| eth_tx_hash(&details, stage));
+            eth_tx_hashes.insert(number, tx_hash);
+        }
+
+        let txs = storage
+            .transactions_web3_dal()
+            .get_tx_initiators_for_l1_batches(l1_batches)
+            .await?;
+        Ok(txs
+            .into_iter()
+            .map(
+                |(tx_hash, initiator, l2_block, l1_batch)| TxStatusNotification {
+                    transaction_hash: tx_hash,
+                    initiator_address: initiator,
+                    stage: tx_stage,
+                    block_number: l2_block,
+                    l1_batch_number: Some(l1_batch),
+                    eth_tx_hash: eth_tx_hashes.get(&l1_batch).copied().flatten(),
+                },
+            )
+            .collect())
+    }
+
+    async fn notify_l1_batches(self, stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let mut storage = self.connection_pool.connection_tagged("api").await?;
+        let mut cursor = L1BatchCursor::load(&mut storage).await?;
+        drop(storage);
+        let mut is_cursor_stale = false;
+
+        let mut timer = interval(self.polling_interval);
+        loop {
+            if *stop_receiver.borrow() {
+                tracing::info!("Stop signal received, pubsub_l1_batch_notifier is shutting down");
+                break;
+            }
+            timer.tick().await;
+
+            // Same as for transaction statuses, we skip polling if there are no subscribers and reset the cursor
+            // once a subscriber appears.
+            if self.sender.receiver_count() == 0 {
+                is_cursor_stale = true;
+                self.emit_event(PubSubEvent::NotifyIterationFinished(
+                    SubscriptionType::L1Batches,
+                ));
+                continue;
+            }
+
+            let db_latency = PUB_SUB_METRICS.db_poll_latency[&SubscriptionType::L1Batches].start();
+            if is_cursor_stale {
+                let mut storage = self.connection_pool.connection_tagged("api").await?;
+                cursor = L1BatchCursor::load(&mut storage).await?;
+                drop(storage);
+                is_cursor_stale = false;
+            }
+            let new_l1_batches = self.new_l1_batches(&mut cursor).await?;
+            db_latency.observe();
+
+            if !new_l1_batches.is_empty() {
+                let new_l1_batches = new_l1_batches
+                    .into_iter()
+                    .map(PubSubResult::L1Batch)
+                    .collect();
+                self.send_pub_sub_results(new_l1_batches, SubscriptionType::L1Batches);
+            }
+            self.emit_event(PubSubEvent::NotifyIterationFinished(
+                SubscriptionType::L1Batches,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Loads L1 batch lifecycle changes since the `cursor` position and advances the cursor.
+    async fn new_l1_batches(
+        &self,
+        cursor: &mut L1BatchCursor,
+    ) -> anyhow::Result<Vec<L1BatchNotification>> {
+        let mut storage = self.connection_pool.connection_tagged("api").await?;
+        let l1_batches = L1BatchCursor::load(&mut storage).await?;
+        let mut notifications = vec![];
+        for (stage, batches) in l1_batches.new_batches(cursor) {
+            for number in batches.start().0..=batches.end().0 {
+                let details = storage
+                    .blocks_web3_dal()
+                    .get_l1_batch_details(L1BatchNumber(number))
+                    .await?;
+                // Details may be missing if the batch was pruned or reverted in the meantime.
+                let Some(details) = details else {
+                    continue;
+                };
+                notifications.push(L1BatchNotification {
+                    l1_batch_number: details.number,
+                    stage,
+                    timestamp: details.base.timestamp,
+                    root_hash: details.base.root_hash,
+                    eth_tx_hash: eth_tx_hash(&details, stage),
+                });
+            }
+        }
+        // The cursor is overwritten rather than advanced so that it follows L1 batch reverts.
+        *cursor = l1_batches;
+        Ok(notifications)
+    }
+
     async fn notify_logs(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
         let Some(mut last_block_number) = self
             .get_starting_l2_block_number(&mut stop_receiver)
@@ -236,6 +561,9 @@ pub(super) struct EthSubscribe {
     blocks: broadcast::Sender<Vec<PubSubResult>>,
     transactions: broadcast::Sender<Vec<PubSubResult>>,
     logs: broadcast::Sender<Vec<PubSubResult>>,
+    tx_statuses: broadcast::Sender<Vec<PubSubResult>>,
+    l1_batches: broadcast::Sender<Vec<PubSubResult>>,
+    sync_state: Option<SyncState>,
     events_sender: Option<mpsc::UnboundedSender<PubSubEvent>>,
 }
 
@@ -244,15 +572,26 @@ impl EthSubscribe {
         let (blocks, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
         let (transactions, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
         let (logs, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
+        let (tx_statuses, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
+        let (l1_batches, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
 
         Self {
             blocks,
             transactions,
             logs,
+            tx_statuses,
+            l1_batches,
+            sync_state: None,
             events_sender: None,
         }
     }
 
+    /// Sets the sync state used to report sync progress to `syncing` subscribers. If not set (e.g., on the main node),
+    /// the node is considered to be always synced.
+    pub fn set_sync_state(&mut self, sync_state: SyncState) {
+        self.sync_state = Some(sync_state);
+    }
+
     pub fn set_events_sender(&mut self, sender: mpsc::UnboundedSender<PubSubEvent>) {
         self.events_sender = Some(sender);
     }
@@ -315,6 +654,69 @@ impl EthSubscribe {
         lifetime_latency.observe();
     }
 
+    /// Notifies a `syncing` subscriber about sync progress until the node is synced. Consistently with Geth,
+    /// progress notifications are followed by a single `false` notification once the node catches up,
+    /// after which the subscription is not notified anymore.
+    async fn run_syncing_subscriber(
+        sink: SubscriptionSink,
+        mut receiver: Option<SyncProgressReceiver>,
+    ) {
+        let subscription_type = SubscriptionType::Syncing;
+        let _guard = PUB_SUB_METRICS.active_subscribers[&subscription_type].inc_guard(1);
+        let lifetime_latency = PUB_SUB_METRICS.subscriber_lifetime[&subscription_type].start();
+        let closed = sink.closed().fuse();
+        tokio::pin!(closed);
+
+        let mut starting_block = None;
+        loop {
+            let progress = receiver.as_mut().map(SyncProgressReceiver::progress);
+            let item = match progress {
+                Some(progress) if !progress.is_synced => {
+                    let starting_block = *starting_block.get_or_insert(progress.local_block);
+                    PubSubResult::SyncProgress(SyncProgress {
+                        syncing: true,
+                        status: SyncInfo {
+                            starting_block: starting_block.0.into(),
+                            current_block: progress.local_block.0.into(),
+                            highest_block: progress.main_node_block.0.into(),
+                        },
+                    })
+                }
+                // Either the node is synced, or it's the main node, which is always synced.
+                _ => PubSubResult::Syncing(false),
+            };
+            let is_final = matches!(item, PubSubResult::Syncing(false));
+
+            let handle_result =
+                Self::handle_new_items(&sink, subscription_type, vec![item], None).await;
+            if handle_result.is_err() {
+                PUB_SUB_METRICS.subscriber_send_timeouts[&subscription_type].inc();
+                break;
+            }
+            let Some(receiver) = receiver.as_mut().filter(|_| !is_final) else {
+                break;
+            };
+
+            // Throttle notifications and wait for the sync state to change.
+            let wait_for_change = async {
+                tokio::time::sleep(SYNC_PROGRESS_NOTIFY_INTERVAL).await;
+                receiver.changed().await
+            };
+            tokio::select! {
+                has_changed = wait_for_change => {
+                    if !has_changed {
+                        // The sync state is dropped, which means that the node is shutting down.
+                        break;
+                    }
+                }
+                _ = &mut closed => {
+                    break;
+                }
+            }
+        }
+        lifetime_latency.observe();
+    }
+
     async fn handle_new_items(
         sink: &SubscriptionSink,
         subscription_type: SubscriptionType,
@@ -323,11 +725,14 @@ impl EthSubscribe {
     ) -> Result<(), SendTimeoutError> {
         let notify_latency = PUB_SUB_METRICS.notify_subscribers_latency[&subscription_type].start();
         for item in new_items {
-            if let PubSubResult::Log(log) = &item {
-                if let Some(filter) = &filter {
-                    if !filter.matches(log) {
-                        continue;
-                    }
+            if let Some(filter) = &filter {
+                let matches = match &item {
+                    PubSubResult::Log(log) => filter.matches(log),
+                    PubSubResult::TxStatus(notification) => filter.matches_tx_status(notification),
+                    _ => true,
+                };
+                if !matches {
+                    continue;
                 }
             }
 
@@ -395,19 +800,41 @@ impl EthSubscribe {
                     Some(SubscriptionType::Logs)
                 }
             }
-            "syncing" => {
+            "zks_txStatus" => {
+                let filter = params.unwrap_or_default();
                 let Ok(sink) = pending_sink.accept().await else {
                     return;
                 };
-
-                tokio::spawn(async move {
-                    sink.send_timeout(
-                        SubscriptionMessage::from_json(&PubSubResult::Syncing(false)).unwrap(),
-                        SUBSCRIPTION_SINK_SEND_TIMEOUT,
+                let tx_statuses_rx = self.tx_statuses.subscribe();
+                tokio::spawn(
+                    Self::run_subscriber(
+                        sink,
+                        SubscriptionType::TxStatuses,
+                        tx_statuses_rx,
+                        Some(filter),
                     )
-                    .await
-                });
-                None
+                    .in_current_span(),
+                );
+                Some(SubscriptionType::TxStatuses)
+            }
+            "zks_l1Batches" => {
+                let Ok(sink) = pending_sink.accept().await else {
+                    return;
+                };
+                let l1_batches_rx = self.l1_batches.subscribe();
+                tokio::spawn(
+                    Self::run_subscriber(sink, SubscriptionType::L1Batches, l1_batches_rx, None)
+                        .in_current_span(),
+                );
+                Some(SubscriptionType::L1Batches)
+            }
+            "syncing" => {
+                let Ok(sink) = pending_sink.accept().await else {
+                    return;
+                };
+                let receiver = self.sync_state.as_ref().map(SyncState::subscribe);
+                tokio::spawn(Self::run_syncing_subscriber(sink, receiver).in_current_span());
+                Some(SubscriptionType::Syncing)
             }
             _ => {
                 Self::reject(pending_sink).await;
@@ -429,7 +856,7 @@ impl EthSubscribe {
         polling_interval: Duration,
         stop_receiver: watch::Receiver<bool>,
     ) -> Vec<JoinHandle<anyhow::Result<()>>> {
-        let mut notifier_tasks = Vec::with_capacity(3);
+        let mut notifier_tasks = Vec::with_capacity(5);
 
         let notifier = PubSubNotifier {
             sender: self.blocks.clone(),
@@ -449,6 +876,24 @@ impl EthSubscribe {
         let notifier_task = tokio::spawn(notifier.notify_txs(stop_receiver.clone()));
         notifier_tasks.push(notifier_task);
 
+        let notifier = PubSubNotifier {
+            sender: self.l1_batches.clone(),
+            connection_pool: connection_pool.clone(),
+            polling_interval,
+            events_sender: self.events_sender.clone(),
+        };
+        let notifier_task = tokio::spawn(notifier.notify_l1_batches(stop_receiver.clone()));
+        notifier_tasks.push(notifier_task);
+
+        let notifier = PubSubNotifier {
+            sender: self.tx_statuses.clone(),
+            connection_pool: connection_pool.clone(),
+            polling_interval,
+            events_sender: self.events_sender.clone(),
+        };
+        let notifier_task = tokio::spawn(notifier.notify_tx_statuses(stop_receiver.clone()));
+        notifier_tasks.push(notifier_task);
+
         let notifier = PubSubNotifier {
             sender: self.logs.clone(),
             connection_pool,
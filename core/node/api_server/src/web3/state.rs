@@ -19,6 +19,7 @@ use zksync_config::{
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal, DalError};
 use zksync_metadata_calculator::api_server::TreeApiClient;
 use zksync_node_sync::SyncState;
+use zksync_state_keeper::MempoolGuard;
 use zksync_types::{
     api, commitment::L1BatchCommitmentMode, l2::L2Tx, transaction_request::CallRequest, Address,
     L1BatchNumber, L1ChainId, L2BlockNumber, L2ChainId, H256, U256, U64,
@@ -109,6 +110,7 @@ pub struct InternalApiConfig {
     pub l2_testnet_paymaster_addr: Option<Address>,
     pub req_entities_limit: usize,
     pub fee_history_limit: u64,
+    pub trace_filter_block_range_limit: u32,
     pub base_token_address: Option<Address>,
     pub filters_disabled: bool,
     pub dummy_verifier: bool,
@@ -160,6 +162,7 @@ impl InternalApiConfig {
             l2_testnet_paymaster_addr: contracts_config.l2_testnet_paymaster_addr,
             req_entities_limit: web3_config.req_entities_limit(),
             fee_history_limit: web3_config.fee_history_limit(),
+            trace_filter_block_range_limit: web3_config.trace_filter_block_range_limit(),
             base_token_address: contracts_config.base_token_addr,
             filters_disabled: web3_config.filters_disabled,
             dummy_verifier: genesis_config.dummy_verifier,
@@ -255,6 +258,8 @@ pub(crate) struct RpcState {
     /// from a snapshot.
     pub(super) start_info: BlockStartInfo,
     pub(super) mempool_cache: Option<MempoolCache>,
+    /// State keeper mempool; only available on the main node if the state keeper runs in the same process.
+    pub(super) mempool: Option<MempoolGuard>,
     pub(super) last_sealed_l2_block: SealedL2BlockNumber,
 }
 
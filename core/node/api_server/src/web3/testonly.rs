@@ -106,13 +106,16 @@ pub async fn spawn_http_server(
     method_tracer: Arc<MethodTracer>,
     stop_receiver: watch::Receiver<bool>,
 ) -> ApiServerHandles {
+    let options = TestServerOptions {
+        tx_executor,
+        method_tracer,
+        ..TestServerOptions::default()
+    };
     spawn_server(
         ApiTransportLabel::Http,
         api_config,
         pool,
-        None,
-        tx_executor,
-        method_tracer,
+        options,
         stop_receiver,
     )
     .await
@@ -124,36 +127,78 @@ pub async fn spawn_ws_server(
     pool: ConnectionPool<Core>,
     stop_receiver: watch::Receiver<bool>,
     websocket_requests_per_minute_limit: Option<NonZeroU32>,
+    sync_state: Option<SyncState>,
 ) -> (ApiServerHandles, mpsc::UnboundedReceiver<PubSubEvent>) {
+    let options = TestServerOptions {
+        websocket_requests_per_minute_limit,
+        sync_state,
+        ..TestServerOptions::default()
+    };
     spawn_server(
         ApiTransportLabel::Ws,
         api_config,
         pool,
-        websocket_requests_per_minute_limit,
-        MockOneshotExecutor::default(),
-        Arc::default(),
+        options,
         stop_receiver,
     )
     .await
 }
 
-async fn spawn_server(
+/// Spawns an HTTP or WS server using the specified compute unit limiter.
+#[cfg(test)]
+pub(crate) async fn spawn_server_with_compute_unit_limiter(
     transport: ApiTransportLabel,
     api_config: InternalApiConfig,
     pool: ConnectionPool<Core>,
+    compute_unit_limiter: ComputeUnitLimiter,
+    stop_receiver: watch::Receiver<bool>,
+) -> ApiServerHandles {
+    let options = TestServerOptions {
+        compute_unit_limiter: Some(compute_unit_limiter),
+        ..TestServerOptions::default()
+    };
+    spawn_server(transport, api_config, pool, options, stop_receiver)
+        .await
+        .0
+}
+
+/// Optional server parameters used in tests.
+#[derive(Default)]
+struct TestServerOptions {
     websocket_requests_per_minute_limit: Option<NonZeroU32>,
+    compute_unit_limiter: Option<ComputeUnitLimiter>,
+    sync_state: Option<SyncState>,
     tx_executor: MockOneshotExecutor,
     method_tracer: Arc<MethodTracer>,
+}
+
+async fn spawn_server(
+    transport: ApiTransportLabel,
+    api_config: InternalApiConfig,
+    pool: ConnectionPool<Core>,
+    options: TestServerOptions,
     stop_receiver: watch::Receiver<bool>,
 ) -> (ApiServerHandles, mpsc::UnboundedReceiver<PubSubEvent>) {
+    let TestServerOptions {
+        websocket_requests_per_minute_limit,
+        compute_unit_limiter,
+        sync_state,
+        tx_executor,
+        method_tracer,
+    } = options;
     let (tx_sender, vm_barrier) =
         create_test_tx_sender(pool.clone(), api_config.l2_chain_id, tx_executor.into()).await;
     let (pub_sub_events_sender, pub_sub_events_receiver) = mpsc::unbounded_channel();
 
     let mut namespaces = Namespace::DEFAULT.to_vec();
-    namespaces.extend([Namespace::Debug, Namespace::Snapshots]);
+    namespaces.extend([
+        Namespace::Debug,
+        Namespace::Snapshots,
+        Namespace::Txpool,
+        Namespace::Trace,
+    ]);
 
-    let server_builder = match transport {
+    let mut server_builder = match transport {
         ApiTransportLabel::Http => ApiBuilder::jsonrpsee_backend(api_config, pool).http(0),
         ApiTransportLabel::Ws => {
             let mut builder = ApiBuilder::jsonrpsee_backend(api_config, pool)
@@ -166,6 +211,12 @@ async fn spawn_server(
             builder
         }
     };
+    if let Some(sync_state) = sync_state {
+        server_builder = server_builder.with_sync_state(sync_state);
+    }
+    if let Some(compute_unit_limiter) = compute_unit_limiter {
+        server_builder = server_builder.with_compute_unit_limiter(compute_unit_limiter);
+    }
     let server_handles = server_builder
         .with_polling_interval(POLL_INTERVAL)
         .with_tx_sender(tx_sender)
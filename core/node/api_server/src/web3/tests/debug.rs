@@ -1,7 +1,14 @@
 //! Tests for the `debug` Web3 namespace.
 
-use zksync_multivm::interface::{Call, TransactionExecutionResult};
-use zksync_types::BOOTLOADER_ADDRESS;
+use std::sync::Mutex;
+
+use zksync_contracts::BaseSystemContractsHashes;
+use zksync_multivm::interface::{
+    Call, CallType, ExecutionResult, TransactionExecutionResult, TxExecutionMode, VmEvent,
+};
+use zksync_types::{
+    tx::IncludedTxLocation, zk_evm_types::FarCallOpcode, BOOTLOADER_ADDRESS, EVENT_WRITER_ADDRESS,
+};
 use zksync_web3_decl::{
     client::{DynClient, L2},
     namespaces::DebugNamespaceClient,
@@ -62,7 +69,7 @@ impl HttpTest for TraceBlockTest {
 
             assert_eq!(block_traces.len(), tx_results.len()); // equals to the number of transactions in the block
             for (trace, tx_result) in block_traces.iter().zip(&tx_results) {
-                let api::ResultDebugCall { result } = trace;
+                let result = trace.result.clone().into_call_trace().unwrap();
                 assert_eq!(result.from, Address::zero());
                 assert_eq!(result.to, BOOTLOADER_ADDRESS);
                 assert_eq!(result.gas, tx_result.transaction.gas_limit());
@@ -204,7 +211,9 @@ impl HttpTest for TraceTransactionTest {
         let result = client
             .trace_transaction(tx_results[0].hash, None)
             .await?
-            .context("no transaction traces")?;
+            .context("no transaction traces")?
+            .into_call_trace()
+            .context("unexpected tracer result")?;
         assert_eq!(result.from, Address::zero());
         assert_eq!(result.to, BOOTLOADER_ADDRESS);
         assert_eq!(result.gas, tx_results[0].transaction.gas_limit());
@@ -219,6 +228,199 @@ async fn tracing_transaction() {
     test_http_server(TraceTransactionTest).await;
 }
 
+#[derive(Debug)]
+struct TraceTransactionWithMuxTracerTest;
+
+#[async_trait]
+impl HttpTest for TraceTransactionWithMuxTracerTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let contract_address = Address::repeat_byte(0x22);
+        let event_writer_call = Call {
+            from: contract_address,
+            to: EVENT_WRITER_ADDRESS,
+            ..Call::default()
+        };
+        let nested_call = Call {
+            r#type: CallType::Call(FarCallOpcode::Delegate),
+            from: contract_address,
+            to: Address::repeat_byte(0x33),
+            input: vec![0x0a, 0x0b, 0x0c, 0x0d],
+            ..Call::default()
+        };
+        let contract_call = Call {
+            from: Address::repeat_byte(0x11),
+            to: contract_address,
+            input: [0x01, 0x02, 0x03, 0x04]
+                .into_iter()
+                .chain([0; 32])
+                .collect(),
+            calls: vec![nested_call, event_writer_call],
+            ..Call::default()
+        };
+        let tx_result = TransactionExecutionResult {
+            call_traces: vec![contract_call],
+            ..execute_l2_transaction(create_l2_transaction(1, 2))
+        };
+
+        let mut storage = pool.connection().await?;
+        store_l2_block(&mut storage, L2BlockNumber(1), &[tx_result.clone()]).await?;
+        let tx_location = IncludedTxLocation {
+            tx_hash: tx_result.hash,
+            tx_index_in_l2_block: 0,
+            tx_initiator_address: tx_result.transaction.initiator_account(),
+        };
+        let event = VmEvent {
+            location: (L1BatchNumber(1), 0),
+            address: contract_address,
+            indexed_topics: vec![H256::repeat_byte(0x42)],
+            value: b"event".to_vec(),
+        };
+        storage
+            .events_dal()
+            .save_events(L2BlockNumber(1), &[(tx_location, vec![&event])])
+            .await?;
+        drop(storage);
+
+        let config = api::TracerConfig::MuxTracer(
+            [
+                (
+                    api::SupportedTracers::CallTracer,
+                    api::TracerConfig::CallTracer(api::CallTracerConfig {
+                        only_top_call: false,
+                        with_log: true,
+                    }),
+                ),
+                (
+                    api::SupportedTracers::FourByteTracer,
+                    api::TracerConfig::FourByteTracer,
+                ),
+            ]
+            .into(),
+        );
+        let result = client
+            .trace_transaction(tx_result.hash, Some(config))
+            .await?
+            .context("no transaction traces")?;
+        let api::TracerResult::Mux(mut results) = result else {
+            panic!("Unexpected tracer result: {result:?}");
+        };
+
+        let four_byte = results.remove(&api::SupportedTracers::FourByteTracer);
+        let Some(api::TracerResult::FourByte(four_byte)) = four_byte else {
+            panic!("Unexpected 4byte tracer result: {four_byte:?}");
+        };
+        // The delegate call must be counted as well.
+        let expected_four_byte = [
+            ("0x01020304-32".to_owned(), 1),
+            ("0x0a0b0c0d-0".to_owned(), 1),
+        ];
+        assert_eq!(four_byte, expected_four_byte.into());
+
+        let call = results
+            .remove(&api::SupportedTracers::CallTracer)
+            .and_then(api::TracerResult::into_call_trace)
+            .context("no call trace")?;
+        assert_eq!(call.to, BOOTLOADER_ADDRESS);
+        assert!(call.logs.is_empty());
+        let contract_call = &call.calls[0];
+        assert_eq!(contract_call.to, contract_address);
+        assert_eq!(
+            contract_call.logs,
+            [api::DebugCallLog {
+                address: contract_address,
+                topics: event.indexed_topics.clone(),
+                data: event.value.clone().into(),
+                position: 1.into(),
+            }]
+        );
+        assert!(contract_call.calls.iter().all(|call| call.logs.is_empty()));
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn tracing_transaction_with_mux_tracer() {
+    test_http_server(TraceTransactionWithMuxTracerTest).await;
+}
+
+/// Parameters of the environment used to replay a transaction.
+type ReplayEnv = (Address, BaseSystemContractsHashes, TxExecutionMode);
+
+#[derive(Debug, Default)]
+struct TraceTransactionWithPrestateTracerTest {
+    replay_envs: Arc<Mutex<Vec<ReplayEnv>>>,
+}
+
+impl TraceTransactionWithPrestateTracerTest {
+    const FEE_ACCOUNT: Address = Address::repeat_byte(0xfe);
+}
+
+#[async_trait]
+impl HttpTest for TraceTransactionWithPrestateTracerTest {
+    fn transaction_executor(&self) -> MockOneshotExecutor {
+        let replay_envs = self.replay_envs.clone();
+        let mut executor = MockOneshotExecutor::default();
+        executor.set_tx_responses(move |_, env| {
+            replay_envs.lock().unwrap().push((
+                env.l1_batch.fee_account,
+                env.system.base_system_smart_contracts.hashes(),
+                env.system.execution_mode,
+            ));
+            ExecutionResult::Success { output: vec![] }
+        });
+        executor
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let mut storage = pool.connection().await?;
+        let genesis_block = storage
+            .blocks_dal()
+            .get_l2_block_header(L2BlockNumber(0))
+            .await?
+            .context("no genesis L2 block")?;
+        let tx_result = execute_l2_transaction(create_l2_transaction(1, 2));
+        let block_header = L2BlockHeader {
+            fee_account_address: Self::FEE_ACCOUNT,
+            base_system_contracts_hashes: genesis_block.base_system_contracts_hashes,
+            ..create_l2_block(1)
+        };
+        store_custom_l2_block(&mut storage, &block_header, &[tx_result.clone()]).await?;
+        drop(storage);
+
+        let config = api::TracerConfig::PrestateTracer(api::PrestateTracerConfig::default());
+        let result = client
+            .trace_transaction(tx_result.hash, Some(config))
+            .await?
+            .context("no transaction traces")?;
+        assert_matches!(result, api::TracerResult::Prestate(_));
+
+        // The transaction must be replayed in the same environment as it was executed by the state keeper.
+        let replay_envs = self.replay_envs.lock().unwrap();
+        assert_eq!(
+            *replay_envs,
+            [(
+                Self::FEE_ACCOUNT,
+                genesis_block.base_system_contracts_hashes,
+                TxExecutionMode::VerifyExecute,
+            )]
+        );
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn tracing_transaction_with_prestate_tracer() {
+    test_http_server(TraceTransactionWithPrestateTracerTest::default()).await;
+}
+
 #[derive(Debug)]
 struct TraceBlockTestWithSnapshotRecovery;
 
@@ -64,7 +64,10 @@ use crate::{
 
 mod debug;
 mod filters;
+mod rate_limit;
 mod snapshots;
+mod trace;
+mod txpool;
 mod vm;
 mod ws;
 
@@ -153,6 +156,11 @@ trait HttpTest: Send + Sync {
     fn filters_disabled(&self) -> bool {
         false
     }
+
+    /// Overrides the Web3 JSON-RPC configuration for HTTP server startup.
+    fn web3_config(&self) -> Web3JsonRpcConfig {
+        Web3JsonRpcConfig::for_tests()
+    }
 }
 
 /// Storage initialization strategy.
@@ -242,7 +250,7 @@ async fn test_http_server(test: impl HttpTest) {
 
     let (stop_sender, stop_receiver) = watch::channel(false);
     let contracts_config = ContractsConfig::for_tests();
-    let web3_config = Web3JsonRpcConfig::for_tests();
+    let web3_config = test.web3_config();
     let genesis = GenesisConfig::for_tests();
     let mut api_config = InternalApiConfig::new(&web3_config, &contracts_config, &genesis);
     api_config.filters_disabled = test.filters_disabled();
@@ -307,6 +315,17 @@ async fn store_l2_block(
     number: L2BlockNumber,
     transaction_results: &[TransactionExecutionResult],
 ) -> anyhow::Result<L2BlockHeader> {
+    let header = create_l2_block(number.0);
+    store_custom_l2_block(storage, &header, transaction_results).await?;
+    Ok(header)
+}
+
+async fn store_custom_l2_block(
+    storage: &mut Connection<'_, Core>,
+    header: &L2BlockHeader,
+    transaction_results: &[TransactionExecutionResult],
+) -> anyhow::Result<()> {
+    let number = header.number;
     for result in transaction_results {
         let l2_tx = result.transaction.clone().try_into().unwrap();
         let tx_submission_result = storage
@@ -329,19 +348,18 @@ async fn store_l2_block(
         .append_storage_logs(number, &[l2_block_log])
         .await?;
 
-    let new_l2_block = create_l2_block(number.0);
-    storage.blocks_dal().insert_l2_block(&new_l2_block).await?;
+    storage.blocks_dal().insert_l2_block(header).await?;
     storage
         .transactions_dal()
         .mark_txs_as_executed_in_l2_block(
-            new_l2_block.number,
+            number,
             transaction_results,
             1.into(),
             ProtocolVersionId::latest(),
             false,
         )
         .await?;
-    Ok(new_l2_block)
+    Ok(())
 }
 
 async fn seal_l1_batch(
@@ -0,0 +1,157 @@
+//! Tests for compute unit limits applied to real HTTP and WS connections.
+
+use std::net::IpAddr;
+
+use http::{HeaderMap, HeaderValue};
+use zksync_config::configs::api::{ComputeUnitLimits, ComputeUnitsByName};
+use zksync_web3_decl::jsonrpsee::{
+    http_client::HttpClientBuilder,
+    ws_client::{WsClient, WsClientBuilder},
+};
+
+use super::*;
+use crate::web3::{
+    backend_jsonrpsee::{ComputeUnitLimiter, RATE_LIMIT_ERROR_CODE},
+    metrics::ApiTransportLabel,
+    testonly::spawn_server_with_compute_unit_limiter,
+};
+
+fn limits(trusted_proxies: Vec<IpAddr>) -> ComputeUnitLimits {
+    ComputeUnitLimits {
+        per_client: NonZeroU32::new(100),
+        per_api_key: ComputeUnitsByName::empty(),
+        // Allows 2 calls per minute.
+        method_costs: [("eth_chainId", NonZeroU32::new(40).unwrap())]
+            .into_iter()
+            .collect(),
+        trusted_proxies,
+    }
+}
+
+fn forwarded_for(ip: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert("x-forwarded-for", HeaderValue::from_str(ip).unwrap());
+    headers
+}
+
+async fn call_chain_id(client: &impl ClientT) -> Result<U64, ClientError> {
+    client.request("eth_chainId", rpc_params![]).await
+}
+
+fn assert_rate_limited(result: Result<U64, ClientError>) {
+    assert_matches!(
+        result,
+        Err(ClientError::Call(err)) if err.code() == RATE_LIMIT_ERROR_CODE
+    );
+}
+
+/// Test fixture with HTTP and WS servers sharing a single compute unit limiter.
+struct TestServers {
+    http_addr: SocketAddr,
+    ws_addr: SocketAddr,
+    stop_sender: watch::Sender<bool>,
+    handles: Vec<ApiServerHandles>,
+}
+
+impl TestServers {
+    async fn spawn(limits: &ComputeUnitLimits) -> Self {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        StorageInitialization::Genesis
+            .prepare_storage(&NetworkConfig::for_tests(), &mut storage)
+            .await
+            .unwrap();
+        drop(storage);
+
+        let limiter = ComputeUnitLimiter::new(limits);
+        let api_config = InternalApiConfig::new(
+            &Web3JsonRpcConfig::for_tests(),
+            &ContractsConfig::for_tests(),
+            &GenesisConfig::for_tests(),
+        );
+        let (stop_sender, stop_receiver) = watch::channel(false);
+        let mut handles = vec![];
+        let mut addrs = vec![];
+        for transport in [ApiTransportLabel::Http, ApiTransportLabel::Ws] {
+            let mut server_handles = spawn_server_with_compute_unit_limiter(
+                transport,
+                api_config.clone(),
+                pool.clone(),
+                limiter.clone(),
+                stop_receiver.clone(),
+            )
+            .await;
+            addrs.push(server_handles.wait_until_ready().await);
+            handles.push(server_handles);
+        }
+
+        Self {
+            http_addr: addrs[0],
+            ws_addr: addrs[1],
+            stop_sender,
+            handles,
+        }
+    }
+
+    fn http_client(&self, headers: HeaderMap) -> HttpClient {
+        HttpClientBuilder::default()
+            .set_headers(headers)
+            .build(format!("http://{}/", self.http_addr))
+            .unwrap()
+    }
+
+    async fn ws_client(&self, headers: HeaderMap) -> WsClient {
+        WsClientBuilder::default()
+            .set_headers(headers)
+            .build(format!("ws://{}", self.ws_addr))
+            .await
+            .unwrap()
+    }
+
+    async fn shutdown(self) {
+        self.stop_sender.send_replace(true);
+        for handles in self.handles {
+            handles.shutdown().await;
+        }
+    }
+}
+
+#[tokio::test]
+async fn compute_units_are_charged_per_connection_peer() {
+    let servers = TestServers::spawn(&limits(vec![])).await;
+
+    let client = servers.http_client(HeaderMap::new());
+    call_chain_id(&client).await.unwrap();
+    call_chain_id(&client).await.unwrap();
+    assert_rate_limited(call_chain_id(&client).await);
+
+    // Forwarding headers must be ignored since the client is not a trusted proxy.
+    let spoofing_client = servers.http_client(forwarded_for("10.0.0.1"));
+    assert_rate_limited(call_chain_id(&spoofing_client).await);
+
+    // The budget is shared among HTTP and WS servers.
+    let ws_client = servers.ws_client(HeaderMap::new()).await;
+    assert_rate_limited(call_chain_id(&ws_client).await);
+
+    servers.shutdown().await;
+}
+
+#[tokio::test]
+async fn compute_units_are_charged_per_forwarded_ip_for_trusted_proxies() {
+    let trusted_proxies = vec![Ipv4Addr::LOCALHOST.into()];
+    let servers = TestServers::spawn(&limits(trusted_proxies)).await;
+
+    let client = servers.http_client(forwarded_for("10.0.0.1"));
+    call_chain_id(&client).await.unwrap();
+    call_chain_id(&client).await.unwrap();
+    assert_rate_limited(call_chain_id(&client).await);
+
+    let other_client = servers.http_client(forwarded_for("10.0.0.2"));
+    call_chain_id(&other_client).await.unwrap();
+    // The client on the other transport shares the budget with `other_client`.
+    let other_ws_client = servers.ws_client(forwarded_for("10.0.0.2")).await;
+    call_chain_id(&other_ws_client).await.unwrap();
+    assert_rate_limited(call_chain_id(&other_ws_client).await);
+
+    servers.shutdown().await;
+}
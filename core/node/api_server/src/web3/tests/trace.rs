@@ -0,0 +1,213 @@
+//! Tests for the `trace` Web3 namespace.
+
+use zksync_multivm::interface::{Call, CallType, TransactionExecutionResult};
+use zksync_types::{
+    api::trace::{TraceAction, TraceCallType, TraceFilter},
+    zk_evm_types::FarCallOpcode,
+    BOOTLOADER_ADDRESS,
+};
+use zksync_web3_decl::namespaces::TraceNamespaceClient;
+
+use super::*;
+
+fn execute_l2_transaction_with_traces(call_traces: Vec<Call>) -> TransactionExecutionResult {
+    TransactionExecutionResult {
+        call_traces,
+        ..execute_l2_transaction(create_l2_transaction(1, 2))
+    }
+}
+
+fn call_addresses(action: &TraceAction) -> (Address, Address) {
+    match action {
+        TraceAction::Call(action) => (action.from, action.to),
+        TraceAction::Create(_) => panic!("Unexpected action: {action:?}"),
+    }
+}
+
+#[derive(Debug)]
+struct TraceBasicsTest;
+
+#[async_trait]
+impl HttpTest for TraceBasicsTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let delegate_call = Call {
+            r#type: CallType::Call(FarCallOpcode::Delegate),
+            from: Address::repeat_byte(0x22),
+            to: Address::repeat_byte(0x33),
+            ..Call::default()
+        };
+        let first_tx_result = execute_l2_transaction_with_traces(vec![Call {
+            from: Address::repeat_byte(0x11),
+            to: Address::repeat_byte(0x22),
+            calls: vec![delegate_call],
+            ..Call::default()
+        }]);
+        let second_tx_result = execute_l2_transaction_with_traces(vec![Call {
+            from: Address::repeat_byte(0x11),
+            to: Address::repeat_byte(0x33),
+            revert_reason: Some("oops".to_owned()),
+            ..Call::default()
+        }]);
+
+        let mut storage = pool.connection().await?;
+        let first_block =
+            store_l2_block(&mut storage, L2BlockNumber(1), &[first_tx_result.clone()]).await?;
+        store_l2_block(&mut storage, L2BlockNumber(2), &[second_tx_result.clone()]).await?;
+        drop(storage);
+
+        let block_traces = client.block(api::BlockNumber::Number(1.into())).await?;
+        assert_eq!(block_traces.len(), 3, "{block_traces:?}");
+        for trace in &block_traces {
+            assert_eq!(trace.block_hash, first_block.hash);
+            assert_eq!(trace.block_number, 1.into());
+            assert_eq!(trace.transaction_hash, first_tx_result.hash);
+            assert_eq!(trace.transaction_position, 0.into());
+        }
+        let trace_addresses: Vec<_> = block_traces
+            .iter()
+            .map(|trace| trace.trace_address.clone())
+            .collect();
+        assert_eq!(trace_addresses, [vec![], vec![0], vec![0, 0]]);
+        assert_eq!(
+            call_addresses(&block_traces[0].action),
+            (Address::zero(), BOOTLOADER_ADDRESS)
+        );
+        assert_eq!(block_traces[0].subtraces, 1);
+        let TraceAction::Call(delegate_action) = &block_traces[2].action else {
+            panic!("Unexpected trace: {:?}", block_traces[2]);
+        };
+        assert_eq!(delegate_action.call_type, TraceCallType::DelegateCall);
+
+        let tx_traces = client
+            .transaction(second_tx_result.hash)
+            .await?
+            .context("no transaction traces")?;
+        assert_eq!(tx_traces.len(), 2, "{tx_traces:?}");
+        assert_eq!(tx_traces[1].error.as_deref(), Some("Reverted"));
+        assert_eq!(tx_traces[1].result, None);
+        assert_eq!(tx_traces[1].block_number, 2.into());
+
+        let missing_tx_traces = client.transaction(H256::repeat_byte(0xff)).await?;
+        assert_eq!(missing_tx_traces, None);
+
+        let filter = TraceFilter {
+            from_block: Some(api::BlockNumber::Number(1.into())),
+            to_block: Some(api::BlockNumber::Latest),
+            to_address: Some(vec![Address::repeat_byte(0x33)]),
+            ..TraceFilter::default()
+        };
+        let filtered_traces = client.filter(filter.clone()).await?;
+        let filtered_addresses: Vec<_> = filtered_traces
+            .iter()
+            .map(|trace| (trace.block_number, trace.trace_address.clone()))
+            .collect();
+        assert_eq!(
+            filtered_addresses,
+            [(1.into(), vec![0, 0]), (2.into(), vec![0])]
+        );
+
+        let paginated_traces = client
+            .filter(TraceFilter {
+                after: Some(1),
+                ..filter.clone()
+            })
+            .await?;
+        assert_eq!(paginated_traces, filtered_traces[1..]);
+        let paginated_traces = client
+            .filter(TraceFilter {
+                count: Some(1),
+                ..filter.clone()
+            })
+            .await?;
+        assert_eq!(paginated_traces, filtered_traces[..1]);
+
+        let traces_from_address = client
+            .filter(TraceFilter {
+                from_address: Some(vec![Address::repeat_byte(0x22)]),
+                ..filter
+            })
+            .await?;
+        assert_eq!(traces_from_address, filtered_traces[..1]);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn trace_basics() {
+    test_http_server(TraceBasicsTest).await;
+}
+
+#[derive(Debug)]
+struct TraceFilterBlockRangeLimitTest;
+
+#[async_trait]
+impl HttpTest for TraceFilterBlockRangeLimitTest {
+    fn web3_config(&self) -> Web3JsonRpcConfig {
+        Web3JsonRpcConfig {
+            trace_filter_block_range_limit: Some(2),
+            ..Web3JsonRpcConfig::for_tests()
+        }
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let mut storage = pool.connection().await?;
+        for number in 1..=3 {
+            let tx_result = execute_l2_transaction_with_traces(vec![Call {
+                from: Address::repeat_byte(0x11),
+                to: Address::repeat_byte(0x22),
+                ..Call::default()
+            }]);
+            store_l2_block(&mut storage, L2BlockNumber(number), &[tx_result]).await?;
+        }
+        drop(storage);
+
+        let filter = TraceFilter {
+            from_block: Some(api::BlockNumber::Number(2.into())),
+            to_block: Some(api::BlockNumber::Latest),
+            ..TraceFilter::default()
+        };
+        let traces = client.filter(filter.clone()).await?;
+        let block_numbers: Vec<_> = traces.iter().map(|trace| trace.block_number).collect();
+        assert_eq!(block_numbers, [2.into(), 2.into(), 3.into(), 3.into()]);
+
+        // The range is capped by the latest block, so a far-off `to_block` is fine.
+        let traces_with_distant_end = client
+            .filter(TraceFilter {
+                to_block: Some(api::BlockNumber::Number(1_000.into())),
+                ..filter.clone()
+            })
+            .await?;
+        assert_eq!(traces_with_distant_end, traces);
+
+        let err = client
+            .filter(TraceFilter {
+                from_block: Some(api::BlockNumber::Number(1.into())),
+                ..filter
+            })
+            .await
+            .unwrap_err();
+        if let ClientError::Call(err) = err {
+            assert_eq!(err.code(), ErrorCode::InvalidParams.code());
+            assert!(
+                err.message().contains("Block range is too large"),
+                "{err:?}"
+            );
+        } else {
+            panic!("Unexpected error: {err:?}");
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn trace_filter_block_range_limit() {
+    test_http_server(TraceFilterBlockRangeLimitTest).await;
+}
@@ -0,0 +1,136 @@
+//! Tests for the `txpool` Web3 namespace.
+
+use std::collections::BTreeMap;
+
+use zksync_web3_decl::namespaces::TxpoolNamespaceClient;
+
+use super::*;
+
+#[derive(Debug)]
+struct TxpoolBasicsTest;
+
+#[async_trait]
+impl HttpTest for TxpoolBasicsTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let status = client.status().await?;
+        assert_eq!(status.pending, 0.into());
+        assert_eq!(status.queued, 0.into());
+        assert_eq!(status.mempool, None);
+
+        let pending_tx = create_l2_transaction(1, 2);
+        let initiator = pending_tx.initiator_account();
+        let mut queued_tx = pending_tx.clone();
+        // Changing transaction fields invalidates its signature, but it's OK for test purposes
+        queued_tx.common_data.nonce = Nonce(2);
+        queued_tx.set_input(H256::random().0.to_vec(), H256::random());
+
+        let mut storage = pool.connection().await?;
+        for tx in [&pending_tx, &queued_tx] {
+            storage
+                .transactions_dal()
+                .insert_transaction_l2(tx, TransactionExecutionMetrics::default())
+                .await?;
+        }
+        drop(storage);
+
+        let status = client.status().await?;
+        assert_eq!(status.pending, 1.into());
+        assert_eq!(status.queued, 1.into());
+
+        let content = client.content().await?;
+        assert_eq!(content.pending.len(), 1);
+        assert_eq!(content.pending[&initiator][&0].hash, pending_tx.hash());
+        assert_eq!(content.queued[&initiator][&2].hash, queued_tx.hash());
+
+        let content = client.content_from(initiator).await?;
+        assert_eq!(content.pending[&0].hash, pending_tx.hash());
+        assert_eq!(content.queued[&2].hash, queued_tx.hash());
+        let content = client.content_from(Address::repeat_byte(1)).await?;
+        assert!(content.pending.is_empty() && content.queued.is_empty());
+
+        let summary = client.inspect().await?;
+        let pending_summary = &summary.pending[&initiator][&0];
+        let expected_prefix = format!("{:?}: 0 wei + ", pending_tx.recipient_account());
+        assert!(
+            pending_summary.starts_with(&expected_prefix),
+            "{pending_summary}"
+        );
+        assert!(summary.queued[&initiator].contains_key(&2));
+        assert!(!summary.truncated);
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct TxpoolTruncationTest;
+
+#[async_trait]
+impl HttpTest for TxpoolTruncationTest {
+    fn web3_config(&self) -> Web3JsonRpcConfig {
+        Web3JsonRpcConfig {
+            req_entities_limit: Some(2),
+            ..Web3JsonRpcConfig::for_tests()
+        }
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let mut storage = pool.connection().await?;
+        let mut initiators = vec![];
+        for _ in 0..3 {
+            let tx = create_l2_transaction(1, 2);
+            initiators.push(tx.initiator_account());
+            storage
+                .transactions_dal()
+                .insert_transaction_l2(&tx, TransactionExecutionMetrics::default())
+                .await?;
+        }
+        // Add a queued transaction for the first account.
+        let mut queued_tx = create_l2_transaction(1, 2);
+        queued_tx.common_data.initiator_address = initiators[0];
+        queued_tx.common_data.nonce = Nonce(5);
+        queued_tx.set_input(H256::random().0.to_vec(), H256::random());
+        storage
+            .transactions_dal()
+            .insert_transaction_l2(&queued_tx, TransactionExecutionMetrics::default())
+            .await?;
+        drop(storage);
+
+        // Counts must not be capped by the entities limit.
+        let status = client.status().await?;
+        assert_eq!(status.pending, 3.into());
+        assert_eq!(status.queued, 1.into());
+
+        let content = client.content().await?;
+        assert!(content.truncated);
+        let tx_count: usize = content
+            .pending
+            .values()
+            .chain(content.queued.values())
+            .map(BTreeMap::len)
+            .sum();
+        assert_eq!(tx_count, 2);
+
+        let content = client.content_from(initiators[1]).await?;
+        assert!(!content.truncated);
+        assert_eq!(content.pending.len(), 1);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn txpool_truncation() {
+    test_http_server(TxpoolTruncationTest).await;
+}
+
+#[tokio::test]
+async fn txpool_basics() {
+    test_http_server(TxpoolBasicsTest).await;
+}
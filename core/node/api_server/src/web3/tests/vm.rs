@@ -4,11 +4,13 @@ use std::sync::atomic::{AtomicU32, Ordering};
 
 use api::state_override::{OverrideAccount, StateOverride};
 use zksync_multivm::interface::{
-    ExecutionResult, VmExecutionLogs, VmExecutionResultAndLogs, VmRevertReason,
+    ExecutionResult, VmExecutionLogs, VmExecutionResultAndLogs, VmExecutionStatistics,
+    VmRevertReason,
 };
 use zksync_types::{
-    api::ApiStorageLog, get_intrinsic_constants, transaction_request::CallRequest, K256PrivateKey,
-    L2ChainId, PackedEthSignature, StorageLogKind, StorageLogWithPreviousValue, U256,
+    api::ApiStorageLog, get_intrinsic_constants, transaction_request::CallRequest,
+    web3::AccessListItem, K256PrivateKey, L2ChainId, PackedEthSignature, StorageLogKind,
+    StorageLogWithPreviousValue, U256,
 };
 use zksync_utils::u256_to_h256;
 use zksync_web3_decl::namespaces::DebugNamespaceClient;
@@ -164,6 +166,250 @@ async fn call_method_after_snapshot_recovery() {
     test_http_server(CallTestAfterSnapshotRecovery).await;
 }
 
+#[derive(Debug)]
+struct CreateAccessListTest;
+
+impl CreateAccessListTest {
+    const CONTRACT_ADDRESS: Address = Address::repeat_byte(2);
+    const TOKEN_ADDRESS: Address = Address::repeat_byte(3);
+
+    fn storage_logs() -> Vec<StorageLogWithPreviousValue> {
+        let contract = AccountTreeId::new(Self::CONTRACT_ADDRESS);
+        let token = AccountTreeId::new(Self::TOKEN_ADDRESS);
+        [
+            StorageLog::new_read_log(
+                StorageKey::new(contract, H256::from_low_u64_be(1)),
+                H256::zero(),
+            ),
+            StorageLog::new_write_log(
+                StorageKey::new(contract, H256::from_low_u64_be(2)),
+                H256::repeat_byte(1),
+            ),
+            StorageLog::new_read_log(StorageKey::new(token, H256::zero()), H256::zero()),
+            // Repeated accesses must be deduplicated.
+            StorageLog::new_write_log(
+                StorageKey::new(contract, H256::from_low_u64_be(1)),
+                H256::repeat_byte(2),
+            ),
+        ]
+        .into_iter()
+        .map(|log| StorageLogWithPreviousValue {
+            log,
+            previous_value: H256::zero(),
+        })
+        .collect()
+    }
+}
+
+#[async_trait]
+impl HttpTest for CreateAccessListTest {
+    fn transaction_executor(&self) -> MockOneshotExecutor {
+        let mut tx_executor = MockOneshotExecutor::default();
+        tx_executor.set_call_responses_with_logs(|tx, _| {
+            let result = match tx.execute.calldata() {
+                b"success" => ExecutionResult::Success {
+                    output: b"output".to_vec(),
+                },
+                b"revert" => ExecutionResult::Revert {
+                    output: VmRevertReason::General {
+                        msg: "oops".to_owned(),
+                        data: vec![],
+                    },
+                },
+                data => panic!("Unexpected calldata: {data:?}"),
+            };
+            VmExecutionResultAndLogs {
+                result,
+                logs: VmExecutionLogs {
+                    storage_logs: Self::storage_logs(),
+                    ..VmExecutionLogs::default()
+                },
+                statistics: VmExecutionStatistics {
+                    gas_used: 42_000,
+                    ..VmExecutionStatistics::default()
+                },
+                refunds: Default::default(),
+            }
+        });
+        tx_executor
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let mut connection = pool.connection().await?;
+        store_l2_block(&mut connection, L2BlockNumber(1), &[]).await?;
+        drop(connection);
+
+        let result = client
+            .create_access_list(CallTest::call_request(b"success"), None, None)
+            .await?;
+        assert_eq!(result.gas_used, 42_000.into());
+        assert_eq!(result.error, None);
+        let expected_access_list = vec![
+            AccessListItem {
+                address: Self::CONTRACT_ADDRESS,
+                storage_keys: vec![H256::from_low_u64_be(1), H256::from_low_u64_be(2)],
+            },
+            AccessListItem {
+                address: Self::TOKEN_ADDRESS,
+                storage_keys: vec![H256::zero()],
+            },
+        ];
+        assert_eq!(result.access_list, expected_access_list);
+
+        // Reverted calls should still return the access list.
+        let result = client
+            .create_access_list(CallTest::call_request(b"revert"), None, None)
+            .await?;
+        assert_eq!(result.access_list, expected_access_list);
+        let error = result.error.expect("no error for reverted call");
+        assert!(error.contains("oops"), "{error}");
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn create_access_list_basics() {
+    test_http_server(CreateAccessListTest).await;
+}
+
+#[derive(Debug)]
+struct SimulateTest;
+
+impl SimulateTest {
+    fn payload(blocks: Vec<(Option<u64>, Vec<&str>)>) -> api::SimulatePayload {
+        let block_state_calls = blocks
+            .into_iter()
+            .map(|(time, calls)| api::SimulateBlock {
+                block_overrides: time.map(|time| api::SimulateBlockOverrides {
+                    time: Some(time.into()),
+                    base_fee_per_gas: None,
+                }),
+                state_overrides: None,
+                calls: calls
+                    .into_iter()
+                    .map(|data| CallTest::call_request(data.as_bytes()))
+                    .collect(),
+            })
+            .collect();
+        api::SimulatePayload { block_state_calls }
+    }
+
+    fn assert_invalid_params(error: ClientError) {
+        if let ClientError::Call(error) = error {
+            assert_eq!(error.code(), ErrorCode::InvalidParams.code());
+        } else {
+            panic!("Unexpected error: {error:?}");
+        }
+    }
+}
+
+#[async_trait]
+impl HttpTest for SimulateTest {
+    fn transaction_executor(&self) -> MockOneshotExecutor {
+        let mut tx_executor = MockOneshotExecutor::default();
+        tx_executor.set_call_responses_with_logs(|tx, env| {
+            let block = &env.l1_batch.first_l2_block;
+            let result = match tx.execute.calldata() {
+                b"first" => {
+                    assert_eq!(block.number, 2);
+                    assert_eq!(block.timestamp, 1_000);
+                    ExecutionResult::Success {
+                        output: b"output".to_vec(),
+                    }
+                }
+                b"second" => {
+                    assert_eq!(block.number, 3);
+                    assert_eq!(block.timestamp, 1_010);
+                    ExecutionResult::Revert {
+                        output: VmRevertReason::General {
+                            msg: "oops".to_owned(),
+                            data: vec![],
+                        },
+                    }
+                }
+                data => panic!("Unexpected calldata: {data:?}"),
+            };
+            VmExecutionResultAndLogs {
+                result,
+                logs: VmExecutionLogs::default(),
+                statistics: VmExecutionStatistics {
+                    gas_used: 21_000,
+                    ..VmExecutionStatistics::default()
+                },
+                refunds: Default::default(),
+            }
+        });
+        tx_executor
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let mut connection = pool.connection().await?;
+        store_l2_block(&mut connection, L2BlockNumber(1), &[]).await?;
+        drop(connection);
+
+        let payload = Self::payload(vec![
+            (Some(1_000), vec!["first", "first"]),
+            (Some(1_010), vec!["second"]),
+        ]);
+        let blocks = client.simulate_v1(payload, None).await?;
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].number, 2.into());
+        assert_eq!(blocks[0].timestamp, 1_000.into());
+        assert_eq!(blocks[0].gas_used, 42_000.into());
+        assert_eq!(blocks[0].calls.len(), 2);
+        for call in &blocks[0].calls {
+            assert_eq!(call.status, 1.into());
+            assert_eq!(call.return_data.0, b"output");
+            assert_eq!(call.error, None);
+        }
+
+        assert_eq!(blocks[1].number, 3.into());
+        assert_eq!(blocks[1].timestamp, 1_010.into());
+        let [call] = blocks[1].calls.as_slice() else {
+            panic!("Unexpected calls: {:?}", blocks[1].calls);
+        };
+        assert_eq!(call.status, 0.into());
+        let error = call.error.as_ref().expect("no error for reverted call");
+        assert_eq!(error.code, 3);
+        assert!(error.message.contains("oops"), "{error:?}");
+
+        // Non-increasing timestamps
+        let payload = Self::payload(vec![
+            (Some(1_000), vec!["first"]),
+            (Some(1_000), vec!["second"]),
+        ]);
+        let error = client.simulate_v1(payload, None).await.unwrap_err();
+        Self::assert_invalid_params(error);
+
+        // State overrides for a non-first block
+        let mut payload = Self::payload(vec![(None, vec!["first"]), (None, vec!["second"])]);
+        payload.block_state_calls[1].state_overrides = Some(StateOverride::new(HashMap::new()));
+        let error = client.simulate_v1(payload, None).await.unwrap_err();
+        Self::assert_invalid_params(error);
+
+        let error = client
+            .simulate_v1(Self::payload(vec![]), None)
+            .await
+            .unwrap_err();
+        Self::assert_invalid_params(error);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn simulate_basics() {
+    test_http_server(SimulateTest).await;
+}
+
 #[derive(Debug)]
 struct SendRawTransactionTest {
     snapshot_recovery: bool,
@@ -270,6 +516,56 @@ async fn send_raw_transaction_after_snapshot_recovery() {
     .await;
 }
 
+#[derive(Debug)]
+struct SendPrivateRawTransactionTest;
+
+#[async_trait]
+impl HttpTest for SendPrivateRawTransactionTest {
+    fn transaction_executor(&self) -> MockOneshotExecutor {
+        SendRawTransactionTest {
+            snapshot_recovery: false,
+        }
+        .transaction_executor()
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        // Manually set sufficient balance for the transaction account.
+        let mut storage = pool.connection().await?;
+        storage
+            .storage_logs_dal()
+            .append_storage_logs(
+                L2BlockNumber(0),
+                &[SendRawTransactionTest::balance_storage_log()],
+            )
+            .await?;
+
+        let (tx_bytes, tx_hash) = SendRawTransactionTest::transaction_bytes_and_hash();
+        let send_result = client.send_private_raw_transaction(tx_bytes.into()).await?;
+        assert_eq!(send_result, tx_hash);
+
+        // The transaction must be persisted, but not exposed via the API.
+        let execution_info = storage
+            .transactions_web3_dal()
+            .get_unstable_transaction_execution_info(tx_hash)
+            .await?;
+        assert!(execution_info.is_some());
+        let tx = client.get_transaction_by_hash(tx_hash).await?;
+        assert!(tx.is_none(), "{tx:?}");
+        let tx_details = client.get_transaction_details(tx_hash).await?;
+        assert!(tx_details.is_none(), "{tx_details:?}");
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn send_private_raw_transaction() {
+    test_http_server(SendPrivateRawTransactionTest).await;
+}
+
 #[derive(Debug)]
 struct SendTransactionWithDetailedOutputTest;
 
@@ -393,7 +689,10 @@ async fn send_raw_transaction_with_detailed_output() {
 struct TraceCallTest;
 
 impl TraceCallTest {
-    fn assert_debug_call(call_request: &CallRequest, call_result: &api::DebugCall) {
+    fn assert_debug_call(call_request: &CallRequest, call_result: &api::TracerResult) {
+        let api::TracerResult::CallTrace(call_result) = call_result else {
+            panic!("Unexpected tracer result: {call_result:?}");
+        };
         assert_eq!(call_result.from, Address::zero());
         assert_eq!(call_result.gas, call_request.gas.unwrap());
         assert_eq!(call_result.value, call_request.value.unwrap());
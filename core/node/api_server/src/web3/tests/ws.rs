@@ -8,7 +8,11 @@ use http::StatusCode;
 use tokio::sync::watch;
 use zksync_config::configs::chain::NetworkConfig;
 use zksync_dal::ConnectionPool;
-use zksync_types::{api, Address, Bloom, L1BatchNumber, H160, H256, U64};
+use zksync_state_keeper::{io::IoCursor, StateKeeperOutputHandler};
+use zksync_types::{
+    aggregated_operations::AggregatedActionType, api, Address, Bloom, L1BatchNumber, H160, H256,
+    U64,
+};
 use zksync_web3_decl::{
     client::{WsClient, L2},
     jsonrpsee::{
@@ -20,7 +24,10 @@ use zksync_web3_decl::{
         rpc_params,
     },
     namespaces::{EthNamespaceClient, ZksNamespaceClient},
-    types::{BlockHeader, Bytes, PubSubFilter},
+    types::{
+        BlockHeader, Bytes, L1BatchNotification, L1BatchStage, PubSubFilter, PubSubResult,
+        TxLifecycleStage, TxStatusNotification,
+    },
 };
 
 use super::*;
@@ -160,6 +167,11 @@ trait WsTest: Send + Sync {
     fn websocket_requests_per_minute_limit(&self) -> Option<NonZeroU32> {
         None
     }
+
+    /// Sync state passed to the server. If set, the server behaves like the external node.
+    fn sync_state(&self) -> Option<SyncState> {
+        None
+    }
 }
 
 async fn test_ws_server(test: impl WsTest) {
@@ -182,6 +194,7 @@ async fn test_ws_server(test: impl WsTest) {
         pool.clone(),
         stop_receiver,
         test.websocket_requests_per_minute_limit(),
+        test.sync_state(),
     )
     .await;
 
@@ -376,6 +389,7 @@ impl LogSubscriptions {
         let address_filter = PubSubFilter {
             address: Some(Address::repeat_byte(23).into()),
             topics: None,
+            transaction_hashes: None,
         };
         let params = rpc_params!["logs", address_filter];
         let address_subscription = client
@@ -384,6 +398,7 @@ impl LogSubscriptions {
         let topic_filter = PubSubFilter {
             address: None,
             topics: Some(vec![Some(H256::repeat_byte(42).into())]),
+            transaction_hashes: None,
         };
         let params = rpc_params!["logs", topic_filter];
         let topic_subscription = client
@@ -623,6 +638,7 @@ impl WsTest for LogSubscriptionsWithDelayTest {
         let address_and_topic_filter = PubSubFilter {
             address: Some(Address::repeat_byte(23).into()),
             topics: Some(vec![Some(H256::repeat_byte(42).into())]),
+            transaction_hashes: None,
         };
         let params = rpc_params!["logs", address_and_topic_filter];
         let mut address_and_topic_subscription = client
@@ -659,6 +675,163 @@ async fn log_subscriptions_with_delay() {
     test_ws_server(LogSubscriptionsWithDelayTest).await;
 }
 
+#[derive(Debug)]
+struct TxStatusSubscriptionTest;
+
+#[async_trait]
+impl WsTest for TxStatusSubscriptionTest {
+    async fn test(
+        &self,
+        client: &WsClient<L2>,
+        pool: &ConnectionPool<Core>,
+        mut pub_sub_events: mpsc::UnboundedReceiver<PubSubEvent>,
+    ) -> anyhow::Result<()> {
+        let tx_result = execute_l2_transaction(create_l2_transaction(1, 2));
+        let tx_hash = tx_result.hash;
+        let initiator = tx_result.transaction.initiator_account();
+
+        let filter = PubSubFilter {
+            address: Some(initiator.into()),
+            ..PubSubFilter::default()
+        };
+        let params = rpc_params!["zks_txStatus", filter];
+        let mut subscription = client
+            .subscribe::<TxStatusNotification, _>("eth_subscribe", params, "eth_unsubscribe")
+            .await?;
+        wait_for_subscription(&mut pub_sub_events, SubscriptionType::TxStatuses).await;
+        // The notifier resets its position once it observes a subscriber. The first iteration may have started
+        // before the subscription was created, so we wait for 2 iterations.
+        for _ in 0..2 {
+            wait_for_notifiers(&mut pub_sub_events, &[SubscriptionType::TxStatuses]).await;
+        }
+
+        let mut storage = pool.connection().await?;
+        let other_tx_result = execute_l2_transaction(create_l2_transaction(3, 4));
+        store_l2_block(
+            &mut storage,
+            L2BlockNumber(1),
+            &[tx_result, other_tx_result],
+        )
+        .await?;
+
+        let notification = tokio::time::timeout(TEST_TIMEOUT, subscription.next())
+            .await
+            .context("Timed out waiting for tx status")?
+            .context("Tx status subscription terminated")??;
+        assert_eq!(
+            notification,
+            TxStatusNotification {
+                transaction_hash: tx_hash,
+                initiator_address: initiator,
+                stage: TxLifecycleStage::Included,
+                block_number: L2BlockNumber(1),
+                l1_batch_number: None,
+                eth_tx_hash: None,
+            }
+        );
+
+        seal_l1_batch(&mut storage, L1BatchNumber(1)).await?;
+        let commit_tx_hash = H256::repeat_byte(0x01);
+        storage
+            .eth_sender_dal()
+            .insert_bogus_confirmed_eth_tx(
+                L1BatchNumber(1),
+                AggregatedActionType::Commit,
+                commit_tx_hash,
+                chrono::Utc::now(),
+            )
+            .await?;
+        drop(storage);
+
+        let notification = tokio::time::timeout(TEST_TIMEOUT, subscription.next())
+            .await
+            .context("Timed out waiting for tx status")?
+            .context("Tx status subscription terminated")??;
+        assert_eq!(notification.transaction_hash, tx_hash);
+        assert_eq!(notification.stage, TxLifecycleStage::Committed);
+        assert_eq!(notification.l1_batch_number, Some(L1BatchNumber(1)));
+        assert_eq!(notification.eth_tx_hash, Some(commit_tx_hash));
+
+        subscription.unsubscribe().await?;
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn tx_status_subscription() {
+    test_ws_server(TxStatusSubscriptionTest).await;
+}
+
+#[derive(Debug)]
+struct L1BatchSubscriptionTest;
+
+#[async_trait]
+impl WsTest for L1BatchSubscriptionTest {
+    async fn test(
+        &self,
+        client: &WsClient<L2>,
+        pool: &ConnectionPool<Core>,
+        mut pub_sub_events: mpsc::UnboundedReceiver<PubSubEvent>,
+    ) -> anyhow::Result<()> {
+        wait_for_notifiers(&mut pub_sub_events, &[SubscriptionType::L1Batches]).await;
+        // Batches sealed while there are no subscribers must not be reported to future subscribers.
+        let mut storage = pool.connection().await?;
+        store_l2_block(&mut storage, L2BlockNumber(1), &[]).await?;
+        seal_l1_batch(&mut storage, L1BatchNumber(1)).await?;
+        wait_for_notifiers(&mut pub_sub_events, &[SubscriptionType::L1Batches]).await;
+
+        let params = rpc_params!["zks_l1Batches"];
+        let mut subscription = client
+            .subscribe::<L1BatchNotification, _>("eth_subscribe", params, "eth_unsubscribe")
+            .await?;
+        wait_for_subscription(&mut pub_sub_events, SubscriptionType::L1Batches).await;
+        // The notifier resets its position once it observes a subscriber. The first iteration may have started
+        // before the subscription was created, so we wait for 2 iterations.
+        for _ in 0..2 {
+            wait_for_notifiers(&mut pub_sub_events, &[SubscriptionType::L1Batches]).await;
+        }
+
+        store_l2_block(&mut storage, L2BlockNumber(2), &[]).await?;
+        seal_l1_batch(&mut storage, L1BatchNumber(2)).await?;
+
+        let notification = tokio::time::timeout(TEST_TIMEOUT, subscription.next())
+            .await
+            .context("Timed out waiting for L1 batch")?
+            .context("L1 batch subscription terminated")??;
+        assert_eq!(notification.l1_batch_number, L1BatchNumber(2));
+        assert_eq!(notification.stage, L1BatchStage::Sealed);
+        assert_eq!(notification.eth_tx_hash, None);
+
+        let commit_tx_hash = H256::repeat_byte(0x01);
+        storage
+            .eth_sender_dal()
+            .insert_bogus_confirmed_eth_tx(
+                L1BatchNumber(1),
+                AggregatedActionType::Commit,
+                commit_tx_hash,
+                chrono::Utc::now(),
+            )
+            .await?;
+        drop(storage);
+
+        let notification = tokio::time::timeout(TEST_TIMEOUT, subscription.next())
+            .await
+            .context("Timed out waiting for L1 batch")?
+            .context("L1 batch subscription terminated")??;
+        assert_eq!(notification.l1_batch_number, L1BatchNumber(1));
+        assert_eq!(notification.stage, L1BatchStage::Committed);
+        assert_eq!(notification.eth_tx_hash, Some(commit_tx_hash));
+
+        subscription.unsubscribe().await?;
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn l1_batch_subscription() {
+    test_ws_server(L1BatchSubscriptionTest).await;
+}
+
 #[derive(Debug)]
 struct RateLimitingTest;
 
@@ -739,3 +912,119 @@ impl WsTest for BatchGetsRateLimitedTest {
 async fn batch_rate_limiting() {
     test_ws_server(BatchGetsRateLimitedTest).await;
 }
+
+#[derive(Debug)]
+struct SyncingSubscriptionOnMainNodeTest;
+
+#[async_trait]
+impl WsTest for SyncingSubscriptionOnMainNodeTest {
+    async fn test(
+        &self,
+        client: &WsClient<L2>,
+        _pool: &ConnectionPool<Core>,
+        mut pub_sub_events: mpsc::UnboundedReceiver<PubSubEvent>,
+    ) -> anyhow::Result<()> {
+        let params = rpc_params!["syncing"];
+        let mut subscription = client
+            .subscribe::<PubSubResult, _>("eth_subscribe", params, "eth_unsubscribe")
+            .await?;
+        wait_for_subscription(&mut pub_sub_events, SubscriptionType::Syncing).await;
+
+        let notification = tokio::time::timeout(TEST_TIMEOUT, subscription.next())
+            .await
+            .context("Timed out waiting for sync notification")?
+            .context("Syncing subscription terminated")??;
+        assert_matches!(notification, PubSubResult::Syncing(false));
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn syncing_subscription_on_main_node() {
+    test_ws_server(SyncingSubscriptionOnMainNodeTest).await;
+}
+
+#[derive(Debug)]
+struct SyncingSubscriptionTest {
+    sync_state: SyncState,
+}
+
+impl SyncingSubscriptionTest {
+    const MAIN_NODE_BLOCK: L2BlockNumber = L2BlockNumber(100);
+
+    fn new() -> Self {
+        let sync_state = SyncState::default();
+        sync_state.set_main_node_block(Self::MAIN_NODE_BLOCK);
+        Self { sync_state }
+    }
+
+    async fn set_local_block(&self, number: L2BlockNumber) {
+        let cursor = IoCursor {
+            next_l2_block: number + 1,
+            prev_l2_block_hash: H256::zero(),
+            prev_l2_block_timestamp: 0,
+            l1_batch: L1BatchNumber(0),
+        };
+        self.sync_state.clone().initialize(&cursor).await.unwrap();
+    }
+}
+
+#[async_trait]
+impl WsTest for SyncingSubscriptionTest {
+    async fn test(
+        &self,
+        client: &WsClient<L2>,
+        _pool: &ConnectionPool<Core>,
+        mut pub_sub_events: mpsc::UnboundedReceiver<PubSubEvent>,
+    ) -> anyhow::Result<()> {
+        self.set_local_block(L2BlockNumber(10)).await;
+
+        let params = rpc_params!["syncing"];
+        let mut subscription = client
+            .subscribe::<PubSubResult, _>("eth_subscribe", params, "eth_unsubscribe")
+            .await?;
+        wait_for_subscription(&mut pub_sub_events, SubscriptionType::Syncing).await;
+
+        let notification = tokio::time::timeout(TEST_TIMEOUT, subscription.next())
+            .await
+            .context("Timed out waiting for sync notification")?
+            .context("Syncing subscription terminated")??;
+        let PubSubResult::SyncProgress(progress) = notification else {
+            panic!("Unexpected notification: {notification:?}");
+        };
+        assert!(progress.syncing);
+        assert_eq!(progress.status.starting_block, 10.into());
+        assert_eq!(progress.status.current_block, 10.into());
+        assert_eq!(progress.status.highest_block, 100.into());
+
+        self.set_local_block(L2BlockNumber(50)).await;
+        let notification = tokio::time::timeout(TEST_TIMEOUT, subscription.next())
+            .await
+            .context("Timed out waiting for sync notification")?
+            .context("Syncing subscription terminated")??;
+        let PubSubResult::SyncProgress(progress) = notification else {
+            panic!("Unexpected notification: {notification:?}");
+        };
+        // The starting block must not change during the subscription lifetime.
+        assert_eq!(progress.status.starting_block, 10.into());
+        assert_eq!(progress.status.current_block, 50.into());
+        assert_eq!(progress.status.highest_block, 100.into());
+
+        self.set_local_block(Self::MAIN_NODE_BLOCK - 1).await;
+        let notification = tokio::time::timeout(TEST_TIMEOUT, subscription.next())
+            .await
+            .context("Timed out waiting for sync notification")?
+            .context("Syncing subscription terminated")??;
+        assert_matches!(notification, PubSubResult::Syncing(false));
+        Ok(())
+    }
+
+    fn sync_state(&self) -> Option<SyncState> {
+        Some(self.sync_state.clone())
+    }
+}
+
+#[tokio::test]
+async fn syncing_subscription() {
+    test_ws_server(SyncingSubscriptionTest::new()).await;
+}
@@ -1,20 +1,28 @@
-use std::{cmp::max, fmt::Debug, sync::Arc, time::Instant};
+use std::{
+    cmp::max,
+    fmt::Debug,
+    num::NonZeroU64,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use anyhow::Context as _;
+use chrono::Utc;
 use tokio::{sync::watch, time::sleep};
 use zksync_config::configs::base_token_adjuster::BaseTokenAdjusterConfig;
 use zksync_dal::{ConnectionPool, Core, CoreDal};
 use zksync_eth_client::{BoundEthInterface, Options};
 use zksync_external_price_api::PriceAPIClient;
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_node_fee_model::l1_gas_price::TxParamsProvider;
 use zksync_types::{
-    base_token_ratio::BaseTokenAPIRatio,
+    base_token_ratio::{BaseTokenAPIRatio, BaseTokenRatio},
     ethabi::{Contract, Token},
     web3::{contract::Tokenize, BlockNumber},
     Address, U256,
 };
 
-use crate::metrics::{OperationResult, OperationResultLabels, METRICS};
+use crate::metrics::{OperationResult, OperationResultLabels, RatioChangeAction, METRICS};
 
 #[derive(Debug, Clone)]
 pub struct BaseTokenRatioPersisterL1Params {
@@ -26,13 +34,25 @@ pub struct BaseTokenRatioPersisterL1Params {
     pub chain_admin_contract_address: Option<Address>,
 }
 
-#[derive(Debug, Clone)]
+/// Result of checking a fetched ratio against the max allowed ratio change.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RatioChange {
+    /// Ratio is within the allowed range.
+    Accepted(BaseTokenAPIRatio),
+    /// Ratio exceeded the allowed change and was clamped to the contained value.
+    Clamped(BaseTokenAPIRatio),
+    /// Ratio exceeded the allowed change and was discarded.
+    Rejected,
+}
+
+#[derive(Debug)]
 pub struct BaseTokenRatioPersister {
     pool: ConnectionPool<Core>,
     config: BaseTokenAdjusterConfig,
     base_token_address: Address,
     price_api_client: Arc<dyn PriceAPIClient>,
     l1_params: Option<BaseTokenRatioPersisterL1Params>,
+    health_updater: HealthUpdater,
 }
 
 impl BaseTokenRatioPersister {
@@ -50,13 +70,22 @@ impl BaseTokenRatioPersister {
             base_token_address,
             price_api_client,
             l1_params,
+            health_updater: ReactiveHealthCheck::new("base_token_ratio_persister").1,
         }
     }
 
+    /// Returns a health check for this persister. The check is [`HealthStatus::Affected`] if the latest
+    /// fetched ratio has exceeded the max allowed ratio change.
+    pub fn health_check(&self) -> ReactiveHealthCheck {
+        self.health_updater.subscribe()
+    }
+
     /// Main loop for the base token ratio persister.
     /// Orchestrates fetching a new ratio, persisting it, and conditionally updating the L1 with it.
     pub async fn run(&mut self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
         let mut timer = tokio::time::interval(self.config.price_polling_interval());
+        self.health_updater
+            .update(Health::from(HealthStatus::Ready));
 
         while !*stop_receiver.borrow_and_update() {
             tokio::select! {
@@ -83,8 +112,122 @@ impl BaseTokenRatioPersister {
     async fn loop_iteration(&self) -> anyhow::Result<()> {
         // TODO(PE-148): Consider shifting retry upon adding external API redundancy.
         let new_ratio = self.retry_fetch_ratio().await?;
-        self.persist_ratio(new_ratio).await?;
-        self.retry_update_ratio_on_l1(new_ratio).await
+        let Some(new_ratio) = self.limit_ratio_change(new_ratio).await? else {
+            return Ok(());
+        };
+        let ratio_id = self.persist_ratio(new_ratio).await?;
+
+        if self.l1_params.is_none() || !self.should_update_ratio_on_l1(new_ratio).await? {
+            return Ok(());
+        }
+        self.retry_update_ratio_on_l1(new_ratio).await?;
+        self.pool
+            .connection_tagged("base_token_ratio_persister")
+            .await?
+            .base_token_dal()
+            .mark_ratio_as_used_in_l1(ratio_id as u32)
+            .await?;
+        Ok(())
+    }
+
+    /// Checks the fetched ratio against the latest persisted ratio. Returns `None` if the ratio is rejected;
+    /// in this case, the persister doesn't persist ratios or update L1 until a ratio within the allowed range
+    /// is fetched.
+    async fn limit_ratio_change(
+        &self,
+        new_ratio: BaseTokenAPIRatio,
+    ) -> anyhow::Result<Option<BaseTokenAPIRatio>> {
+        let Some(max_change_percent) = self.config.max_ratio_change_percent else {
+            return Ok(Some(new_ratio));
+        };
+        let mut conn = self
+            .pool
+            .connection_tagged("base_token_ratio_persister")
+            .await?;
+        let Some(prev_ratio) = conn.base_token_dal().get_latest_ratio().await? else {
+            return Ok(Some(new_ratio));
+        };
+
+        let change = check_ratio_change(
+            &prev_ratio,
+            new_ratio,
+            max_change_percent,
+            self.config.clamp_ratio_changes,
+        );
+        let (action, output) = match change {
+            RatioChange::Accepted(ratio) => {
+                self.health_updater
+                    .update(Health::from(HealthStatus::Ready));
+                return Ok(Some(ratio));
+            }
+            RatioChange::Clamped(ratio) => (RatioChangeAction::Clamped, Some(ratio)),
+            RatioChange::Rejected => (RatioChangeAction::Rejected, None),
+        };
+
+        tracing::warn!(
+            "Fetched base token ratio {}/{} deviates from the latest persisted ratio {}/{} by more than {max_change_percent}%; \
+             the ratio is {action:?}",
+            new_ratio.numerator,
+            new_ratio.denominator,
+            prev_ratio.numerator,
+            prev_ratio.denominator
+        );
+        METRICS.ratio_changes_exceeding_limit[&action].inc();
+        conn.base_token_dal()
+            .insert_rejected_ratio(
+                new_ratio.numerator,
+                new_ratio.denominator,
+                &new_ratio.ratio_timestamp.naive_utc(),
+                prev_ratio.id,
+                output.is_some(),
+            )
+            .await
+            .context("Failed to insert rejected base token ratio into the database")?;
+
+        let health = Health::from(HealthStatus::Affected).with_details(serde_json::json!({
+            "error": format!("base token ratio change exceeds {max_change_percent}%"),
+            "action": action,
+            "fetched_ratio": [new_ratio.numerator, new_ratio.denominator],
+            "latest_persisted_ratio": [prev_ratio.numerator, prev_ratio.denominator],
+        }));
+        self.health_updater.update(health);
+        Ok(output)
+    }
+
+    /// Checks whether the ratio should be sent to L1 based on its deviation from the ratio last sent to L1
+    /// and the time since that update.
+    async fn should_update_ratio_on_l1(
+        &self,
+        new_ratio: BaseTokenAPIRatio,
+    ) -> anyhow::Result<bool> {
+        let last_l1_ratio = self
+            .pool
+            .connection_tagged("base_token_ratio_persister")
+            .await?
+            .base_token_dal()
+            .get_latest_l1_ratio()
+            .await?;
+        let Some(last_l1_ratio) = last_l1_ratio else {
+            return Ok(true);
+        };
+
+        let should_update = is_l1_update_required(
+            &last_l1_ratio,
+            new_ratio,
+            self.config.l1_update_deviation_percent,
+            self.config.l1_update_max_staleness(),
+        );
+        if !should_update {
+            tracing::debug!(
+                "Skipping L1 update: ratio {}/{} is close to the ratio {}/{} last sent to L1",
+                new_ratio.numerator,
+                new_ratio.denominator,
+                last_l1_ratio.numerator,
+                last_l1_ratio.denominator
+            );
+            METRICS.skipped_l1_updates.inc();
+        }
+        Ok(should_update)
     }
 
     fn get_eth_fees(
@@ -330,3 +473,185 @@ impl BaseTokenRatioPersister {
         )))
     }
 }
+
+fn ratio_to_f64(numerator: NonZeroU64, denominator: NonZeroU64) -> f64 {
+    numerator.get() as f64 / denominator.get() as f64
+}
+
+/// Returns the relative change of `new_ratio` compared to `prev_ratio`, in percent.
+fn ratio_change_percent(prev_ratio: &BaseTokenRatio, new_ratio: &BaseTokenAPIRatio) -> f64 {
+    let prev_value = ratio_to_f64(prev_ratio.numerator, prev_ratio.denominator);
+    let new_value = ratio_to_f64(new_ratio.numerator, new_ratio.denominator);
+    (new_value - prev_value).abs() * 100.0 / prev_value
+}
+
+fn check_ratio_change(
+    prev_ratio: &BaseTokenRatio,
+    new_ratio: BaseTokenAPIRatio,
+    max_change_percent: u32,
+    clamp: bool,
+) -> RatioChange {
+    if ratio_change_percent(prev_ratio, &new_ratio) <= f64::from(max_change_percent) {
+        return RatioChange::Accepted(new_ratio);
+    }
+    if !clamp {
+        return RatioChange::Rejected;
+    }
+
+    // Since the ratio is positive, a decrease can exceed the limit only if `max_change_percent < 100`.
+    let is_increase = u128::from(new_ratio.numerator.get())
+        * u128::from(prev_ratio.denominator.get())
+        > u128::from(prev_ratio.numerator.get()) * u128::from(new_ratio.denominator.get());
+    let multiplier = if is_increase {
+        100 + u128::from(max_change_percent)
+    } else {
+        100 - u128::from(max_change_percent)
+    };
+    let (numerator, denominator) = reduce_fraction(
+        u128::from(prev_ratio.numerator.get()) * multiplier,
+        u128::from(prev_ratio.denominator.get()) * 100,
+    );
+    RatioChange::Clamped(BaseTokenAPIRatio {
+        numerator,
+        denominator,
+        ratio_timestamp: new_ratio.ratio_timestamp,
+    })
+}
+
+/// Reduces a positive fraction so that both its parts fit into `u64`, losing precision if necessary.
+fn reduce_fraction(mut numerator: u128, mut denominator: u128) -> (NonZeroU64, NonZeroU64) {
+    let divisor = gcd(numerator, denominator);
+    numerator /= divisor;
+    denominator /= divisor;
+    while numerator > u128::from(u64::MAX) || denominator > u128::from(u64::MAX) {
+        numerator = (numerator >> 1).max(1);
+        denominator = (denominator >> 1).max(1);
+    }
+    (
+        NonZeroU64::new(numerator as u64).unwrap(),
+        NonZeroU64::new(denominator as u64).unwrap(),
+    )
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn is_l1_update_required(
+    last_l1_ratio: &BaseTokenRatio,
+    new_ratio: BaseTokenAPIRatio,
+    deviation_percent: u32,
+    max_staleness: Duration,
+) -> bool {
+    let elapsed = (Utc::now() - last_l1_ratio.ratio_timestamp)
+        .to_std()
+        .unwrap_or_default();
+    elapsed >= max_staleness
+        || ratio_change_percent(last_l1_ratio, &new_ratio) >= f64::from(deviation_percent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prev_ratio(numerator: u64, denominator: u64, age: chrono::Duration) -> BaseTokenRatio {
+        BaseTokenRatio {
+            id: 1,
+            ratio_timestamp: Utc::now() - age,
+            numerator: NonZeroU64::new(numerator).unwrap(),
+            denominator: NonZeroU64::new(denominator).unwrap(),
+            used_in_l1: true,
+        }
+    }
+
+    fn api_ratio(numerator: u64, denominator: u64) -> BaseTokenAPIRatio {
+        BaseTokenAPIRatio {
+            numerator: NonZeroU64::new(numerator).unwrap(),
+            denominator: NonZeroU64::new(denominator).unwrap(),
+            ratio_timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn checking_ratio_change() {
+        let prev = prev_ratio(100, 1, chrono::Duration::zero());
+        let new_ratio = api_ratio(1_100, 10);
+        assert_eq!(
+            check_ratio_change(&prev, new_ratio, 10, false),
+            RatioChange::Accepted(new_ratio)
+        );
+        assert_eq!(
+            check_ratio_change(&prev, api_ratio(111, 1), 10, false),
+            RatioChange::Rejected
+        );
+
+        let RatioChange::Clamped(clamped) = check_ratio_change(&prev, api_ratio(200, 1), 10, true)
+        else {
+            panic!("ratio wasn't clamped");
+        };
+        assert_eq!(
+            (clamped.numerator.get(), clamped.denominator.get()),
+            (110, 1)
+        );
+        let RatioChange::Clamped(clamped) = check_ratio_change(&prev, api_ratio(1, 3), 25, true)
+        else {
+            panic!("ratio wasn't clamped");
+        };
+        assert_eq!(
+            (clamped.numerator.get(), clamped.denominator.get()),
+            (75, 1)
+        );
+    }
+
+    #[test]
+    fn clamping_large_ratios() {
+        let prev = prev_ratio(u64::MAX, 7, chrono::Duration::zero());
+        let RatioChange::Clamped(clamped) =
+            check_ratio_change(&prev, api_ratio(u64::MAX, 1), 50, true)
+        else {
+            panic!("ratio wasn't clamped");
+        };
+        let clamped_value = ratio_to_f64(clamped.numerator, clamped.denominator);
+        let expected_value = u64::MAX as f64 / 7.0 * 1.5;
+        assert!(
+            (clamped_value - expected_value).abs() / expected_value < 1e-9,
+            "{clamped:?}"
+        );
+    }
+
+    #[test]
+    fn checking_l1_update_hysteresis() {
+        let max_staleness = Duration::from_secs(3_600);
+        let last_l1_ratio = prev_ratio(100, 1, chrono::Duration::minutes(10));
+        assert!(!is_l1_update_required(
+            &last_l1_ratio,
+            api_ratio(101, 1),
+            2,
+            max_staleness
+        ));
+        assert!(is_l1_update_required(
+            &last_l1_ratio,
+            api_ratio(98, 1),
+            2,
+            max_staleness
+        ));
+        // With zero deviation threshold, each ratio is sent to L1.
+        assert!(is_l1_update_required(
+            &last_l1_ratio,
+            api_ratio(100, 1),
+            0,
+            max_staleness
+        ));
+
+        let stale_l1_ratio = prev_ratio(100, 1, chrono::Duration::hours(2));
+        assert!(is_l1_update_required(
+            &stale_l1_ratio,
+            api_ratio(100, 1),
+            2,
+            max_staleness
+        ));
+    }
+}
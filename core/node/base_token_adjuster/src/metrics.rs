@@ -1,6 +1,10 @@
 use std::time::Duration;
 
-use vise::{Buckets, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Metrics};
+use serde::Serialize;
+use vise::{
+    Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, LabeledFamily,
+    Metrics,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelSet, EncodeLabelValue)]
 #[metrics(label = "operation_result", rename_all = "snake_case")]
@@ -14,6 +18,15 @@ pub(crate) struct OperationResultLabels {
     pub result: OperationResult,
 }
 
+/// Action taken on a ratio that exceeded the max allowed ratio change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, Serialize)]
+#[metrics(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum RatioChangeAction {
+    Clamped,
+    Rejected,
+}
+
 #[derive(Debug, Metrics)]
 #[metrics(prefix = "base_token_adjuster")]
 pub(crate) struct BaseTokenAdjusterMetrics {
@@ -22,6 +35,11 @@ pub(crate) struct BaseTokenAdjusterMetrics {
     pub external_price_api_latency: Family<OperationResultLabels, Histogram<Duration>>,
     #[metrics(buckets = Buckets::LATENCIES)]
     pub l1_update_latency: Family<OperationResultLabels, Histogram<Duration>>,
+    /// Number of fetched ratios that exceeded the max allowed ratio change.
+    #[metrics(labels = ["action"])]
+    pub ratio_changes_exceeding_limit: LabeledFamily<RatioChangeAction, Counter>,
+    /// Number of persisted ratios that weren't sent to L1 because they were close to the ratio last sent to L1.
+    pub skipped_l1_updates: Counter,
 }
 
 #[vise::register]
@@ -614,6 +614,128 @@ impl BlockReverter {
             .await?;
         Ok(())
     }
+
+    /// Reconciles unconfirmed L1 transactions of the Ethereum sender with the on-chain nonce of the operator.
+    /// Transactions that were mined are confirmed; transactions which nonces were consumed by other transactions,
+    /// together with all subsequent ones, get consecutive nonces starting from the operator's latest nonce, so that
+    /// they are sent again by the Ethereum sender. Must be run while the Ethereum sender is stopped.
+    ///
+    /// `eth_tx_from_addr` is the sender address recorded for the operator transactions (`None` for the main operator).
+    pub async fn reconcile_eth_sender_nonces(
+        &self,
+        eth_client: &dyn EthInterface,
+        operator_address: Address,
+        eth_tx_from_addr: Option<Address>,
+    ) -> anyhow::Result<EthSenderNoncesReconciliation> {
+        let latest_nonce = eth_client
+            .nonce_at_for_account(operator_address, BlockNumber::Latest)
+            .await
+            .with_context(|| format!("failed getting latest nonce for {operator_address:?}"))?
+            .as_u64();
+        let pending_nonce = eth_client
+            .nonce_at_for_account(operator_address, BlockNumber::Pending)
+            .await
+            .with_context(|| format!("failed getting pending nonce for {operator_address:?}"))?
+            .as_u64();
+        anyhow::ensure!(
+            pending_nonce == latest_nonce,
+            "Operator {operator_address:?} has pending transactions (latest nonce: {latest_nonce}, \
+             pending nonce: {pending_nonce}); wait until they are mined or dropped"
+        );
+        tracing::info!(
+            "Reconciling eth_sender state with operator {operator_address:?} nonce {latest_nonce}"
+        );
+
+        let mut storage = self.connection_pool.connection().await?;
+        let unconfirmed_txs = storage
+            .eth_sender_dal()
+            .get_unconfirmed_txs(eth_tx_from_addr, false)
+            .await?;
+
+        let mut confirmed_txs = 0;
+        let mut first_tx_to_resend = None;
+        for tx in &unconfirmed_txs {
+            if u64::from(tx.nonce.0) >= latest_nonce {
+                first_tx_to_resend.get_or_insert(tx);
+                continue;
+            }
+
+            let mut receipt = None;
+            for history_item in storage
+                .eth_sender_dal()
+                .get_tx_history_to_check(tx.id)
+                .await?
+            {
+                receipt = eth_client
+                    .tx_receipt(history_item.tx_hash)
+                    .await
+                    .with_context(|| {
+                        format!("failed getting receipt for {:?}", history_item.tx_hash)
+                    })?;
+                if receipt.is_some() {
+                    break;
+                }
+            }
+
+            let Some(receipt) = receipt else {
+                tracing::info!(
+                    "Nonce {} of eth_tx {} was consumed by another transaction",
+                    tx.nonce,
+                    tx.id
+                );
+                first_tx_to_resend.get_or_insert(tx);
+                continue;
+            };
+            if let Some(lost_tx) = first_tx_to_resend {
+                anyhow::bail!(
+                    "eth_tx {} was mined in {:?} after the nonce of eth_tx {} was consumed by another transaction; \
+                     reconcile the state manually",
+                    tx.id,
+                    receipt.transaction_hash,
+                    lost_tx.id
+                );
+            }
+            anyhow::ensure!(
+                receipt.status == Some(1.into()),
+                "eth_tx {} failed in {:?}; clear failed transactions first",
+                tx.id,
+                receipt.transaction_hash
+            );
+            let gas_used = receipt
+                .gas_used
+                .context("light ETH clients are not supported")?;
+            storage
+                .eth_sender_dal()
+                .confirm_tx(receipt.transaction_hash, gas_used)
+                .await?;
+            confirmed_txs += 1;
+        }
+
+        let reassigned_txs = if let Some(tx) = first_tx_to_resend {
+            storage
+                .eth_sender_dal()
+                .reassign_nonces(tx.id, eth_tx_from_addr, false, latest_nonce)
+                .await?
+        } else {
+            0
+        };
+        tracing::info!(
+            "Confirmed {confirmed_txs} eth_txs, reassigned nonces of {reassigned_txs} eth_txs"
+        );
+        Ok(EthSenderNoncesReconciliation {
+            latest_nonce,
+            confirmed_txs,
+            reassigned_txs,
+        })
+    }
+}
+
+/// Outcome of [`BlockReverter::reconcile_eth_sender_nonces()`].
+#[derive(Debug, Serialize)]
+pub struct EthSenderNoncesReconciliation {
+    pub latest_nonce: u64,
+    pub confirmed_txs: usize,
+    pub reassigned_txs: u64,
 }
 
 #[derive(Debug, Serialize)]
@@ -7,6 +7,7 @@ use async_trait::async_trait;
 use test_casing::test_casing;
 use tokio::sync::watch;
 use zksync_dal::Connection;
+use zksync_eth_client::clients::MockSettlementLayer;
 use zksync_merkle_tree::TreeInstruction;
 use zksync_object_store::{Bucket, MockObjectStore};
 use zksync_state::interface::ReadStorage;
@@ -471,3 +472,89 @@ async fn reverter_handles_incomplete_snapshot() {
         assert_matches!(chunk_result.unwrap_err(), ObjectStoreError::KeyNotFound(_));
     }
 }
+
+#[tokio::test]
+async fn reconciling_eth_sender_nonces() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    let eth_client = MockSettlementLayer::default();
+    let operator_address = eth_client.sender_account();
+
+    let mut txs = vec![];
+    for nonce in 0..3 {
+        let tx = storage
+            .eth_sender_dal()
+            .save_eth_tx(
+                nonce,
+                vec![],
+                AggregatedActionType::Commit,
+                Address::repeat_byte(1),
+                100_000,
+                None,
+                None,
+                false,
+            )
+            .await
+            .unwrap();
+        txs.push(tx);
+    }
+    // Only the first 2 transactions are sent.
+    let mut tx_hashes = vec![];
+    for tx in &txs[..2] {
+        let options = Options {
+            nonce: Some(tx.nonce.0.into()),
+            ..Options::default()
+        };
+        let signed_tx = eth_client
+            .sign_prepared_tx(vec![], tx.contract_address, options)
+            .unwrap();
+        eth_client
+            .as_ref()
+            .send_raw_tx(signed_tx.raw_tx.clone())
+            .await
+            .unwrap();
+        storage
+            .eth_sender_dal()
+            .insert_tx_history(
+                tx.id,
+                100,
+                10,
+                None,
+                signed_tx.hash,
+                signed_tx.raw_tx.as_ref(),
+                0,
+            )
+            .await
+            .unwrap();
+        tx_hashes.push(signed_tx.hash);
+    }
+    // The first tx is mined, and the nonce of the second one is consumed by another tx.
+    eth_client.execute_tx(tx_hashes[0], true, 1);
+    eth_client.execute_external_tx(1);
+
+    let block_reverter = BlockReverter::new(NodeRole::Main, pool.clone());
+    let reconciliation = block_reverter
+        .reconcile_eth_sender_nonces(eth_client.as_ref(), operator_address, None)
+        .await
+        .unwrap();
+    assert_eq!(reconciliation.latest_nonce, 2);
+    assert_eq!(reconciliation.confirmed_txs, 1);
+    assert_eq!(reconciliation.reassigned_txs, 2);
+
+    let unconfirmed_txs = storage
+        .eth_sender_dal()
+        .get_unconfirmed_txs(None, false)
+        .await
+        .unwrap();
+    let unconfirmed_txs: Vec<_> = unconfirmed_txs
+        .iter()
+        .map(|tx| (tx.id, tx.nonce.0))
+        .collect();
+    assert_eq!(unconfirmed_txs, [(txs[1].id, 2), (txs[2].id, 3)]);
+    let tx_history = storage
+        .eth_sender_dal()
+        .get_tx_history_to_check(txs[1].id)
+        .await
+        .unwrap();
+    assert!(tx_history.is_empty());
+}
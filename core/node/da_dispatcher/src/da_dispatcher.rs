@@ -10,7 +10,7 @@ use zksync_da_client::{
     DataAvailabilityClient,
 };
 use zksync_dal::{ConnectionPool, Core, CoreDal};
-use zksync_types::L1BatchNumber;
+use zksync_types::{pubdata_da::DataAvailabilityBlob, L1BatchNumber};
 
 use crate::metrics::METRICS;
 
@@ -73,6 +73,10 @@ impl DataAvailabilityDispatcher {
     }
 
     /// Dispatches the blobs to the data availability layer, and saves the blob_id in the database.
+    ///
+    /// If the pubdata for a batch exceeds the blob size limit of the DA client, it is split into several parts
+    /// dispatched as separate blobs. Blob IDs of the parts are persisted as soon as they are dispatched, so that
+    /// the dispatch can be resumed after a restart without re-sending the already dispatched parts.
     async fn dispatch(&self) -> anyhow::Result<()> {
         let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
         let batches = conn
@@ -82,44 +86,108 @@ impl DataAvailabilityDispatcher {
         drop(conn);
 
         for batch in batches {
-            let dispatch_latency = METRICS.blob_dispatch_latency.start();
-            let dispatch_response = retry(self.config.max_retries(), batch.l1_batch_number, || {
-                self.client
-                    .dispatch_blob(batch.l1_batch_number.0, batch.pubdata.clone())
-            })
-            .await
-            .with_context(|| {
-                format!(
-                    "failed to dispatch a blob with batch_number: {}, pubdata_len: {}",
-                    batch.l1_batch_number,
-                    batch.pubdata.len()
-                )
-            })?;
-            let dispatch_latency_duration = dispatch_latency.observe();
-
-            let sent_at = Utc::now().naive_utc();
-
-            let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
-            conn.data_availability_dal()
-                .insert_l1_batch_da(
-                    batch.l1_batch_number,
-                    dispatch_response.blob_id.as_str(),
-                    sent_at,
-                )
-                .await?;
-            drop(conn);
+            let parts = split_pubdata(&batch.pubdata, self.client.blob_size_limit())?;
+            if parts.len() == 1 {
+                let blob_id = self.dispatch_blob(batch.l1_batch_number, parts[0]).await?;
+                let sent_at = Utc::now().naive_utc();
+                let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+                conn.data_availability_dal()
+                    .insert_l1_batch_da(batch.l1_batch_number, &blob_id, sent_at)
+                    .await?;
+                drop(conn);
+            } else {
+                self.dispatch_parts(batch.l1_batch_number, &parts).await?;
+            }
 
             METRICS
                 .last_dispatched_l1_batch
                 .set(batch.l1_batch_number.0 as usize);
-            METRICS.blob_size.observe(batch.pubdata.len());
             tracing::info!(
-                "Dispatched a DA for batch_number: {}, pubdata_size: {}, dispatch_latency: {dispatch_latency_duration:?}",
+                "Dispatched a DA for batch_number: {}, pubdata_size: {}, parts: {}",
                 batch.l1_batch_number,
                 batch.pubdata.len(),
+                parts.len()
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Dispatches a single blob with retries and returns its ID.
+    async fn dispatch_blob(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        data: &[u8],
+    ) -> anyhow::Result<String> {
+        let dispatch_latency = METRICS.blob_dispatch_latency.start();
+        let dispatch_response = retry(self.config.max_retries(), l1_batch_number, || {
+            self.client.dispatch_blob(l1_batch_number.0, data.to_vec())
+        })
+        .await
+        .with_context(|| {
+            format!(
+                "failed to dispatch a blob with batch_number: {l1_batch_number}, pubdata_len: {}",
+                data.len()
+            )
+        })?;
+        let dispatch_latency = dispatch_latency.observe();
+        METRICS.blob_size.observe(data.len());
+        tracing::debug!(
+            "Dispatched a blob for batch_number: {l1_batch_number}, blob_size: {}, dispatch_latency: {dispatch_latency:?}",
+            data.len()
+        );
+        Ok(dispatch_response.blob_id)
+    }
+
+    /// Dispatches pubdata parts for a batch, skipping the parts dispatched previously. Once all parts are dispatched,
+    /// records the batch as dispatched; its blob ID is the JSON array of the part blob IDs.
+    async fn dispatch_parts(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        parts: &[&[u8]],
+    ) -> anyhow::Result<()> {
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        let mut blob_ids: Vec<_> = conn
+            .data_availability_dal()
+            .get_l1_batch_da_parts(l1_batch_number)
+            .await?
+            .into_iter()
+            .map(|part| part.blob_id)
+            .collect();
+        drop(conn);
+
+        anyhow::ensure!(
+            blob_ids.len() <= parts.len(),
+            "L1 batch #{l1_batch_number} has {} parts dispatched, but its pubdata is split into {} parts; \
+             was the DA client blob size limit changed?",
+            blob_ids.len(),
+            parts.len()
+        );
+        if !blob_ids.is_empty() {
+            tracing::info!(
+                "Resuming dispatch for batch_number: {l1_batch_number} from part #{}",
+                blob_ids.len()
             );
         }
 
+        for (part_index, part) in parts.iter().enumerate().skip(blob_ids.len()) {
+            let blob_id = self.dispatch_blob(l1_batch_number, part).await?;
+            let sent_at = Utc::now().naive_utc();
+            let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+            conn.data_availability_dal()
+                .insert_l1_batch_da_part(l1_batch_number, part_index as u32, &blob_id, sent_at)
+                .await?;
+            drop(conn);
+            blob_ids.push(blob_id);
+        }
+
+        let composite_blob_id =
+            serde_json::to_string(&blob_ids).context("failed serializing blob IDs")?;
+        let sent_at = Utc::now().naive_utc();
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        conn.data_availability_dal()
+            .insert_l1_batch_da(l1_batch_number, &composite_blob_id, sent_at)
+            .await?;
         Ok(())
     }
 
@@ -137,15 +205,7 @@ impl DataAvailabilityDispatcher {
         };
 
         let inclusion_data = if self.config.use_dummy_inclusion_data() {
-            self.client
-                .get_inclusion_data(blob_info.blob_id.as_str())
-                .await
-                .with_context(|| {
-                    format!(
-                        "failed to get inclusion data for blob_id: {}, batch_number: {}",
-                        blob_info.blob_id, blob_info.l1_batch_number
-                    )
-                })?
+            self.get_inclusion_data(&blob_info).await?
         } else {
             // if the inclusion verification is disabled, we don't need to wait for the inclusion
             // data before committing the batch, so simply return an empty vector
@@ -181,6 +241,84 @@ impl DataAvailabilityDispatcher {
 
         Ok(())
     }
+
+    /// Gets composite inclusion data for the blob (see [`InclusionData::from_parts()`]). If the pubdata was dispatched
+    /// in several parts, returns the inclusion data once it is available for all parts; inclusion data for separate
+    /// parts is persisted as soon as it's received.
+    async fn get_inclusion_data(
+        &self,
+        blob_info: &DataAvailabilityBlob,
+    ) -> anyhow::Result<Option<InclusionData>> {
+        let l1_batch_number = blob_info.l1_batch_number;
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        let parts = conn
+            .data_availability_dal()
+            .get_l1_batch_da_parts(l1_batch_number)
+            .await?;
+        drop(conn);
+
+        if parts.is_empty() {
+            let inclusion_data = self
+                .client
+                .get_inclusion_data(blob_info.blob_id.as_str())
+                .await
+                .with_context(|| {
+                    format!(
+                        "failed to get inclusion data for blob_id: {}, batch_number: {l1_batch_number}",
+                        blob_info.blob_id
+                    )
+                })?;
+            // Wrap the inclusion data so that it has the same format as for the pubdata dispatched in several parts.
+            return Ok(inclusion_data.map(|data| InclusionData::from_parts(vec![data])));
+        }
+
+        let mut part_inclusion_data = Vec::with_capacity(parts.len());
+        for part in parts {
+            if let Some(data) = part.inclusion_data {
+                part_inclusion_data.push(InclusionData { data });
+                continue;
+            }
+
+            let inclusion_data = self
+                .client
+                .get_inclusion_data(part.blob_id.as_str())
+                .await
+                .with_context(|| {
+                    format!(
+                        "failed to get inclusion data for blob_id: {}, batch_number: {l1_batch_number}, part: {}",
+                        part.blob_id, part.part_index
+                    )
+                })?;
+            let Some(inclusion_data) = inclusion_data else {
+                tracing::debug!(
+                    "Inclusion data for batch_number: {l1_batch_number}, part: {} is not available yet",
+                    part.part_index
+                );
+                return Ok(None);
+            };
+
+            let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+            conn.data_availability_dal()
+                .save_l1_batch_da_part_inclusion_data(
+                    l1_batch_number,
+                    part.part_index,
+                    &inclusion_data.data,
+                )
+                .await?;
+            drop(conn);
+            part_inclusion_data.push(inclusion_data);
+        }
+        Ok(Some(InclusionData::from_parts(part_inclusion_data)))
+    }
+}
+
+/// Splits the pubdata into parts not exceeding the blob size limit.
+fn split_pubdata(pubdata: &[u8], blob_size_limit: Option<usize>) -> anyhow::Result<Vec<&[u8]>> {
+    match blob_size_limit {
+        Some(0) => anyhow::bail!("DA client reported zero blob size limit"),
+        Some(limit) if pubdata.len() > limit => Ok(pubdata.chunks(limit).collect()),
+        _ => Ok(vec![pubdata]),
+    }
 }
 
 async fn retry<T, Fut, F>(
@@ -216,3 +354,302 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashSet,
+        sync::{Arc, Mutex},
+    };
+
+    use async_trait::async_trait;
+    use zksync_da_client::types::DispatchResponse;
+    use zksync_dal::Connection;
+    use zksync_types::{block::L1BatchHeader, ProtocolVersion, ProtocolVersionId};
+
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct MockDAState {
+        /// Dispatched blobs; the blob ID is the index in this vector.
+        blobs: Vec<Vec<u8>>,
+        /// IDs of blobs with available inclusion data.
+        included_blobs: HashSet<String>,
+        /// If set, dispatch fails after the specified number of blobs is dispatched.
+        max_blobs: Option<usize>,
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct MockDAClient {
+        state: Arc<Mutex<MockDAState>>,
+        blob_size_limit: Option<usize>,
+    }
+
+    impl MockDAClient {
+        fn new(blob_size_limit: usize) -> Self {
+            Self {
+                state: Arc::default(),
+                blob_size_limit: Some(blob_size_limit),
+            }
+        }
+
+        fn include_blobs(&self, blob_ids: &[&str]) {
+            let mut state = self.state.lock().unwrap();
+            state
+                .included_blobs
+                .extend(blob_ids.iter().map(|&id| id.to_owned()));
+        }
+
+        fn inclusion_data(blob_id: &str) -> InclusionData {
+            InclusionData {
+                data: format!("proof-{blob_id}").into_bytes(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl DataAvailabilityClient for MockDAClient {
+        async fn dispatch_blob(
+            &self,
+            _batch_number: u32,
+            data: Vec<u8>,
+        ) -> Result<DispatchResponse, DAError> {
+            let mut state = self.state.lock().unwrap();
+            if state.max_blobs.is_some_and(|max| state.blobs.len() >= max) {
+                return Err(DAError {
+                    error: anyhow::anyhow!("DA layer is unavailable"),
+                    is_retriable: false,
+                });
+            }
+            state.blobs.push(data);
+            Ok(DispatchResponse {
+                blob_id: (state.blobs.len() - 1).to_string(),
+            })
+        }
+
+        async fn get_inclusion_data(
+            &self,
+            blob_id: &str,
+        ) -> Result<Option<InclusionData>, DAError> {
+            let state = self.state.lock().unwrap();
+            Ok(state
+                .included_blobs
+                .contains(blob_id)
+                .then(|| Self::inclusion_data(blob_id)))
+        }
+
+        fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+            Box::new(self.clone())
+        }
+
+        fn blob_size_limit(&self) -> Option<usize> {
+            self.blob_size_limit
+        }
+    }
+
+    async fn prepare_storage(conn: &mut Connection<'_, Core>, pubdata: Vec<u8>) {
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        let mut header = L1BatchHeader::new(
+            L1BatchNumber(1),
+            100,
+            Default::default(),
+            ProtocolVersionId::latest(),
+        );
+        header.pubdata_input = Some(pubdata);
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&header)
+            .await
+            .unwrap();
+    }
+
+    fn create_dispatcher(
+        pool: &ConnectionPool<Core>,
+        client: &MockDAClient,
+    ) -> DataAvailabilityDispatcher {
+        let config = DADispatcherConfig {
+            // Enables fetching inclusion data from the DA client.
+            use_dummy_inclusion_data: Some(true),
+            ..DADispatcherConfig::for_tests()
+        };
+        DataAvailabilityDispatcher::new(pool.clone(), config, Box::new(client.clone()))
+    }
+
+    fn pubdata() -> Vec<u8> {
+        (0_u8..100).collect()
+    }
+
+    async fn blob_awaiting_inclusion(
+        conn: &mut Connection<'_, Core>,
+    ) -> Option<DataAvailabilityBlob> {
+        conn.data_availability_dal()
+            .get_first_da_blob_awaiting_inclusion()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn dispatching_pubdata_in_parts() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        prepare_storage(&mut conn, pubdata()).await;
+
+        let client = MockDAClient::new(30);
+        let dispatcher = create_dispatcher(&pool, &client);
+        dispatcher.dispatch().await.unwrap();
+
+        let blobs = client.state.lock().unwrap().blobs.clone();
+        assert_eq!(blobs.len(), 4);
+        assert_eq!(blobs.concat(), pubdata());
+
+        let parts = conn
+            .data_availability_dal()
+            .get_l1_batch_da_parts(L1BatchNumber(1))
+            .await
+            .unwrap();
+        let blob_ids: Vec<_> = parts.iter().map(|part| part.blob_id.as_str()).collect();
+        assert_eq!(blob_ids, ["0", "1", "2", "3"]);
+        let blob = blob_awaiting_inclusion(&mut conn)
+            .await
+            .expect("batch is not dispatched");
+        assert_eq!(blob.l1_batch_number, L1BatchNumber(1));
+        assert_eq!(blob.blob_id, r#"["0","1","2","3"]"#);
+
+        // Repeated dispatch is a no-op.
+        dispatcher.dispatch().await.unwrap();
+        assert_eq!(client.state.lock().unwrap().blobs.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn resuming_dispatch_after_restart() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        prepare_storage(&mut conn, pubdata()).await;
+
+        let client = MockDAClient::new(30);
+        client.state.lock().unwrap().max_blobs = Some(2);
+        let dispatcher = create_dispatcher(&pool, &client);
+        dispatcher.dispatch().await.unwrap_err();
+
+        let parts = conn
+            .data_availability_dal()
+            .get_l1_batch_da_parts(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(parts.len(), 2);
+        let blob = blob_awaiting_inclusion(&mut conn).await;
+        assert!(blob.is_none(), "{blob:?}");
+
+        // Emulate a restart with the DA layer available again.
+        client.state.lock().unwrap().max_blobs = None;
+        let dispatcher = create_dispatcher(&pool, &client);
+        dispatcher.dispatch().await.unwrap();
+
+        // Only the remaining parts should be dispatched.
+        let blobs = client.state.lock().unwrap().blobs.clone();
+        assert_eq!(blobs.len(), 4);
+        assert_eq!(blobs.concat(), pubdata());
+        let blob = blob_awaiting_inclusion(&mut conn)
+            .await
+            .expect("batch is not dispatched");
+        assert_eq!(blob.blob_id, r#"["0","1","2","3"]"#);
+    }
+
+    #[tokio::test]
+    async fn merging_inclusion_data_for_parts() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        prepare_storage(&mut conn, pubdata()).await;
+
+        let client = MockDAClient::new(30);
+        let dispatcher = create_dispatcher(&pool, &client);
+        dispatcher.dispatch().await.unwrap();
+
+        // Inclusion data is only available for some parts.
+        client.include_blobs(&["0", "1", "3"]);
+        dispatcher.poll_for_inclusion().await.unwrap();
+        let blob = blob_awaiting_inclusion(&mut conn)
+            .await
+            .expect("batch is included without inclusion data for all parts");
+        assert_eq!(blob.inclusion_data, None);
+        let parts = conn
+            .data_availability_dal()
+            .get_l1_batch_da_parts(L1BatchNumber(1))
+            .await
+            .unwrap();
+        // Inclusion data for parts before the first missing one should be persisted.
+        assert!(parts[0].inclusion_data.is_some());
+        assert!(parts[1].inclusion_data.is_some());
+        assert!(parts[2].inclusion_data.is_none());
+
+        client.include_blobs(&["2"]);
+        let inclusion_data = dispatcher
+            .get_inclusion_data(&blob)
+            .await
+            .unwrap()
+            .expect("no inclusion data");
+        let expected_parts: Vec<_> = ["0", "1", "2", "3"]
+            .into_iter()
+            .map(MockDAClient::inclusion_data)
+            .collect();
+        assert_eq!(inclusion_data.decode_parts().unwrap(), expected_parts);
+
+        dispatcher.poll_for_inclusion().await.unwrap();
+        let blob = blob_awaiting_inclusion(&mut conn).await;
+        assert!(blob.is_none(), "{blob:?}");
+    }
+
+    #[tokio::test]
+    async fn inclusion_data_for_single_blob_has_composite_format() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        prepare_storage(&mut conn, pubdata()).await;
+
+        let client = MockDAClient::new(1_000);
+        let dispatcher = create_dispatcher(&pool, &client);
+        dispatcher.dispatch().await.unwrap();
+        let parts = conn
+            .data_availability_dal()
+            .get_l1_batch_da_parts(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert!(parts.is_empty());
+        let blob = blob_awaiting_inclusion(&mut conn)
+            .await
+            .expect("batch is not dispatched");
+        assert_eq!(blob.blob_id, "0");
+
+        client.include_blobs(&["0"]);
+        let inclusion_data = dispatcher
+            .get_inclusion_data(&blob)
+            .await
+            .unwrap()
+            .expect("no inclusion data");
+        assert_eq!(
+            inclusion_data.decode_parts().unwrap(),
+            [MockDAClient::inclusion_data("0")]
+        );
+
+        dispatcher.poll_for_inclusion().await.unwrap();
+        let blob = blob_awaiting_inclusion(&mut conn).await;
+        assert!(blob.is_none(), "{blob:?}");
+    }
+
+    #[test]
+    fn splitting_pubdata() {
+        let pubdata = vec![1_u8; 100];
+        assert_eq!(split_pubdata(&pubdata, None).unwrap(), [&pubdata[..]]);
+        assert_eq!(split_pubdata(&pubdata, Some(100)).unwrap(), [&pubdata[..]]);
+
+        let parts = split_pubdata(&pubdata, Some(30)).unwrap();
+        assert_eq!(
+            parts.iter().map(|part| part.len()).collect::<Vec<_>>(),
+            [30, 30, 30, 10]
+        );
+        assert_eq!(parts.concat(), pubdata);
+
+        split_pubdata(&pubdata, Some(0)).unwrap_err();
+    }
+}
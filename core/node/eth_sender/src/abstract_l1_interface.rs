@@ -16,6 +16,9 @@ use zksync_types::{
 
 use crate::EthSenderError;
 
+/// Gas limit of a plain ETH transfer used to cancel stuck transactions.
+const CANCELLATION_TX_GAS: u64 = 21_000;
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct OperatorNonce {
     // Nonce on finalized block
@@ -76,6 +79,12 @@ pub(super) trait AbstractL1Interface: 'static + Sync + Send + fmt::Debug {
         operator_type: OperatorType,
     ) -> Result<Option<OperatorNonce>, EthSenderError>;
 
+    /// Returns the operator nonce including transactions in the mempool.
+    async fn get_pending_operator_nonce(
+        &self,
+        operator_type: OperatorType,
+    ) -> Result<Nonce, EthSenderError>;
+
     async fn sign_tx(
         &self,
         tx: &EthTx,
@@ -86,6 +95,16 @@ pub(super) trait AbstractL1Interface: 'static + Sync + Send + fmt::Debug {
         operator_type: OperatorType,
     ) -> SignedCallResult;
 
+    /// Signs a zero-value self-transfer with the specified nonce. Once mined, it cancels
+    /// an in-flight transaction with the same nonce.
+    async fn sign_cancellation_tx(
+        &self,
+        nonce: Nonce,
+        base_fee_per_gas: u64,
+        priority_fee_per_gas: u64,
+        operator_type: OperatorType,
+    ) -> SignedCallResult;
+
     async fn get_l1_block_numbers(
         &self,
         operator_type: OperatorType,
@@ -203,6 +222,17 @@ impl AbstractL1Interface for RealL1Interface {
         Ok(Some(OperatorNonce { finalized, latest }))
     }
 
+    async fn get_pending_operator_nonce(
+        &self,
+        operator_type: OperatorType,
+    ) -> Result<Nonce, EthSenderError> {
+        let pending = self
+            .bound_query_client(operator_type)
+            .pending_nonce()
+            .await?;
+        Ok(pending.as_u32().into())
+    }
+
     async fn sign_tx(
         &self,
         tx: &EthTx,
@@ -240,6 +270,31 @@ impl AbstractL1Interface for RealL1Interface {
             .expect("Failed to sign transaction")
     }
 
+    async fn sign_cancellation_tx(
+        &self,
+        nonce: Nonce,
+        base_fee_per_gas: u64,
+        priority_fee_per_gas: u64,
+        operator_type: OperatorType,
+    ) -> SignedCallResult {
+        let client = self.bound_query_client(operator_type);
+        client
+            .sign_prepared_tx_for_addr(
+                vec![],
+                client.sender_account(),
+                Options::with(|opt| {
+                    opt.gas = Some(CANCELLATION_TX_GAS.into());
+                    opt.value = Some(U256::zero());
+                    opt.max_fee_per_gas = Some(U256::from(base_fee_per_gas + priority_fee_per_gas));
+                    opt.max_priority_fee_per_gas = Some(U256::from(priority_fee_per_gas));
+                    opt.nonce = Some(nonce.0.into());
+                    opt.transaction_type = Some(EIP_1559_TX_TYPE.into());
+                }),
+            )
+            .await
+            .expect("Failed to sign cancellation transaction")
+    }
+
     async fn get_l1_block_numbers(
         &self,
         operator_type: OperatorType,
@@ -9,6 +9,10 @@ pub enum EthSenderError {
     ContractCall(#[from] ContractCallError),
     #[error("Token parsing error: {0}")]
     Parse(#[from] contract::Error),
+    #[error("Internal error: {0:#}")]
+    Internal(#[from] anyhow::Error),
+    #[error("Priority fee per gas {fee} exceeds the acceptable maximum {max}")]
+    PriorityFeeTooHigh { fee: u64, max: u64 },
 }
 
 impl EthSenderError {
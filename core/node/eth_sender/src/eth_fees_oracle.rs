@@ -96,14 +96,7 @@ impl GasAdjusterFeesOracle {
             );
         }
 
-        // Extra check to prevent sending transaction will extremely high priority fee.
-        if priority_fee_per_gas > self.max_acceptable_priority_fee_in_gwei {
-            panic!(
-                "Extremely high value of priority_fee_per_gas is suggested: {}, while max acceptable is {}",
-                priority_fee_per_gas,
-                self.max_acceptable_priority_fee_in_gwei
-            );
-        }
+        self.check_priority_fee_is_acceptable(priority_fee_per_gas)?;
 
         Ok(EthFees {
             base_fee_per_gas,
@@ -113,6 +106,26 @@ impl GasAdjusterFeesOracle {
         })
     }
 
+    // Extra check to prevent sending transaction will extremely high priority fee. Such fees may be suggested
+    // during L1 fee spikes; in this case, sending is skipped until fees go down.
+    fn check_priority_fee_is_acceptable(
+        &self,
+        priority_fee_per_gas: u64,
+    ) -> Result<(), EthSenderError> {
+        if priority_fee_per_gas > self.max_acceptable_priority_fee_in_gwei {
+            tracing::warn!(
+                "Extremely high value of priority_fee_per_gas is suggested: {priority_fee_per_gas}, \
+                 while max acceptable is {}; skipping sending",
+                self.max_acceptable_priority_fee_in_gwei
+            );
+            return Err(EthSenderError::PriorityFeeTooHigh {
+                fee: priority_fee_per_gas,
+                max: self.max_acceptable_priority_fee_in_gwei,
+            });
+        }
+        Ok(())
+    }
+
     fn verify_base_fee_not_too_low_on_resend(
         &self,
         tx_id: u32,
@@ -159,3 +172,298 @@ impl EthFeesOracle for GasAdjusterFeesOracle {
         }
     }
 }
+
+/// Fees oracle that bumps fees of a resent transaction by a fixed percentage of the previous attempt, so that fees grow
+/// exponentially with the number of resends. If configured, `base_fee_per_gas + priority_fee_per_gas` is capped
+/// by the fee budget; once the budget doesn't allow to replace the previous attempt, resending fails.
+#[derive(Debug)]
+pub(crate) struct ExponentialBumpFeesOracle {
+    pub inner: GasAdjusterFeesOracle,
+    pub bump_percent: u32,
+    pub max_fee_per_gas: Option<u64>,
+}
+
+impl ExponentialBumpFeesOracle {
+    fn bump(&self, fee: u64, operator_type: OperatorType) -> u64 {
+        // Blob transactions require all fees to be doubled on replacement.
+        let bump_percent = if operator_type == OperatorType::Blob {
+            max(self.bump_percent, 100)
+        } else {
+            self.bump_percent
+        };
+        let bumped_fee = u128::from(fee) * (100 + u128::from(bump_percent)) / 100;
+        bumped_fee.try_into().unwrap_or(u64::MAX)
+    }
+
+    /// Returns the minimal base fee that allows to replace the previous attempt in the mempool.
+    fn min_replacement_base_fee(previous_base_fee: u64, operator_type: OperatorType) -> u64 {
+        if operator_type == OperatorType::Blob {
+            previous_base_fee.saturating_mul(2)
+        } else {
+            previous_base_fee + previous_base_fee / 10 + 1
+        }
+    }
+
+    fn budget_exhausted_error(
+        fees: &EthFees,
+        max_fee_per_gas: u64,
+        previous_sent_tx: Option<&TxHistory>,
+    ) -> EthSenderError {
+        let err = ClientError::Custom("resend fee budget exhausted".into());
+        let mut err = EnrichedClientError::new(err, "calculate_fees")
+            .with_arg("base_fee_per_gas", &fees.base_fee_per_gas)
+            .with_arg("priority_fee_per_gas", &fees.priority_fee_per_gas)
+            .with_arg("max_fee_per_gas", &max_fee_per_gas);
+        if let Some(previous_sent_tx) = previous_sent_tx {
+            err = err.with_arg("previous_base_fee", &previous_sent_tx.base_fee_per_gas);
+        }
+        err.into()
+    }
+}
+
+impl EthFeesOracle for ExponentialBumpFeesOracle {
+    fn calculate_fees(
+        &self,
+        previous_sent_tx: &Option<TxHistory>,
+        time_in_mempool: u32,
+        operator_type: OperatorType,
+    ) -> Result<EthFees, EthSenderError> {
+        let mut fees =
+            self.inner
+                .calculate_fees(previous_sent_tx, time_in_mempool, operator_type)?;
+
+        if let Some(previous_sent_tx) = previous_sent_tx {
+            fees.base_fee_per_gas = max(
+                fees.base_fee_per_gas,
+                self.bump(previous_sent_tx.base_fee_per_gas, operator_type),
+            );
+            fees.priority_fee_per_gas = max(
+                fees.priority_fee_per_gas,
+                self.bump(previous_sent_tx.priority_fee_per_gas, operator_type),
+            );
+            if operator_type == OperatorType::Blob {
+                fees.blob_base_fee_per_gas = max(
+                    fees.blob_base_fee_per_gas,
+                    previous_sent_tx
+                        .blob_base_fee_per_gas
+                        .map(|fee| self.bump(fee, operator_type)),
+                );
+            }
+            self.inner
+                .check_priority_fee_is_acceptable(fees.priority_fee_per_gas)?;
+        }
+
+        let Some(max_fee_per_gas) = self.max_fee_per_gas else {
+            return Ok(fees);
+        };
+        if fees.priority_fee_per_gas >= max_fee_per_gas {
+            return Err(Self::budget_exhausted_error(
+                &fees,
+                max_fee_per_gas,
+                previous_sent_tx.as_ref(),
+            ));
+        }
+        fees.base_fee_per_gas = min(
+            fees.base_fee_per_gas,
+            max_fee_per_gas - fees.priority_fee_per_gas,
+        );
+
+        if let Some(previous_sent_tx) = previous_sent_tx {
+            let min_base_fee =
+                Self::min_replacement_base_fee(previous_sent_tx.base_fee_per_gas, operator_type);
+            if fees.base_fee_per_gas < min_base_fee {
+                tracing::warn!(
+                    "Resend fee budget {max_fee_per_gas} is exhausted for tx {}, \
+                     capped base_fee_per_gas {} is below the replacement minimum {min_base_fee}",
+                    previous_sent_tx.id,
+                    fees.base_fee_per_gas
+                );
+                return Err(Self::budget_exhausted_error(
+                    &fees,
+                    max_fee_per_gas,
+                    Some(previous_sent_tx),
+                ));
+            }
+        }
+        Ok(fees)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use zksync_types::H256;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct MockTxParams {
+        base_fee: u64,
+        priority_fee: u64,
+    }
+
+    impl TxParamsProvider for MockTxParams {
+        fn get_base_fee(&self, _time_in_mempool: u32) -> u64 {
+            self.base_fee
+        }
+
+        fn get_priority_fee(&self) -> u64 {
+            self.priority_fee
+        }
+
+        fn get_next_block_minimal_base_fee(&self) -> u64 {
+            self.base_fee * 7 / 8
+        }
+
+        fn get_blob_tx_base_fee(&self) -> u64 {
+            self.base_fee * 2
+        }
+
+        fn get_blob_tx_blob_base_fee(&self) -> u64 {
+            1
+        }
+
+        fn get_blob_tx_priority_fee(&self) -> u64 {
+            self.priority_fee
+        }
+
+        fn get_gateway_tx_base_fee(&self) -> u64 {
+            self.base_fee
+        }
+
+        fn get_gateway_tx_pubdata_price(&self) -> u64 {
+            0
+        }
+    }
+
+    #[test]
+    fn excessive_priority_fee_is_rejected() {
+        let oracle = GasAdjusterFeesOracle {
+            gas_adjuster: Arc::new(MockTxParams {
+                base_fee: 1_000,
+                priority_fee: 100,
+            }),
+            max_acceptable_priority_fee_in_gwei: 110,
+        };
+        let fees = oracle
+            .calculate_fees(&None, 0, OperatorType::NonBlob)
+            .unwrap();
+        assert_eq!(fees.priority_fee_per_gas, 100);
+
+        // The priority fee is bumped by 20% on resend, which exceeds the acceptable maximum.
+        let err = oracle
+            .calculate_fees(&sent_tx(&fees), 0, OperatorType::NonBlob)
+            .unwrap_err();
+        assert_matches!(
+            err,
+            EthSenderError::PriorityFeeTooHigh { fee: 121, max: 110 }
+        );
+    }
+
+    fn exponential_oracle(max_fee_per_gas: Option<u64>) -> ExponentialBumpFeesOracle {
+        ExponentialBumpFeesOracle {
+            inner: GasAdjusterFeesOracle {
+                gas_adjuster: Arc::new(MockTxParams {
+                    base_fee: 1_000,
+                    priority_fee: 100,
+                }),
+                max_acceptable_priority_fee_in_gwei: u64::MAX,
+            },
+            bump_percent: 50,
+            max_fee_per_gas,
+        }
+    }
+
+    fn sent_tx(fees: &EthFees) -> Option<TxHistory> {
+        Some(TxHistory {
+            id: 1,
+            eth_tx_id: 1,
+            base_fee_per_gas: fees.base_fee_per_gas,
+            priority_fee_per_gas: fees.priority_fee_per_gas,
+            blob_base_fee_per_gas: fees.blob_base_fee_per_gas,
+            tx_hash: H256::zero(),
+            signed_raw_tx: vec![],
+            sent_at_block: Some(1),
+        })
+    }
+
+    #[test]
+    fn exponential_bump_on_resends() {
+        let oracle = exponential_oracle(None);
+        let fees = oracle
+            .calculate_fees(&None, 0, OperatorType::NonBlob)
+            .unwrap();
+        assert_eq!(fees.base_fee_per_gas, 1_000);
+        assert_eq!(fees.priority_fee_per_gas, 100);
+
+        let fees = oracle
+            .calculate_fees(&sent_tx(&fees), 0, OperatorType::NonBlob)
+            .unwrap();
+        assert_eq!(fees.base_fee_per_gas, 1_500);
+        assert_eq!(fees.priority_fee_per_gas, 150);
+
+        let fees = oracle
+            .calculate_fees(&sent_tx(&fees), 0, OperatorType::NonBlob)
+            .unwrap();
+        assert_eq!(fees.base_fee_per_gas, 2_250);
+        assert_eq!(fees.priority_fee_per_gas, 225);
+    }
+
+    #[test]
+    fn blob_fees_are_at_least_doubled_on_resend() {
+        let oracle = exponential_oracle(None);
+        let fees = oracle.calculate_fees(&None, 0, OperatorType::Blob).unwrap();
+        assert_eq!(fees.base_fee_per_gas, 2_000);
+        assert_eq!(fees.blob_base_fee_per_gas, Some(1));
+
+        let fees = oracle
+            .calculate_fees(&sent_tx(&fees), 0, OperatorType::Blob)
+            .unwrap();
+        assert_eq!(fees.base_fee_per_gas, 4_000);
+        assert_eq!(fees.priority_fee_per_gas, 200);
+        assert_eq!(fees.blob_base_fee_per_gas, Some(2));
+    }
+
+    #[test]
+    fn resend_fees_are_capped_by_budget() {
+        let oracle = exponential_oracle(Some(2_000));
+        let fees = oracle
+            .calculate_fees(&None, 0, OperatorType::NonBlob)
+            .unwrap();
+        assert_eq!(fees.base_fee_per_gas, 1_000);
+
+        let fees = oracle
+            .calculate_fees(&sent_tx(&fees), 0, OperatorType::NonBlob)
+            .unwrap();
+        assert_eq!(fees.base_fee_per_gas, 1_500);
+        assert_eq!(fees.priority_fee_per_gas, 150);
+
+        // The bumped base fee (2_250) is capped, but is still enough to replace the previous attempt.
+        let fees = oracle
+            .calculate_fees(&sent_tx(&fees), 0, OperatorType::NonBlob)
+            .unwrap();
+        assert_eq!(fees.base_fee_per_gas, 1_775);
+        assert_eq!(fees.priority_fee_per_gas, 225);
+
+        // The next resend cannot replace the previous attempt within the budget.
+        let err = oracle
+            .calculate_fees(&sent_tx(&fees), 0, OperatorType::NonBlob)
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("resend fee budget exhausted"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn priority_fee_exceeding_budget_is_rejected() {
+        let oracle = exponential_oracle(Some(100));
+        let err = oracle
+            .calculate_fees(&None, 0, OperatorType::NonBlob)
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("resend fee budget exhausted"),
+            "{err}"
+        );
+    }
+}
@@ -1,14 +1,18 @@
-use std::{sync::Arc, time::Duration};
+use std::{cmp::max, collections::BTreeMap, sync::Arc, time::Duration};
 
+use anyhow::Context as _;
 use tokio::sync::watch;
-use zksync_config::configs::eth_sender::SenderConfig;
+use zksync_config::configs::eth_sender::{ResendFeeStrategy, SenderConfig};
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
 use zksync_eth_client::{
     encode_blob_tx_with_sidecar, BoundEthInterface, ExecutedTxStatus, RawTransactionBytes,
 };
 use zksync_node_fee_model::l1_gas_price::TxParamsProvider;
 use zksync_shared_metrics::BlockL1Stage;
-use zksync_types::{eth_sender::EthTx, Address, L1BlockNumber, H256, U256};
+use zksync_types::{
+    eth_sender::{EthTx, TxCancellation},
+    Address, L1BlockNumber, Nonce, H256, U256,
+};
 use zksync_utils::time::seconds_since_epoch;
 
 use super::{metrics::METRICS, EthSenderError};
@@ -16,7 +20,7 @@ use crate::{
     abstract_l1_interface::{
         AbstractL1Interface, L1BlockNumbers, OperatorNonce, OperatorType, RealL1Interface,
     },
-    eth_fees_oracle::{EthFees, EthFeesOracle, GasAdjusterFeesOracle},
+    eth_fees_oracle::{EthFees, EthFeesOracle, ExponentialBumpFeesOracle, GasAdjusterFeesOracle},
     metrics::TransactionType,
 };
 
@@ -30,6 +34,12 @@ pub struct EthTxManager {
     l1_interface: Box<dyn AbstractL1Interface>,
     config: SenderConfig,
     fees_oracle: Box<dyn EthFeesOracle>,
+    gas_adjuster: Arc<dyn TxParamsProvider>,
+    /// Pending cancellations of the main operator transactions, keyed by nonce. New transactions are not sent
+    /// while there are pending cancellations, since they would be mined before the cancelled operations are resent.
+    /// Mirrors the cancellations persisted in Postgres, which are loaded on the first loop iteration.
+    cancellations: BTreeMap<Nonce, TxCancellation>,
+    cancellations_loaded: bool,
     pool: ConnectionPool<Core>,
 }
 
@@ -45,10 +55,18 @@ impl EthTxManager {
         let ethereum_gateway = ethereum_gateway.map(|eth| eth.for_component("eth_tx_manager"));
         let ethereum_gateway_blobs =
             ethereum_gateway_blobs.map(|eth| eth.for_component("eth_tx_manager"));
-        let fees_oracle = GasAdjusterFeesOracle {
-            gas_adjuster,
+        let gas_adjuster_oracle = GasAdjusterFeesOracle {
+            gas_adjuster: gas_adjuster.clone(),
             max_acceptable_priority_fee_in_gwei: config.max_acceptable_priority_fee_in_gwei,
         };
+        let fees_oracle: Box<dyn EthFeesOracle> = match config.resend_fee_strategy {
+            ResendFeeStrategy::Minimal => Box::new(gas_adjuster_oracle),
+            ResendFeeStrategy::Exponential => Box::new(ExponentialBumpFeesOracle {
+                inner: gas_adjuster_oracle,
+                bump_percent: config.resend_fee_bump_percent,
+                max_fee_per_gas: config.max_resend_fee_per_gas,
+            }),
+        };
         let l1_interface = Box::new(RealL1Interface {
             ethereum_gateway,
             ethereum_gateway_blobs,
@@ -62,7 +80,10 @@ impl EthTxManager {
         Self {
             l1_interface,
             config,
-            fees_oracle: Box::new(fees_oracle),
+            fees_oracle,
+            gas_adjuster,
+            cancellations: BTreeMap::new(),
+            cancellations_loaded: false,
             pool,
         }
     }
@@ -72,6 +93,13 @@ impl EthTxManager {
         self.l1_interface.as_ref()
     }
 
+    #[cfg(test)]
+    pub(crate) fn cancellation_tx_hash(&self, nonce: Nonce) -> Option<H256> {
+        self.cancellations
+            .get(&nonce)
+            .map(|cancellation| cancellation.tx_hash)
+    }
+
     async fn check_all_sending_attempts(
         &self,
         storage: &mut Connection<'_, Core>,
@@ -282,6 +310,20 @@ impl EthTxManager {
             .await?;
 
         if let Some(operator_nonce) = operator_nonce {
+            if operator_type == OperatorType::NonBlob {
+                // Nonces consumed on a finalized block cannot be reused, so cancellations for them are no longer needed.
+                let cancellations_count = self.cancellations.len();
+                self.cancellations
+                    .retain(|&nonce, _| nonce >= operator_nonce.finalized);
+                if self.cancellations.len() < cancellations_count {
+                    storage
+                        .eth_sender_dal()
+                        .delete_tx_cancellations_before(operator_nonce.finalized)
+                        .await
+                        .context("delete_tx_cancellations_before()")?;
+                }
+            }
+
             let inflight_txs = storage
                 .eth_sender_dal()
                 .get_inflight_txs(
@@ -322,6 +364,7 @@ impl EthTxManager {
             operator_nonce.finalized,
         );
 
+        let mut unmined_tx_found = false;
         // Not confirmed transactions, ordered by nonce
         for tx in inflight_txs {
             tracing::info!(
@@ -335,6 +378,21 @@ impl EthTxManager {
             // that `tx` is not mined and we should resend it.
             // We only resend the first un-mined transaction.
             if operator_nonce.latest <= tx.nonce {
+                // If the first un-mined transaction has a nonce greater than the operator's nonce,
+                // neither it nor any of the subsequent transactions can be mined until the gap is filled.
+                if !unmined_tx_found && operator_nonce.latest < tx.nonce {
+                    tracing::warn!(
+                        "Nonce gap detected: operator's latest nonce is {}, but the first un-mined tx {} has nonce {}",
+                        operator_nonce.latest,
+                        tx.id,
+                        tx.nonce,
+                    );
+                    self.fill_nonce_gap(&tx, operator_nonce.latest, l1_block_numbers.latest)
+                        .await?;
+                    return Ok(None);
+                }
+                unmined_tx_found = true;
+
                 let last_sent_at_block = storage
                     .eth_sender_dal()
                     .get_block_number_on_last_sent_attempt(tx.id)
@@ -384,14 +442,20 @@ impl EthTxManager {
                         .await;
                 }
                 Ok(None) => {
-                    // The nonce has increased but we did not find the receipt.
-                    // This is an error because such a big re-org may cause transactions that were
-                    // previously recorded as confirmed to become pending again and we have to
-                    // make sure it's not the case - otherwise `eth_sender` may not work properly.
-                    tracing::error!(
-                        "Possible block reorgs: finalized nonce increase detected, but no tx receipt found for tx {:?}",
+                    // The nonce has increased on a finalized block but we did not find the receipt.
+                    // This means that the nonce was consumed by another transaction (e.g., a cancellation
+                    // or a transaction sent by another process using the operator key), so the operation
+                    // has to be sent again with a new nonce.
+                    tracing::warn!(
+                        "Finalized nonce increase detected, but no tx receipt found for tx {:?}; the tx was replaced",
                         &tx
                     );
+                    if self
+                        .reassign_nonces_after_replaced_tx(storage, &tx, operator_nonce)
+                        .await?
+                    {
+                        return Ok(None);
+                    }
                 }
                 Err(err) => {
                     // An error here means that we weren't able to check status of one of the txs
@@ -405,6 +469,154 @@ impl EthTxManager {
         Ok(None)
     }
 
+    /// Reassigns nonces starting from `replaced_tx` unless one of the subsequent transactions
+    /// with consumed nonces was mined; in the latter case, the state cannot be reconciled automatically.
+    /// Returns whether the nonces were reassigned.
+    async fn reassign_nonces_after_replaced_tx(
+        &mut self,
+        storage: &mut Connection<'_, Core>,
+        replaced_tx: &EthTx,
+        operator_nonce: OperatorNonce,
+    ) -> Result<bool, EthSenderError> {
+        let subsequent_txs = storage
+            .eth_sender_dal()
+            .get_inflight_txs(replaced_tx.from_addr, replaced_tx.is_gateway)
+            .await
+            .context("get_inflight_txs()")?;
+        for tx in subsequent_txs
+            .iter()
+            .filter(|tx| tx.id > replaced_tx.id && tx.nonce < operator_nonce.latest)
+        {
+            if let Some(tx_status) = self.check_all_sending_attempts(storage, tx).await? {
+                tracing::error!(
+                    "Tx {} was replaced, but the subsequent tx {} was mined in block {:?}; nonces cannot be \
+                     reassigned automatically, run `reconcile-eth-sender-nonces` command of the block reverter",
+                    replaced_tx.id,
+                    tx.id,
+                    tx_status.receipt.block_number,
+                );
+                return Ok(false);
+            }
+        }
+
+        self.reassign_nonces(storage, replaced_tx, operator_nonce.latest)
+            .await
+    }
+
+    /// Fills the gap between the operator's nonce on the latest block and the nonce of `first_tx`
+    /// with cancellation (i.e., empty self-transfer) transactions.
+    ///
+    /// The nonces of `first_tx` and subsequent transactions are intentionally left intact: their previous attempts
+    /// may be queued in the mempool, so they could be mined after nonce reassignment, sending the same operations twice.
+    /// Nonces already occupied by the operator transactions in the mempool (i.e., below the pending nonce) are skipped,
+    /// so that the gap is not filled again on subsequent iterations.
+    async fn fill_nonce_gap(
+        &self,
+        first_tx: &EthTx,
+        latest_nonce: Nonce,
+        current_block: L1BlockNumber,
+    ) -> Result<(), EthSenderError> {
+        let operator_type = self.operator_type(first_tx);
+        let pending_nonce = self
+            .l1_interface
+            .get_pending_operator_nonce(operator_type)
+            .await?;
+        if pending_nonce >= first_tx.nonce {
+            tracing::info!(
+                "Nonce gap before tx {} (nonce {}) is being filled by {operator_type:?} operator's mempool txs \
+                 (latest nonce {latest_nonce}, pending nonce {pending_nonce})",
+                first_tx.id,
+                first_tx.nonce,
+            );
+            return Ok(());
+        }
+
+        let base_fee_per_gas = self.gas_adjuster.get_base_fee(0);
+        let priority_fee_per_gas = self.gas_adjuster.get_priority_fee();
+        for nonce in max(latest_nonce.0, pending_nonce.0)..first_tx.nonce.0 {
+            let nonce = Nonce(nonce);
+            let signed_tx = self
+                .l1_interface
+                .sign_cancellation_tx(nonce, base_fee_per_gas, priority_fee_per_gas, operator_type)
+                .await;
+            if let Err(error) = self
+                .l1_interface
+                .send_raw_tx(signed_tx.raw_tx, operator_type)
+                .await
+            {
+                tracing::warn!(
+                    "Error sending tx filling the nonce gap before tx {} (nonce {nonce}): {error}",
+                    first_tx.id
+                );
+                if error.is_retriable() {
+                    METRICS.l1_transient_errors.inc();
+                }
+                // Subsequent nonces cannot be mined without this one anyway.
+                break;
+            }
+            tracing::info!(
+                "Sent tx {:?} filling the nonce gap before tx {} (nonce {nonce}) at block {current_block} \
+                 with base_fee_per_gas {base_fee_per_gas}, priority_fee_per_gas {priority_fee_per_gas}",
+                signed_tx.hash,
+                first_tx.id,
+            );
+            METRICS.nonce_gap_fill_txs_sent.inc();
+        }
+        Ok(())
+    }
+
+    /// Assigns consecutive nonces starting from `first_nonce` (the operator's nonce on the latest block)
+    /// to `first_tx`, the nonce of which was consumed by another transaction, and all subsequent unconfirmed
+    /// transactions of the same operator. The transactions are then sent again as new ones.
+    ///
+    /// Nonces are only reassigned if the operator has no transactions in the mempool (i.e., its pending nonce is equal
+    /// to the latest one); otherwise, a mempool transaction may still consume one of the reassigned nonces.
+    /// Returns whether the nonces were reassigned.
+    async fn reassign_nonces(
+        &mut self,
+        storage: &mut Connection<'_, Core>,
+        first_tx: &EthTx,
+        first_nonce: Nonce,
+    ) -> Result<bool, EthSenderError> {
+        let operator_type = self.operator_type(first_tx);
+        let pending_nonce = self
+            .l1_interface
+            .get_pending_operator_nonce(operator_type)
+            .await?;
+        if pending_nonce != first_nonce {
+            tracing::warn!(
+                "Not reassigning nonces starting from tx {} yet: {operator_type:?} operator's pending nonce \
+                 {pending_nonce} differs from its latest nonce {first_nonce}",
+                first_tx.id,
+            );
+            return Ok(false);
+        }
+
+        let reassigned_count = storage
+            .eth_sender_dal()
+            .reassign_nonces(
+                first_tx.id,
+                first_tx.from_addr,
+                first_tx.is_gateway,
+                first_nonce.0.into(),
+            )
+            .await
+            .context("reassign_nonces()")?;
+        tracing::warn!(
+            "Reassigned nonces of {reassigned_count} txs starting from tx {}; \
+             first tx nonce changed from {} to {first_nonce}",
+            first_tx.id,
+            first_tx.nonce,
+        );
+        METRICS.nonce_reassignments.inc();
+
+        // Persisted cancellations of the main operator are removed by the DAL method.
+        if operator_type == OperatorType::NonBlob {
+            self.cancellations.clear();
+        }
+        Ok(true)
+    }
+
     async fn apply_tx_status(
         &self,
         storage: &mut Connection<'_, Core>,
@@ -543,6 +755,14 @@ impl EthTxManager {
         current_block: L1BlockNumber,
         operator_type: OperatorType,
     ) {
+        if operator_type == OperatorType::NonBlob && !self.cancellations.is_empty() {
+            tracing::debug!(
+                "Not sending new {operator_type:?} transactions while {} cancellations are pending",
+                self.cancellations.len()
+            );
+            return;
+        }
+
         let number_inflight_txs = storage
             .eth_sender_dal()
             .get_inflight_txs(
@@ -603,6 +823,18 @@ impl EthTxManager {
             // New gas price depends on the time this tx spent in mempool.
             let time_in_mempool = l1_block_numbers.latest.0 - sent_at_block;
 
+            if operator_type == OperatorType::NonBlob {
+                let is_stuck = self
+                    .config
+                    .cancel_stuck_txs_after_blocks
+                    .is_some_and(|blocks| time_in_mempool >= blocks);
+                if is_stuck || !self.cancellations.is_empty() {
+                    self.cancel_stuck_txs(storage, &tx, l1_block_numbers.latest)
+                        .await?;
+                    return Ok(());
+                }
+            }
+
             // We don't want to return early in case resend does not succeed -
             // the error is logged anyway, but early returns will prevent
             // sending new operations.
@@ -613,6 +845,160 @@ impl EthTxManager {
         Ok(())
     }
 
+    /// Cancels `stuck_tx` and all subsequent in-flight transactions of the main operator (or resends pending
+    /// cancellations with increased fees). All subsequent transactions are cancelled, since otherwise they would
+    /// be mined right after the stuck transaction is cancelled, i.e., before the cancelled operation is sent again.
+    async fn cancel_stuck_txs(
+        &mut self,
+        storage: &mut Connection<'_, Core>,
+        stuck_tx: &EthTx,
+        current_block: L1BlockNumber,
+    ) -> Result<(), EthSenderError> {
+        let inflight_txs = storage
+            .eth_sender_dal()
+            .get_inflight_txs(self.operator_address(OperatorType::NonBlob), false)
+            .await
+            .context("get_inflight_txs()")?;
+
+        // Cancellations are sent starting from the greatest nonce, so that subsequent transactions
+        // are already replaced in the mempool once the stuck transaction is.
+        for tx in inflight_txs
+            .iter()
+            .rev()
+            .filter(|tx| tx.nonce >= stuck_tx.nonce)
+        {
+            let (previous_base_fee_per_gas, previous_priority_fee_per_gas) =
+                match self.cancellations.get(&tx.nonce) {
+                    // The cancellation may still be included in the last block, we shouldn't resend it yet.
+                    Some(cancellation) if cancellation.sent_at_block >= current_block.0 => continue,
+                    Some(cancellation) => (
+                        cancellation.base_fee_per_gas,
+                        cancellation.priority_fee_per_gas,
+                    ),
+                    None => {
+                        let Some(last_sent_tx) = storage
+                            .eth_sender_dal()
+                            .get_last_sent_eth_tx(tx.id)
+                            .await
+                            .context("get_last_sent_eth_tx()")?
+                        else {
+                            continue;
+                        };
+                        tracing::warn!(
+                            "Cancelling tx {} (nonce {}) stuck since tx {} (nonce {})",
+                            tx.id,
+                            tx.nonce,
+                            stuck_tx.id,
+                            stuck_tx.nonce
+                        );
+                        (
+                            last_sent_tx.base_fee_per_gas,
+                            last_sent_tx.priority_fee_per_gas,
+                        )
+                    }
+                };
+
+            self.send_cancellation(
+                storage,
+                tx.nonce,
+                previous_base_fee_per_gas,
+                previous_priority_fee_per_gas,
+                current_block,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn send_cancellation(
+        &mut self,
+        storage: &mut Connection<'_, Core>,
+        nonce: Nonce,
+        previous_base_fee_per_gas: u64,
+        previous_priority_fee_per_gas: u64,
+        current_block: L1BlockNumber,
+    ) -> Result<(), EthSenderError> {
+        // Replacing a transaction in the mempool requires increasing both fees; the resend budget
+        // is intentionally ignored since a stuck operation blocks all subsequent ones.
+        let base_fee_per_gas = max(
+            self.gas_adjuster.get_base_fee(0),
+            previous_base_fee_per_gas + (previous_base_fee_per_gas / 10) + 1,
+        );
+        let priority_fee_per_gas = max(
+            self.gas_adjuster.get_priority_fee(),
+            (previous_priority_fee_per_gas * 6) / 5 + 1,
+        );
+
+        let signed_tx = self
+            .l1_interface
+            .sign_cancellation_tx(
+                nonce,
+                base_fee_per_gas,
+                priority_fee_per_gas,
+                OperatorType::NonBlob,
+            )
+            .await;
+        match self
+            .l1_interface
+            .send_raw_tx(signed_tx.raw_tx, OperatorType::NonBlob)
+            .await
+        {
+            Ok(_) => {
+                tracing::info!(
+                    "Sent cancellation tx {:?} (nonce {nonce}) at block {current_block} with \
+                     base_fee_per_gas {base_fee_per_gas}, priority_fee_per_gas {priority_fee_per_gas}",
+                    signed_tx.hash
+                );
+                METRICS.cancellation_txs_sent.inc();
+                let cancellation = TxCancellation {
+                    nonce,
+                    tx_hash: signed_tx.hash,
+                    base_fee_per_gas,
+                    priority_fee_per_gas,
+                    sent_at_block: current_block.0,
+                };
+                storage
+                    .eth_sender_dal()
+                    .upsert_tx_cancellation(&cancellation)
+                    .await
+                    .context("upsert_tx_cancellation()")?;
+                self.cancellations.insert(nonce, cancellation);
+            }
+            Err(error) => {
+                tracing::warn!("Error sending cancellation tx (nonce {nonce}): {error}");
+                if error.is_retriable() {
+                    METRICS.l1_transient_errors.inc();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Loads pending cancellations persisted by the previous runs of the manager.
+    async fn load_cancellations(
+        &mut self,
+        storage: &mut Connection<'_, Core>,
+    ) -> Result<(), EthSenderError> {
+        if self.cancellations_loaded {
+            return Ok(());
+        }
+
+        let cancellations = storage
+            .eth_sender_dal()
+            .get_tx_cancellations()
+            .await
+            .context("get_tx_cancellations()")?;
+        if !cancellations.is_empty() {
+            tracing::info!("Loaded {} pending cancellations", cancellations.len());
+        }
+        self.cancellations = cancellations
+            .into_iter()
+            .map(|cancellation| (cancellation.nonce, cancellation))
+            .collect();
+        self.cancellations_loaded = true;
+        Ok(())
+    }
+
     pub async fn assert_there_are_no_pre_gateway_txs_with_gateway_enabled(
         &mut self,
         storage: &mut Connection<'_, Core>,
@@ -639,6 +1025,10 @@ impl EthTxManager {
     pub async fn loop_iteration(&mut self, storage: &mut Connection<'_, Core>) {
         self.assert_there_are_no_pre_gateway_txs_with_gateway_enabled(storage)
             .await;
+        if let Err(error) = self.load_cancellations(storage).await {
+            tracing::warn!("eth_sender error {:?}", error);
+            return;
+        }
 
         // We can treat blob and non-blob operators independently as they have different nonces and
         // aggregator makes sure that corresponding Commit transaction is confirmed before creating
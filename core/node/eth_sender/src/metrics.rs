@@ -108,6 +108,13 @@ pub(super) struct EthSenderMetrics {
     /// Number of L1 batches aggregated for publishing with a specific reason.
     pub block_aggregation_reason: Family<AggregationReasonLabels, Counter>,
     pub l1_transient_errors: Counter,
+    /// Number of times nonces of unconfirmed transactions were reassigned after their nonce was consumed
+    /// by another transaction.
+    pub nonce_reassignments: Counter,
+    /// Number of transactions sent to fill nonce gaps before unconfirmed transactions.
+    pub nonce_gap_fill_txs_sent: Counter,
+    /// Number of cancellation transactions sent for stuck transactions, including resent ones.
+    pub cancellation_txs_sent: Counter,
 }
 
 impl EthSenderMetrics {
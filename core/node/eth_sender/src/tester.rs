@@ -155,6 +155,8 @@ impl EthSenderTester {
                 base_fee_per_gas,
                 base_fee_per_blob_gas: 0.into(),
                 l2_pubdata_price: 0.into(),
+                gas_used_ratio: None,
+                priority_fee_per_gas: None,
             })
             .collect();
 
@@ -164,6 +166,8 @@ impl EthSenderTester {
                     base_fee_per_gas: 0,
                     base_fee_per_blob_gas: 0.into(),
                     l2_pubdata_price: 0.into(),
+                    gas_used_ratio: None,
+                    priority_fee_per_gas: None,
                 })
                 .take(Self::WAIT_CONFIRMATIONS as usize)
                 .chain(history.clone())
@@ -184,6 +188,8 @@ impl EthSenderTester {
                     base_fee_per_gas: 0,
                     base_fee_per_blob_gas: 0.into(),
                     l2_pubdata_price: 0.into(),
+                    gas_used_ratio: None,
+                    priority_fee_per_gas: None,
                 })
                 .take(Self::WAIT_CONFIRMATIONS as usize)
                 .chain(history.clone())
@@ -204,6 +210,8 @@ impl EthSenderTester {
                     base_fee_per_gas: 0,
                     base_fee_per_blob_gas: 0.into(),
                     l2_pubdata_price: 0.into(),
+                    gas_used_ratio: None,
+                    priority_fee_per_gas: None,
                 })
                 .take(Self::WAIT_CONFIRMATIONS as usize)
                 .chain(history)
@@ -316,6 +324,20 @@ impl EthSenderTester {
         tracing::info!("Switched eth-sender tester to use Gateway!");
     }
 
+    pub fn enable_stuck_tx_cancellation(&mut self, after_blocks: u32) {
+        self.manager = EthTxManager::new(
+            self.conn.clone(),
+            SenderConfig {
+                cancel_stuck_txs_after_blocks: Some(after_blocks),
+                ..EthConfig::for_tests().sender.unwrap()
+            },
+            self.gas_adjuster.clone(),
+            Some(self.gateway.clone()),
+            Some(self.gateway_blobs.clone()),
+            None,
+        );
+    }
+
     pub async fn storage(&self) -> Connection<'_, Core> {
         self.conn.connection().await.unwrap()
     }
@@ -12,7 +12,7 @@ use zksync_types::{
     ethabi::Token,
     helpers::unix_timestamp_ms,
     web3::contract::Error,
-    ProtocolVersionId, H256,
+    Nonce, ProtocolVersionId, H256,
 };
 
 use crate::{
@@ -432,6 +432,234 @@ async fn transactions_are_not_resent_on_the_same_block() {
     tester.assert_just_sent_tx_count_equals(0).await;
 }
 
+async fn get_eth_tx_nonce(tester: &EthSenderTester, eth_tx_id: u32) -> Nonce {
+    tester
+        .storage()
+        .await
+        .eth_sender_dal()
+        .get_eth_tx(eth_tx_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .nonce
+}
+
+#[test_log::test(tokio::test)]
+async fn nonce_gap_is_filled_without_reassigning_nonces() {
+    let mut tester = EthSenderTester::new(
+        ConnectionPool::<Core>::test_pool().await,
+        vec![100; 100],
+        false,
+        false,
+        L1BatchCommitmentMode::Rollup,
+    )
+    .await;
+
+    let _genesis_l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let tx = tester.save_commit_tx(l1_batch.number).await;
+    assert_eq!(tx.nonce, Nonce(0));
+    // Emulate a nonce gap, e.g. caused by a transaction dropped from the mempool.
+    tester
+        .storage()
+        .await
+        .eth_sender_dal()
+        .reassign_nonces(tx.id, tx.from_addr, false, 5)
+        .await
+        .unwrap();
+
+    // The tx is sent with the gapped nonce and queued in the mempool; the gap is filled right away.
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.assert_just_sent_tx_count_equals(6).await;
+    assert_eq!(get_eth_tx_nonce(&tester, tx.id).await, Nonce(5));
+    tester.assert_inflight_txs_count_equals(1).await;
+
+    // The gap is not filled again while the filling txs are in the mempool.
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.assert_just_sent_tx_count_equals(0).await;
+
+    // Once the gap is filled, the queued tx is mined with its original nonce, so the operation is sent only once.
+    for _ in 0..5 {
+        tester.gateway.execute_external_tx(0);
+    }
+    l1_batch.execute_commit_tx(&mut tester).await;
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.assert_just_sent_tx_count_equals(0).await;
+    tester.assert_inflight_txs_count_equals(0).await;
+    assert_eq!(get_eth_tx_nonce(&tester, tx.id).await, Nonce(5));
+}
+
+#[test_log::test(tokio::test)]
+async fn replaced_tx_is_resent_with_new_nonce() {
+    let mut tester = EthSenderTester::new(
+        ConnectionPool::<Core>::test_pool().await,
+        vec![100; 100],
+        false,
+        false,
+        L1BatchCommitmentMode::Rollup,
+    )
+    .await;
+
+    let _genesis_l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let tx = tester.save_commit_tx(l1_batch.number).await;
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.assert_just_sent_tx_count_equals(1).await;
+
+    // Another process using the operator key consumes the nonce.
+    tester
+        .gateway
+        .execute_external_tx(EthSenderTester::WAIT_CONFIRMATIONS);
+    tester.run_eth_sender_tx_manager_iteration().await;
+    assert_eq!(get_eth_tx_nonce(&tester, tx.id).await, Nonce(1));
+    tester.assert_inflight_txs_count_equals(0).await;
+
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.assert_just_sent_tx_count_equals(1).await;
+    l1_batch.execute_commit_tx(&mut tester).await;
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.assert_inflight_txs_count_equals(0).await;
+}
+
+#[test_log::test(tokio::test)]
+async fn stuck_txs_are_cancelled_and_resent() {
+    let mut tester = EthSenderTester::new(
+        ConnectionPool::<Core>::test_pool().await,
+        vec![100; 100],
+        false,
+        false,
+        L1BatchCommitmentMode::Rollup,
+    )
+    .await;
+    tester.enable_stuck_tx_cancellation(5);
+
+    let _genesis_l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let first_l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let second_l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let first_tx = tester.save_commit_tx(first_l1_batch.number).await;
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.assert_just_sent_tx_count_equals(1).await;
+    assert!(tester.manager.cancellation_tx_hash(Nonce(0)).is_none());
+
+    tester
+        .run_eth_sender_tx_manager_iteration_after_n_blocks(5)
+        .await;
+    tester.assert_just_sent_tx_count_equals(1).await;
+    let cancellation_hash = tester.manager.cancellation_tx_hash(Nonce(0)).unwrap();
+
+    // New txs are not sent while the cancellation is pending; the cancellation itself is resent with increased fees.
+    let second_tx = tester.save_commit_tx(second_l1_batch.number).await;
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.assert_just_sent_tx_count_equals(1).await;
+    let resent_cancellation_hash = tester.manager.cancellation_tx_hash(Nonce(0)).unwrap();
+    assert_ne!(resent_cancellation_hash, cancellation_hash);
+    tester.assert_inflight_txs_count_equals(1).await;
+
+    tester.gateway.execute_tx(
+        resent_cancellation_hash,
+        true,
+        EthSenderTester::WAIT_CONFIRMATIONS,
+    );
+    tester.run_eth_sender_tx_manager_iteration().await;
+    assert!(tester.manager.cancellation_tx_hash(Nonce(0)).is_none());
+    assert_eq!(get_eth_tx_nonce(&tester, first_tx.id).await, Nonce(1));
+    assert_eq!(get_eth_tx_nonce(&tester, second_tx.id).await, Nonce(2));
+
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.assert_just_sent_tx_count_equals(2).await;
+    first_l1_batch.execute_commit_tx(&mut tester).await;
+    second_l1_batch.execute_commit_tx(&mut tester).await;
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.assert_inflight_txs_count_equals(0).await;
+}
+
+#[test_log::test(tokio::test)]
+async fn nonce_gap_is_only_filled_above_operator_pending_nonce() {
+    let mut tester = EthSenderTester::new(
+        ConnectionPool::<Core>::test_pool().await,
+        vec![100; 100],
+        false,
+        false,
+        L1BatchCommitmentMode::Rollup,
+    )
+    .await;
+
+    let _genesis_l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let tx = tester.save_commit_tx(l1_batch.number).await;
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.assert_just_sent_tx_count_equals(1).await;
+
+    // Emulate a nonce gap while the previously sent tx is still in the mempool.
+    tester
+        .storage()
+        .await
+        .eth_sender_dal()
+        .reassign_nonces(tx.id, tx.from_addr, false, 5)
+        .await
+        .unwrap();
+    // Only nonces 1..5 are filled, since nonce 0 is occupied by the mempool tx.
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.assert_just_sent_tx_count_equals(5).await;
+    assert_eq!(get_eth_tx_nonce(&tester, tx.id).await, Nonce(5));
+    tester.assert_inflight_txs_count_equals(1).await;
+}
+
+#[test_log::test(tokio::test)]
+async fn cancellations_are_restored_after_restart() {
+    let mut tester = EthSenderTester::new(
+        ConnectionPool::<Core>::test_pool().await,
+        vec![100; 100],
+        false,
+        false,
+        L1BatchCommitmentMode::Rollup,
+    )
+    .await;
+    tester.enable_stuck_tx_cancellation(5);
+
+    let _genesis_l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let tx = tester.save_commit_tx(l1_batch.number).await;
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester
+        .run_eth_sender_tx_manager_iteration_after_n_blocks(5)
+        .await;
+    let cancellation_hash = tester.manager.cancellation_tx_hash(Nonce(0)).unwrap();
+
+    // Restart the manager; the cancellation is loaded from the storage and resent with increased fees.
+    tester.enable_stuck_tx_cancellation(5);
+    assert!(tester.manager.cancellation_tx_hash(Nonce(0)).is_none());
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.assert_just_sent_tx_count_equals(1).await;
+    let resent_cancellation_hash = tester.manager.cancellation_tx_hash(Nonce(0)).unwrap();
+    assert_ne!(resent_cancellation_hash, cancellation_hash);
+    let cancellations = tester
+        .storage()
+        .await
+        .eth_sender_dal()
+        .get_tx_cancellations()
+        .await
+        .unwrap();
+    assert_eq!(cancellations.len(), 1);
+    assert_eq!(cancellations[0].tx_hash, resent_cancellation_hash);
+
+    tester.gateway.execute_tx(
+        resent_cancellation_hash,
+        true,
+        EthSenderTester::WAIT_CONFIRMATIONS,
+    );
+    tester.run_eth_sender_tx_manager_iteration().await;
+    assert_eq!(get_eth_tx_nonce(&tester, tx.id).await, Nonce(1));
+    let cancellations = tester
+        .storage()
+        .await
+        .eth_sender_dal()
+        .get_tx_cancellations()
+        .await
+        .unwrap();
+    assert!(cancellations.is_empty());
+}
+
 #[should_panic(
     expected = "eth-sender was switched to gateway, but there are still 1 pre-gateway transactions in-flight!"
 )]
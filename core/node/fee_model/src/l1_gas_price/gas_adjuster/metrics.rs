@@ -11,6 +11,8 @@ pub(super) struct GasAdjusterMetrics {
     pub median_base_fee_per_gas: Gauge<u64>,
     pub median_blob_base_fee_per_gas: Gauge<u64>,
     pub median_blob_base_fee: Gauge<u64>,
+    pub projected_base_fee_per_gas: Gauge<u64>,
+    pub percentile_priority_fee_per_gas: Gauge<u64>,
 }
 
 #[vise::register]
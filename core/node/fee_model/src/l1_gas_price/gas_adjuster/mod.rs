@@ -2,12 +2,18 @@
 
 use std::{
     collections::VecDeque,
-    sync::{Arc, RwLock},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
 };
 
 use tokio::sync::watch;
-use zksync_config::{configs::eth_sender::PubdataSendingMode, GasAdjusterConfig};
-use zksync_eth_client::EthFeeInterface;
+use zksync_config::{
+    configs::eth_sender::{BaseFeeStrategy, PriorityFeeStrategy, PubdataSendingMode},
+    GasAdjusterConfig,
+};
+use zksync_eth_client::{BaseFees, EthFeeInterface};
 use zksync_types::{commitment::L1BatchCommitmentMode, L1_GAS_PER_PUBDATA_BYTE, U256};
 use zksync_web3_decl::client::{DynClient, L1, L2};
 
@@ -16,6 +22,8 @@ use super::TxParamsProvider;
 
 mod metrics;
 #[cfg(test)]
+mod simulation;
+#[cfg(test)]
 mod tests;
 
 #[derive(Debug)]
@@ -55,6 +63,9 @@ impl From<Box<DynClient<L2>>> for GasAdjusterClient {
 /// This component keeps track of the median `base_fee` from the last `max_base_fee_samples` blocks
 /// and of the median `blob_base_fee` from the last `max_blob_base_fee_sample` blocks.
 /// It is used to adjust the base_fee of transactions sent to L1.
+///
+/// The way base and priority fees are estimated is defined by [`BaseFeeStrategy`] and [`PriorityFeeStrategy`]
+/// in the config.
 #[derive(Debug)]
 pub struct GasAdjuster {
     pub(super) base_fee_statistics: GasStatistics<u64>,
@@ -66,6 +77,11 @@ pub struct GasAdjuster {
     pub(super) blob_base_fee_statistics: GasStatistics<U256>,
     // Note, that for L1-based chains the following field contains only zeroes.
     pub(super) l2_pubdata_price_statistics: GasStatistics<U256>,
+    // Priority fees paid at the configured percentile in recent blocks. Only populated
+    // with `PriorityFeeStrategy::Percentile`.
+    pub(super) priority_fee_statistics: GasStatistics<u64>,
+    // Base fee of the next block projected using the EIP-1559 update rule.
+    projected_base_fee: AtomicU64,
 
     pub(super) config: GasAdjusterConfig,
     pubdata_sending_mode: PubdataSendingMode,
@@ -96,6 +112,11 @@ impl GasAdjuster {
                 "Relayed L2 calldata is only available in L2 mode"
             );
         }
+        anyhow::ensure!(
+            (0.0..=100.0).contains(&config.priority_fee_percentile),
+            "Priority fee percentile must be in 0..=100, got {}",
+            config.priority_fee_percentile
+        );
 
         // Subtracting 1 from the "latest" block number to prevent errors in case
         // the info about the latest block is not yet present on the node.
@@ -108,7 +129,11 @@ impl GasAdjuster {
             .saturating_sub(1);
         let fee_history = client
             .inner
-            .base_fee_history(current_block, config.max_base_fee_samples)
+            .base_fee_history(
+                current_block,
+                config.max_base_fee_samples,
+                Self::reward_percentile(&config),
+            )
             .await?;
 
         let base_fee_statistics = GasStatistics::new(
@@ -129,10 +154,22 @@ impl GasAdjuster {
             fee_history.iter().map(|fee| fee.l2_pubdata_price),
         );
 
+        let priority_fee_statistics = GasStatistics::new(
+            config.max_base_fee_samples,
+            current_block,
+            fee_history
+                .iter()
+                .filter_map(|fee| fee.priority_fee_per_gas),
+        );
+
+        let projected_base_fee = fee_history.last().map_or(0, project_next_base_fee);
+
         Ok(Self {
             base_fee_statistics,
             blob_base_fee_statistics,
             l2_pubdata_price_statistics,
+            priority_fee_statistics,
+            projected_base_fee: AtomicU64::new(projected_base_fee),
             config,
             pubdata_sending_mode,
             client,
@@ -140,6 +177,14 @@ impl GasAdjuster {
         })
     }
 
+    /// Returns the percentile of priority fees to request from the fee history, if required by the priority fee strategy.
+    fn reward_percentile(config: &GasAdjusterConfig) -> Option<f32> {
+        match config.priority_fee_strategy {
+            PriorityFeeStrategy::Fixed => None,
+            PriorityFeeStrategy::Percentile => Some(config.priority_fee_percentile as f32),
+        }
+    }
+
     /// Performs an actualization routine for `GasAdjuster`.
     /// This method is intended to be invoked periodically.
     pub async fn keep_updated(&self) -> anyhow::Result<()> {
@@ -161,7 +206,11 @@ impl GasAdjuster {
             let fee_data = self
                 .client
                 .inner
-                .base_fee_history(current_block, n_blocks)
+                .base_fee_history(
+                    current_block,
+                    n_blocks,
+                    Self::reward_percentile(&self.config),
+                )
                 .await?;
 
             // We shouldn't rely on L1 provider to return consistent results, so we check that we have at least one new sample.
@@ -173,6 +222,14 @@ impl GasAdjuster {
             }
             self.base_fee_statistics
                 .add_samples(fee_data.iter().map(|fee| fee.base_fee_per_gas));
+            self.priority_fee_statistics
+                .add_samples(fee_data.iter().filter_map(|fee| fee.priority_fee_per_gas));
+            if let Some(last_fees) = fee_data.last() {
+                let projected_base_fee = project_next_base_fee(last_fees);
+                METRICS.projected_base_fee_per_gas.set(projected_base_fee);
+                self.projected_base_fee
+                    .store(projected_base_fee, Ordering::Relaxed);
+            }
 
             if let Some(current_blob_base_fee) =
                 fee_data.last().map(|fee| fee.base_fee_per_blob_gas)
@@ -294,6 +351,14 @@ impl GasAdjuster {
         }
     }
 
+    /// Returns the latest base fee according to the base fee strategy.
+    fn latest_base_fee(&self) -> u64 {
+        match self.config.base_fee_strategy {
+            BaseFeeStrategy::Median => self.base_fee_statistics.last_added_value(),
+            BaseFeeStrategy::Eip1559Projection => self.projected_base_fee.load(Ordering::Relaxed),
+        }
+    }
+
     fn cap_pubdata_fee(&self, pubdata_fee: f64) -> u64 {
         // We will treat the max blob base fee as the maximal fee that we can take for each byte of pubdata.
         let max_blob_base_fee = self.config.max_blob_base_fee();
@@ -325,9 +390,15 @@ impl TxParamsProvider for GasAdjuster {
         // The alternative is a linear one:
         // `let scale_factor = a + b * time_in_mempool as f64;`
         let scale_factor = a * b.powf(time_in_mempool as f64);
-        let median = self.base_fee_statistics.median();
-        METRICS.median_base_fee_per_gas.set(median);
-        let new_fee = median as f64 * scale_factor;
+        let base_fee = match self.config.base_fee_strategy {
+            BaseFeeStrategy::Median => {
+                let median = self.base_fee_statistics.median();
+                METRICS.median_base_fee_per_gas.set(median);
+                median
+            }
+            BaseFeeStrategy::Eip1559Projection => self.projected_base_fee.load(Ordering::Relaxed),
+        };
+        let new_fee = base_fee as f64 * scale_factor;
         new_fee as u64
     }
 
@@ -338,15 +409,28 @@ impl TxParamsProvider for GasAdjuster {
         last_block_base_fee * 875 / 1000
     }
 
-    // Priority fee is set to constant, sourced from config.
+    // By default, priority fee is set to constant, sourced from config.
     // Reasoning behind this is the following:
     // High `priority_fee` means high demand for block space,
     // which means `base_fee` will increase, which means `priority_fee`
     // will decrease. The EIP-1559 mechanism is designed such that
     // `base_fee` will balance out `priority_fee` in such a way that
     // `priority_fee` will be a small fraction of the overall fee.
+    //
+    // With the percentile strategy, the median of priority fees paid at the configured percentile
+    // in recent blocks is used instead, falling back to the constant if there are no samples yet.
     fn get_priority_fee(&self) -> u64 {
-        self.config.default_priority_fee_per_gas
+        match self.config.priority_fee_strategy {
+            PriorityFeeStrategy::Fixed => self.config.default_priority_fee_per_gas,
+            PriorityFeeStrategy::Percentile => {
+                if self.priority_fee_statistics.is_empty() {
+                    return self.config.default_priority_fee_per_gas;
+                }
+                let priority_fee = self.priority_fee_statistics.median();
+                METRICS.percentile_priority_fee_per_gas.set(priority_fee);
+                priority_fee
+            }
+        }
     }
 
     // The idea is that when we finally decide to send blob tx, we want to offer gas fees high
@@ -354,7 +438,7 @@ impl TxParamsProvider for GasAdjuster {
     // the gas prices as then we have very little control how much we pay in the end. This strategy
     // works as no matter if we double or triple such price, we pay the same block base fees.
     fn get_blob_tx_base_fee(&self) -> u64 {
-        self.base_fee_statistics.last_added_value() * 2
+        self.latest_base_fee() * 2
     }
 
     fn get_blob_tx_blob_base_fee(&self) -> u64 {
@@ -374,6 +458,24 @@ impl TxParamsProvider for GasAdjuster {
     }
 }
 
+/// Projects the base fee of the block following the block with the specified `fees` using the EIP-1559 update rule.
+/// If the gas used ratio is unknown (i.e., `fees` correspond to the pending block, whose base fee is already
+/// derived from its parent), the base fee is returned as is.
+fn project_next_base_fee(fees: &BaseFees) -> u64 {
+    /// Ratio of the block gas limit to the gas target.
+    const ELASTICITY_MULTIPLIER: f64 = 2.0;
+    /// Bounds the base fee change between consecutive blocks to 12.5%.
+    const BASE_FEE_MAX_CHANGE_DENOMINATOR: f64 = 8.0;
+
+    let Some(gas_used_ratio) = fees.gas_used_ratio else {
+        return fees.base_fee_per_gas;
+    };
+    let target_deviation = (gas_used_ratio * ELASTICITY_MULTIPLIER - 1.0).clamp(-1.0, 1.0);
+    let multiplier = 1.0 + target_deviation / BASE_FEE_MAX_CHANGE_DENOMINATOR;
+    // Round up so that the projection errs on the side of inclusion.
+    (fees.base_fee_per_gas as f64 * multiplier).ceil() as u64
+}
+
 /// Helper structure responsible for collecting the data about recent transactions,
 /// calculating the median base fee.
 #[derive(Debug, Clone, Default)]
@@ -409,6 +511,10 @@ impl<T: Ord + Copy + Default> GasStatisticsInner<T> {
         self.samples.back().copied().unwrap_or(self.median_cached)
     }
 
+    fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
     fn add_samples(&mut self, fees: impl IntoIterator<Item = T>) {
         let old_len = self.samples.len();
         self.samples.extend(fees);
@@ -447,6 +553,10 @@ impl<T: Ord + Copy + Default> GasStatistics<T> {
         self.0.read().unwrap().last_added_value()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.0.read().unwrap().is_empty()
+    }
+
     pub fn add_samples(&self, fees: impl IntoIterator<Item = T>) {
         self.0.write().unwrap().add_samples(fees)
     }
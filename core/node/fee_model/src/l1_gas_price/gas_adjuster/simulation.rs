@@ -0,0 +1,180 @@
+//! Simulation harness replaying fee histories against [`GasAdjuster`] fee estimation strategies.
+//!
+//! Fee histories are stored as raw `eth_feeHistory` responses (with a single reward percentile) in the `testdata` dir.
+//! `fee_history_spike.json` is synthetic: base fees are derived from gas used ratios using the EIP-1559 update rule
+//! for a 30M gas limit, and model a calm period followed by a demand spike and a cool-down. Responses recorded
+//! from a real L1 node can be dropped in and replayed the same way.
+
+use test_casing::test_casing;
+use zksync_config::{
+    configs::eth_sender::{BaseFeeStrategy, PriorityFeeStrategy, PubdataSendingMode},
+    GasAdjusterConfig,
+};
+use zksync_eth_client::{clients::MockSettlementLayer, BaseFees};
+use zksync_types::{commitment::L1BatchCommitmentMode, web3};
+
+use super::GasAdjuster;
+use crate::l1_gas_price::{GasAdjusterClient, TxParamsProvider};
+
+const SPIKE_HISTORY: &str = include_str!("testdata/fee_history_spike.json");
+/// Number of blocks sampled by the adjuster; the replay starts once the adjuster has a full window.
+const WINDOW: usize = 10;
+const DEFAULT_PRIORITY_FEE: u64 = 1_500_000_000;
+
+/// Converts an `eth_feeHistory` response into per-block fees. The trailing base fee (which is derived
+/// for the block following the returned range) is skipped since it has no gas used ratio.
+fn load_fee_history(raw: &str) -> Vec<BaseFees> {
+    let history: web3::FeeHistory = serde_json::from_str(raw).unwrap();
+    let rewards = history.reward.unwrap_or_default();
+    assert_eq!(rewards.len(), history.gas_used_ratio.len());
+
+    history
+        .gas_used_ratio
+        .iter()
+        .zip(&rewards)
+        .zip(
+            history
+                .base_fee_per_gas
+                .iter()
+                .zip(&history.base_fee_per_blob_gas),
+        )
+        .map(
+            |((&gas_used_ratio, reward), (&base_fee, &blob_base_fee))| BaseFees {
+                base_fee_per_gas: base_fee.as_u64(),
+                base_fee_per_blob_gas: blob_base_fee,
+                l2_pubdata_price: 0.into(),
+                gas_used_ratio: Some(gas_used_ratio),
+                priority_fee_per_gas: reward.first().map(|fee| fee.as_u64()),
+            },
+        )
+        .collect()
+}
+
+fn simulation_config(
+    base_fee_strategy: BaseFeeStrategy,
+    priority_fee_strategy: PriorityFeeStrategy,
+) -> GasAdjusterConfig {
+    GasAdjusterConfig {
+        default_priority_fee_per_gas: DEFAULT_PRIORITY_FEE,
+        max_base_fee_samples: WINDOW,
+        // Disable the time-in-mempool scaling so that raw estimates are compared.
+        pricing_formula_parameter_a: 1.0,
+        pricing_formula_parameter_b: 1.0,
+        num_samples_for_blob_base_fee_estimate: WINDOW,
+        base_fee_strategy,
+        priority_fee_strategy,
+        priority_fee_percentile: 50.0,
+        ..GasAdjusterConfig::default()
+    }
+}
+
+#[derive(Debug)]
+struct ReplayReport {
+    /// Number of blocks for which the estimated base fee was lower than the actual base fee of the next block.
+    underpriced_blocks: usize,
+    /// Mean ratio of the estimated base fee to the actual base fee of the next block.
+    mean_overpay_ratio: f64,
+    /// Priority fees recommended before each replayed block.
+    priority_fees: Vec<u64>,
+}
+
+/// Replays `history` block by block, comparing the adjuster estimates with the base fee of the next block.
+async fn replay(history: &[BaseFees], config: GasAdjusterConfig) -> ReplayReport {
+    let eth_client = MockSettlementLayer::builder()
+        .with_fee_history(history.to_vec())
+        .build();
+    // The adjuster ignores the latest block, so we need an extra one to fill the window.
+    eth_client.advance_block_number(WINDOW as u64 + 1);
+
+    let adjuster = GasAdjuster::new(
+        GasAdjusterClient::from_l1(Box::new(eth_client.clone().into_client())),
+        config,
+        PubdataSendingMode::Calldata,
+        L1BatchCommitmentMode::Rollup,
+    )
+    .await
+    .unwrap();
+
+    let mut underpriced_blocks = 0;
+    let mut overpay_ratios = vec![];
+    let mut priority_fees = vec![];
+    for next_block in (WINDOW + 1)..history.len() {
+        let estimated_base_fee = adjuster.get_base_fee(0);
+        let actual_base_fee = history[next_block].base_fee_per_gas;
+        if estimated_base_fee < actual_base_fee {
+            underpriced_blocks += 1;
+        }
+        overpay_ratios.push(estimated_base_fee as f64 / actual_base_fee as f64);
+        priority_fees.push(adjuster.get_priority_fee());
+
+        eth_client.advance_block_number(1);
+        adjuster.keep_updated().await.unwrap();
+    }
+
+    ReplayReport {
+        underpriced_blocks,
+        mean_overpay_ratio: overpay_ratios.iter().sum::<f64>() / overpay_ratios.len() as f64,
+        priority_fees,
+    }
+}
+
+#[tokio::test]
+async fn eip1559_projection_tracks_base_fee_spike() {
+    let history = load_fee_history(SPIKE_HISTORY);
+    let config = simulation_config(
+        BaseFeeStrategy::Eip1559Projection,
+        PriorityFeeStrategy::Fixed,
+    );
+    let report = replay(&history, config).await;
+
+    assert_eq!(report.underpriced_blocks, 0, "{report:?}");
+    assert!((report.mean_overpay_ratio - 1.0).abs() < 1e-6, "{report:?}");
+}
+
+#[tokio::test]
+async fn median_base_fee_lags_behind_spike() {
+    let history = load_fee_history(SPIKE_HISTORY);
+    let projection_report = replay(
+        &history,
+        simulation_config(
+            BaseFeeStrategy::Eip1559Projection,
+            PriorityFeeStrategy::Fixed,
+        ),
+    )
+    .await;
+    let median_report = replay(
+        &history,
+        simulation_config(BaseFeeStrategy::Median, PriorityFeeStrategy::Fixed),
+    )
+    .await;
+
+    assert!(
+        median_report.underpriced_blocks > projection_report.underpriced_blocks,
+        "{median_report:?}"
+    );
+}
+
+#[test_casing(2, [PriorityFeeStrategy::Fixed, PriorityFeeStrategy::Percentile])]
+#[tokio::test]
+async fn priority_fee_strategies(priority_fee_strategy: PriorityFeeStrategy) {
+    let history = load_fee_history(SPIKE_HISTORY);
+    let config = simulation_config(BaseFeeStrategy::Median, priority_fee_strategy);
+    let report = replay(&history, config).await;
+
+    for (i, &priority_fee) in report.priority_fees.iter().enumerate() {
+        let expected_priority_fee = match priority_fee_strategy {
+            PriorityFeeStrategy::Fixed => DEFAULT_PRIORITY_FEE,
+            PriorityFeeStrategy::Percentile => {
+                // The adjuster samples `WINDOW` blocks preceding the next block.
+                let next_block = WINDOW + 1 + i;
+                let mut window: Vec<_> = history[next_block - WINDOW..next_block]
+                    .iter()
+                    .map(|fee| fee.priority_fee_per_gas.unwrap())
+                    .collect();
+                window.sort_unstable();
+                window[WINDOW / 2]
+            }
+        };
+        assert_eq!(priority_fee, expected_priority_fee, "block #{i}");
+    }
+}
@@ -1,7 +1,10 @@
 use std::{collections::VecDeque, sync::RwLockReadGuard};
 
 use test_casing::test_casing;
-use zksync_config::{configs::eth_sender::PubdataSendingMode, GasAdjusterConfig};
+use zksync_config::{
+    configs::eth_sender::{BaseFeeStrategy, PriorityFeeStrategy, PubdataSendingMode},
+    GasAdjusterConfig,
+};
 use zksync_eth_client::{clients::MockSettlementLayer, BaseFees};
 use zksync_types::{commitment::L1BatchCommitmentMode, settlement::SettlementMode};
 use zksync_web3_decl::client::L2;
@@ -71,6 +74,9 @@ fn test_config(settlement_mode: SettlementMode) -> GasAdjusterConfig {
         internal_pubdata_pricing_multiplier: 1.0,
         max_blob_base_fee: None,
         settlement_mode,
+        base_fee_strategy: BaseFeeStrategy::Median,
+        priority_fee_strategy: PriorityFeeStrategy::Fixed,
+        priority_fee_percentile: 50.0,
     }
 }
 
@@ -90,6 +96,8 @@ async fn kept_updated(commitment_mode: L1BatchCommitmentMode) {
             base_fee_per_gas: block,
             base_fee_per_blob_gas: blob.into(),
             l2_pubdata_price: 0.into(),
+            gas_used_ratio: None,
+            priority_fee_per_gas: None,
         })
         .collect();
 
@@ -154,6 +162,8 @@ async fn kept_updated_l2(commitment_mode: L1BatchCommitmentMode) {
             base_fee_per_gas: block,
             base_fee_per_blob_gas: 0.into(),
             l2_pubdata_price: pubdata.into(),
+            gas_used_ratio: None,
+            priority_fee_per_gas: None,
         })
         .collect();
 
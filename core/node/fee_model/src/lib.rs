@@ -773,6 +773,8 @@ mod tests {
             base_fee_per_gas: block,
             base_fee_per_blob_gas: blob,
             l2_pubdata_price: pubdata,
+            gas_used_ratio: None,
+            priority_fee_per_gas: None,
         }
     }
 
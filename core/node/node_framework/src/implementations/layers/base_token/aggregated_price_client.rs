@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use zksync_config::configs::ExternalPriceApiClientConfig;
+use zksync_external_price_api::{
+    aggregated_client::{AggregatedPriceAPIClient, PriceSource},
+    cmc_api::CmcPriceAPIClient,
+    coingecko_api::CoinGeckoPriceAPIClient,
+    uniswap_v3::UniswapV3TwapClient,
+    PriceAPIClient,
+};
+
+use crate::{
+    implementations::resources::{
+        eth_interface::EthInterfaceResource, healthcheck::AppHealthCheckResource,
+        price_api_client::PriceAPIClientResource,
+    },
+    wiring_layer::{WiringError, WiringLayer},
+    FromContext, IntoContext,
+};
+
+/// Wiring layer for `AggregatedPriceAPIClient`
+///
+/// Responsible for inserting a resource with a client that aggregates base token prices from several sources
+/// (CoinGecko, CoinMarketCap and a Uniswap v3 pool on L1) to be used by the `BaseTokenRatioPersister`.
+/// Adds a health check for each price source to the app health.
+#[derive(Debug)]
+pub struct AggregatedPriceClientLayer {
+    config: ExternalPriceApiClientConfig,
+}
+
+impl AggregatedPriceClientLayer {
+    /// Identifier of used client type.
+    /// Can be used to choose the layer for the client based on configuration variables.
+    pub const CLIENT_NAME: &'static str = "aggregated";
+
+    pub fn new(config: ExternalPriceApiClientConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[derive(Debug, FromContext)]
+#[context(crate = crate)]
+pub struct Input {
+    /// L1 client; only required for the `uniswap_v3` source.
+    pub eth_client: Option<EthInterfaceResource>,
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    pub price_api_client: PriceAPIClientResource,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for AggregatedPriceClientLayer {
+    type Input = Input;
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "aggregated_price_api_client"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let aggregated_config = self
+            .config
+            .aggregated
+            .as_ref()
+            .context("aggregated price client started with no config")?;
+
+        let mut sources = Vec::with_capacity(aggregated_config.sources.len());
+        for source_name in &aggregated_config.sources {
+            let (name, client): (_, Arc<dyn PriceAPIClient>) = match source_name.as_str() {
+                "coingecko" => (
+                    "coingecko",
+                    Arc::new(CoinGeckoPriceAPIClient::new(self.config.clone())),
+                ),
+                "coinmarketcap" => (
+                    "coinmarketcap",
+                    Arc::new(CmcPriceAPIClient::new(self.config.clone())),
+                ),
+                "uniswap_v3" => {
+                    let eth_client = input
+                        .eth_client
+                        .as_ref()
+                        .context("`uniswap_v3` price source requires an L1 client")?
+                        .0
+                        .clone();
+                    let pool_address = aggregated_config
+                        .uniswap_v3_pool_address
+                        .context("`uniswap_v3` price source requires a pool address")?;
+                    let quote_token_address = aggregated_config
+                        .uniswap_v3_quote_token_address
+                        .context("`uniswap_v3` price source requires a quote token address")?;
+                    let client = UniswapV3TwapClient::new(
+                        eth_client,
+                        pool_address,
+                        quote_token_address,
+                        aggregated_config.uniswap_v3_twap_interval(),
+                    );
+                    ("uniswap_v3", Arc::new(client))
+                }
+                _ => {
+                    return Err(WiringError::Configuration(format!(
+                        "Unknown aggregated price source: {source_name}"
+                    )));
+                }
+            };
+            sources.push(PriceSource::new(name, client));
+        }
+
+        let client = AggregatedPriceAPIClient::new(aggregated_config, sources)?;
+        for health_check in client.health_checks() {
+            input
+                .app_health
+                .0
+                .insert_component(health_check)
+                .map_err(WiringError::internal)?;
+        }
+        Ok(Output {
+            price_api_client: Arc::new(client).into(),
+        })
+    }
+}
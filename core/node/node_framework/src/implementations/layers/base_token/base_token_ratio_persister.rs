@@ -10,6 +10,7 @@ use zksync_types::L1ChainId;
 use crate::{
     implementations::resources::{
         eth_interface::EthInterfaceResource,
+        healthcheck::AppHealthCheckResource,
         l1_tx_params::TxParamsResource,
         pools::{MasterPool, PoolResource},
         price_api_client::PriceAPIClientResource,
@@ -40,6 +41,8 @@ pub struct Input {
     pub price_api_client: PriceAPIClientResource,
     pub eth_client: EthInterfaceResource,
     pub tx_params: TxParamsResource,
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
 }
 
 #[derive(Debug, IntoContext)]
@@ -116,6 +119,11 @@ impl WiringLayer for BaseTokenRatioPersisterLayer {
             price_api_client.0,
             l1_params,
         );
+        input
+            .app_health
+            .0
+            .insert_component(persister.health_check())
+            .map_err(WiringError::internal)?;
 
         Ok(Output { persister })
     }
@@ -1,3 +1,4 @@
+pub mod aggregated_price_client;
 pub mod base_token_ratio_persister;
 pub mod base_token_ratio_provider;
 pub mod coingecko_client;
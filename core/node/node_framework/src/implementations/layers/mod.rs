@@ -21,6 +21,7 @@ pub mod main_node_fee_params_fetcher;
 pub mod metadata_calculator;
 pub mod node_storage_init;
 pub mod object_store;
+pub mod object_store_pruner;
 pub mod pk_signing_eth_client;
 pub mod pools_layer;
 pub mod postgres_metrics;
@@ -0,0 +1,79 @@
+use zksync_config::configs::object_store_pruning::ObjectStorePruningConfig;
+use zksync_object_store_pruner::ObjectStorePruner;
+
+use crate::{
+    implementations::resources::{
+        healthcheck::AppHealthCheckResource,
+        object_store::ObjectStoreResource,
+        pools::{MasterPool, PoolResource},
+    },
+    service::StopReceiver,
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+    FromContext, IntoContext,
+};
+
+/// Wiring layer for the object store pruner removing stale objects from object store buckets.
+#[derive(Debug)]
+pub struct ObjectStorePrunerLayer {
+    config: ObjectStorePruningConfig,
+}
+
+#[derive(Debug, FromContext)]
+#[context(crate = crate)]
+pub struct Input {
+    pub master_pool: PoolResource<MasterPool>,
+    pub object_store: ObjectStoreResource,
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    #[context(task)]
+    pub object_store_pruner: ObjectStorePruner,
+}
+
+impl ObjectStorePrunerLayer {
+    pub fn new(config: ObjectStorePruningConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for ObjectStorePrunerLayer {
+    type Input = Input;
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "object_store_pruner_layer"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let main_pool = input.master_pool.get_singleton().await?;
+        let object_store_pruner =
+            ObjectStorePruner::new(&self.config, main_pool, input.object_store.0)
+                .map_err(|err| WiringError::Configuration(format!("{err:#}")))?;
+
+        input
+            .app_health
+            .0
+            .insert_component(object_store_pruner.health_check())
+            .map_err(WiringError::internal)?;
+        Ok(Output {
+            object_store_pruner,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for ObjectStorePruner {
+    fn id(&self) -> TaskId {
+        "object_store_pruner".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
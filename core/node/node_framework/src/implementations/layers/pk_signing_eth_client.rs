@@ -1,10 +1,14 @@
 use anyhow::Context as _;
 use zksync_config::{
-    configs::{wallets, ContractsConfig},
+    configs::{eth_sender::RemoteSignerConfig, wallets, ContractsConfig},
     EthConfig,
 };
-use zksync_eth_client::clients::PKSigningClient;
-use zksync_types::SLChainId;
+use zksync_eth_client::{
+    clients::{DynClient, PKSigningClient, RemoteSigningClient, L1},
+    BoundEthInterface,
+};
+use zksync_eth_signer::RemoteSigner;
+use zksync_types::{Address, SLChainId};
 
 use crate::{
     implementations::resources::eth_interface::{
@@ -15,12 +19,15 @@ use crate::{
 };
 
 /// Wiring layer for [`PKSigningClient`].
+///
+/// If the remote signer is configured in [`EthConfig`], transactions are signed by it using [`RemoteSigningClient`],
+/// and operator private keys are not required.
 #[derive(Debug)]
 pub struct PKSigningEthClientLayer {
     eth_sender_config: EthConfig,
     contracts_config: ContractsConfig,
     sl_chain_id: SLChainId,
-    wallets: wallets::EthSender,
+    wallets: Option<wallets::EthSender>,
 }
 
 #[derive(Debug, FromContext)]
@@ -42,7 +49,7 @@ impl PKSigningEthClientLayer {
         eth_sender_config: EthConfig,
         contracts_config: ContractsConfig,
         sl_chain_id: SLChainId,
-        wallets: wallets::EthSender,
+        wallets: Option<wallets::EthSender>,
     ) -> Self {
         Self {
             eth_sender_config,
@@ -63,29 +70,56 @@ impl WiringLayer for PKSigningEthClientLayer {
     }
 
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
-        let private_key = self.wallets.operator.private_key();
         let gas_adjuster_config = self
             .eth_sender_config
             .gas_adjuster
             .as_ref()
             .context("gas_adjuster config is missing")?;
+        let default_priority_fee_per_gas = gas_adjuster_config.default_priority_fee_per_gas;
         let EthInterfaceResource(query_client) = input.eth_client;
 
+        if let Some(remote_signer_config) = &self.eth_sender_config.remote_signer {
+            let create_client = |address| {
+                self.create_remote_signing_client(
+                    remote_signer_config,
+                    address,
+                    default_priority_fee_per_gas,
+                    query_client.clone(),
+                )
+            };
+            let signing_client = create_client(remote_signer_config.operator_address)?;
+            let signing_client_for_blobs = remote_signer_config
+                .blob_operator_address
+                .map(create_client)
+                .transpose()?
+                .map(BoundEthInterfaceForBlobsResource);
+            return Ok(Output {
+                signing_client: BoundEthInterfaceResource(signing_client),
+                signing_client_for_blobs,
+            });
+        }
+
+        let wallets = self.wallets.ok_or_else(|| {
+            WiringError::Configuration(
+                "either operator wallets or remote signer must be configured".to_owned(),
+            )
+        })?;
+        let private_key = wallets.operator.private_key();
         let signing_client = PKSigningClient::new_raw(
             private_key.clone(),
             self.contracts_config.diamond_proxy_addr,
-            gas_adjuster_config.default_priority_fee_per_gas,
+            default_priority_fee_per_gas,
             self.sl_chain_id,
             query_client.clone(),
         );
         let signing_client = BoundEthInterfaceResource(Box::new(signing_client));
 
-        let signing_client_for_blobs = self.wallets.blob_operator.map(|blob_operator| {
+        let signing_client_for_blobs = wallets.blob_operator.map(|blob_operator| {
             let private_key = blob_operator.private_key();
             let signing_client_for_blobs = PKSigningClient::new_raw(
                 private_key.clone(),
                 self.contracts_config.diamond_proxy_addr,
-                gas_adjuster_config.default_priority_fee_per_gas,
+                default_priority_fee_per_gas,
                 self.sl_chain_id,
                 query_client,
             );
@@ -98,3 +132,23 @@ impl WiringLayer for PKSigningEthClientLayer {
         })
     }
 }
+
+impl PKSigningEthClientLayer {
+    fn create_remote_signing_client(
+        &self,
+        config: &RemoteSignerConfig,
+        address: Address,
+        default_priority_fee_per_gas: u64,
+        query_client: Box<DynClient<L1>>,
+    ) -> Result<Box<dyn BoundEthInterface>, WiringError> {
+        let signer = RemoteSigner::new(&config.url, address, config.request_timeout())
+            .map_err(WiringError::internal)?;
+        Ok(Box::new(RemoteSigningClient::new_raw(
+            signer,
+            self.contracts_config.diamond_proxy_addr,
+            default_priority_fee_per_gas,
+            self.sl_chain_id,
+            query_client,
+        )))
+    }
+}
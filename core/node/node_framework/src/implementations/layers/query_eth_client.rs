@@ -1,6 +1,9 @@
+use std::num::NonZeroUsize;
+
 use anyhow::Context;
+use zksync_eth_client::clients::MultiProviderClient;
 use zksync_types::{settlement::SettlementMode, url::SensitiveUrl, L2ChainId, SLChainId};
-use zksync_web3_decl::client::Client;
+use zksync_web3_decl::client::{Client, DynClient, L1};
 
 use crate::{
     implementations::resources::eth_interface::{EthInterfaceResource, L2InterfaceResource},
@@ -13,6 +16,8 @@ use crate::{
 pub struct QueryEthClientLayer {
     chain_id: SLChainId,
     web3_url: SensitiveUrl,
+    fallback_web3_urls: Vec<SensitiveUrl>,
+    quorum: Option<NonZeroUsize>,
     settlement_mode: SettlementMode,
 }
 
@@ -25,9 +30,57 @@ impl QueryEthClientLayer {
         Self {
             chain_id,
             web3_url,
+            fallback_web3_urls: vec![],
+            quorum: None,
             settlement_mode,
         }
     }
+
+    /// Adds fallback L1 providers. If specified, the L1 client is backed by [`MultiProviderClient`]
+    /// with `quorum` providers required to agree on event logs and finalized / safe blocks.
+    pub fn with_fallback_urls(
+        mut self,
+        fallback_web3_urls: Vec<SensitiveUrl>,
+        quorum: Option<NonZeroUsize>,
+    ) -> Self {
+        self.fallback_web3_urls = fallback_web3_urls;
+        self.quorum = quorum;
+        self
+    }
+
+    fn create_l1_client(&self, url: SensitiveUrl) -> anyhow::Result<Box<DynClient<L1>>> {
+        Ok(Box::new(
+            Client::http(url)
+                .context("Client::new()")?
+                .for_network(self.chain_id.into())
+                .build(),
+        ))
+    }
+
+    fn create_query_client_l1(&self) -> anyhow::Result<Box<DynClient<L1>>> {
+        let provider_count = self.fallback_web3_urls.len() + 1;
+        if let Some(quorum) = self.quorum {
+            anyhow::ensure!(
+                quorum.get() <= provider_count,
+                "L1 RPC quorum ({quorum}) exceeds the number of L1 providers ({provider_count})"
+            );
+        }
+        if self.fallback_web3_urls.is_empty() {
+            return self.create_l1_client(self.web3_url.clone());
+        }
+
+        // Provider names are used in logs and metrics, so URLs (which may contain API keys) are not used as names.
+        let mut builder = MultiProviderClient::builder()
+            .with_provider("main", self.create_l1_client(self.web3_url.clone())?);
+        for (i, url) in self.fallback_web3_urls.iter().enumerate() {
+            builder =
+                builder.with_provider(format!("fallback_{i}"), self.create_l1_client(url.clone())?);
+        }
+        if let Some(quorum) = self.quorum {
+            builder = builder.with_quorum(quorum);
+        }
+        Ok(Box::new(builder.build()))
+    }
 }
 
 #[derive(Debug, IntoContext)]
@@ -49,12 +102,7 @@ impl WiringLayer for QueryEthClientLayer {
     async fn wire(self, _input: Self::Input) -> Result<Output, WiringError> {
         // Both the L1 and L2 client have the same URL, but provide different type guarantees.
         Ok(Output {
-            query_client_l1: EthInterfaceResource(Box::new(
-                Client::http(self.web3_url.clone())
-                    .context("Client::new()")?
-                    .for_network(self.chain_id.into())
-                    .build(),
-            )),
+            query_client_l1: EthInterfaceResource(self.create_query_client_l1()?),
             query_client_l2: if self.settlement_mode.is_gateway() {
                 Some(L2InterfaceResource(Box::new(
                     Client::http(self.web3_url.clone())
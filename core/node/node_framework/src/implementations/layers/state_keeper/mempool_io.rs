@@ -3,14 +3,17 @@ use zksync_config::configs::{
     chain::{MempoolConfig, StateKeeperConfig},
     wallets,
 };
-use zksync_state_keeper::{MempoolFetcher, MempoolGuard, MempoolIO, SequencerSealer};
+use zksync_state_keeper::{
+    seal_criteria::{SealCriteriaRegistry, SealCriterion},
+    MempoolFetcher, MempoolGuard, MempoolIO, SequencerSealer,
+};
 use zksync_types::L2ChainId;
 
 use crate::{
     implementations::resources::{
         fee_input::SequencerFeeInputResource,
         pools::{MasterPool, PoolResource},
-        state_keeper::{ConditionalSealerResource, StateKeeperIOResource},
+        state_keeper::{ConditionalSealerResource, MempoolResource, StateKeeperIOResource},
     },
     service::StopReceiver,
     task::{Task, TaskId},
@@ -29,6 +32,7 @@ use crate::{
 ///
 /// - `StateKeeperIOResource`
 /// - `ConditionalSealerResource`
+/// - `MempoolResource`
 ///
 /// ## Adds tasks
 ///
@@ -39,6 +43,7 @@ pub struct MempoolIOLayer {
     state_keeper_config: StateKeeperConfig,
     mempool_config: MempoolConfig,
     wallets: wallets::StateKeeper,
+    custom_seal_criteria: Vec<Box<dyn SealCriterion>>,
 }
 
 #[derive(Debug, FromContext)]
@@ -53,6 +58,7 @@ pub struct Input {
 pub struct Output {
     pub state_keeper_io: StateKeeperIOResource,
     pub conditional_sealer: ConditionalSealerResource,
+    pub mempool: MempoolResource,
     #[context(task)]
     pub mempool_fetcher: MempoolFetcher,
 }
@@ -69,9 +75,17 @@ impl MempoolIOLayer {
             state_keeper_config,
             mempool_config,
             wallets,
+            custom_seal_criteria: vec![],
         }
     }
 
+    /// Adds a custom seal criterion that will be checked by the sequencer in addition to the built-in ones.
+    /// Like built-in criteria, custom criteria can be disabled via [`StateKeeperConfig::disabled_seal_criteria`].
+    pub fn with_seal_criterion(mut self, criterion: impl SealCriterion) -> Self {
+        self.custom_seal_criteria.push(Box::new(criterion));
+        self
+    }
+
     async fn build_mempool_guard(
         &self,
         master_pool: &PoolResource<MasterPool>,
@@ -84,7 +98,7 @@ impl MempoolIOLayer {
             .connection()
             .await
             .context("Access storage to build mempool")?;
-        let mempool = MempoolGuard::from_storage(&mut storage, self.mempool_config.capacity).await;
+        let mempool = MempoolGuard::from_storage(&mut storage, &self.mempool_config).await;
         mempool.register_metrics();
         Ok(mempool)
     }
@@ -122,7 +136,7 @@ impl WiringLayer for MempoolIOLayer {
             .await
             .context("Get master pool")?;
         let io = MempoolIO::new(
-            mempool_guard,
+            mempool_guard.clone(),
             batch_fee_input_provider,
             mempool_db_pool,
             &self.state_keeper_config,
@@ -132,11 +146,18 @@ impl WiringLayer for MempoolIOLayer {
         )?;
 
         // Create sealer.
-        let sealer = SequencerSealer::new(self.state_keeper_config);
+        let mut seal_criteria =
+            SealCriteriaRegistry::with_default_criteria(&self.state_keeper_config);
+        for criterion in self.custom_seal_criteria {
+            seal_criteria.register_boxed(criterion);
+        }
+        let sealer = SequencerSealer::from_registry(self.state_keeper_config, seal_criteria)
+            .context("Create sequencer sealer")?;
 
         Ok(Output {
             state_keeper_io: io.into(),
             conditional_sealer: sealer.into(),
+            mempool: mempool_guard.into(),
             mempool_fetcher,
         })
     }
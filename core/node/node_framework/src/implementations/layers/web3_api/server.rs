@@ -3,13 +3,17 @@ use std::{num::NonZeroU32, time::Duration};
 use tokio::{sync::oneshot, task::JoinHandle};
 use zksync_circuit_breaker::replication_lag::ReplicationLagChecker;
 use zksync_config::configs::api::MaxResponseSize;
-use zksync_node_api_server::web3::{state::InternalApiConfig, ApiBuilder, ApiServer, Namespace};
+use zksync_node_api_server::web3::{
+    backend_jsonrpsee::ComputeUnitLimiter, state::InternalApiConfig, ApiBuilder, ApiServer,
+    Namespace,
+};
 
 use crate::{
     implementations::resources::{
         circuit_breakers::CircuitBreakersResource,
         healthcheck::AppHealthCheckResource,
         pools::{PoolResource, ReplicaPool},
+        state_keeper::MempoolResource,
         sync_state::SyncStateResource,
         web3_api::{MempoolCacheResource, TreeApiClientResource, TxSenderResource},
     },
@@ -28,6 +32,8 @@ pub struct Web3ServerOptionalConfig {
     pub batch_request_size_limit: Option<usize>,
     pub response_body_size_limit: Option<MaxResponseSize>,
     pub websocket_requests_per_minute_limit: Option<NonZeroU32>,
+    // Should be shared among HTTP and WS servers so that clients have a single budget.
+    pub compute_unit_limiter: Option<ComputeUnitLimiter>,
     pub with_extended_tracing: bool,
     // Used by circuit breaker.
     pub replication_lag_limit: Option<Duration>,
@@ -58,6 +64,9 @@ impl Web3ServerOptionalConfig {
             api_builder = api_builder
                 .with_websocket_requests_per_minute_limit(websocket_requests_per_minute_limit);
         }
+        if let Some(compute_unit_limiter) = self.compute_unit_limiter {
+            api_builder = api_builder.with_compute_unit_limiter(compute_unit_limiter);
+        }
         if let Some(polling_interval) = self.polling_interval {
             api_builder = api_builder.with_polling_interval(polling_interval);
         }
@@ -82,6 +91,7 @@ enum Transport {
 /// - `SyncStateResource` (optional)
 /// - `TreeApiClientResource` (optional)
 /// - `MempoolCacheResource`
+/// - `MempoolResource` (optional)
 /// - `CircuitBreakersResource` (adds a circuit breaker)
 /// - `AppHealthCheckResource` (adds a health check)
 ///
@@ -105,6 +115,7 @@ pub struct Input {
     pub sync_state: Option<SyncStateResource>,
     pub tree_api_client: Option<TreeApiClientResource>,
     pub mempool_cache: MempoolCacheResource,
+    pub mempool: Option<MempoolResource>,
     #[context(default)]
     pub circuit_breakers: CircuitBreakersResource,
     #[context(default)]
@@ -188,6 +199,9 @@ impl WiringLayer for Web3ServerLayer {
                 api_builder = api_builder.ws(self.port);
             }
         }
+        if let Some(MempoolResource(mempool)) = input.mempool {
+            api_builder = api_builder.with_mempool(mempool);
+        }
         if let Some(sync_state) = sync_state {
             api_builder = api_builder.with_sync_state(sync_state);
         }
@@ -1,3 +1,4 @@
+use zksync_config::configs::chain::MempoolConfig;
 use zksync_node_api_server::tx_sender::master_pool_sink::MasterPoolSink;
 
 use crate::{
@@ -10,7 +11,18 @@ use crate::{
 };
 
 /// Wiring layer for [`MasterPoolSink`], [`TxSink`](zksync_node_api_server::tx_sender::tx_sink::TxSink) implementation.
-pub struct MasterPoolSinkLayer;
+#[derive(Debug, Default)]
+pub struct MasterPoolSinkLayer {
+    replacement_fee_bump_percent: u64,
+}
+
+impl MasterPoolSinkLayer {
+    pub fn new(mempool_config: &MempoolConfig) -> Self {
+        Self {
+            replacement_fee_bump_percent: mempool_config.replacement_fee_bump_percent,
+        }
+    }
+}
 
 #[derive(Debug, FromContext)]
 #[context(crate = crate)]
@@ -35,8 +47,10 @@ impl WiringLayer for MasterPoolSinkLayer {
 
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
         let pool = input.master_pool.get().await?;
+        let tx_sink = MasterPoolSink::new(pool)
+            .with_replacement_fee_bump_percent(self.replacement_fee_bump_percent);
         Ok(Output {
-            tx_sink: MasterPoolSink::new(pool).into(),
+            tx_sink: tx_sink.into(),
         })
     }
 }
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use zksync_state_keeper::{
-    seal_criteria::ConditionalSealer, BatchExecutor, OutputHandler, StateKeeperIO,
+    seal_criteria::ConditionalSealer, BatchExecutor, MempoolGuard, OutputHandler, StateKeeperIO,
 };
 
 use crate::resource::{Resource, Unique};
@@ -75,3 +75,19 @@ where
         Self(Arc::new(sealer))
     }
 }
+
+/// A resource that provides access to the state keeper mempool (only available on the main node).
+#[derive(Debug, Clone)]
+pub struct MempoolResource(pub MempoolGuard);
+
+impl Resource for MempoolResource {
+    fn name() -> String {
+        "state_keeper/mempool".into()
+    }
+}
+
+impl From<MempoolGuard> for MempoolResource {
+    fn from(mempool: MempoolGuard) -> Self {
+        Self(mempool)
+    }
+}
@@ -76,6 +76,16 @@ impl SyncState {
         self.0.borrow().is_synced().0
     }
 
+    /// Returns a snapshot of the current sync progress.
+    pub fn progress(&self) -> SyncProgress {
+        SyncProgress::from(&*self.0.borrow())
+    }
+
+    /// Subscribes to sync progress updates.
+    pub fn subscribe(&self) -> SyncProgressReceiver {
+        SyncProgressReceiver(self.0.subscribe())
+    }
+
     pub async fn run_updater(
         self,
         connection_pool: ConnectionPool<Core>,
@@ -131,6 +141,43 @@ impl StateKeeperOutputHandler for SyncState {
     }
 }
 
+/// Snapshot of the syncing process returned by [`SyncState::progress()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncProgress {
+    /// Latest L2 block sealed locally.
+    pub local_block: L2BlockNumber,
+    /// Latest L2 block known to be available on the main node.
+    pub main_node_block: L2BlockNumber,
+    pub is_synced: bool,
+}
+
+impl From<&SyncStateInner> for SyncProgress {
+    fn from(inner: &SyncStateInner) -> Self {
+        Self {
+            local_block: inner.local_block.unwrap_or_default(),
+            main_node_block: inner.main_node_block.unwrap_or_default(),
+            is_synced: inner.is_synced().0,
+        }
+    }
+}
+
+/// Receiver of [`SyncState`] updates returned by [`SyncState::subscribe()`].
+#[derive(Debug, Clone)]
+pub struct SyncProgressReceiver(watch::Receiver<SyncStateInner>);
+
+impl SyncProgressReceiver {
+    /// Returns the current sync progress and marks it as seen.
+    pub fn progress(&mut self) -> SyncProgress {
+        SyncProgress::from(&*self.0.borrow_and_update())
+    }
+
+    /// Waits until the sync progress changes since the last [`Self::progress()`] call.
+    /// Returns `false` if the sync state is dropped, meaning that no more updates will arrive.
+    pub async fn changed(&mut self) -> bool {
+        self.0.changed().await.is_ok()
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub(crate) struct SyncStateInner {
     pub(crate) main_node_block: Option<L2BlockNumber>,
@@ -263,6 +310,35 @@ mod tests {
         assert!(!sync_state.is_synced());
     }
 
+    #[tokio::test]
+    async fn sync_progress_updates() {
+        let sync_state = SyncState::default();
+        let mut receiver = sync_state.subscribe();
+        let progress = receiver.progress();
+        assert_eq!(progress.local_block, L2BlockNumber(0));
+        assert!(!progress.is_synced);
+
+        sync_state.set_local_block(L2BlockNumber(5));
+        sync_state.set_main_node_block(L2BlockNumber(100));
+        assert!(receiver.changed().await);
+        assert_eq!(
+            receiver.progress(),
+            SyncProgress {
+                local_block: L2BlockNumber(5),
+                main_node_block: L2BlockNumber(100),
+                is_synced: false,
+            }
+        );
+
+        sync_state.set_local_block(L2BlockNumber(95));
+        assert!(receiver.changed().await);
+        assert!(receiver.progress().is_synced);
+        assert_eq!(sync_state.progress(), receiver.progress());
+
+        drop(sync_state);
+        assert!(!receiver.changed().await);
+    }
+
     #[test]
     fn test_sync_state_doesnt_panic_on_local_block() {
         let sync_state = SyncState::default();
@@ -0,0 +1,67 @@
+//! Mapping of object keys to L1 batch numbers.
+
+use zksync_object_store::Bucket;
+use zksync_types::L1BatchNumber;
+
+/// Returns key prefixes of objects in the `bucket` that are keyed by an L1 batch number, or `None` if the bucket
+/// is not supported by the pruner. The batch number must immediately follow the prefix.
+pub(crate) fn l1_batch_key_prefixes(bucket: Bucket) -> Option<&'static [&'static str]> {
+    Some(match bucket {
+        // See `StoredObject` implementations in `zksync_prover_interface`.
+        Bucket::WitnessInput => &["merkel_tree_paths_", "vm_run_data_", "witness_inputs_"],
+        Bucket::ProofsFri => &["l1_batch_proof_"],
+        Bucket::ProofsTee => &["l1_batch_tee_proof_"],
+        Bucket::TeeVerifierInput => &["tee_verifier_input_for_l1_batch_"],
+        // See `StoredObject` implementation in `zksync_default_da_clients`.
+        Bucket::DataAvailability => &["l1_batch_"],
+        _ => return None,
+    })
+}
+
+/// Parses the L1 batch number from the key of an object with the specified prefix.
+pub(crate) fn parse_l1_batch_number(key: &str, prefix: &str) -> Option<L1BatchNumber> {
+    let rest = key.strip_prefix(prefix)?;
+    let digits_len = rest.find(|ch: char| !ch.is_ascii_digit())?;
+    let (digits, suffix) = rest.split_at(digits_len);
+    if !suffix.starts_with(['_', '.']) {
+        return None;
+    }
+    digits.parse().ok().map(L1BatchNumber)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_l1_batch_numbers() {
+        let valid_keys = [
+            ("witness_inputs_42.bin", "witness_inputs_"),
+            ("vm_run_data_42.bin", "vm_run_data_"),
+            ("l1_batch_proof_42_0_24_0.bin", "l1_batch_proof_"),
+            ("l1_batch_42_pubdata.gzip", "l1_batch_"),
+            (
+                "tee_verifier_input_for_l1_batch_42.bin",
+                "tee_verifier_input_for_l1_batch_",
+            ),
+        ];
+        for (key, prefix) in valid_keys {
+            assert_eq!(
+                parse_l1_batch_number(key, prefix),
+                Some(L1BatchNumber(42)),
+                "{key}"
+            );
+        }
+
+        let invalid_keys = [
+            ("witness_inputs_.bin", "witness_inputs_"),
+            ("witness_inputs_42", "witness_inputs_"),
+            ("witness_inputs_42x.bin", "witness_inputs_"),
+            ("proof_42.bin", "l1_batch_proof_"),
+            ("l1_batch_99999999999_pubdata.gzip", "l1_batch_"),
+        ];
+        for (key, prefix) in invalid_keys {
+            assert_eq!(parse_l1_batch_number(key, prefix), None, "{key}");
+        }
+    }
+}
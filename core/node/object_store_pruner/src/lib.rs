@@ -0,0 +1,315 @@
+//! Object store pruning component removing stale objects from object store buckets according to retention policies.
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context as _;
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+use zksync_config::configs::object_store_pruning::{
+    ObjectRetentionPolicy, ObjectStorePruningConfig,
+};
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+use zksync_object_store::{Bucket, ObjectStore, ObjectStoreError};
+use zksync_types::L1BatchNumber;
+
+use self::{
+    keys::{l1_batch_key_prefixes, parse_l1_batch_number},
+    metrics::{BucketLabels, PruningMode, METRICS},
+};
+
+mod keys;
+mod metrics;
+#[cfg(test)]
+mod tests;
+
+/// Retention policy for a single bucket with the bucket name parsed.
+#[derive(Debug, Clone)]
+struct BucketPolicy {
+    bucket: Bucket,
+    max_age: Option<Duration>,
+    remove_executed: bool,
+}
+
+impl BucketPolicy {
+    fn new(policy: &ObjectRetentionPolicy) -> anyhow::Result<Self> {
+        let bucket: Bucket = policy
+            .bucket
+            .parse()
+            .map_err(|err: String| anyhow::anyhow!(err))?;
+        anyhow::ensure!(
+            l1_batch_key_prefixes(bucket).is_some(),
+            "bucket `{bucket}` is not supported by object store pruner"
+        );
+        anyhow::ensure!(
+            policy.max_age_sec.is_some() || policy.remove_executed,
+            "policy for bucket `{bucket}` does not specify any removal criteria"
+        );
+        Ok(Self {
+            bucket,
+            max_age: policy.max_age(),
+            remove_executed: policy.remove_executed,
+        })
+    }
+}
+
+/// Statistics for a single pruning iteration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObjectStorePruningStats {
+    /// Number of removed objects (or objects eligible for removal in the dry-run mode).
+    pub removed_objects: u64,
+    /// Number of reclaimed bytes (or reclaimable bytes in the dry-run mode).
+    pub reclaimed_bytes: u64,
+}
+
+impl ObjectStorePruningStats {
+    fn merge(&mut self, other: Self) {
+        self.removed_objects += other.removed_objects;
+        self.reclaimed_bytes += other.reclaimed_bytes;
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ObjectStorePrunerHealth {
+    dry_run: bool,
+    last_prunable_l1_batches: HashMap<String, L1BatchNumber>,
+    last_iteration_stats: ObjectStorePruningStats,
+}
+
+/// Object store pruning component.
+///
+/// For each bucket with a configured retention policy, the pruner lists objects keyed by L1 batch numbers and removes
+/// objects for L1 batches that are older than the configured age, or are executed on L1 (depending on the policy).
+/// Objects not keyed by an L1 batch number are never removed.
+#[derive(Debug)]
+pub struct ObjectStorePruner {
+    policies: Vec<BucketPolicy>,
+    interval: Duration,
+    dry_run: bool,
+    pool: ConnectionPool<Core>,
+    object_store: Arc<dyn ObjectStore>,
+    health_updater: HealthUpdater,
+}
+
+impl ObjectStorePruner {
+    /// Creates a new pruner.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config contains a zero pruning interval, an unknown or unsupported bucket,
+    /// or a policy without removal criteria.
+    pub fn new(
+        config: &ObjectStorePruningConfig,
+        pool: ConnectionPool<Core>,
+        object_store: Arc<dyn ObjectStore>,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            config.interval_sec > 0,
+            "pruning interval (`interval_sec`) must be positive"
+        );
+        let policies = config
+            .policies
+            .iter()
+            .enumerate()
+            .map(|(i, policy)| BucketPolicy::new(policy).with_context(|| format!("policies[{i}]")))
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self {
+            policies,
+            interval: config.interval(),
+            dry_run: config.dry_run,
+            pool,
+            object_store,
+            health_updater: ReactiveHealthCheck::new("object_store_pruner").1,
+        })
+    }
+
+    pub fn health_check(&self) -> ReactiveHealthCheck {
+        self.health_updater.subscribe()
+    }
+
+    /// Returns the last L1 batch with objects eligible for removal according to the policy.
+    async fn last_prunable_l1_batch(
+        &self,
+        policy: &BucketPolicy,
+    ) -> anyhow::Result<Option<L1BatchNumber>> {
+        let mut storage = self.pool.connection_tagged("object_store_pruner").await?;
+        let last_executed_l1_batch = if policy.remove_executed {
+            storage
+                .blocks_dal()
+                .get_number_of_last_l1_batch_executed_on_eth()
+                .await?
+        } else {
+            None
+        };
+
+        let last_old_l1_batch = if let Some(max_age) = policy.max_age {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .context("invalid system time")?;
+            let cutoff_timestamp = now.saturating_sub(max_age).as_secs();
+            storage
+                .blocks_dal()
+                .get_last_l1_batch_number_older_than(cutoff_timestamp)
+                .await?
+        } else {
+            None
+        };
+        Ok(last_executed_l1_batch.max(last_old_l1_batch))
+    }
+
+    async fn prune_bucket(
+        &self,
+        policy: &BucketPolicy,
+        stop_receiver: &watch::Receiver<bool>,
+    ) -> anyhow::Result<(Option<L1BatchNumber>, ObjectStorePruningStats)> {
+        let bucket = policy.bucket;
+        let latency = METRICS.bucket_pruning_duration[&bucket.as_str()].start();
+        let Some(last_prunable_l1_batch) = self.last_prunable_l1_batch(policy).await? else {
+            tracing::debug!("No L1 batches are eligible for pruning in bucket `{bucket}`");
+            return Ok((None, ObjectStorePruningStats::default()));
+        };
+        METRICS.last_prunable_l1_batch[&bucket.as_str()].set(last_prunable_l1_batch.0.into());
+
+        let labels = BucketLabels {
+            bucket: bucket.as_str(),
+            mode: if self.dry_run {
+                PruningMode::DryRun
+            } else {
+                PruningMode::Removal
+            },
+        };
+        let mut stats = ObjectStorePruningStats::default();
+        // `unwrap()` is safe: policies are checked for supported buckets on creation
+        for &prefix in l1_batch_key_prefixes(bucket).unwrap() {
+            let objects = self
+                .object_store
+                .list_raw(bucket, prefix)
+                .await
+                .with_context(|| format!("failed listing objects in bucket `{bucket}`"))?;
+
+            for object in objects {
+                if *stop_receiver.borrow() {
+                    tracing::info!("Stop signal received, interrupting pruning bucket `{bucket}`");
+                    return Ok((Some(last_prunable_l1_batch), stats));
+                }
+
+                let Some(l1_batch) = parse_l1_batch_number(&object.key, prefix) else {
+                    continue;
+                };
+                if l1_batch > last_prunable_l1_batch {
+                    continue;
+                }
+
+                if self.dry_run {
+                    tracing::debug!(
+                        "Object `{}` ({} bytes) in bucket `{bucket}` is eligible for removal",
+                        object.key,
+                        object.size
+                    );
+                } else {
+                    match self.object_store.remove_raw(bucket, &object.key).await {
+                        Ok(()) => {}
+                        // The object may have been removed concurrently, e.g. by the prover archiver
+                        Err(ObjectStoreError::KeyNotFound(_)) => continue,
+                        Err(err) => {
+                            return Err(anyhow::Error::from(err).context(format!(
+                                "failed removing object `{}` from bucket `{bucket}`",
+                                object.key
+                            )));
+                        }
+                    }
+                    tracing::debug!(
+                        "Removed object `{}` ({} bytes) from bucket `{bucket}`",
+                        object.key,
+                        object.size
+                    );
+                }
+                METRICS.removed_objects[&labels].inc();
+                METRICS.reclaimed_bytes[&labels].inc_by(object.size);
+                stats.merge(ObjectStorePruningStats {
+                    removed_objects: 1,
+                    reclaimed_bytes: object.size,
+                });
+            }
+        }
+
+        let latency = latency.observe();
+        tracing::info!(
+            "Pruned bucket `{bucket}` up to L1 batch #{last_prunable_l1_batch} (dry run: {}): {stats:?}, took {latency:?}",
+            self.dry_run
+        );
+        Ok((Some(last_prunable_l1_batch), stats))
+    }
+
+    /// Runs a single pruning iteration for all configured buckets. This can be used to run the pruner as
+    /// a one-off job (e.g., on a schedule) instead of a long-running component.
+    ///
+    /// # Errors
+    ///
+    /// Propagates DB and object store errors.
+    pub async fn run_once(
+        &self,
+        stop_receiver: &watch::Receiver<bool>,
+    ) -> anyhow::Result<ObjectStorePruningStats> {
+        let mut stats = ObjectStorePruningStats::default();
+        let mut last_prunable_l1_batches = HashMap::new();
+        for policy in &self.policies {
+            if *stop_receiver.borrow() {
+                break;
+            }
+            let (last_prunable_l1_batch, bucket_stats) = self
+                .prune_bucket(policy, stop_receiver)
+                .await
+                .with_context(|| format!("failed pruning bucket `{}`", policy.bucket))?;
+            if let Some(l1_batch) = last_prunable_l1_batch {
+                last_prunable_l1_batches.insert(policy.bucket.to_string(), l1_batch);
+            }
+            stats.merge(bucket_stats);
+        }
+
+        let health = ObjectStorePrunerHealth {
+            dry_run: self.dry_run,
+            last_prunable_l1_batches,
+            last_iteration_stats: stats,
+        };
+        self.health_updater
+            .update(Health::from(HealthStatus::Ready).with_details(health));
+        Ok(stats)
+    }
+
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        tracing::info!(
+            "Starting object store pruning with policies {:?}, interval {:?}, dry run: {}",
+            self.policies,
+            self.interval,
+            self.dry_run
+        );
+
+        while !*stop_receiver.borrow_and_update() {
+            if let Err(err) = self.run_once(&stop_receiver).await {
+                // As this component is not mission-critical, errors are logged and the iteration is retried later
+                tracing::warn!(
+                    "Object store pruning error, retrying in {:?}, error was: {err:?}",
+                    self.interval
+                );
+                let health = Health::from(HealthStatus::Affected).with_details(serde_json::json!({
+                    "error": err.to_string(),
+                }));
+                self.health_updater.update(health);
+            }
+
+            if tokio::time::timeout(self.interval, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+        tracing::info!("Stop signal received, shutting down object store pruning");
+        Ok(())
+    }
+}
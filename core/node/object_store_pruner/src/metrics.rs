@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use vise::{
+    Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, LabeledFamily,
+    Metrics, Unit,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue)]
+#[metrics(rename_all = "snake_case")]
+pub(crate) enum PruningMode {
+    Removal,
+    DryRun,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelSet)]
+pub(crate) struct BucketLabels {
+    pub bucket: &'static str,
+    pub mode: PruningMode,
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "object_store_pruner")]
+pub(crate) struct ObjectStorePrunerMetrics {
+    /// Number of objects removed from the store (or eligible for removal in the dry-run mode).
+    pub removed_objects: Family<BucketLabels, Counter>,
+    /// Number of bytes reclaimed by removing objects (or reclaimable in the dry-run mode).
+    #[metrics(unit = Unit::Bytes)]
+    pub reclaimed_bytes: Family<BucketLabels, Counter>,
+    /// Last L1 batch with objects eligible for removal.
+    #[metrics(labels = ["bucket"])]
+    pub last_prunable_l1_batch: LabeledFamily<&'static str, Gauge<u64>>,
+    /// Latency of pruning a single bucket.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds, labels = ["bucket"])]
+    pub bucket_pruning_duration: LabeledFamily<&'static str, Histogram<Duration>>,
+}
+
+#[vise::register]
+pub(crate) static METRICS: vise::Global<ObjectStorePrunerMetrics> = vise::Global::new();
@@ -0,0 +1,190 @@
+use assert_matches::assert_matches;
+use test_log::test;
+use zksync_dal::Connection;
+use zksync_health_check::CheckHealth;
+use zksync_node_genesis::{insert_genesis_batch, GenesisParams};
+use zksync_node_test_utils::{create_l1_batch, create_l2_block};
+use zksync_object_store::MockObjectStore;
+use zksync_types::{aggregated_operations::AggregatedActionType, H256};
+
+use super::*;
+
+async fn seal_l1_batch(storage: &mut Connection<'_, Core>, number: u32, timestamp: u64) {
+    let block_header = create_l2_block(number);
+    storage
+        .blocks_dal()
+        .insert_l2_block(&block_header)
+        .await
+        .unwrap();
+
+    let mut header = create_l1_batch(number);
+    header.timestamp = timestamp;
+    storage
+        .blocks_dal()
+        .insert_mock_l1_batch(&header)
+        .await
+        .unwrap();
+    storage
+        .blocks_dal()
+        .mark_l2_blocks_as_executed_in_l1_batch(L1BatchNumber(number))
+        .await
+        .unwrap();
+}
+
+async fn mark_l1_batch_as_executed(storage: &mut Connection<'_, Core>, number: u32) {
+    storage
+        .eth_sender_dal()
+        .insert_bogus_confirmed_eth_tx(
+            L1BatchNumber(number),
+            AggregatedActionType::Execute,
+            H256::from_low_u64_be(number.into()),
+            chrono::Utc::now(),
+        )
+        .await
+        .unwrap();
+}
+
+/// Creates 3 L1 batches; batch #1 is executed, batch #2 is old, and batch #3 is new.
+async fn prepare_storage(pool: &ConnectionPool<Core>) {
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    seal_l1_batch(&mut storage, 1, now - 7_200).await;
+    seal_l1_batch(&mut storage, 2, now - 3_600).await;
+    seal_l1_batch(&mut storage, 3, now).await;
+    mark_l1_batch_as_executed(&mut storage, 1).await;
+}
+
+async fn prepare_object_store() -> Arc<dyn ObjectStore> {
+    let object_store = MockObjectStore::arc();
+    for l1_batch in 0..=3 {
+        let key = format!("witness_inputs_{l1_batch}.bin");
+        object_store
+            .put_raw(Bucket::WitnessInput, &key, vec![0; 100])
+            .await
+            .unwrap();
+        let key = format!("l1_batch_proof_{l1_batch}_0_24_0.bin");
+        object_store
+            .put_raw(Bucket::ProofsFri, &key, vec![0; 10])
+            .await
+            .unwrap();
+    }
+    // Object not keyed by an L1 batch number.
+    object_store
+        .put_raw(Bucket::ProofsFri, "proof_1.bin", vec![0; 10])
+        .await
+        .unwrap();
+    object_store
+}
+
+async fn list_keys(object_store: &dyn ObjectStore, bucket: Bucket) -> Vec<String> {
+    let objects = object_store.list_raw(bucket, "").await.unwrap();
+    let mut keys: Vec<_> = objects.into_iter().map(|object| object.key).collect();
+    keys.sort_unstable();
+    keys
+}
+
+fn create_config(dry_run: bool) -> ObjectStorePruningConfig {
+    ObjectStorePruningConfig {
+        interval_sec: 1,
+        dry_run,
+        policies: vec![
+            ObjectRetentionPolicy {
+                bucket: "witness_inputs".to_owned(),
+                max_age_sec: None,
+                remove_executed: true,
+            },
+            ObjectRetentionPolicy {
+                bucket: "proofs_fri".to_owned(),
+                max_age_sec: Some(1_800),
+                remove_executed: false,
+            },
+        ],
+    }
+}
+
+#[test(tokio::test)]
+async fn invalid_configs_are_rejected() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut config = create_config(false);
+    config.policies[0].bucket = "unknown".to_owned();
+    let err = ObjectStorePruner::new(&config, pool.clone(), MockObjectStore::arc()).unwrap_err();
+    assert!(format!("{err:#}").contains("unknown bucket"), "{err:#}");
+
+    let mut config = create_config(false);
+    config.policies[0].bucket = "storage_logs_snapshots".to_owned();
+    let err = ObjectStorePruner::new(&config, pool.clone(), MockObjectStore::arc()).unwrap_err();
+    assert!(format!("{err:#}").contains("not supported"), "{err:#}");
+
+    let mut config = create_config(false);
+    config.policies[0].remove_executed = false;
+    let err = ObjectStorePruner::new(&config, pool.clone(), MockObjectStore::arc()).unwrap_err();
+    assert!(format!("{err:#}").contains("removal criteria"), "{err:#}");
+
+    let mut config = create_config(false);
+    config.interval_sec = 0;
+    let err = ObjectStorePruner::new(&config, pool, MockObjectStore::arc()).unwrap_err();
+    assert!(format!("{err:#}").contains("interval_sec"), "{err:#}");
+}
+
+#[test(tokio::test)]
+async fn pruning_objects() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    prepare_storage(&pool).await;
+    let object_store = prepare_object_store().await;
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+
+    let pruner = ObjectStorePruner::new(&create_config(false), pool, object_store.clone()).unwrap();
+    let stats = pruner.run_once(&stop_receiver).await.unwrap();
+    // Witness inputs for batches #0 and #1, and proofs for batches #0..=#2.
+    assert_eq!(
+        stats,
+        ObjectStorePruningStats {
+            removed_objects: 5,
+            reclaimed_bytes: 230,
+        }
+    );
+
+    let witness_inputs = list_keys(object_store.as_ref(), Bucket::WitnessInput).await;
+    assert_eq!(
+        witness_inputs,
+        ["witness_inputs_2.bin", "witness_inputs_3.bin"]
+    );
+    let proofs = list_keys(object_store.as_ref(), Bucket::ProofsFri).await;
+    assert_eq!(proofs, ["l1_batch_proof_3_0_24_0.bin", "proof_1.bin"]);
+
+    let health = pruner.health_check().check_health().await;
+    assert_matches!(health.status(), HealthStatus::Ready);
+
+    // Repeated pruning should be a no-op.
+    let stats = pruner.run_once(&stop_receiver).await.unwrap();
+    assert_eq!(stats, ObjectStorePruningStats::default());
+}
+
+#[test(tokio::test)]
+async fn pruning_objects_in_dry_run_mode() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    prepare_storage(&pool).await;
+    let object_store = prepare_object_store().await;
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+
+    let pruner = ObjectStorePruner::new(&create_config(true), pool, object_store.clone()).unwrap();
+    let stats = pruner.run_once(&stop_receiver).await.unwrap();
+    assert_eq!(
+        stats,
+        ObjectStorePruningStats {
+            removed_objects: 5,
+            reclaimed_bytes: 230,
+        }
+    );
+
+    let witness_inputs = list_keys(object_store.as_ref(), Bucket::WitnessInput).await;
+    assert_eq!(witness_inputs.len(), 4);
+    let proofs = list_keys(object_store.as_ref(), Bucket::ProofsFri).await;
+    assert_eq!(proofs.len(), 5);
+}
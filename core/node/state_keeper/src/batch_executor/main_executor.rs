@@ -52,6 +52,14 @@ impl MainBatchExecutor {
                 "Running new VM with mode {fast_vm_mode:?}; this can lead to incorrect node behavior"
             );
         }
+        if matches!(fast_vm_mode, FastVmMode::New) && self.save_call_traces {
+            // The fast VM doesn't support tracers yet, so the call tracer is silently dropped by it.
+            // (In the shadow mode, traces are still produced by the old VM.)
+            tracing::warn!(
+                "Call traces are requested to be saved, but they are not produced by the new VM; \
+                 `debug_trace*` methods will return traces without internal calls for transactions executed in this mode"
+            );
+        }
         self.fast_vm_mode = fast_vm_mode;
     }
 }
@@ -59,6 +59,8 @@ impl Tester {
                 base_fee_per_gas,
                 base_fee_per_blob_gas: 1.into(), // Not relevant for the test
                 l2_pubdata_price: 0.into(),      // Not relevant for the test
+                gas_used_ratio: None,
+                priority_fee_per_gas: None,
             })
             .collect();
         let eth_client = MockSettlementLayer::builder()
@@ -79,6 +81,9 @@ impl Tester {
             internal_pubdata_pricing_multiplier: 1.0,
             max_blob_base_fee: None,
             settlement_mode: Default::default(),
+            base_fee_strategy: Default::default(),
+            priority_fee_strategy: Default::default(),
+            priority_fee_percentile: 50.0,
         };
 
         GasAdjuster::new(
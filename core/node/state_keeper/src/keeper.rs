@@ -864,6 +864,8 @@ impl ZkSyncStateKeeper {
                     cumulative_size: encoding_len,
                     writes_metrics: tx_writes_metrics,
                     gas_remaining: *gas_remaining,
+                    initiator: tx.initiator_account(),
+                    tx_type: tx.tx_format(),
                 };
                 let block_data = SealData {
                     execution_metrics: tx_data.execution_metrics
@@ -875,6 +877,8 @@ impl ZkSyncStateKeeper {
                         + updates_manager.pending_txs_encoding_size(),
                     writes_metrics: block_writes_metrics,
                     gas_remaining: *gas_remaining,
+                    initiator: tx_data.initiator,
+                    tx_type: tx_data.tx_type,
                 };
 
                 self.sealer.should_seal_l1_batch(
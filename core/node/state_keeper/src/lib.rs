@@ -65,7 +65,8 @@ pub async fn create_state_keeper(
     )
     .expect("Failed initializing main node I/O for state keeper");
 
-    let sealer = SequencerSealer::new(state_keeper_config);
+    let sealer =
+        SequencerSealer::new(state_keeper_config).expect("Failed initializing sequencer sealer");
 
     ZkSyncStateKeeper::new(
         stop_receiver,
@@ -4,7 +4,7 @@ use anyhow::Context as _;
 #[cfg(test)]
 use tokio::sync::mpsc;
 use tokio::sync::watch;
-use zksync_config::configs::chain::MempoolConfig;
+use zksync_config::configs::chain::{MempoolConfig, MempoolOrdering};
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
 use zksync_mempool::L2TxFilter;
 use zksync_multivm::utils::derive_base_fee_and_gas_per_pubdata;
@@ -38,6 +38,7 @@ pub struct MempoolFetcher {
     batch_fee_input_provider: Arc<dyn BatchFeeModelInputProvider>,
     sync_interval: Duration,
     sync_batch_size: usize,
+    order_by_fee: bool,
     stuck_tx_timeout: Option<Duration>,
     #[cfg(test)]
     transaction_hashes_sender: mpsc::UnboundedSender<Vec<H256>>,
@@ -56,6 +57,7 @@ impl MempoolFetcher {
             batch_fee_input_provider,
             sync_interval: config.sync_interval(),
             sync_batch_size: config.sync_batch_size,
+            order_by_fee: config.ordering == MempoolOrdering::FeePriority,
             stuck_tx_timeout: config.remove_stuck_txs.then(|| config.stuck_tx_timeout()),
             #[cfg(test)]
             transaction_hashes_sender: mpsc::unbounded_channel().0,
@@ -101,8 +103,10 @@ impl MempoolFetcher {
                 .sync_mempool(
                     &mempool_info.stashed_accounts,
                     &mempool_info.purged_accounts,
+                    &mempool_info.evicted_transactions,
                     l2_tx_filter.gas_per_pubdata,
                     l2_tx_filter.fee_per_gas,
+                    self.order_by_fee,
                     self.sync_batch_size,
                 )
                 .await
@@ -174,6 +178,8 @@ mod tests {
         stuck_tx_timeout: 0,
         remove_stuck_txs: false,
         delay_interval: 10,
+        ordering: MempoolOrdering::Fifo,
+        replacement_fee_bump_percent: MempoolConfig::default_replacement_fee_bump_percent(),
     };
 
     #[tokio::test]
@@ -116,9 +116,62 @@ impl ConditionalSealer for SequencerSealer {
 }
 
 impl SequencerSealer {
-    pub fn new(config: StateKeeperConfig) -> Self {
-        let sealers = Self::default_sealers(&config);
-        Self { config, sealers }
+    /// Seal criteria that cannot be disabled: without them, the sequencer may produce batches
+    /// that cannot be sealed by the bootloader or committed on L1.
+    const MANDATORY_CRITERIA: [&'static str; 2] = ["gas_for_batch_tip", "pub_data_size"];
+
+    /// Creates a sealer with the built-in seal criteria.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors from [`Self::from_registry()`].
+    pub fn new(config: StateKeeperConfig) -> anyhow::Result<Self> {
+        let registry = SealCriteriaRegistry::with_default_criteria(&config);
+        Self::from_registry(config, registry)
+    }
+
+    /// Creates a sealer with criteria from the provided `registry`. Criteria disabled in the `config`
+    /// (see [`StateKeeperConfig::disabled_seal_criteria`]) are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a disabled criterion is not registered, or if it cannot be disabled.
+    pub fn from_registry(
+        config: StateKeeperConfig,
+        registry: SealCriteriaRegistry,
+    ) -> anyhow::Result<Self> {
+        for name in &config.disabled_seal_criteria {
+            anyhow::ensure!(
+                !Self::MANDATORY_CRITERIA.contains(&name.as_str()),
+                "Seal criterion `{name}` cannot be disabled"
+            );
+            anyhow::ensure!(
+                registry.contains(name),
+                "Seal criterion `{name}` is disabled in config, but is not registered; registered criteria: {:?}",
+                registry.names().collect::<Vec<_>>()
+            );
+        }
+
+        let sealers: Vec<_> = registry
+            .criteria
+            .into_iter()
+            .filter(|sealer| {
+                let name = sealer.prom_criterion_name();
+                let is_disabled = config.is_seal_criterion_disabled(name);
+                if is_disabled {
+                    tracing::info!("Seal criterion `{name}` is disabled in config");
+                }
+                !is_disabled
+            })
+            .collect();
+        tracing::info!(
+            "Using seal criteria: {:?}",
+            sealers
+                .iter()
+                .map(|sealer| sealer.prom_criterion_name())
+                .collect::<Vec<_>>()
+        );
+        Ok(Self { config, sealers })
     }
 
     #[cfg(test)]
@@ -128,18 +181,66 @@ impl SequencerSealer {
     ) -> Self {
         Self { config, sealers }
     }
+}
+
+/// Ordered collection of [`SealCriterion`]s used to construct a [`SequencerSealer`].
+///
+/// Can be used to add chain-specific seal criteria on top of the built-in ones.
+#[derive(Debug, Default)]
+pub struct SealCriteriaRegistry {
+    criteria: Vec<Box<dyn SealCriterion>>,
+}
+
+impl SealCriteriaRegistry {
+    /// Creates a registry without any criteria.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry with the built-in seal criteria.
+    pub fn with_default_criteria(config: &StateKeeperConfig) -> Self {
+        let mut this = Self::empty();
+        this.register(criteria::SlotsCriterion);
+        this.register(criteria::GasCriterion);
+        this.register(criteria::PubDataBytesCriterion {
+            max_pubdata_per_batch: config.max_pubdata_per_batch,
+        });
+        this.register(criteria::CircuitsCriterion);
+        this.register(criteria::TxEncodingSizeCriterion);
+        this.register(criteria::GasForBatchTipCriterion);
+        this
+    }
+
+    /// Registers a new criterion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a criterion with the same name is already registered.
+    pub fn register(&mut self, criterion: impl SealCriterion) -> &mut Self {
+        self.register_boxed(Box::new(criterion))
+    }
+
+    /// Same as [`Self::register()`], but accepts a boxed criterion.
+    pub fn register_boxed(&mut self, criterion: Box<dyn SealCriterion>) -> &mut Self {
+        let name = criterion.prom_criterion_name();
+        assert!(
+            !self.contains(name),
+            "Seal criterion `{name}` is already registered"
+        );
+        self.criteria.push(criterion);
+        self
+    }
+
+    /// Checks whether a criterion with the specified name is registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.names().any(|registered| registered == name)
+    }
 
-    fn default_sealers(config: &StateKeeperConfig) -> Vec<Box<dyn SealCriterion>> {
-        vec![
-            Box::new(criteria::SlotsCriterion),
-            Box::new(criteria::GasCriterion),
-            Box::new(criteria::PubDataBytesCriterion {
-                max_pubdata_per_batch: config.max_pubdata_per_batch,
-            }),
-            Box::new(criteria::CircuitsCriterion),
-            Box::new(criteria::TxEncodingSizeCriterion),
-            Box::new(criteria::GasForBatchTipCriterion),
-        ]
+    /// Iterates over names of registered criteria in the registration order.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.criteria
+            .iter()
+            .map(|criterion| criterion.prom_criterion_name())
     }
 }
 
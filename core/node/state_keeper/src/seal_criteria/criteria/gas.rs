@@ -26,17 +26,17 @@ impl SealCriterion for GasCriterion {
         tx_data: &SealData,
         _protocol_version_id: ProtocolVersionId,
     ) -> SealResolution {
-        let tx_bound =
-            (config.max_single_tx_gas as f64 * config.reject_tx_at_gas_percentage).round() as u32;
-        let block_bound =
-            (config.max_single_tx_gas as f64 * config.close_block_at_gas_percentage).round() as u32;
+        let max_gas = super::limit_with_threshold(
+            config,
+            self.prom_criterion_name(),
+            config.max_single_tx_gas as usize,
+        ) as u32;
+        let tx_bound = (max_gas as f64 * config.reject_tx_at_gas_percentage).round() as u32;
+        let block_bound = (max_gas as f64 * config.close_block_at_gas_percentage).round() as u32;
 
         if (tx_data.gas_count + new_block_gas_count()).any_field_greater_than(tx_bound) {
             UnexecutableReason::TooMuchGas.into()
-        } else if block_data
-            .gas_count
-            .any_field_greater_than(config.max_single_tx_gas)
-        {
+        } else if block_data.gas_count.any_field_greater_than(max_gas) {
             SealResolution::ExcludeAndSeal
         } else if block_data.gas_count.any_field_greater_than(block_bound) {
             SealResolution::IncludeAndSeal
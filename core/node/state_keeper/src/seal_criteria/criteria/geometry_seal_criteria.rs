@@ -32,19 +32,24 @@ impl SealCriterion for CircuitsCriterion {
             config.max_circuits_per_batch, max_allowed_base_layer_circuits, protocol_version as u16
         );
 
+        let max_circuits_per_batch = super::limit_with_threshold(
+            config,
+            self.prom_criterion_name(),
+            config.max_circuits_per_batch,
+        );
+
         let batch_tip_circuit_overhead =
             circuit_statistics_bootloader_batch_tip_overhead(protocol_version.into());
 
         // Double checking that it is possible to seal batches
         assert!(
-            batch_tip_circuit_overhead < config.max_circuits_per_batch,
+            batch_tip_circuit_overhead < max_circuits_per_batch,
             "Invalid circuit criteria"
         );
 
-        let reject_bound = (config.max_circuits_per_batch as f64
-            * config.reject_tx_at_geometry_percentage)
+        let reject_bound = (max_circuits_per_batch as f64 * config.reject_tx_at_geometry_percentage)
             .round() as usize;
-        let include_and_seal_bound = (config.max_circuits_per_batch as f64
+        let include_and_seal_bound = (max_circuits_per_batch as f64
             * config.close_block_at_geometry_percentage)
             .round() as usize;
 
@@ -53,8 +58,7 @@ impl SealCriterion for CircuitsCriterion {
 
         if used_circuits_tx + batch_tip_circuit_overhead >= reject_bound {
             UnexecutableReason::ProofWillFail.into()
-        } else if used_circuits_batch + batch_tip_circuit_overhead >= config.max_circuits_per_batch
-        {
+        } else if used_circuits_batch + batch_tip_circuit_overhead >= max_circuits_per_batch {
             SealResolution::ExcludeAndSeal
         } else if used_circuits_batch + batch_tip_circuit_overhead >= include_and_seal_bound {
             SealResolution::IncludeAndSeal
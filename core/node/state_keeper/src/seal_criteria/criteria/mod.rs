@@ -5,8 +5,20 @@ mod pubdata_bytes;
 mod slots;
 mod tx_encoding_size;
 
+use super::StateKeeperConfig;
+
 pub(crate) use self::{
     gas::GasCriterion, gas_for_batch_tip::GasForBatchTipCriterion,
     geometry_seal_criteria::CircuitsCriterion, pubdata_bytes::PubDataBytesCriterion,
     slots::SlotsCriterion, tx_encoding_size::TxEncodingSizeCriterion,
 };
+
+/// Caps the `limit` of a built-in criterion by the threshold configured for it in
+/// [`StateKeeperConfig::seal_criteria_thresholds`]. Built-in limits are derived from protocol and L1 constraints,
+/// so a threshold can only make them stricter.
+fn limit_with_threshold(config: &StateKeeperConfig, criterion_name: &str, limit: usize) -> usize {
+    match config.seal_criterion_threshold(criterion_name) {
+        Some(threshold) => limit.min(threshold.try_into().unwrap_or(usize::MAX)),
+        None => limit,
+    }
+}
@@ -24,7 +24,12 @@ impl SealCriterion for SlotsCriterion {
             config.transaction_slots, max_txs_in_batch, protocol_version as u16
         );
 
-        if tx_count >= config.transaction_slots {
+        let transaction_slots = super::limit_with_threshold(
+            config,
+            self.prom_criterion_name(),
+            config.transaction_slots,
+        );
+        if tx_count >= transaction_slots {
             SealResolution::IncludeAndSeal
         } else {
             SealResolution::NoSeal
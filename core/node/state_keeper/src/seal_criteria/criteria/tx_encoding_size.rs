@@ -18,8 +18,11 @@ impl SealCriterion for TxEncodingSizeCriterion {
         tx_data: &SealData,
         protocol_version_id: ProtocolVersionId,
     ) -> SealResolution {
-        let bootloader_tx_encoding_space =
-            get_bootloader_encoding_space(protocol_version_id.into());
+        let bootloader_tx_encoding_space = super::limit_with_threshold(
+            config,
+            self.prom_criterion_name(),
+            get_bootloader_encoding_space(protocol_version_id.into()) as usize,
+        );
 
         let reject_bound =
             (bootloader_tx_encoding_space as f64 * config.reject_tx_at_geometry_percentage).round();
@@ -29,7 +32,7 @@ impl SealCriterion for TxEncodingSizeCriterion {
 
         if tx_data.cumulative_size > reject_bound as usize {
             UnexecutableReason::LargeEncodingSize.into()
-        } else if block_data.cumulative_size > bootloader_tx_encoding_space as usize {
+        } else if block_data.cumulative_size > bootloader_tx_encoding_space {
             SealResolution::ExcludeAndSeal
         } else if block_data.cumulative_size > include_and_seal_bound as usize {
             SealResolution::IncludeAndSeal
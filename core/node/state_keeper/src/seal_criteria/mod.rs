@@ -18,14 +18,17 @@ use zksync_multivm::{
     vm_latest::TransactionVmExt,
 };
 use zksync_types::{
-    block::BlockGasCount, utils::display_timestamp, ProtocolVersionId, Transaction,
+    block::BlockGasCount, l2::TransactionType, utils::display_timestamp, Address,
+    ProtocolVersionId, Transaction,
 };
 use zksync_utils::time::millis_since;
 
 mod conditional_sealer;
 pub(super) mod criteria;
 
-pub use self::conditional_sealer::{ConditionalSealer, NoopSealer, SequencerSealer};
+pub use self::conditional_sealer::{
+    ConditionalSealer, NoopSealer, SealCriteriaRegistry, SequencerSealer,
+};
 use super::{
     metrics::AGGREGATION_METRICS,
     updates::UpdatesManager,
@@ -156,13 +159,31 @@ impl SealResolution {
 
 /// Information about transaction or block applicable either to a single transaction, or
 /// to the entire L2 block / L1 batch.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct SealData {
     pub(super) execution_metrics: VmExecutionMetrics,
     pub(super) gas_count: BlockGasCount,
     pub(super) cumulative_size: usize,
     pub(super) writes_metrics: DeduplicatedWritesMetrics,
     pub(super) gas_remaining: u32,
+    /// Initiator of the transaction. For the L1 batch data, this is the initiator of the last executed transaction.
+    pub(super) initiator: Address,
+    /// Type of the transaction. For the L1 batch data, this is the type of the last executed transaction.
+    pub(super) tx_type: TransactionType,
+}
+
+impl Default for SealData {
+    fn default() -> Self {
+        Self {
+            execution_metrics: VmExecutionMetrics::default(),
+            gas_count: BlockGasCount::default(),
+            cumulative_size: 0,
+            writes_metrics: DeduplicatedWritesMetrics::default(),
+            gas_remaining: 0,
+            initiator: Address::zero(),
+            tx_type: TransactionType::EIP712Transaction,
+        }
+    }
 }
 
 impl SealData {
@@ -183,11 +204,55 @@ impl SealData {
             cumulative_size: transaction.bootloader_encoding_size(),
             writes_metrics,
             gas_remaining: tx_metrics.gas_remaining,
+            initiator: transaction.initiator_account(),
+            tx_type: transaction.tx_format(),
         }
     }
+
+    /// Returns VM execution metrics.
+    pub fn execution_metrics(&self) -> &VmExecutionMetrics {
+        &self.execution_metrics
+    }
+
+    /// Returns the gas count used to estimate L1 gas consumption.
+    pub fn gas_count(&self) -> BlockGasCount {
+        self.gas_count
+    }
+
+    /// Returns the cumulative bootloader encoding size of transactions.
+    pub fn cumulative_size(&self) -> usize {
+        self.cumulative_size
+    }
+
+    /// Returns metrics for deduplicated storage writes.
+    pub fn writes_metrics(&self) -> &DeduplicatedWritesMetrics {
+        &self.writes_metrics
+    }
+
+    /// Returns the amount of gas remaining in the batch.
+    pub fn gas_remaining(&self) -> u32 {
+        self.gas_remaining
+    }
+
+    /// Returns the initiator of the (last executed) transaction.
+    pub fn initiator(&self) -> Address {
+        self.initiator
+    }
+
+    /// Returns the type of the (last executed) transaction.
+    pub fn tx_type(&self) -> TransactionType {
+        self.tx_type
+    }
 }
 
-pub(super) trait SealCriterion: fmt::Debug + Send + Sync + 'static {
+/// Deterministic criterion checking whether an L1 batch should be sealed after executing a transaction.
+///
+/// Besides built-in criteria, custom criteria can be registered in [`SealCriteriaRegistry`]. Criteria may read
+/// their parameters from [`StateKeeperConfig`]; in particular, [`StateKeeperConfig::seal_criterion_threshold()`]
+/// allows configuring a threshold for a criterion by its name.
+pub trait SealCriterion: fmt::Debug + Send + Sync + 'static {
+    /// Returns the action that should be taken after executing a transaction with the specified `tx_data`.
+    /// `block_data` contains cumulative data for the L1 batch, including the transaction.
     fn should_seal(
         &self,
         config: &StateKeeperConfig,
@@ -198,6 +263,8 @@ pub(super) trait SealCriterion: fmt::Debug + Send + Sync + 'static {
         protocol_version: ProtocolVersionId,
     ) -> SealResolution;
 
+    /// Returns the name of this criterion. The name is used in metrics, logs and configuration,
+    /// so it must be unique among criteria used by the sequencer.
     // We need self here only for rust restrictions for creating an object from trait
     // https://doc.rust-lang.org/reference/items/traits.html#object-safety
     fn prom_criterion_name(&self) -> &'static str;
@@ -348,4 +415,164 @@ mod tests {
             "L2 block with payload encoding size equal or greater than max payload size should be sealed"
         );
     }
+
+    /// Custom criterion sealing the batch once it has the configured number of transactions.
+    /// L1 transactions are not limited.
+    #[derive(Debug)]
+    struct TxCountCriterion;
+
+    impl SealCriterion for TxCountCriterion {
+        fn should_seal(
+            &self,
+            config: &StateKeeperConfig,
+            _block_open_timestamp_ms: u128,
+            tx_count: usize,
+            _block_data: &SealData,
+            tx_data: &SealData,
+            _protocol_version: ProtocolVersionId,
+        ) -> SealResolution {
+            if tx_data.tx_type() == TransactionType::PriorityOpTransaction {
+                return SealResolution::NoSeal;
+            }
+            match config.seal_criterion_threshold(self.prom_criterion_name()) {
+                Some(max_tx_count) if tx_count as u64 >= max_tx_count => {
+                    SealResolution::IncludeAndSeal
+                }
+                _ => SealResolution::NoSeal,
+            }
+        }
+
+        fn prom_criterion_name(&self) -> &'static str {
+            "tx_count"
+        }
+    }
+
+    #[test]
+    fn custom_seal_criterion() {
+        let config = StateKeeperConfig {
+            seal_criteria_thresholds: [("tx_count", 3)].into_iter().collect(),
+            ..StateKeeperConfig::for_tests()
+        };
+        let mut registry = SealCriteriaRegistry::with_default_criteria(&config);
+        registry.register(TxCountCriterion);
+        assert!(registry.contains("tx_count"));
+        assert!(registry.contains("slots"));
+        let sealer = SequencerSealer::from_registry(config, registry).unwrap();
+
+        let protocol_version = ProtocolVersionId::latest();
+        let data = SealData {
+            gas_remaining: u32::MAX,
+            ..SealData::default()
+        };
+        let resolution = sealer.should_seal_l1_batch(1, 0, 2, &data, &data, protocol_version);
+        assert_eq!(resolution, SealResolution::NoSeal);
+        let resolution = sealer.should_seal_l1_batch(1, 0, 3, &data, &data, protocol_version);
+        assert_eq!(resolution, SealResolution::IncludeAndSeal);
+
+        let l1_tx_data = SealData {
+            tx_type: TransactionType::PriorityOpTransaction,
+            ..data
+        };
+        let resolution =
+            sealer.should_seal_l1_batch(1, 0, 3, &l1_tx_data, &l1_tx_data, protocol_version);
+        assert_eq!(resolution, SealResolution::NoSeal);
+    }
+
+    #[test]
+    fn seal_data_for_transaction() {
+        let tx = create_transaction(10, 100);
+        let data = SealData::for_transaction(
+            &tx,
+            &TransactionExecutionMetrics::default(),
+            ProtocolVersionId::latest(),
+        );
+        assert_eq!(data.initiator(), tx.initiator_account());
+        assert_eq!(data.tx_type(), tx.tx_format());
+    }
+
+    #[test]
+    fn thresholds_for_built_in_seal_criteria() {
+        let protocol_version = ProtocolVersionId::latest();
+        let data = SealData {
+            gas_remaining: u32::MAX,
+            ..SealData::default()
+        };
+
+        let sealer = SequencerSealer::new(StateKeeperConfig::for_tests()).unwrap();
+        let resolution = sealer.should_seal_l1_batch(1, 0, 2, &data, &data, protocol_version);
+        assert_eq!(resolution, SealResolution::NoSeal);
+
+        let config = StateKeeperConfig {
+            seal_criteria_thresholds: [("slots", 2)].into_iter().collect(),
+            ..StateKeeperConfig::for_tests()
+        };
+        let sealer = SequencerSealer::new(config).unwrap();
+        let resolution = sealer.should_seal_l1_batch(1, 0, 2, &data, &data, protocol_version);
+        assert_eq!(resolution, SealResolution::IncludeAndSeal);
+
+        // Thresholds cannot relax built-in limits.
+        let config = StateKeeperConfig {
+            seal_criteria_thresholds: [("slots", u64::MAX)].into_iter().collect(),
+            ..StateKeeperConfig::for_tests()
+        };
+        let transaction_slots = config.transaction_slots;
+        let sealer = SequencerSealer::new(config).unwrap();
+        let resolution =
+            sealer.should_seal_l1_batch(1, 0, transaction_slots, &data, &data, protocol_version);
+        assert_eq!(resolution, SealResolution::IncludeAndSeal);
+    }
+
+    #[test]
+    #[should_panic(expected = "already registered")]
+    fn registering_duplicate_seal_criterion() {
+        let mut registry = SealCriteriaRegistry::empty();
+        registry
+            .register(TxCountCriterion)
+            .register(TxCountCriterion);
+    }
+
+    #[test]
+    fn disabling_seal_criteria() {
+        let protocol_version = ProtocolVersionId::latest();
+        // Transaction is too large to fit into the bootloader memory
+        let data = SealData {
+            cumulative_size: usize::MAX,
+            gas_remaining: u32::MAX,
+            ..SealData::default()
+        };
+
+        let sealer = SequencerSealer::new(StateKeeperConfig::for_tests()).unwrap();
+        let reason = sealer.find_unexecutable_reason(&data, protocol_version);
+        assert_eq!(reason, Some("tx_encoding_size"));
+
+        let config = StateKeeperConfig {
+            disabled_seal_criteria: vec!["tx_encoding_size".to_owned()],
+            ..StateKeeperConfig::for_tests()
+        };
+        let sealer = SequencerSealer::new(config).unwrap();
+        let reason = sealer.find_unexecutable_reason(&data, protocol_version);
+        assert_eq!(reason, None);
+    }
+
+    #[test]
+    fn disabling_unknown_seal_criterion() {
+        let config = StateKeeperConfig {
+            disabled_seal_criteria: vec!["unknown".to_owned()],
+            ..StateKeeperConfig::for_tests()
+        };
+        let err = SequencerSealer::new(config).unwrap_err().to_string();
+        assert!(err.contains("not registered"), "{err}");
+    }
+
+    #[test]
+    fn disabling_mandatory_seal_criteria() {
+        for name in ["gas_for_batch_tip", "pub_data_size"] {
+            let config = StateKeeperConfig {
+                disabled_seal_criteria: vec![name.to_owned()],
+                ..StateKeeperConfig::for_tests()
+            };
+            let err = SequencerSealer::new(config).unwrap_err().to_string();
+            assert!(err.contains("cannot be disabled"), "{err}");
+        }
+    }
 }
@@ -3,6 +3,7 @@ use std::{
     sync::{Arc, Mutex},
 };
 
+use zksync_config::configs::chain::{MempoolConfig, MempoolOrdering};
 use zksync_dal::{Connection, Core, CoreDal};
 use zksync_mempool::{L2TxFilter, MempoolInfo, MempoolStore};
 use zksync_multivm::interface::{VmExecutionMetrics, VmExecutionResultAndLogs};
@@ -17,16 +18,27 @@ use super::{
 pub struct MempoolGuard(Arc<Mutex<MempoolStore>>);
 
 impl MempoolGuard {
-    pub async fn from_storage(storage_processor: &mut Connection<'_, Core>, capacity: u64) -> Self {
+    pub async fn from_storage(
+        storage_processor: &mut Connection<'_, Core>,
+        config: &MempoolConfig,
+    ) -> Self {
         let next_priority_id = storage_processor
             .transactions_dal()
             .next_priority_id()
             .await;
-        Self::new(next_priority_id, capacity)
+        Self::with_ordering(next_priority_id, config.capacity, config.ordering)
     }
 
     pub(super) fn new(next_priority_id: PriorityOpId, capacity: u64) -> Self {
-        let store = MempoolStore::new(next_priority_id, capacity);
+        Self::with_ordering(next_priority_id, capacity, MempoolOrdering::default())
+    }
+
+    fn with_ordering(
+        next_priority_id: PriorityOpId,
+        capacity: u64,
+        ordering: MempoolOrdering,
+    ) -> Self {
+        let store = MempoolStore::new(next_priority_id, capacity).with_ordering(ordering);
         Self(Arc::new(Mutex::new(store)))
     }
 
@@ -65,7 +77,6 @@ impl MempoolGuard {
             .get_mempool_info()
     }
 
-    #[cfg(test)]
     pub fn stats(&self) -> zksync_mempool::MempoolStats {
         self.0
             .lock()
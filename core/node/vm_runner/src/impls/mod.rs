@@ -3,6 +3,7 @@
 mod bwip;
 mod playground;
 mod protective_reads;
+mod replay;
 
 pub use self::{
     bwip::{
@@ -13,4 +14,8 @@ pub use self::{
         VmPlaygroundTasks,
     },
     protective_reads::{ProtectiveReadsIo, ProtectiveReadsWriter, ProtectiveReadsWriterTasks},
+    replay::{BatchReplayReport, BatchReplayer, Divergence, ReportedEvent},
 };
+
+#[cfg(test)]
+pub(crate) use self::replay::{BatchOutputs, TransactionOutcome};
@@ -0,0 +1,451 @@
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::Context as _;
+use serde::Serialize;
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_multivm::interface::{FinishedL1Batch, L2BlockEnv, VmEvent, VmExecutionResultAndLogs};
+use zksync_state::OwnedStorage;
+use zksync_state_keeper::{
+    BatchExecutor, BatchExecutorHandle, MainBatchExecutor, TxExecutionResult,
+};
+use zksync_system_constants::STATE_DIFF_HASH_KEY;
+use zksync_types::{
+    block::L2BlockExecutionData, l2_to_l1_log::SystemL2ToL1Log, vm::FastVmMode, web3::Bytes,
+    Address, L1BatchNumber, L2ChainId, H256, U256,
+};
+use zksync_utils::u256_to_h256;
+use zksync_vm_utils::storage::L1BatchParamsProvider;
+
+use crate::storage::load_batch_execute_data;
+
+/// Event emitted by the VM in the format used in [`BatchReplayReport`]s.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReportedEvent {
+    /// Address of the contract that has emitted the event.
+    pub address: Address,
+    /// Indexed topics of the event.
+    pub topics: Vec<H256>,
+    /// Non-indexed event data.
+    pub data: Bytes,
+}
+
+impl From<&VmEvent> for ReportedEvent {
+    fn from(event: &VmEvent) -> Self {
+        Self {
+            address: event.address,
+            topics: event.indexed_topics.clone(),
+            data: event.value.clone().into(),
+        }
+    }
+}
+
+/// Single divergence between the sealed L1 batch data and the data produced by replaying the batch.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Divergence {
+    /// Final value of a storage slot written to in the batch differs. `None` means that the slot was not written to.
+    StorageWrite {
+        hashed_key: H256,
+        sealed: Option<H256>,
+        replayed: Option<H256>,
+    },
+    /// Number of events emitted in the batch differs.
+    EventCount { sealed: usize, replayed: usize },
+    /// Event at the specified 0-based index in the batch differs.
+    Event {
+        index: usize,
+        sealed: ReportedEvent,
+        replayed: ReportedEvent,
+    },
+    /// Transaction was rejected by the VM during replay, although it was included into the batch.
+    TransactionRejected { tx_hash: H256, reason: String },
+    /// Execution status of a transaction differs.
+    TransactionStatus {
+        tx_hash: H256,
+        sealed_success: bool,
+        replayed_success: bool,
+    },
+    /// Amount of gas used by a transaction differs.
+    GasUsed {
+        tx_hash: H256,
+        sealed: U256,
+        replayed: U256,
+    },
+    /// State diff hash reported by the bootloader in system logs differs. `None` means that the hash is not present
+    /// in system logs (e.g., for pre-boojum batches).
+    StateDiffHash {
+        sealed: Option<H256>,
+        replayed: Option<H256>,
+    },
+}
+
+/// Machine-readable report produced by [`BatchReplayer`] for a single L1 batch.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BatchReplayReport {
+    /// Number of the replayed L1 batch.
+    pub l1_batch_number: L1BatchNumber,
+    /// VM mode used to replay the batch.
+    pub vm_mode: FastVmMode,
+    /// Number of transactions in the batch.
+    pub transaction_count: usize,
+    /// Error that has occurred during batch execution, e.g. a divergence detected by the shadow VM.
+    /// If set, the batch wasn't fully replayed, and [`Self::divergences`] are empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_error: Option<String>,
+    /// Divergences between the sealed batch data and the replayed data.
+    pub divergences: Vec<Divergence>,
+}
+
+impl BatchReplayReport {
+    /// Checks whether replaying the batch has produced any divergences or errors.
+    pub fn is_diverged(&self) -> bool {
+        self.execution_error.is_some() || !self.divergences.is_empty()
+    }
+}
+
+/// Outputs of an L1 batch relevant for comparison. Can be either loaded from Postgres for a sealed batch,
+/// or produced by replaying the batch.
+#[derive(Debug, Default)]
+pub(crate) struct BatchOutputs {
+    /// Final values of slots written to in the batch, keyed by the hashed storage key.
+    pub storage_writes: HashMap<H256, H256>,
+    /// All events emitted in the batch, including the ones emitted by the bootloader in the fictive L2 block.
+    pub events: Vec<VmEvent>,
+    /// Outcomes of transactions in the batch keyed by the transaction hash.
+    pub transactions: HashMap<H256, TransactionOutcome>,
+    pub state_diff_hash: Option<H256>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TransactionOutcome {
+    pub is_success: bool,
+    pub gas_used: Option<U256>,
+}
+
+impl BatchOutputs {
+    fn state_diff_hash(system_logs: &[SystemL2ToL1Log]) -> Option<H256> {
+        let state_diff_hash_key = u256_to_h256(STATE_DIFF_HASH_KEY.into());
+        system_logs
+            .iter()
+            .find_map(|log| (log.0.key == state_diff_hash_key).then_some(log.0.value))
+    }
+
+    fn extend_from_logs(&mut self, result: &VmExecutionResultAndLogs) {
+        let writes = result
+            .logs
+            .storage_logs
+            .iter()
+            .filter(|log| log.log.is_write())
+            .map(|log| (log.log.key.hashed_key(), log.log.value));
+        self.storage_writes.extend(writes);
+        self.events.extend(result.logs.events.iter().cloned());
+    }
+
+    fn extend_from_finished_batch(&mut self, finished_batch: &FinishedL1Batch) {
+        self.extend_from_logs(&finished_batch.block_tip_execution_result);
+        self.state_diff_hash =
+            Self::state_diff_hash(&finished_batch.final_execution_state.system_logs);
+    }
+
+    /// Compares sealed outputs (`self`) with the replayed ones. Transactions are compared in the order
+    /// specified by `tx_hashes`.
+    pub fn diff(&self, replayed: &Self, tx_hashes: &[H256]) -> Vec<Divergence> {
+        let mut divergences = vec![];
+
+        let all_keys = self
+            .storage_writes
+            .keys()
+            .chain(replayed.storage_writes.keys());
+        let mut sorted_writes: BTreeMap<_, _> = all_keys
+            .map(|key| {
+                let values = (
+                    self.storage_writes.get(key).copied(),
+                    replayed.storage_writes.get(key).copied(),
+                );
+                (*key, values)
+            })
+            .collect();
+        sorted_writes.retain(|_, (sealed, replayed)| sealed != replayed);
+        divergences.extend(
+            sorted_writes
+                .into_iter()
+                .map(
+                    |(hashed_key, (sealed, replayed))| Divergence::StorageWrite {
+                        hashed_key,
+                        sealed,
+                        replayed,
+                    },
+                ),
+        );
+
+        if self.events.len() != replayed.events.len() {
+            divergences.push(Divergence::EventCount {
+                sealed: self.events.len(),
+                replayed: replayed.events.len(),
+            });
+        }
+        for (index, (sealed, replayed)) in self.events.iter().zip(&replayed.events).enumerate() {
+            let (sealed, replayed) = (ReportedEvent::from(sealed), ReportedEvent::from(replayed));
+            if sealed != replayed {
+                divergences.push(Divergence::Event {
+                    index,
+                    sealed,
+                    replayed,
+                });
+            }
+        }
+
+        for &tx_hash in tx_hashes {
+            let (Some(sealed), Some(replayed)) = (
+                self.transactions.get(&tx_hash),
+                replayed.transactions.get(&tx_hash),
+            ) else {
+                // Rejected transactions are reported separately, and a missing receipt is not a VM divergence.
+                continue;
+            };
+            if sealed.is_success != replayed.is_success {
+                divergences.push(Divergence::TransactionStatus {
+                    tx_hash,
+                    sealed_success: sealed.is_success,
+                    replayed_success: replayed.is_success,
+                });
+            }
+            if let (Some(sealed), Some(replayed)) = (sealed.gas_used, replayed.gas_used) {
+                if sealed != replayed {
+                    divergences.push(Divergence::GasUsed {
+                        tx_hash,
+                        sealed,
+                        replayed,
+                    });
+                }
+            }
+        }
+
+        if self.state_diff_hash != replayed.state_diff_hash {
+            divergences.push(Divergence::StateDiffHash {
+                sealed: self.state_diff_hash,
+                replayed: replayed.state_diff_hash,
+            });
+        }
+        divergences
+    }
+}
+
+/// Re-executes historical L1 batches stored in Postgres with the specified VM mode and compares the execution outputs
+/// (storage writes, events, transaction statuses and gas usage, and the state diff hash) with the sealed batch data.
+///
+/// Unlike [`VmPlayground`](super::VmPlayground), the replayer doesn't require a RocksDB cache and processes batches
+/// one-by-one on demand, reading storage directly from Postgres. Thus, it can replay arbitrary batches,
+/// including ones on a node recovered from a snapshot (as long as the batch is after the snapshot).
+#[derive(Debug)]
+pub struct BatchReplayer {
+    pool: ConnectionPool<Core>,
+    vm_mode: FastVmMode,
+    chain_id: L2ChainId,
+    batch_executor: MainBatchExecutor,
+    l1_batch_params_provider: L1BatchParamsProvider,
+}
+
+impl BatchReplayer {
+    /// Creates a new replayer.
+    pub async fn new(
+        pool: ConnectionPool<Core>,
+        vm_mode: FastVmMode,
+        chain_id: L2ChainId,
+    ) -> anyhow::Result<Self> {
+        let mut conn = pool.connection_tagged("batch_replayer").await?;
+        let mut l1_batch_params_provider = L1BatchParamsProvider::new();
+        l1_batch_params_provider
+            .initialize(&mut conn)
+            .await
+            .context("Failed initializing L1 batch params provider")?;
+        drop(conn);
+
+        let mut batch_executor = MainBatchExecutor::new(false, false);
+        batch_executor.set_fast_vm_mode(vm_mode);
+        Ok(Self {
+            pool,
+            vm_mode,
+            chain_id,
+            batch_executor,
+            l1_batch_params_provider,
+        })
+    }
+
+    /// Replays the specified L1 batch and reports divergences with the sealed batch data.
+    /// Returns `None` if the batch is not sealed or cannot be replayed (e.g., it precedes the snapshot
+    /// the node was recovered from).
+    ///
+    /// # Errors
+    ///
+    /// Propagates Postgres errors. Errors during batch execution are not propagated; instead,
+    /// they are recorded in the returned report.
+    pub async fn replay_batch(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> anyhow::Result<Option<BatchReplayReport>> {
+        let mut conn = self.pool.connection_tagged("batch_replayer").await?;
+        let Some(header) = conn
+            .blocks_dal()
+            .get_l1_batch_header(l1_batch_number)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let Some(execute_data) = load_batch_execute_data(
+            &mut conn,
+            l1_batch_number,
+            &self.l1_batch_params_provider,
+            self.chain_id,
+        )
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let tx_hashes: Vec<_> = execute_data
+            .l2_blocks
+            .iter()
+            .flat_map(|block| block.txs.iter().map(|tx| tx.hash()))
+            .collect();
+        let sealed_outputs =
+            Self::load_sealed_outputs(&mut conn, l1_batch_number, &tx_hashes, &header.system_logs)
+                .await?;
+        drop(conn);
+
+        let storage_conn = self.pool.connection_tagged("batch_replayer").await?;
+        let storage = OwnedStorage::postgres(storage_conn, l1_batch_number - 1).await?;
+        let batch_executor = self.batch_executor.init_batch(
+            storage,
+            execute_data.l1_batch_env,
+            execute_data.system_env,
+        );
+
+        tracing::info!(
+            "Replaying L1 batch #{l1_batch_number} with {} transactions in {:?} VM mode",
+            tx_hashes.len(),
+            self.vm_mode
+        );
+        let mut report = BatchReplayReport {
+            l1_batch_number,
+            vm_mode: self.vm_mode,
+            transaction_count: tx_hashes.len(),
+            execution_error: None,
+            divergences: vec![],
+        };
+        match Self::execute_batch(batch_executor, execute_data.l2_blocks).await {
+            Ok((replayed_outputs, rejections)) => {
+                report.divergences = rejections;
+                report
+                    .divergences
+                    .extend(sealed_outputs.diff(&replayed_outputs, &tx_hashes));
+            }
+            Err(err) => {
+                tracing::warn!("Failed replaying L1 batch #{l1_batch_number}: {err:#}");
+                report.execution_error = Some(format!("{err:#}"));
+            }
+        }
+        Ok(Some(report))
+    }
+
+    async fn load_sealed_outputs(
+        conn: &mut Connection<'_, Core>,
+        l1_batch_number: L1BatchNumber,
+        tx_hashes: &[H256],
+        system_logs: &[SystemL2ToL1Log],
+    ) -> anyhow::Result<BatchOutputs> {
+        let storage_writes = conn
+            .storage_logs_dal()
+            .get_touched_slots_for_l1_batch(l1_batch_number)
+            .await?;
+        let events = conn
+            .events_dal()
+            .get_vm_events_for_l1_batch(l1_batch_number)
+            .await?
+            .with_context(|| format!("L1 batch #{l1_batch_number} has no L2 blocks"))?;
+        let receipts = conn
+            .transactions_web3_dal()
+            .get_transaction_receipts(tx_hashes)
+            .await?;
+        let transactions = receipts
+            .into_iter()
+            .map(|receipt| {
+                let outcome = TransactionOutcome {
+                    is_success: receipt.status == 1.into(),
+                    gas_used: receipt.gas_used,
+                };
+                (receipt.transaction_hash, outcome)
+            })
+            .collect();
+
+        Ok(BatchOutputs {
+            storage_writes,
+            events,
+            transactions,
+            state_diff_hash: BatchOutputs::state_diff_hash(system_logs),
+        })
+    }
+
+    /// Executes all transactions in the batch, collecting outputs in the same way the state keeper does
+    /// before persisting them. Transactions rejected by the VM are rolled back and returned as divergences.
+    async fn execute_batch(
+        mut batch_executor: BatchExecutorHandle,
+        l2_blocks: Vec<L2BlockExecutionData>,
+    ) -> anyhow::Result<(BatchOutputs, Vec<Divergence>)> {
+        let mut outputs = BatchOutputs::default();
+        let mut rejections = vec![];
+        for (i, l2_block) in l2_blocks.into_iter().enumerate() {
+            if i > 0 {
+                // First L2 block in every batch is already preloaded
+                let block_env = L2BlockEnv::from_l2_block_data(&l2_block);
+                batch_executor
+                    .start_next_l2_block(block_env)
+                    .await
+                    .with_context(|| {
+                        format!("failed starting L2 block with {block_env:?} in batch executor")
+                    })?;
+            }
+
+            for tx in l2_block.txs {
+                let tx_hash = tx.hash();
+                let gas_limit = tx.gas_limit();
+                let exec_result = batch_executor
+                    .execute_tx(tx)
+                    .await
+                    .with_context(|| format!("failed executing transaction {tx_hash:?}"))?;
+                let tx_result = match exec_result {
+                    TxExecutionResult::Success { tx_result, .. } => tx_result,
+                    TxExecutionResult::RejectedByVm { reason } => {
+                        rejections.push(Divergence::TransactionRejected {
+                            tx_hash,
+                            reason: reason.to_string(),
+                        });
+                        batch_executor.rollback_last_tx().await?;
+                        continue;
+                    }
+                    TxExecutionResult::BootloaderOutOfGasForTx => {
+                        rejections.push(Divergence::TransactionRejected {
+                            tx_hash,
+                            reason: "bootloader is out of gas".to_owned(),
+                        });
+                        batch_executor.rollback_last_tx().await?;
+                        continue;
+                    }
+                };
+
+                outputs.transactions.insert(
+                    tx_hash,
+                    TransactionOutcome {
+                        is_success: !tx_result.result.is_failed(),
+                        gas_used: Some(gas_limit - U256::from(tx_result.refunds.gas_refunded)),
+                    },
+                );
+                outputs.extend_from_logs(&tx_result);
+            }
+        }
+
+        let finished_batch = batch_executor.finish_batch().await?;
+        outputs.extend_from_finished_batch(&finished_batch);
+        Ok((outputs, rejections))
+    }
+}
@@ -31,6 +31,7 @@ use crate::storage::{load_batch_execute_data, StorageLoader};
 mod output_handler;
 mod playground;
 mod process;
+mod replay;
 mod storage;
 mod storage_writer;
 
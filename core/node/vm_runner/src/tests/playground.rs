@@ -10,7 +10,7 @@ use zksync_types::vm::FastVmMode;
 use super::*;
 use crate::impls::{VmPlayground, VmPlaygroundCursorOptions, VmPlaygroundTasks};
 
-async fn setup_storage(pool: &ConnectionPool<Core>, batch_count: u32) -> GenesisParams {
+pub(super) async fn setup_storage(pool: &ConnectionPool<Core>, batch_count: u32) -> GenesisParams {
     let mut conn = pool.connection().await.unwrap();
     let genesis_params = GenesisParams::mock();
     if !conn.blocks_dal().is_genesis_needed().await.unwrap() {
@@ -0,0 +1,143 @@
+use test_casing::test_casing;
+use zksync_multivm::interface::VmEvent;
+use zksync_types::vm::FastVmMode;
+
+use super::{playground::setup_storage, *};
+use crate::impls::{BatchOutputs, BatchReplayer, Divergence, TransactionOutcome};
+
+fn mock_event(value: u8) -> VmEvent {
+    VmEvent {
+        address: Address::repeat_byte(1),
+        indexed_topics: vec![H256::repeat_byte(value)],
+        value: vec![value],
+        ..VmEvent::default()
+    }
+}
+
+#[test]
+fn diffing_batch_outputs() {
+    let tx_hash = H256::repeat_byte(0xaa);
+    let sealed = BatchOutputs {
+        storage_writes: HashMap::from([
+            (H256::repeat_byte(1), H256::repeat_byte(1)),
+            (H256::repeat_byte(2), H256::repeat_byte(2)),
+        ]),
+        events: vec![mock_event(1), mock_event(2)],
+        transactions: HashMap::from([(
+            tx_hash,
+            TransactionOutcome {
+                is_success: true,
+                gas_used: Some(100.into()),
+            },
+        )]),
+        state_diff_hash: Some(H256::repeat_byte(0xff)),
+    };
+    assert_eq!(sealed.diff(&sealed, &[tx_hash]), []);
+
+    let replayed = BatchOutputs {
+        storage_writes: HashMap::from([
+            (H256::repeat_byte(1), H256::repeat_byte(1)),
+            (H256::repeat_byte(3), H256::repeat_byte(3)),
+        ]),
+        events: vec![mock_event(1), mock_event(3), mock_event(4)],
+        transactions: HashMap::from([(
+            tx_hash,
+            TransactionOutcome {
+                is_success: false,
+                gas_used: Some(120.into()),
+            },
+        )]),
+        state_diff_hash: None,
+    };
+    let divergences = sealed.diff(&replayed, &[tx_hash]);
+    assert_eq!(
+        divergences,
+        [
+            Divergence::StorageWrite {
+                hashed_key: H256::repeat_byte(2),
+                sealed: Some(H256::repeat_byte(2)),
+                replayed: None,
+            },
+            Divergence::StorageWrite {
+                hashed_key: H256::repeat_byte(3),
+                sealed: None,
+                replayed: Some(H256::repeat_byte(3)),
+            },
+            Divergence::EventCount {
+                sealed: 2,
+                replayed: 3,
+            },
+            Divergence::Event {
+                index: 1,
+                sealed: (&mock_event(2)).into(),
+                replayed: (&mock_event(3)).into(),
+            },
+            Divergence::TransactionStatus {
+                tx_hash,
+                sealed_success: true,
+                replayed_success: false,
+            },
+            Divergence::GasUsed {
+                tx_hash,
+                sealed: 100.into(),
+                replayed: 120.into(),
+            },
+            Divergence::StateDiffHash {
+                sealed: Some(H256::repeat_byte(0xff)),
+                replayed: None,
+            },
+        ]
+    );
+
+    let serialized = serde_json::to_value(&divergences[2]).unwrap();
+    assert_eq!(
+        serialized,
+        serde_json::json!({ "kind": "event_count", "sealed": 2, "replayed": 3 })
+    );
+}
+
+#[test_casing(2, [FastVmMode::Old, FastVmMode::Shadow])]
+#[tokio::test]
+async fn replaying_batches(vm_mode: FastVmMode) {
+    let pool = ConnectionPool::test_pool().await;
+    let genesis_params = setup_storage(&pool, 3).await;
+    let mut replayer =
+        BatchReplayer::new(pool.clone(), vm_mode, genesis_params.config().l2_chain_id)
+            .await
+            .unwrap();
+
+    for l1_batch_number in 1..=3 {
+        let report = replayer
+            .replay_batch(L1BatchNumber(l1_batch_number))
+            .await
+            .unwrap()
+            .expect("no replay report");
+        assert_eq!(report.l1_batch_number, L1BatchNumber(l1_batch_number));
+        assert_eq!(report.vm_mode, vm_mode);
+        assert_eq!(report.transaction_count, 1);
+        assert_eq!(report.execution_error, None);
+
+        // Mock batches contain random storage writes and don't contain system logs, so the replay must diverge.
+        assert!(report.is_diverged());
+        assert!(
+            report.divergences.iter().any(|divergence| matches!(
+                divergence,
+                Divergence::StorageWrite { replayed: None, .. }
+            )),
+            "{report:?}"
+        );
+        assert!(
+            report.divergences.iter().any(|divergence| matches!(
+                divergence,
+                Divergence::StateDiffHash {
+                    sealed: None,
+                    replayed: Some(_)
+                }
+            )),
+            "{report:?}"
+        );
+    }
+
+    let report = replayer.replay_batch(L1BatchNumber(100)).await.unwrap();
+    assert_eq!(report, None);
+}
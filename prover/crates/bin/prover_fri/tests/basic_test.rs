@@ -32,6 +32,8 @@ async fn prover_and_assert_base_layer(
         },
         max_retries: 5,
         local_mirror_path: None,
+        compression_level: None,
+        compression_excluded_buckets: vec![],
     };
     let object_store = ObjectStoreFactory::new(object_store_config)
         .create_store()
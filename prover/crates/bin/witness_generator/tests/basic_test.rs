@@ -44,6 +44,8 @@ async fn test_leaf_witness_gen() {
         },
         max_retries: 5,
         local_mirror_path: None,
+        compression_level: None,
+        compression_excluded_buckets: vec![],
     };
     let object_store = ObjectStoreFactory::new(object_store_config)
         .create_store()
@@ -74,6 +76,8 @@ async fn test_leaf_witness_gen() {
         },
         max_retries: 5,
         local_mirror_path: None,
+        compression_level: None,
+        compression_excluded_buckets: vec![],
     };
     let expected_object_store = ObjectStoreFactory::new(expected_results_object_store_config)
         .create_store()
@@ -126,6 +130,8 @@ async fn test_node_witness_gen() {
         },
         max_retries: 5,
         local_mirror_path: None,
+        compression_level: None,
+        compression_excluded_buckets: vec![],
     };
     let object_store = ObjectStoreFactory::new(object_store_config)
         .create_store()
@@ -166,6 +172,8 @@ async fn test_node_witness_gen() {
         },
         max_retries: 5,
         local_mirror_path: None,
+        compression_level: None,
+        compression_excluded_buckets: vec![],
     };
     let expected_object_store = ObjectStoreFactory::new(expected_results_object_store_config)
         .create_store()
@@ -80,6 +80,8 @@ fn prepare_configs(
         }),
         l1: Some(L1Secrets {
             l1_rpc_url: SensitiveUrl::from_str(&args.l1_rpc_url).context("l1_rpc_url")?,
+            fallback_l1_rpc_urls: vec![],
+            l1_rpc_quorum: None,
         }),
     };
     secrets.save_with_base_path(shell, en_configs_path)?;
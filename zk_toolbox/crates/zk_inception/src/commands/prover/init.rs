@@ -155,6 +155,8 @@ fn get_object_store_config(
             },
             max_retries: PROVER_STORE_MAX_RETRIES,
             local_mirror_path: None,
+            compression_level: None,
+            compression_excluded_buckets: vec![],
         }),
         Some(ProofStorageConfig::GCS(config)) => Some(ObjectStoreConfig {
             mode: ObjectStoreMode::GCSWithCredentialFile {
@@ -163,6 +165,8 @@ fn get_object_store_config(
             },
             max_retries: PROVER_STORE_MAX_RETRIES,
             local_mirror_path: None,
+            compression_level: None,
+            compression_excluded_buckets: vec![],
         }),
         Some(ProofStorageConfig::GCSCreateBucket(config)) => {
             Some(create_gcs_bucket(shell, config)?)